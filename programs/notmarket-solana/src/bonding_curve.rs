@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 use magic_curves::ExponentialBondingCurve;
 use crate::errors::LaunchpadError;
-use crate::state::{CURVE_SUPPLY, START_PRICE_USD, END_PRICE_USD, USD_SCALE};
+use crate::state::{CurveType, TradeSide, CURVE_SUPPLY, GRADUATION_USD, START_PRICE_USD, END_PRICE_USD, PRICE_SCALE, USD_SCALE, TOKEN_DECIMALS};
 
 /// Bonding curve implementation for exponential price discovery
 /// Formula: price(x) = START_PRICE * e^(k*x)
@@ -10,10 +10,16 @@ use crate::state::{CURVE_SUPPLY, START_PRICE_USD, END_PRICE_USD, USD_SCALE};
 /// Fixed parameters:
 /// - Total supply on curve: 800M tokens
 /// - Price range: $0.00000420 → $0.00006900
-/// - Exponential growth throughout the range
+/// - Exponential growth throughout the range, or a logistic S-curve
+///   (`CurveType::Sigmoid`) over the same range
 pub struct BondingCurveCalculator;
 
 impl BondingCurveCalculator {
+    /// Logistic steepness for `CurveType::Sigmoid`, over the curve supply normalized to [0, 1]
+    const SIGMOID_STEEPNESS: f64 = 10.0;
+    /// Fraction of the curve supply where the sigmoid rises fastest
+    const SIGMOID_MIDPOINT: f64 = 0.5;
+    
     /// Create exponential bonding curve using magic-curves
     /// 
     /// Formula: P(x) = base * e^(growth * x)
@@ -23,19 +29,30 @@ impl BondingCurveCalculator {
     /// Converting to exponential: P(x) = Pmin * e^(ln(r) * x/N)
     /// So: base = Pmin, growth = ln(r) / N
     fn create_curve() -> ExponentialBondingCurve {
-        let base = START_PRICE_USD as f64 / USD_SCALE as f64;
+        let (base, growth) = Self::curve_params();
+        ExponentialBondingCurve::new(base, growth)
+    }
+    
+    /// Curve parameters (base, growth) shared by all price calculations
+    /// 
+    /// base = Pmin in USD, growth = ln(Pmax/Pmin) / N
+    fn curve_params() -> (f64, f64) {
+        let base = START_PRICE_USD as f64 / PRICE_SCALE as f64;
         
         // Calculate growth rate: ln(Pmax/Pmin) / N
         let r = END_PRICE_USD as f64 / START_PRICE_USD as f64;
-        let n = (CURVE_SUPPLY / 1_000_000_000) as f64;
+        let n = Self::to_token_count(CURVE_SUPPLY);
         let growth = r.ln() / n;
         
-        ExponentialBondingCurve::new(base, growth)
+        (base, growth)
     }
     
-    /// Convert token amount with decimals to actual token count
-    fn to_token_count(amount_with_decimals: u64) -> u64 {
-        amount_with_decimals / 1_000_000_000
+    /// Convert token amount with TOKEN_DECIMALS to a (fractional) token count
+    /// 
+    /// Fractions are kept so sub-token amounts are priced fairly rather than
+    /// truncating to zero tokens.
+    fn to_token_count(amount_with_decimals: u64) -> f64 {
+        amount_with_decimals as f64 / 10f64.powi(TOKEN_DECIMALS as i32)
     }
     
     /// Calculate price for buying tokens using exponential bonding curve
@@ -48,6 +65,7 @@ impl BondingCurveCalculator {
     /// C(s,q) = (Pmin/k) * [e^(k*(s+q)) - e^(k*s)]
     /// 
     /// # Arguments
+    /// * `curve_type` - Shape of the launch's price curve
     /// * `tokens_sold` - Number of tokens already sold on curve (with 9 decimals)
     /// * `amount` - Number of tokens to buy (with 9 decimals)
     /// * `sol_price_usd` - Current SOL price in USD (scaled by 1e8)
@@ -55,6 +73,7 @@ impl BondingCurveCalculator {
     /// # Returns
     /// * `Result<u64>` - Cost in lamports
     pub fn calculate_buy_price(
+        curve_type: CurveType,
         tokens_sold: u64,
         amount: u64,
         sol_price_usd: u64,
@@ -65,47 +84,192 @@ impl BondingCurveCalculator {
             LaunchpadError::InsufficientSupply
         );
         
-        let curve = Self::create_curve();
+        let cost_usd = Self::buy_cost_usd(curve_type, tokens_sold, amount);
+        
+        // Convert USD to lamports
+        let sol_price_usd_f64 = sol_price_usd as f64 / USD_SCALE as f64;
+        let cost_sol = cost_usd / sol_price_usd_f64;
+        let lamports = (cost_sol * 1e9) as u64;
+        
+        // Ensure minimum price to avoid 0
+        let lamports = if lamports == 0 { 1 } else { lamports };
+        
+        Ok(lamports)
+    }
+    
+    /// Calculate buy cost by summing the integral over sub-intervals of at most
+    /// `chunk_size` tokens; buys no larger than `chunk_size` are priced single-shot
+    /// 
+    /// Accuracy/compute tradeoff: each chunk costs two more `exp` evaluations
+    /// (soft-float on-chain), so a 400M buy in 10M chunks is ~40x the work of
+    /// `calculate_buy_price`. With the current curve parameters the single-shot
+    /// closed form already lands within a lamport of a high-precision reference
+    /// across the whole 800M range, so trades keep using it; this variant exists
+    /// for cross-checking and for curves steep enough that one exponential over
+    /// the full range would lose precision.
+    /// 
+    /// Chunk costs are summed in USD and converted to lamports once, so the
+    /// per-chunk lamport truncation doesn't accumulate.
+    /// 
+    /// # Arguments
+    /// * `curve_type` - Shape of the launch's price curve
+    /// * `tokens_sold` - Number of tokens already sold on curve (with 9 decimals)
+    /// * `amount` - Number of tokens to buy (with 9 decimals)
+    /// * `sol_price_usd` - Current SOL price in USD (scaled by 1e8)
+    /// * `chunk_size` - Largest sub-interval priced in one step (with 9 decimals)
+    /// 
+    /// # Returns
+    /// * `Result<u64>` - Cost in lamports
+    pub fn calculate_buy_price_streamed(
+        curve_type: CurveType,
+        tokens_sold: u64,
+        amount: u64,
+        sol_price_usd: u64,
+        chunk_size: u64,
+    ) -> Result<u64> {
+        require!(amount > 0 && chunk_size > 0, LaunchpadError::InvalidAmount);
+        require!(
+            tokens_sold.checked_add(amount).ok_or(LaunchpadError::MathOverflow)? <= CURVE_SUPPLY,
+            LaunchpadError::InsufficientSupply
+        );
+        
+        if amount <= chunk_size {
+            return Self::calculate_buy_price(curve_type, tokens_sold, amount, sol_price_usd);
+        }
         
-        // Convert to actual token counts (without decimals)
+        let mut cost_usd = 0f64;
+        let mut start = tokens_sold;
+        let mut remaining = amount;
+        while remaining > 0 {
+            let step = remaining.min(chunk_size);
+            cost_usd += Self::buy_cost_usd(curve_type, start, step);
+            start += step;
+            remaining -= step;
+        }
+        
+        let sol_price_usd_f64 = sol_price_usd as f64 / USD_SCALE as f64;
+        let lamports = (cost_usd / sol_price_usd_f64 * 1e9) as u64;
+        
+        Ok(lamports.max(1))
+    }
+    
+    /// USD cost of buying `amount` tokens starting at `tokens_sold` (unvalidated)
+    fn buy_cost_usd(curve_type: CurveType, tokens_sold: u64, amount: u64) -> f64 {
+        match curve_type {
+            CurveType::Exponential => Self::exponential_cost_usd(tokens_sold, amount),
+            CurveType::Sigmoid => Self::sigmoid_cost_usd(tokens_sold, amount),
+        }
+    }
+    
+    /// USD cost of `amount` tokens from `tokens_sold` on the exponential curve
+    fn exponential_cost_usd(tokens_sold: u64, amount: u64) -> f64 {
+        let (base, growth) = Self::curve_params();
+        
+        // Convert to (fractional) token counts so sub-token buys are not truncated
         let s = Self::to_token_count(tokens_sold);
         let q = Self::to_token_count(amount);
         
-        // Get prices at both points using magic-curves
-        let price_at_s = curve.calculate_price_lossy(s);
-        let price_at_s_plus_q = curve.calculate_price_lossy(s + q);
-        
         // Calculate cost using integral formula
         // The curve uses P(x) = base * e^(growth * x)
         // Integral from s to s+q: (base/growth) * [e^(growth*(s+q)) - e^(growth*s)]
-        // But we can derive this from the prices:
+        // Factoring out the price at s:
         // price_at_s = base * e^(growth*s)
-        // price_at_s_plus_q = base * e^(growth*(s+q))
-        // cost = (base/growth) * [price_at_s_plus_q/base - price_at_s/base]
-        //      = (1/growth) * [price_at_s_plus_q - price_at_s]
+        // cost = (price_at_s/growth) * [e^(growth*q) - 1]
+        // exp_m1 keeps precision when growth*q is tiny (small buys)
+        let price_at_s = base * (growth * s).exp();
         
-        let base_price = START_PRICE_USD as f64 / USD_SCALE as f64;
-        let r = END_PRICE_USD as f64 / START_PRICE_USD as f64;
-        let n = (CURVE_SUPPLY / 1_000_000_000) as f64;
-        let growth = r.ln() / n;
-        
-        // Cost in USD = (1/growth) * [price_at_s_plus_q - price_at_s]
-        let cost_usd = (1.0 / growth) * (price_at_s_plus_q - price_at_s);
-        
-        // Convert USD to lamports
-        let sol_price_usd_f64 = sol_price_usd as f64 / 1e8;
-        let cost_sol = cost_usd / sol_price_usd_f64;
-        let lamports = (cost_sol * 1e9) as u64;
+        // Cost in USD = (price_at_s/growth) * (e^(growth*q) - 1)
+        (price_at_s / growth) * (growth * q).exp_m1()
+    }
+    
+    /// Logistic sigmoid bounds (value at u = 0, value at u = 1) for normalizing the S-curve
+    fn sigmoid_bounds() -> (f64, f64) {
+        let a = Self::SIGMOID_STEEPNESS;
+        let c = Self::SIGMOID_MIDPOINT;
+        (Self::logistic(-a * c), Self::logistic(a * (1.0 - c)))
+    }
+    
+    fn logistic(t: f64) -> f64 {
+        1.0 / (1.0 + (-t).exp())
+    }
+    
+    /// USD price per token at `tokens_sold` on the sigmoid curve
+    /// 
+    /// P(u) = Pmin + (Pmax - Pmin) * (σ(a(u - c)) - σ(-ac)) / (σ(a(1 - c)) - σ(-ac))
+    /// with u = x / N, so P(0) = Pmin and P(N) = Pmax exactly. The price climbs
+    /// fastest around the midpoint and flattens as it approaches Pmax.
+    fn sigmoid_price_usd(tokens_sold: u64) -> f64 {
+        let (low, high) = Self::sigmoid_bounds();
+        let p_min = START_PRICE_USD as f64 / PRICE_SCALE as f64;
+        let p_max = END_PRICE_USD as f64 / PRICE_SCALE as f64;
+        let u = Self::to_token_count(tokens_sold) / Self::to_token_count(CURVE_SUPPLY);
+        let sigma = Self::logistic(Self::SIGMOID_STEEPNESS * (u - Self::SIGMOID_MIDPOINT));
+        
+        p_min + (p_max - p_min) * (sigma - low) / (high - low)
+    }
+    
+    /// USD cost of `amount` tokens from `tokens_sold` on the sigmoid curve
+    /// 
+    /// Integral of P over [s, s+q]: the σ term integrates to (N/a) * ln(1 + e^(a(u - c))).
+    /// The difference of those logs is taken as ln_1p(σ(t) * expm1(Δ)), which keeps
+    /// precision for small buys the same way `exp_m1` does on the exponential curve
+    fn sigmoid_cost_usd(tokens_sold: u64, amount: u64) -> f64 {
+        let (low, high) = Self::sigmoid_bounds();
+        let a = Self::SIGMOID_STEEPNESS;
+        let c = Self::SIGMOID_MIDPOINT;
+        let p_min = START_PRICE_USD as f64 / PRICE_SCALE as f64;
+        let p_max = END_PRICE_USD as f64 / PRICE_SCALE as f64;
+        let n = Self::to_token_count(CURVE_SUPPLY);
+        let s = Self::to_token_count(tokens_sold);
+        let q = Self::to_token_count(amount);
         
-        // Ensure minimum price to avoid 0
-        let lamports = if lamports == 0 { 1 } else { lamports };
+        let sigma_integral = (n / a)
+            * (Self::logistic(a * (s / n - c)) * (a * q / n).exp_m1()).ln_1p();
         
-        Ok(lamports)
+        p_min * q + (p_max - p_min) * (sigma_integral - low * q) / (high - low)
+    }
+    
+    /// USD price per token at `tokens_sold` (fractional tokens kept)
+    fn spot_price_usd(curve_type: CurveType, tokens_sold: u64) -> f64 {
+        match curve_type {
+            CurveType::Exponential => {
+                let (base, growth) = Self::curve_params();
+                base * (growth * Self::to_token_count(tokens_sold)).exp()
+            }
+            CurveType::Sigmoid => Self::sigmoid_price_usd(tokens_sold),
+        }
+    }
+    
+    /// Calculate the SOL the curve will hold at graduation (cost of the full curve supply)
+    /// 
+    /// Moves with SOL price: the USD raised is fixed, so a cheaper SOL means more lamports.
+    /// 
+    /// # Arguments
+    /// * `curve_type` - Shape of the launch's price curve
+    /// * `curve_supply` - Tokens sellable on the launch's curve (CURVE_SUPPLY less any creator allocation)
+    /// * `sol_price_usd` - Current SOL price in USD (scaled by 1e8)
+    /// 
+    /// # Returns
+    /// * `Result<u64>` - Expected SOL reserve at graduation in lamports
+    pub fn calculate_expected_graduation_sol(curve_type: CurveType, curve_supply: u64, sol_price_usd: u64) -> Result<u64> {
+        Self::calculate_buy_price(curve_type, 0, curve_supply, sol_price_usd)
+    }
+    
+    /// Whether selling out `curve_supply` tokens raises at least GRADUATION_USD
+    /// 
+    /// The USD cost of the curve is independent of the SOL price, so this is a fixed
+    /// property of the curve shape and supply. A creator allocation shrinks the
+    /// supply from the top, where tokens are most expensive, so too large a one
+    /// leaves a curve that sells out without ever reaching the USD threshold
+    pub fn can_raise_graduation_usd(curve_type: CurveType, curve_supply: u64) -> bool {
+        curve_supply <= CURVE_SUPPLY
+            && Self::buy_cost_usd(curve_type, 0, curve_supply) >= GRADUATION_USD as f64
     }
     
     /// Calculate proceeds from selling tokens back to the bonding curve
     /// 
     /// # Arguments
+    /// * `curve_type` - Shape of the launch's price curve
     /// * `tokens_sold` - Number of tokens currently sold on curve
     /// * `amount` - Number of tokens to sell back
     /// * `sol_price_usd` - Current SOL price in USD (scaled by 1e8)
@@ -113,45 +277,91 @@ impl BondingCurveCalculator {
     /// # Returns
     /// * `Result<u64>` - Proceeds in lamports
     pub fn calculate_sell_price(
+        curve_type: CurveType,
         tokens_sold: u64,
         amount: u64,
         sol_price_usd: u64,
     ) -> Result<u64> {
         require!(amount > 0, LaunchpadError::InvalidAmount);
-        require!(tokens_sold >= amount, LaunchpadError::InsufficientSupply);
+        require!(tokens_sold >= amount, LaunchpadError::SellExceedsTokensSold);
         
         // For selling, calculate from (tokens_sold - amount) to tokens_sold
         let new_tokens_sold = tokens_sold
             .checked_sub(amount)
             .ok_or(LaunchpadError::MathOverflow)?;
         
-        Self::calculate_buy_price(new_tokens_sold, amount, sol_price_usd)
+        Self::calculate_buy_price(curve_type, new_tokens_sold, amount, sol_price_usd)
+    }
+    
+    /// Clamp sell proceeds to what the SOL reserve actually backs
+    /// 
+    /// The curve values the sold supply at `calculate_sell_price(s, s)`. When the
+    /// reserve holds less than that (e.g. the SOL price fell after the buys), every
+    /// sell is scaled by `sol_reserve / curve_value`. The ratio is preserved after
+    /// each sell, so remaining holders stay backed and the reserve never runs dry.
+    /// A fully backed curve pays the plain curve price.
+    /// 
+    /// # Arguments
+    /// * `curve_type` - Shape of the launch's price curve
+    /// * `tokens_sold` - Number of tokens currently sold on curve
+    /// * `proceeds` - Curve proceeds for the sell in lamports
+    /// * `sol_reserve` - SOL reserve currently held by the curve
+    /// * `sol_price_usd` - Current SOL price in USD (scaled by 1e8)
+    /// 
+    /// # Returns
+    /// * `Result<u64>` - Proceeds the reserve can pay, never more than `proceeds`
+    pub fn apply_reserve_backing(
+        curve_type: CurveType,
+        tokens_sold: u64,
+        proceeds: u64,
+        sol_reserve: u64,
+        sol_price_usd: u64,
+    ) -> Result<u64> {
+        if tokens_sold == 0 {
+            return Ok(proceeds);
+        }
+        let curve_value = Self::calculate_sell_price(curve_type, tokens_sold, tokens_sold, sol_price_usd)?;
+        if sol_reserve >= curve_value {
+            return Ok(proceeds);
+        }
+        
+        let backed = (proceeds as u128)
+            .checked_mul(sol_reserve as u128)
+            .ok_or(LaunchpadError::MathOverflow)?
+            / (curve_value as u128);
+        
+        u64::try_from(backed).map_err(|_| LaunchpadError::MathOverflow.into())
     }
     
     /// Calculate the current spot price at a given supply level
     /// Formula: price(tokens_sold) = START_PRICE * e^(k * tokens_sold)
     /// 
     /// # Arguments
+    /// * `curve_type` - Shape of the launch's price curve
     /// * `tokens_sold` - Number of tokens already sold (with 9 decimals)
     /// * `sol_price_usd` - Current SOL price in USD (scaled by 1e8)
     /// 
     /// # Returns
     /// * `Result<u64>` - Current spot price in lamports per token
-    
     pub fn get_spot_price(
+        curve_type: CurveType,
         tokens_sold: u64,
         sol_price_usd: u64,
     ) -> Result<u64> {
-        let curve = Self::create_curve();
-        
-        // Convert to actual token count
-        let tokens_sold_count = Self::to_token_count(tokens_sold);
+        // magic-curves takes whole tokens
+        let tokens_sold_count = Self::to_token_count(tokens_sold) as u64;
         
         // Get price at current supply
-        let price_usd = curve.calculate_price_lossy(tokens_sold_count);
+        let price_usd = match curve_type {
+            CurveType::Exponential => Self::create_curve().calculate_price_lossy(tokens_sold_count),
+            // magic-curves has no logistic curve; price the same whole-token supply directly
+            CurveType::Sigmoid => Self::sigmoid_price_usd(
+                tokens_sold_count.saturating_mul(10u64.pow(TOKEN_DECIMALS as u32))
+            ),
+        };
         
         // Convert USD to lamports per token
-        let sol_price_usd_f64 = sol_price_usd as f64 / 1e8;
+        let sol_price_usd_f64 = sol_price_usd as f64 / USD_SCALE as f64;
         let price_sol = price_usd / sol_price_usd_f64;
         let lamports = (price_sol * 1e9) as u64;
         
@@ -161,9 +371,65 @@ impl BondingCurveCalculator {
         Ok(lamports)
     }
     
+    /// Lightweight spot price for hot view paths
+    /// 
+    /// Evaluates `base * exp(growth * x)` inline instead of constructing an
+    /// `ExponentialBondingCurve`, which saves compute units per call.
+    /// Matches `get_spot_price` within floating point tolerance.
+    /// 
+    /// # Arguments
+    /// * `curve_type` - Shape of the launch's price curve
+    /// * `tokens_sold` - Number of tokens already sold (with 9 decimals)
+    /// * `sol_price_usd` - Current SOL price in USD (scaled by 1e8)
+    /// 
+    /// # Returns
+    /// * `Result<u64>` - Current spot price in lamports per token
+    pub fn get_spot_price_lite(
+        curve_type: CurveType,
+        tokens_sold: u64,
+        sol_price_usd: u64,
+    ) -> Result<u64> {
+        let price_usd = Self::spot_price_usd(curve_type, tokens_sold);
+        
+        // Convert USD to lamports per token
+        let sol_price_usd_f64 = sol_price_usd as f64 / USD_SCALE as f64;
+        let lamports = (price_usd / sol_price_usd_f64 * 1e9) as u64;
+        
+        // Ensure minimum price to avoid 0
+        Ok(lamports.max(1))
+    }
+    
+    /// Average fill price in lamports per whole token, comparable to spot prices
+    fn average_price_per_token(total_cost: u64, amount: u64) -> Result<u64> {
+        let average_price = (total_cost as u128)
+            .checked_mul(10u128.pow(TOKEN_DECIMALS as u32))
+            .ok_or(LaunchpadError::MathOverflow)?
+            .checked_div(amount as u128)
+            .ok_or(LaunchpadError::MathOverflow)?;
+        
+        u64::try_from(average_price).map_err(|_| LaunchpadError::MathOverflow.into())
+    }
+    
+    /// Value of `amount` tokens at the spot price, in lamports
+    /// 
+    /// Priced straight off the USD spot rather than the whole-lamport spot price, which
+    /// late in a cheap curve rounds away several percent of the true price.
+    fn spot_value_lamports(
+        curve_type: CurveType,
+        tokens_sold: u64,
+        amount: u64,
+        sol_price_usd: u64,
+    ) -> u64 {
+        let price_usd = Self::spot_price_usd(curve_type, tokens_sold);
+        let sol_price_usd_f64 = sol_price_usd as f64 / USD_SCALE as f64;
+        
+        ((price_usd * Self::to_token_count(amount) / sol_price_usd_f64 * 1e9) as u64).max(1)
+    }
+    
     /// Calculate slippage for a given trade
     /// 
     /// # Arguments
+    /// * `curve_type` - Shape of the launch's price curve
     /// * `tokens_sold` - Tokens already sold
     /// * `amount` - Trade amount
     /// * `sol_price_usd` - SOL price in USD
@@ -171,29 +437,115 @@ impl BondingCurveCalculator {
     /// # Returns
     /// * `Result<u16>` - Slippage in basis points
     pub fn calculate_slippage(
+        curve_type: CurveType,
         tokens_sold: u64,
         amount: u64,
         sol_price_usd: u64,
     ) -> Result<u16> {
-        let spot_price = Self::get_spot_price(tokens_sold, sol_price_usd)?;
-        let total_cost = Self::calculate_buy_price(tokens_sold, amount, sol_price_usd)?;
-        let average_price = total_cost
-            .checked_div(amount)
-            .ok_or(LaunchpadError::MathOverflow)?;
-        
-        if spot_price == 0 {
-            return Ok(0);
+        let total_cost = Self::calculate_buy_price(curve_type, tokens_sold, amount, sol_price_usd)?;
+        Self::realized_slippage(curve_type, TradeSide::Buy, tokens_sold, amount, total_cost, sol_price_usd)
+    }
+    
+    /// Slippage of an executed trade: its average fill against the pre-trade spot price
+    /// 
+    /// Buys fill above spot and sells below it; either way the gap is reported as a
+    /// positive share of the spot price. Fills on the favourable side report 0.
+    /// 
+    /// # Arguments
+    /// * `curve_type` - Shape of the launch's price curve
+    /// * `side` - Whether the trade bought from or sold to the curve
+    /// * `tokens_sold` - Tokens sold before the trade
+    /// * `amount` - Tokens filled
+    /// * `sol_amount` - Curve cost (buy) or proceeds (sell) in lamports, before fees
+    /// * `sol_price_usd` - SOL price in USD the trade executed at
+    /// 
+    /// # Returns
+    /// * `Result<u16>` - Slippage in basis points
+    pub fn realized_slippage(
+        curve_type: CurveType,
+        side: TradeSide,
+        tokens_sold: u64,
+        amount: u64,
+        sol_amount: u64,
+        sol_price_usd: u64,
+    ) -> Result<u16> {
+        // Compare whole-trade values so per-token rounding can't swamp small fills
+        let spot_value = Self::spot_value_lamports(curve_type, tokens_sold, amount, sol_price_usd);
+        
+        match side {
+            TradeSide::Buy => Self::slippage_bps(sol_amount, spot_value),
+            TradeSide::Sell => {
+                let shortfall = (spot_value.saturating_sub(sol_amount) as u128)
+                    .checked_mul(10000)
+                    .ok_or(LaunchpadError::MathOverflow)?
+                    / spot_value as u128;
+                // A sell can lose at most the whole spot value, so this never exceeds 10_000
+                Ok(shortfall as u16)
+            }
         }
+    }
+    
+    /// Calculate slippage for a given trade measured against the launch start price
+    /// 
+    /// Shows total appreciation of the average fill over the curve's first token,
+    /// saturating at u16::MAX basis points late in the curve.
+    /// 
+    /// # Arguments
+    /// * `curve_type` - Shape of the launch's price curve
+    /// * `tokens_sold` - Tokens already sold
+    /// * `amount` - Trade amount
+    /// * `sol_price_usd` - SOL price in USD
+    /// 
+    /// # Returns
+    /// * `Result<u16>` - Slippage in basis points
+    pub fn calculate_slippage_from_start(
+        curve_type: CurveType,
+        tokens_sold: u64,
+        amount: u64,
+        sol_price_usd: u64,
+    ) -> Result<u16> {
+        let start_price = Self::get_spot_price_lite(curve_type, 0, sol_price_usd)?;
+        let total_cost = Self::calculate_buy_price(curve_type, tokens_sold, amount, sol_price_usd)?;
+        let average_price = Self::average_price_per_token(total_cost, amount)?;
+        
+        Self::slippage_bps(average_price, start_price)
+    }
+    
+    /// Lowest acceptable proceeds when tolerating `max_slippage_bps` off `expected`
+    /// 
+    /// # Arguments
+    /// * `expected` - Quoted proceeds in lamports
+    /// * `max_slippage_bps` - Tolerance in basis points (10_000 accepts any fill)
+    /// 
+    /// # Returns
+    /// * `Result<u64>` - `expected * (10_000 - max_slippage_bps) / 10_000`, rounded down
+    pub fn min_output_after_slippage(
+        expected: u64,
+        max_slippage_bps: u16,
+    ) -> Result<u64> {
+        require!(max_slippage_bps <= 10_000, LaunchpadError::InvalidSlippage);
+        
+        let min_output = (expected as u128)
+            .checked_mul((10_000 - max_slippage_bps) as u128)
+            .ok_or(LaunchpadError::MathOverflow)?
+            .checked_div(10_000)
+            .ok_or(LaunchpadError::MathOverflow)?;
         
-        let slippage = average_price
-            .checked_sub(spot_price)
-            .unwrap_or(0)
+        Ok(min_output as u64)
+    }
+    
+    /// How far `average_price` sits above `reference_price`, in basis points
+    /// 
+    /// Saturates at u16::MAX (655.35%) rather than wrapping, so extreme slippage
+    /// is never reported as a small number.
+    fn slippage_bps(average_price: u64, reference_price: u64) -> Result<u16> {
+        let slippage = (average_price.saturating_sub(reference_price) as u128)
             .checked_mul(10000)
             .ok_or(LaunchpadError::MathOverflow)?
-            .checked_div(spot_price)
+            .checked_div(reference_price as u128)
             .ok_or(LaunchpadError::MathOverflow)?;
         
-        Ok(slippage as u16)
+        Ok(u16::try_from(slippage).unwrap_or(u16::MAX))
     }
     
     /// Calculate the total USD value raised so far
@@ -231,8 +583,8 @@ mod tests {
         println!("\n=== TOKENOMICS CONSTANTS ===");
         println!("CURVE_SUPPLY (with decimals): {}", CURVE_SUPPLY);
         println!("CURVE_SUPPLY (actual tokens): {}", CURVE_SUPPLY / 1_000_000_000);
-        println!("START_PRICE_USD (scaled): ${}", START_PRICE_USD as f64 / USD_SCALE as f64);
-        println!("END_PRICE_USD (scaled): ${}", END_PRICE_USD as f64 / USD_SCALE as f64);
+        println!("START_PRICE_USD (scaled): ${}", START_PRICE_USD as f64 / PRICE_SCALE as f64);
+        println!("END_PRICE_USD (scaled): ${}", END_PRICE_USD as f64 / PRICE_SCALE as f64);
         println!("Price ratio: {}", END_PRICE_USD as f64 / START_PRICE_USD as f64);
         
         // Calculate what k SHOULD be
@@ -251,6 +603,7 @@ mod tests {
         let amount = ONE_TOKEN; // Buy exactly 1 token
         
         let result = BondingCurveCalculator::calculate_buy_price(
+            CurveType::Exponential,
             tokens_sold,
             amount,
             SOL_PRICE_USD,
@@ -279,12 +632,43 @@ mod tests {
         );
     }
     
+    #[test]
+    fn test_first_token_price_precision() {
+        println!("\n=== FIRST TOKEN PRICE PRECISION (1e8 vs 1e12) ===");
+        // The shipped start price is exact at both scales, so the curve is unchanged
+        assert_eq!(START_PRICE_USD as f64 / PRICE_SCALE as f64, 420.0 / 1e8);
+        
+        // A start price with more significant digits than 1e8 can hold
+        let target_usd = 0.0000042137_f64;
+        let at_1e8 = (target_usd * 1e8).round() / 1e8;
+        let at_1e12 = (target_usd * PRICE_SCALE as f64).round() / PRICE_SCALE as f64;
+        
+        // Priced over the first 1M tokens so the difference survives lamport rounding
+        let first_million_lamports = |price_usd: f64| (price_usd * 1_000_000.0 / 150.0 * 1e9).round() as u64;
+        let target_lamports = first_million_lamports(target_usd);
+        let lamports_1e8 = first_million_lamports(at_1e8);
+        let lamports_1e12 = first_million_lamports(at_1e12);
+        println!(
+            "target: {} lamports, 1e8: {} lamports, 1e12: {} lamports",
+            target_lamports, lamports_1e8, lamports_1e12
+        );
+        
+        // 1e8 truncates to $0.00000421 and misprices the first tokens by ~0.08%
+        let error_1e8 = (at_1e8 - target_usd).abs() / target_usd;
+        let error_1e12 = (at_1e12 - target_usd).abs() / target_usd;
+        assert!(error_1e8 > 0.0005, "1e8 should lose precision: {}", error_1e8);
+        assert!(error_1e12 < 1e-9, "1e12 should represent the price: {}", error_1e12);
+        assert_ne!(lamports_1e8, target_lamports);
+        assert_eq!(lamports_1e12, target_lamports);
+    }
+    
     #[test]
     fn test_spot_price_at_start() {
         println!("\n=== SPOT PRICE AT START ===");
         let tokens_sold = 0;
         
         let result = BondingCurveCalculator::get_spot_price(
+            CurveType::Exponential,
             tokens_sold,
             SOL_PRICE_USD,
         );
@@ -315,6 +699,7 @@ mod tests {
         let tokens_sold = CURVE_SUPPLY; // All 800M tokens sold
         
         let result = BondingCurveCalculator::get_spot_price(
+            CurveType::Exponential,
             tokens_sold,
             SOL_PRICE_USD,
         );
@@ -347,6 +732,7 @@ mod tests {
         let amount = ONE_MILLION_TOKENS; // 1M tokens
         
         let result = BondingCurveCalculator::calculate_buy_price(
+            CurveType::Exponential,
             tokens_sold,
             amount,
             SOL_PRICE_USD,
@@ -377,6 +763,7 @@ mod tests {
         
         for (amount, label) in purchase_amounts.iter() {
             let result = BondingCurveCalculator::calculate_buy_price(
+                CurveType::Exponential,
                 tokens_sold,
                 *amount,
                 SOL_PRICE_USD,
@@ -402,6 +789,7 @@ mod tests {
         let amount = CURVE_SUPPLY; // All 800M tokens
         
         let result = BondingCurveCalculator::calculate_buy_price(
+            CurveType::Exponential,
             tokens_sold,
             amount,
             SOL_PRICE_USD,
@@ -427,18 +815,175 @@ mod tests {
         
         // Verify the curve requires a reasonable amount of SOL
         assert!(
-            sol_cost >= 80.0 && sol_cost <= 150.0,
+            (80.0..=150.0).contains(&sol_cost),
             "Should require 80-150 SOL to complete curve, got {:.2}",
             sol_cost
         );
     }
     
+    #[test]
+    fn test_sigmoid_spans_start_to_end_price() {
+        let start_usd = START_PRICE_USD as f64 / PRICE_SCALE as f64;
+        let end_usd = END_PRICE_USD as f64 / PRICE_SCALE as f64;
+        let at_start = BondingCurveCalculator::spot_price_usd(CurveType::Sigmoid, 0);
+        let at_end = BondingCurveCalculator::spot_price_usd(CurveType::Sigmoid, CURVE_SUPPLY);
+        assert!((at_start - start_usd).abs() / start_usd < 1e-9, "sigmoid starts at {}", at_start);
+        assert!((at_end - end_usd).abs() / end_usd < 1e-9, "sigmoid ends at {}", at_end);
+        
+        // Same endpoints in lamports as the exponential curve
+        for tokens_sold in [0, CURVE_SUPPLY] {
+            let sigmoid = BondingCurveCalculator::get_spot_price_lite(CurveType::Sigmoid, tokens_sold, SOL_PRICE_USD).unwrap();
+            let exponential = BondingCurveCalculator::get_spot_price_lite(CurveType::Exponential, tokens_sold, SOL_PRICE_USD).unwrap();
+            assert!(sigmoid.abs_diff(exponential) <= 1, "endpoint {}: {} vs {}", tokens_sold, sigmoid, exponential);
+        }
+        
+        // Strictly increasing across the range
+        let mut previous = 0f64;
+        for step in 0..=80 {
+            let price = BondingCurveCalculator::spot_price_usd(CurveType::Sigmoid, step * 10 * ONE_MILLION_TOKENS);
+            assert!(price > previous);
+            previous = price;
+        }
+        
+        // The closed-form integral matches a midpoint sum of the spot price
+        let steps = 10_000u64;
+        let width = CURVE_SUPPLY / steps;
+        let riemann: f64 = (0..steps)
+            .map(|i| BondingCurveCalculator::spot_price_usd(CurveType::Sigmoid, i * width + width / 2))
+            .sum::<f64>() * (width as f64 / ONE_TOKEN as f64);
+        let integral = BondingCurveCalculator::buy_cost_usd(CurveType::Sigmoid, 0, CURVE_SUPPLY);
+        println!("Sigmoid full curve: ${:.2} integral, ${:.2} midpoint sum", integral, riemann);
+        assert!((integral - riemann).abs() / riemann < 1e-6);
+        
+        // Sub-token buys are still priced at the spot
+        let tiny = BondingCurveCalculator::buy_cost_usd(CurveType::Sigmoid, 400 * ONE_MILLION_TOKENS, 1_000);
+        let spot = BondingCurveCalculator::spot_price_usd(CurveType::Sigmoid, 400 * ONE_MILLION_TOKENS);
+        assert!((tiny / 1e-6 - spot).abs() / spot < 1e-6);
+    }
+    
+    #[test]
+    fn test_last_100m_tokens_exponential_vs_sigmoid() {
+        println!("\n=== LAST 100M TOKENS: EXPONENTIAL VS SIGMOID ===");
+        let tail_start = CURVE_SUPPLY - 100 * ONE_MILLION_TOKENS;
+        let tail = 100 * ONE_MILLION_TOKENS;
+        
+        let exponential_cost = BondingCurveCalculator::calculate_buy_price(CurveType::Exponential, tail_start, tail, SOL_PRICE_USD).unwrap();
+        let sigmoid_cost = BondingCurveCalculator::calculate_buy_price(CurveType::Sigmoid, tail_start, tail, SOL_PRICE_USD).unwrap();
+        let exponential_slippage = BondingCurveCalculator::calculate_slippage(CurveType::Exponential, tail_start, tail, SOL_PRICE_USD).unwrap();
+        let sigmoid_slippage = BondingCurveCalculator::calculate_slippage(CurveType::Sigmoid, tail_start, tail, SOL_PRICE_USD).unwrap();
+        println!("Exponential: {} lamports, {} bps over spot", exponential_cost, exponential_slippage);
+        println!("Sigmoid:     {} lamports, {} bps over spot", sigmoid_cost, sigmoid_slippage);
+        
+        // The sigmoid has flattened by the tail: the price barely moves over the last
+        // 100M, so the final buyers pay close to the spot they were quoted
+        assert!(sigmoid_slippage < 200, "sigmoid tail slippage {} bps", sigmoid_slippage);
+        assert!(exponential_slippage > 1_500, "exponential tail slippage {} bps", exponential_slippage);
+        
+        // Both curves end at the same price, so flattening into it means the sigmoid
+        // reaches the top of the range sooner: the tail as a whole costs more
+        assert!(sigmoid_cost > exponential_cost);
+        
+        // The spot price at the start of the tail is higher, but the run-up to graduation is gone
+        let exponential_run_up = BondingCurveCalculator::get_spot_price_lite(CurveType::Exponential, CURVE_SUPPLY, SOL_PRICE_USD).unwrap() as f64
+            / BondingCurveCalculator::get_spot_price_lite(CurveType::Exponential, tail_start, SOL_PRICE_USD).unwrap() as f64;
+        let sigmoid_run_up = BondingCurveCalculator::get_spot_price_lite(CurveType::Sigmoid, CURVE_SUPPLY, SOL_PRICE_USD).unwrap() as f64
+            / BondingCurveCalculator::get_spot_price_lite(CurveType::Sigmoid, tail_start, SOL_PRICE_USD).unwrap() as f64;
+        println!("Spot run-up over the tail: {:.3}x exponential, {:.3}x sigmoid", exponential_run_up, sigmoid_run_up);
+        assert!(sigmoid_run_up < 1.05 && exponential_run_up > 1.3);
+    }
+    
+    #[test]
+    fn test_reserve_backing_clamps_drifted_curve() {
+        let tokens_sold = 400 * ONE_MILLION_TOKENS;
+        let curve_value = BondingCurveCalculator::calculate_sell_price(CurveType::Exponential, tokens_sold, tokens_sold, SOL_PRICE_USD).unwrap();
+        let amount = 10 * ONE_MILLION_TOKENS;
+        let proceeds = BondingCurveCalculator::calculate_sell_price(CurveType::Exponential, tokens_sold, amount, SOL_PRICE_USD).unwrap();
+        
+        // Fully backed (or over-backed) reserve pays the curve price
+        for sol_reserve in [curve_value, curve_value * 2] {
+            assert_eq!(
+                BondingCurveCalculator::apply_reserve_backing(CurveType::Exponential, tokens_sold, proceeds, sol_reserve, SOL_PRICE_USD).unwrap(),
+                proceeds
+            );
+        }
+        
+        // Reserve drifted to 60% of the curve value: proceeds scale to 60%
+        let sol_reserve = curve_value / 10 * 6;
+        let backed = BondingCurveCalculator::apply_reserve_backing(CurveType::Exponential, tokens_sold, proceeds, sol_reserve, SOL_PRICE_USD).unwrap();
+        assert!(backed < proceeds);
+        assert_eq!(backed as u128, proceeds as u128 * sol_reserve as u128 / curve_value as u128);
+        
+        // Selling everything drains exactly the reserve, never more
+        let all = BondingCurveCalculator::apply_reserve_backing(CurveType::Exponential, tokens_sold, curve_value, sol_reserve, SOL_PRICE_USD).unwrap();
+        assert_eq!(all, sol_reserve);
+        
+        // Sequential sells keep every remaining holder backed
+        let mut sold = tokens_sold;
+        let mut reserve = sol_reserve;
+        while sold > 0 {
+            let chunk = amount.min(sold);
+            let raw = BondingCurveCalculator::calculate_sell_price(CurveType::Exponential, sold, chunk, SOL_PRICE_USD).unwrap();
+            let paid = BondingCurveCalculator::apply_reserve_backing(CurveType::Exponential, sold, raw, reserve, SOL_PRICE_USD).unwrap();
+            reserve = reserve.checked_sub(paid).expect("reserve must cover every backed sell");
+            sold -= chunk;
+        }
+        println!("Reserve left after draining a 60%-backed curve: {} lamports", reserve);
+    }
+    
+    #[test]
+    fn test_expected_graduation_sol_matches_incremental_buys() {
+        println!("\n=== EXPECTED GRADUATION SOL ===");
+        let expected = BondingCurveCalculator::calculate_expected_graduation_sol(CurveType::Exponential, CURVE_SUPPLY, SOL_PRICE_USD).unwrap();
+        
+        // Walk to the top of the curve in 100 equal buys
+        let chunk = CURVE_SUPPLY / 100;
+        let mut tokens_sold = 0;
+        let mut total_cost = 0u64;
+        while tokens_sold < CURVE_SUPPLY {
+            total_cost += BondingCurveCalculator::calculate_buy_price(CurveType::Exponential, tokens_sold, chunk, SOL_PRICE_USD).unwrap();
+            tokens_sold += chunk;
+        }
+        
+        println!("Expected graduation SOL: {} lamports", expected);
+        println!("Sum of 100 incremental buys: {} lamports", total_cost);
+        
+        // Each chunk truncates to whole lamports, so allow 1 lamport per buy
+        assert!(
+            expected.abs_diff(total_cost) <= 100,
+            "Expected {} lamports, incremental buys cost {}",
+            expected,
+            total_cost
+        );
+        
+        // Half the SOL price needs twice the lamports for the same USD
+        let at_half_price = BondingCurveCalculator::calculate_expected_graduation_sol(CurveType::Exponential, CURVE_SUPPLY, SOL_PRICE_USD / 2).unwrap();
+        assert!(at_half_price.abs_diff(expected * 2) <= 2);
+    }
+    
+    #[test]
+    fn test_creator_allocation_must_leave_graduation_reachable() {
+        // A full sell-out raises ~$18.5k on the exponential curve and ~$29k on the sigmoid
+        for curve_type in [CurveType::Exponential, CurveType::Sigmoid] {
+            assert!(BondingCurveCalculator::can_raise_graduation_usd(curve_type, CURVE_SUPPLY));
+            // 10% of total supply carved off the top still clears $12k
+            assert!(BondingCurveCalculator::can_raise_graduation_usd(curve_type, CURVE_SUPPLY - 100 * ONE_MILLION_TOKENS));
+            // Half the curve can never raise it
+            assert!(!BondingCurveCalculator::can_raise_graduation_usd(curve_type, CURVE_SUPPLY / 2));
+            assert!(!BondingCurveCalculator::can_raise_graduation_usd(curve_type, CURVE_SUPPLY + 1));
+        }
+        
+        // Exponential runs out just under 685M tokens
+        assert!(!BondingCurveCalculator::can_raise_graduation_usd(CurveType::Exponential, 680 * ONE_MILLION_TOKENS));
+        assert!(BondingCurveCalculator::can_raise_graduation_usd(CurveType::Exponential, 690 * ONE_MILLION_TOKENS));
+    }
+    
     #[test]
     fn test_sell_tokens() {
         println!("\n=== SELL TOKENS ===");
         // First buy some tokens
         let initial_buy = 10 * ONE_MILLION_TOKENS; // Buy 10M tokens
         let buy_price = BondingCurveCalculator::calculate_buy_price(
+            CurveType::Exponential,
             0,
             initial_buy,
             SOL_PRICE_USD,
@@ -450,6 +995,7 @@ mod tests {
         // Now sell half
         let sell_amount = 5 * ONE_MILLION_TOKENS;
         let sell_price = BondingCurveCalculator::calculate_sell_price(
+            CurveType::Exponential,
             initial_buy,
             sell_amount,
             SOL_PRICE_USD,
@@ -475,6 +1021,7 @@ mod tests {
         
         for (amount, label) in test_amounts.iter() {
             let slippage = BondingCurveCalculator::calculate_slippage(
+                CurveType::Exponential,
                 tokens_sold,
                 *amount,
                 SOL_PRICE_USD,
@@ -488,6 +1035,146 @@ mod tests {
         }
     }
     
+    #[test]
+    fn test_streamed_buy_matches_high_precision_reference() {
+        println!("\n=== 400M BUY: SINGLE-SHOT VS STREAMED ===");
+        let chunk = 10 * ONE_MILLION_TOKENS;
+        // Exact integrals at $150/SOL, evaluated with 50-digit arithmetic (mpmath)
+        let cases = [
+            (0, 24_434_274_058.548_533_f64),
+            (400 * ONE_MILLION_TOKENS, 99_037_425_183.145_25_f64),
+        ];
+        
+        for (tokens_sold, reference) in cases.iter() {
+            let amount = 400 * ONE_MILLION_TOKENS;
+            let single = BondingCurveCalculator::calculate_buy_price(CurveType::Exponential, *tokens_sold, amount, SOL_PRICE_USD).unwrap();
+            let streamed = BondingCurveCalculator::calculate_buy_price_streamed(
+                CurveType::Exponential,
+                *tokens_sold,
+                amount,
+                SOL_PRICE_USD,
+                chunk,
+            ).unwrap();
+            
+            let single_err = (single as f64 - reference).abs();
+            let streamed_err = (streamed as f64 - reference).abs();
+            println!(
+                "from {}M: single {} (err {:.3}), streamed {} (err {:.3}), reference {:.3}",
+                tokens_sold / ONE_MILLION_TOKENS, single, single_err, streamed, streamed_err, reference
+            );
+            
+            // Summing 40 chunks in USD before converting loses nothing at lamport resolution
+            assert!(streamed_err < 1.0, "Streamed cost off by {:.3} lamports", streamed_err);
+            assert!(single_err < 1.0, "Single-shot cost off by {:.3} lamports", single_err);
+        }
+        
+        // Buys within one chunk are priced single-shot
+        assert_eq!(
+            BondingCurveCalculator::calculate_buy_price_streamed(CurveType::Exponential, 0, chunk, SOL_PRICE_USD, chunk).unwrap(),
+            BondingCurveCalculator::calculate_buy_price(CurveType::Exponential, 0, chunk, SOL_PRICE_USD).unwrap()
+        );
+        assert!(BondingCurveCalculator::calculate_buy_price_streamed(CurveType::Exponential, 0, chunk, SOL_PRICE_USD, 0).is_err());
+    }
+    
+    #[test]
+    fn test_sell_more_than_sold_has_a_sell_specific_error() {
+        let tokens_sold = 10 * ONE_MILLION_TOKENS;
+        
+        let err = BondingCurveCalculator::calculate_sell_price(CurveType::Exponential, tokens_sold, tokens_sold + 1, SOL_PRICE_USD)
+            .unwrap_err();
+        assert_eq!(err, LaunchpadError::SellExceedsTokensSold.into());
+        assert!(BondingCurveCalculator::calculate_sell_price(CurveType::Exponential, tokens_sold, tokens_sold, SOL_PRICE_USD).is_ok());
+    }
+    
+    #[test]
+    fn test_min_output_after_slippage() {
+        assert_eq!(BondingCurveCalculator::min_output_after_slippage(1_000_000, 0).unwrap(), 1_000_000);
+        assert_eq!(BondingCurveCalculator::min_output_after_slippage(1_000_000, 250).unwrap(), 975_000);
+        assert_eq!(BondingCurveCalculator::min_output_after_slippage(999, 5_000).unwrap(), 499);
+        assert_eq!(BondingCurveCalculator::min_output_after_slippage(1_000_000, 10_000).unwrap(), 0);
+        
+        let err = BondingCurveCalculator::min_output_after_slippage(1_000_000, 10_001).unwrap_err();
+        assert_eq!(err, LaunchpadError::InvalidSlippage.into());
+    }
+    
+    #[test]
+    fn test_slippage_saturates_instead_of_wrapping() {
+        println!("\n=== SLIPPAGE ABOVE u16::MAX ===");
+        // An average fill 8x the reference is 70,000 bps; a plain `as u16` wraps to 4,464
+        let slippage = BondingCurveCalculator::slippage_bps(8 * 28_000, 28_000).unwrap();
+        assert_eq!(slippage, u16::MAX, "Slippage wrapped to {} bps", slippage);
+        
+        // Right at the boundary nothing is lost
+        assert_eq!(BondingCurveCalculator::slippage_bps(10_000 + 65_535, 10_000).unwrap(), u16::MAX);
+        assert_eq!(BondingCurveCalculator::slippage_bps(10_000 + 65_534, 10_000).unwrap(), 65_534);
+        
+        // Buying the whole curve from the start stays below the cap and is reported as-is
+        let whole_curve = BondingCurveCalculator::calculate_slippage(CurveType::Exponential, 0, CURVE_SUPPLY, SOL_PRICE_USD).unwrap();
+        println!("Whole-curve buy slippage: {} bps", whole_curve);
+        assert!(whole_curve > 10_000 && whole_curve < u16::MAX);
+    }
+    
+    #[test]
+    fn test_slippage_references_mid_curve() {
+        println!("\n=== SLIPPAGE REFERENCES (MID-CURVE) ===");
+        let tokens_sold = 200 * ONE_MILLION_TOKENS;
+        let amount = 10 * ONE_MILLION_TOKENS;
+        
+        let vs_spot = BondingCurveCalculator::calculate_slippage(
+            CurveType::Exponential,
+            tokens_sold,
+            amount,
+            SOL_PRICE_USD,
+        ).unwrap();
+        let vs_start = BondingCurveCalculator::calculate_slippage_from_start(
+            CurveType::Exponential,
+            tokens_sold,
+            amount,
+            SOL_PRICE_USD,
+        ).unwrap();
+        
+        println!("Against current spot: {} bps", vs_spot);
+        println!("Against start price: {} bps", vs_start);
+        
+        // At 200M sold the spot is ~2x the start price, so appreciation dwarfs impact
+        assert!(vs_spot < 1000, "Spot slippage should be small: {} bps", vs_spot);
+        assert!(vs_start > 9000, "Start-price slippage should reflect ~2x appreciation: {} bps", vs_start);
+        assert!(vs_start > vs_spot);
+    }
+    
+    #[test]
+    fn test_realized_slippage_both_sides() {
+        let tokens_sold = 200 * ONE_MILLION_TOKENS;
+        let amount = 10 * ONE_MILLION_TOKENS;
+        
+        for curve_type in [CurveType::Exponential, CurveType::Sigmoid] {
+            // An executed buy reports exactly what the quote predicted
+            let cost = BondingCurveCalculator::calculate_buy_price(curve_type, tokens_sold, amount, SOL_PRICE_USD).unwrap();
+            let buy = BondingCurveCalculator::realized_slippage(
+                curve_type, TradeSide::Buy, tokens_sold, amount, cost, SOL_PRICE_USD,
+            ).unwrap();
+            assert_eq!(
+                buy,
+                BondingCurveCalculator::calculate_slippage(curve_type, tokens_sold, amount, SOL_PRICE_USD).unwrap()
+            );
+            assert!(buy > 0);
+            
+            // Selling the same size back down the curve fills below spot by a similar margin
+            let proceeds = BondingCurveCalculator::calculate_sell_price(curve_type, tokens_sold, amount, SOL_PRICE_USD).unwrap();
+            let sell = BondingCurveCalculator::realized_slippage(
+                curve_type, TradeSide::Sell, tokens_sold, amount, proceeds, SOL_PRICE_USD,
+            ).unwrap();
+            assert!(sell > 0 && sell < 1000, "Sell slippage {} bps", sell);
+            assert!(sell.abs_diff(buy) < buy / 4 + 5, "Buy {} bps vs sell {} bps", buy, sell);
+        }
+        
+        // A sell paid nothing lost the whole spot value, and no more
+        let lost = BondingCurveCalculator::realized_slippage(
+            CurveType::Exponential, TradeSide::Sell, tokens_sold, amount, 0, SOL_PRICE_USD,
+        ).unwrap();
+        assert_eq!(lost, 10_000);
+    }
+    
     #[test]
     fn test_graduation_threshold() {
         println!("\n=== GRADUATION THRESHOLD ===");
@@ -519,6 +1206,7 @@ mod tests {
         
         // Buy 10M tokens from 0
         let buy_price = BondingCurveCalculator::calculate_buy_price(
+            CurveType::Exponential,
             tokens_sold,
             amount,
             SOL_PRICE_USD,
@@ -526,6 +1214,7 @@ mod tests {
         
         // Sell 10M tokens back (from 10M sold to 0)
         let sell_price = BondingCurveCalculator::calculate_sell_price(
+            CurveType::Exponential,
             amount,
             amount,
             SOL_PRICE_USD,
@@ -543,6 +1232,104 @@ mod tests {
         assert!(diff_pct < 1.0, "Buy and sell prices should be nearly equal, diff: {:.2}%", diff_pct);
     }
     
+    #[test]
+    fn test_sub_token_buy_is_priced_fairly() {
+        println!("\n=== SUB-TOKEN BUY ===");
+        let half_token = ONE_TOKEN / 2;
+        
+        let half_cost = BondingCurveCalculator::calculate_buy_price(CurveType::Exponential, 0, half_token, SOL_PRICE_USD).unwrap();
+        let full_cost = BondingCurveCalculator::calculate_buy_price(CurveType::Exponential, 0, ONE_TOKEN, SOL_PRICE_USD).unwrap();
+        
+        println!("Cost for 0.5 tokens: {} lamports", half_cost);
+        println!("Cost for 1 token: {} lamports", full_cost);
+        
+        // Must not collapse to the 1-lamport floor; should be ~half of a whole token
+        assert!(half_cost > 1, "Sub-token buy was mispriced to zero");
+        assert!(
+            half_cost.abs_diff(full_cost / 2) <= 1,
+            "0.5 tokens should cost half of 1 token: {} vs {}",
+            half_cost,
+            full_cost
+        );
+        
+        // 1.5 tokens is no longer truncated to 1
+        let one_and_half_cost = BondingCurveCalculator::calculate_buy_price(CurveType::Exponential, 0, ONE_TOKEN + half_token, SOL_PRICE_USD).unwrap();
+        assert!(one_and_half_cost > full_cost, "Fractional tokens must be priced");
+    }
+    
+    #[test]
+    fn test_spot_price_lite_matches_curve() {
+        println!("\n=== LITE SPOT PRICE VS MAGIC-CURVES ===");
+        let supply_levels = [0, ONE_TOKEN, ONE_MILLION_TOKENS, CURVE_SUPPLY / 2, CURVE_SUPPLY];
+        
+        for tokens_sold in supply_levels.iter() {
+            let full = BondingCurveCalculator::get_spot_price(CurveType::Exponential, *tokens_sold, SOL_PRICE_USD).unwrap();
+            let lite = BondingCurveCalculator::get_spot_price_lite(CurveType::Exponential, *tokens_sold, SOL_PRICE_USD).unwrap();
+            
+            println!("At {} sold: full={} lite={}", tokens_sold / ONE_TOKEN, full, lite);
+            
+            // Allow 1 lamport of rounding difference
+            assert!(full.abs_diff(lite) <= 1, "Lite spot price diverged: {} vs {}", full, lite);
+        }
+    }
+    
+    #[test]
+    fn test_price_at_supply_samples() {
+        println!("\n=== PRICE AT SUPPLY SAMPLES ===");
+        let samples = [
+            (0, START_PRICE_USD as f64 / PRICE_SCALE as f64, "start"),
+            // Exponential curve midpoint is the geometric mean of start and end
+            (
+                CURVE_SUPPLY / 2,
+                ((START_PRICE_USD as f64) * (END_PRICE_USD as f64)).sqrt() / PRICE_SCALE as f64,
+                "mid",
+            ),
+            (CURVE_SUPPLY, END_PRICE_USD as f64 / PRICE_SCALE as f64, "end"),
+        ];
+        
+        for (tokens_sold, expected_usd, label) in samples.iter() {
+            let lamports = BondingCurveCalculator::get_spot_price_lite(CurveType::Exponential, *tokens_sold, SOL_PRICE_USD).unwrap();
+            let expected_lamports = (expected_usd / 150.0 * 1e9) as u64;
+            
+            println!("{} ({} sold): {} lamports, expected ~{}", label, tokens_sold / ONE_TOKEN, lamports, expected_lamports);
+            
+            // Allow 1% tolerance
+            let tolerance = (expected_lamports as f64 * 0.01) as u64 + 1;
+            assert!(
+                lamports.abs_diff(expected_lamports) <= tolerance,
+                "{} price mismatch: got {}, expected ~{}",
+                label,
+                lamports,
+                expected_lamports
+            );
+        }
+    }
+    
+    #[test]
+    fn test_spot_price_lite_benchmark() {
+        println!("\n=== SPOT PRICE BENCHMARK ===");
+        const ITERATIONS: u64 = 100_000;
+        
+        let start = std::time::Instant::now();
+        for i in 0..ITERATIONS {
+            std::hint::black_box(
+                BondingCurveCalculator::get_spot_price(CurveType::Exponential, (i % 800) * ONE_MILLION_TOKENS, SOL_PRICE_USD).unwrap()
+            );
+        }
+        let full_elapsed = start.elapsed();
+        
+        let start = std::time::Instant::now();
+        for i in 0..ITERATIONS {
+            std::hint::black_box(
+                BondingCurveCalculator::get_spot_price_lite(CurveType::Exponential, (i % 800) * ONE_MILLION_TOKENS, SOL_PRICE_USD).unwrap()
+            );
+        }
+        let lite_elapsed = start.elapsed();
+        
+        println!("get_spot_price:      {:?} for {} calls", full_elapsed, ITERATIONS);
+        println!("get_spot_price_lite: {:?} for {} calls", lite_elapsed, ITERATIONS);
+    }
+    
     #[test]
     fn test_realistic_user_purchase() {
         println!("\n=== REALISTIC USER PURCHASE ===");
@@ -564,6 +1351,7 @@ mod tests {
         
         for amount in test_amounts.iter() {
             let cost = BondingCurveCalculator::calculate_buy_price(
+                CurveType::Exponential,
                 0,
                 *amount,
                 SOL_PRICE_USD,
@@ -11,10 +11,10 @@ pub enum LaunchpadError {
     #[msg("Invalid fee percentage")]
     InvalidFee,
     
-    #[msg("Name is too long")]
+    #[msg("Name is too long (max 32 bytes / 32 characters)")]
     NameTooLong,
     
-    #[msg("Symbol is too long")]
+    #[msg("Symbol is too long (max 10 bytes / 10 characters)")]
     SymbolTooLong,
     
     #[msg("URI is too long")]
@@ -38,7 +38,7 @@ pub enum LaunchpadError {
     #[msg("Trading is currently inactive")]
     TradingInactive,
     
-    #[msg("Bonding curve has graduated to DEX")]
+    #[msg("Bonding curve has graduated; trade on the DEX pool (see get_pool_address)")]
     CurveGraduated,
     
     #[msg("Slippage tolerance exceeded")]
@@ -106,4 +106,103 @@ pub enum LaunchpadError {
     
     #[msg("Invalid configuration")]
     InvalidConfiguration,
+    
+    #[msg("Trading has not started yet")]
+    TradingNotStarted,
+    
+    #[msg("Oracle price is stale")]
+    PriceStale,
+    
+    #[msg("Launch is frozen by the platform admin")]
+    LaunchFrozen,
+    
+    #[msg("Token metadata is immutable")]
+    MetadataFrozen,
+    
+    #[msg("Oracle price deviates too far from the recently stored price")]
+    PriceDeviationTooHigh,
+    
+    #[msg("Launch has reached its maximum SOL raise")]
+    RaiseCapReached,
+    
+    #[msg("Metadata URI is not hosted on an allowed host")]
+    InvalidUri,
+    
+    #[msg("Insufficient SOL to cover the buy cost, fee and vault rent")]
+    InsufficientSolForRent,
+    
+    #[msg("Token name must be printable ASCII without leading or trailing spaces")]
+    InvalidTokenName,
+    
+    #[msg("Token symbol must be printable ASCII without spaces")]
+    InvalidTokenSymbol,
+    
+    #[msg("Price feed account does not match the launch's expected feed")]
+    InvalidPriceFeed,
+    
+    #[msg("Graduation action is not supported yet")]
+    GraduationActionNotSupported,
+    
+    #[msg("Liquidity is locked for this launch and cannot be withdrawn")]
+    LiquidityLocked,
+    
+    #[msg("Launch is still active; pause it or let it graduate before closing")]
+    LaunchStillLive,
+    
+    #[msg("Launch still holds holder funds, fees or liquidity and cannot be closed")]
+    LaunchStillFunded,
+    
+    #[msg("No SOL/USD price feed provided and manual pricing is disabled")]
+    PriceFeedMissing,
+    
+    #[msg("The same position was supplied more than once")]
+    DuplicatePosition,
+    
+    #[msg("Too many positions supplied for a single query")]
+    TooManyPositions,
+    
+    #[msg("Transaction landed after its deadline")]
+    DeadlineExceeded,
+    
+    #[msg("This curve has reached its maximum number of trades")]
+    TradeLimitReached,
+    
+    #[msg("Fee splits must be distinct recipients with non-zero weights summing to 10000 bps")]
+    InvalidFeeSplit,
+    
+    #[msg("This launch is buy-only; sell on the DEX after graduation")]
+    SellsDisabled,
+    
+    #[msg("Cannot sell more tokens than the curve has sold")]
+    SellExceedsTokensSold,
+    
+    #[msg("Curve is still in its post-graduation sell window")]
+    GraduationGraceActive,
+    
+    #[msg("Slippage tolerance must be at most 10000 basis points")]
+    InvalidSlippage,
+    
+    #[msg("Liquidity is still inside its post-graduation lock period")]
+    LiquidityLockActive,
+    
+    #[msg("Price staleness window must be between 5 and 3600 seconds")]
+    InvalidPriceStaleness,
+    
+    #[msg("The initial price can only be fixed before the first trade")]
+    TradingStarted,
+    
+    #[msg("Liquidity has already been withdrawn; use recover_withdraw for anything left behind")]
+    LiquidityAlreadyWithdrawn,
+    
+    #[msg("No outstanding liquidity to recover")]
+    NothingToRecover,
+    
+    #[msg("Creator allocation would leave the curve unable to raise the graduation threshold")]
+    InvalidCreatorAllocation,
+    
+    #[msg("No vested creator allocation to claim")]
+    NothingToClaim,
+    
+    #[msg("Oracle price is timestamped in the future")]
+    FuturePriceTimestamp,
 }
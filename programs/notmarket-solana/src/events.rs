@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::state::{CurveType, FeeSchedule, FeeSplit, GraduationAction, LaunchStatusChange, SolPriceSource};
 
 /// Emitted when the launchpad configuration is initialized
 #[event]
@@ -16,6 +17,15 @@ pub struct FeeRecipientUpdated {
     pub new_fee_recipient: Pubkey,
 }
 
+/// Emitted when the admin updates the platform trading fee
+#[event]
+pub struct PlatformFeeUpdated {
+    pub authority: Pubkey,
+    pub old_platform_fee_bps: u16,
+    pub new_platform_fee_bps: u16,
+    pub timestamp: i64,
+}
+
 /// Emitted when a new token launch is created
 #[event]
 pub struct TokenLaunchCreated {
@@ -28,9 +38,27 @@ pub struct TokenLaunchCreated {
     pub uri: String,
     pub description: String,
     pub total_supply: u64,
-    pub curve_supply: u64,
+    /// Tokens actually minted into the curve's token account
+    pub minted_to_curve_account: u64,
+    /// Of those, tokens sellable on the bonding curve
+    pub sellable_on_curve: u64,
+    /// Tokens held in the separate LP vault for DEX liquidity at graduation
+    pub lp_reserved: u64,
+    /// Tokens held in the creator vault, vesting to the creator
     pub creator_allocation: u64,
+    pub creator_vesting_seconds: u32,
     pub initial_price_usd: u64,
+    pub trading_starts_at: i64,
+    pub is_mutable: bool,
+    pub sells_enabled: bool,
+    pub max_sol_raise_lamports: u64,
+    pub target_sol_vault_lamports: u64,
+    pub max_trades: u64,
+    pub expected_price_feed: Pubkey,
+    pub graduation_action: GraduationAction,
+    pub curve_type: CurveType,
+    /// Slot the launch was created in, alongside the clock-based timestamp
+    pub launch_slot: u64,
     pub timestamp: i64,
 }
 
@@ -46,6 +74,8 @@ pub struct TokensPurchased {
     pub tokens_sold_after: u64,
     pub sol_reserve_after: u64,
     pub price_per_token: u64, // in lamports per token (with decimals)
+    /// Average fill above the pre-trade spot price, in basis points
+    pub slippage_bps: u16,
     pub timestamp: i64,
 }
 
@@ -61,6 +91,29 @@ pub struct TokensSold {
     pub tokens_sold_after: u64,
     pub sol_reserve_after: u64,
     pub price_per_token: u64, // in lamports per token (with decimals)
+    /// Average fill below the pre-trade spot price, in basis points
+    pub slippage_bps: u16,
+    pub timestamp: i64,
+}
+
+/// Emitted when a buy or sell transfers its platform fee to the fee recipient
+/// Not emitted while fees accrue in the vault; FeesSwept covers those
+#[event]
+pub struct PlatformFeeCollected {
+    pub launch: Pubkey,
+    pub payer: Pubkey,
+    pub amount: u64,
+    pub recipient: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted once when a bonding curve first crosses 95% of its supply sold
+#[event]
+pub struct NearGraduation {
+    pub launch: Pubkey,
+    pub bonding_curve: Pubkey,
+    pub tokens_sold: u64,
+    pub tokens_until_graduation: u64,
     pub timestamp: i64,
 }
 
@@ -71,6 +124,7 @@ pub struct CurveGraduated {
     pub bonding_curve: Pubkey,
     pub tokens_sold: u64,
     pub sol_raised: u64,
+    pub graduation_action: GraduationAction,
     pub timestamp: i64,
 }
 
@@ -79,10 +133,207 @@ pub struct CurveGraduated {
 pub struct LaunchStatusToggled {
     pub launch: Pubkey,
     pub is_active: bool,
+    pub change: LaunchStatusChange,
+    /// Length of the pause that just ended (0 unless change is Resume)
+    pub paused_seconds: u64,
+    pub total_paused_seconds: u64,
+    /// When the pause takes effect (0 unless change is PauseScheduled)
+    pub deactivates_at: i64,
     pub toggled_by: Pubkey,
     pub timestamp: i64,
 }
 
+/// Emitted when the admin updates the graduation success fee
+#[event]
+pub struct GraduationFeeUpdated {
+    pub authority: Pubkey,
+    pub old_graduation_fee_bps: u16,
+    pub new_graduation_fee_bps: u16,
+    pub timestamp: i64,
+}
+
+/// Emitted when the admin updates the platform fee on withdrawn liquidity
+#[event]
+pub struct WithdrawFeeUpdated {
+    pub authority: Pubkey,
+    pub old_withdraw_fee_bps: u16,
+    pub new_withdraw_fee_bps: u16,
+    pub timestamp: i64,
+}
+
+/// Emitted when the weighted platform fee recipients are updated
+#[event]
+pub struct FeeSplitsUpdated {
+    pub authority: Pubkey,
+    pub old_fee_splits: Vec<FeeSplit>,
+    pub new_fee_splits: Vec<FeeSplit>,
+    pub timestamp: i64,
+}
+
+/// Emitted when the size-based sell fee ramp is updated
+#[event]
+pub struct SellFeeRampUpdated {
+    pub authority: Pubkey,
+    pub old_max_bps: u16,
+    pub new_max_bps: u16,
+    pub old_full_share_bps: u16,
+    pub new_full_share_bps: u16,
+    pub timestamp: i64,
+}
+
+/// Emitted when the allowed metadata URI prefixes are updated
+#[event]
+pub struct AllowedUriPrefixesUpdated {
+    pub authority: Pubkey,
+    pub old_allowed_uri_prefixes: Vec<String>,
+    pub new_allowed_uri_prefixes: Vec<String>,
+    pub timestamp: i64,
+}
+
+/// Emitted when the admin completes a graduated launch's liquidity withdrawal
+#[event]
+pub struct LiquidityRecovered {
+    pub launch: Pubkey,
+    pub bonding_curve: Pubkey,
+    pub authority: Pubkey,
+    pub sol_recipient: Pubkey,
+    /// Lamports taken from the SOL vault, including graduation and withdraw fees
+    pub sol_amount: u64,
+    pub token_amount: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a creator claims vested tokens from their allocation
+#[event]
+pub struct CreatorAllocationClaimed {
+    pub launch: Pubkey,
+    pub creator: Pubkey,
+    pub amount: u64,
+    /// Claimed so far, including this claim
+    pub total_claimed: u64,
+    /// Allocation left in the creator vault, vested or not
+    pub remaining: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when the graduation success fee is skimmed during withdraw_liquidity
+#[event]
+pub struct GraduationFeeCollected {
+    pub launch: Pubkey,
+    pub bonding_curve: Pubkey,
+    pub fee_recipient: Pubkey,
+    pub sol_withdrawn: u64,
+    pub fee: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when the platform withdrawal fee is skimmed during withdraw_liquidity
+#[event]
+pub struct WithdrawFeeCollected {
+    pub launch: Pubkey,
+    pub bonding_curve: Pubkey,
+    pub fee_recipient: Pubkey,
+    pub sol_withdrawn: u64,
+    pub fee: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a wound-down launch is closed and its account rent reclaimed
+#[event]
+pub struct LaunchClosed {
+    pub launch: Pubkey,
+    pub mint: Pubkey,
+    pub creator: Pubkey,
+    /// Unsold curve and LP vault tokens burned before closing the token accounts
+    pub tokens_burned: u64,
+    /// Lamports returned to the creator from all closed accounts and the SOL vault
+    pub lamports_reclaimed: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when the admin toggles burning unsold tokens at graduation
+#[event]
+pub struct BurnUnsoldOnGraduationUpdated {
+    pub authority: Pubkey,
+    pub burn_unsold_on_graduation: bool,
+    pub timestamp: i64,
+}
+
+/// Emitted when the admin toggles trading off the backup price without a Pyth feed
+#[event]
+pub struct ManualPriceAllowedUpdated {
+    pub authority: Pubkey,
+    pub manual_price_allowed: bool,
+    pub timestamp: i64,
+}
+
+/// Emitted when unsold curve tokens are burned at graduation
+#[event]
+pub struct UnsoldTokensBurned {
+    pub launch: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when stray tokens are swept out of the curve token account
+#[event]
+pub struct StrayCurveTokensSwept {
+    pub launch: Pubkey,
+    pub mint: Pubkey,
+    pub recipient_token_account: Pubkey,
+    pub amount: u64,
+    pub swept_by: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when the admin updates the loyalty token fee discount
+#[event]
+pub struct FeeDiscountUpdated {
+    pub authority: Pubkey,
+    pub fee_discount_mint: Pubkey,
+    pub fee_discount_bps: u16,
+    pub timestamp: i64,
+}
+
+/// Emitted when the admin registers graduation liquidity destinations
+#[event]
+pub struct LiquidityDestinationsUpdated {
+    pub authority: Pubkey,
+    pub liquidity_program: Pubkey,
+    pub liquidity_treasury: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when the admin freezes or unfreezes a launch
+#[event]
+pub struct LaunchFreezeUpdated {
+    pub launch: Pubkey,
+    pub admin_frozen: bool,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when a launch is pointed at a different Pyth price feed account
+#[event]
+pub struct PriceFeedUpdated {
+    pub launch: Pubkey,
+    pub old_price_feed: Pubkey,
+    pub new_price_feed: Pubkey,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when a launch's platform fee schedule is set or cleared
+#[event]
+pub struct FeeScheduleUpdated {
+    pub launch: Pubkey,
+    pub old_fee_schedule: Option<FeeSchedule>,
+    pub new_fee_schedule: Option<FeeSchedule>,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
 /// Emitted when metadata URI is updated
 #[event]
 pub struct MetadataUpdated {
@@ -116,6 +367,30 @@ pub struct UserPositionUpdated {
     pub timestamp: i64,
 }
 
+/// Emitted when a creator corrects a launch's SOL/USD price before its first trade
+#[event]
+pub struct InitialPriceFixed {
+    pub launch: Pubkey,
+    pub bonding_curve: Pubkey,
+    pub creator: Pubkey,
+    pub old_price_usd: u64,
+    pub new_price_usd: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted whenever a curve's stored SOL/USD price changes, including the
+/// creator's initial seed (old_price 0), so audits can tell whether trades ran
+/// on a creator-supplied price or an oracle one. Only `BondingCurve::set_sol_price`
+/// emits it, so each change is reported once whichever instruction made it
+#[event]
+pub struct SolPriceUpdated {
+    pub launch: Pubkey,
+    pub old_price: u64,
+    pub new_price: u64,
+    pub source: SolPriceSource,
+    pub timestamp: i64,
+}
+
 /// Emitted when price quote is requested (for analytics)
 #[event]
 pub struct PriceQuoteRequested {
@@ -128,6 +403,134 @@ pub struct PriceQuoteRequested {
     pub timestamp: i64,
 }
 
+/// Emitted when fee accrual is toggled
+#[event]
+pub struct FeeAccrualUpdated {
+    pub authority: Pubkey,
+    pub accrue_fees: bool,
+    pub timestamp: i64,
+}
+
+/// Emitted when the minimum graduation SOL reserve is updated
+#[event]
+pub struct MinGraduationSolUpdated {
+    pub authority: Pubkey,
+    pub old_min_graduation_sol_lamports: u64,
+    pub new_min_graduation_sol_lamports: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when the minimum trade size is updated
+#[event]
+pub struct MinTradeTokensUpdated {
+    pub authority: Pubkey,
+    pub old_min_trade_tokens: u64,
+    pub new_min_trade_tokens: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when the curve-wide minimum time between trades is updated
+#[event]
+pub struct MinInterTradeSecondsUpdated {
+    pub authority: Pubkey,
+    pub old_min_inter_trade_seconds: u32,
+    pub new_min_inter_trade_seconds: u32,
+    pub timestamp: i64,
+}
+
+/// Emitted when the post-graduation sell grace window is updated
+#[event]
+pub struct GraduationGraceUpdated {
+    pub authority: Pubkey,
+    pub old_graduation_grace_seconds: u32,
+    pub new_graduation_grace_seconds: u32,
+    pub timestamp: i64,
+}
+
+/// Emitted when the notice delay before creator pauses take effect is updated
+#[event]
+pub struct DeactivationDelayUpdated {
+    pub authority: Pubkey,
+    pub old_deactivation_delay_seconds: u32,
+    pub new_deactivation_delay_seconds: u32,
+    pub timestamp: i64,
+}
+
+/// Emitted when the post-graduation liquidity lock is updated
+#[event]
+pub struct MinLiquidityLockUpdated {
+    pub authority: Pubkey,
+    pub old_min_lock_after_graduation_seconds: u32,
+    pub new_min_lock_after_graduation_seconds: u32,
+    pub timestamp: i64,
+}
+
+/// Emitted when the Pyth price staleness window is updated
+#[event]
+pub struct MaxPriceStalenessUpdated {
+    pub authority: Pubkey,
+    pub old_max_price_staleness_seconds: u32,
+    pub new_max_price_staleness_seconds: u32,
+    pub timestamp: i64,
+}
+
+/// Emitted when the holder minimum for graduation is updated
+#[event]
+pub struct MinHoldersForGraduationUpdated {
+    pub authority: Pubkey,
+    pub old_min_holders_for_graduation: u32,
+    pub new_min_holders_for_graduation: u32,
+    pub timestamp: i64,
+}
+
+/// Emitted when the creator allocation vesting period for new launches is updated
+#[event]
+pub struct CreatorVestingUpdated {
+    pub authority: Pubkey,
+    pub old_creator_vesting_seconds: u32,
+    pub new_creator_vesting_seconds: u32,
+    pub timestamp: i64,
+}
+
+/// Emitted when the maximum buy fraction of the remaining curve supply is updated
+#[event]
+pub struct MaxBuyFractionUpdated {
+    pub authority: Pubkey,
+    pub old_max_buy_fraction_bps: u16,
+    pub new_max_buy_fraction_bps: u16,
+    pub timestamp: i64,
+}
+
+/// Emitted when the per-launch metadata update cooldown is updated
+#[event]
+pub struct MetadataUpdateCooldownUpdated {
+    pub authority: Pubkey,
+    pub old_cooldown_seconds: u32,
+    pub new_cooldown_seconds: u32,
+    pub timestamp: i64,
+}
+
+/// Emitted once per position by snapshot_positions; positions from one call share a snapshot_id
+#[event]
+pub struct PositionSnapshot {
+    pub user: Pubkey,
+    pub token_amount: u64,
+    pub launch: Pubkey,
+    pub snapshot_id: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when accrued fees are swept from a SOL vault
+#[event]
+pub struct FeesWithdrawn {
+    pub launch: Pubkey,
+    pub bonding_curve: Pubkey,
+    pub fee_recipient: Pubkey,
+    pub amount: u64,
+    pub withdrawn_by: Pubkey,
+    pub timestamp: i64,
+}
+
 /// Emitted when admin authority is changed
 #[event]
 pub struct AdminChanged {
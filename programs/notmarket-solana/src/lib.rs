@@ -16,10 +16,13 @@ use events::*;
 use token_creation::*;
 use trading::*;
 use liquidity::*;
-use pyth_price::*;
 
 // Re-export return types for IDL generation
-pub use state::{BuyQuote, SpotPrice};
+pub use state::{BuyQuote, SpotPrice, LaunchSummary, VerifiedLaunch, LaunchAddresses, TradeSide, TradeSimulation, SlippageReference, VaultRecon, CreateLaunchParams, GraduationAction, CurveType, LaunchStatusChange, SolPriceSource, PositionReturn, GraduationStatus, GraduationBlocker, PositionShare, LaunchPositions, FeeSplit, FeeSchedule, CurveHealth, SupplyBreakdown, GlobalTotals, TradeRecord, RecentTrades};
+
+/// Longest metadata URI this build accepts, in bytes (see `TokenLaunch::MAX_URI_LEN`)
+#[constant]
+pub const MAX_METADATA_URI_LEN: u32 = TokenLaunch::MAX_URI_LEN as u32;
 
 #[program]
 pub mod notmarket_solana {
@@ -30,7 +33,7 @@ pub mod notmarket_solana {
         ctx: Context<InitializeLaunchpad>,
         platform_fee_bps: u16,
     ) -> Result<()> {
-        ctx.accounts.initialize(platform_fee_bps, ctx.bumps.config)?;
+        ctx.accounts.initialize(platform_fee_bps, ctx.bumps.config, ctx.bumps.global_stats)?;
         
         emit!(LaunchpadInitialized {
             authority: ctx.accounts.authority.key(),
@@ -41,6 +44,18 @@ pub mod notmarket_solana {
         Ok(())
     }
 
+    /// Create the global stats account on a launchpad initialized before it existed (admin only)
+    /// Totals count from this call onward
+    pub fn initialize_global_stats(ctx: Context<InitializeGlobalStats>) -> Result<()> {
+        ctx.accounts.initialize(ctx.bumps.global_stats)
+    }
+
+    /// Create the trade history account for a launch created before it existed (permissionless)
+    /// Buys and sells on the launch require it; history counts from this call onward
+    pub fn initialize_trade_history(ctx: Context<InitializeTradeHistory>) -> Result<()> {
+        ctx.accounts.initialize(ctx.bumps.trade_history)
+    }
+
     /// Update the fee recipient address (admin only)
     pub fn update_fee_recipient(
         ctx: Context<UpdateFeeRecipient>,
@@ -57,6 +72,25 @@ pub mod notmarket_solana {
         Ok(())
     }
 
+    /// Update the platform trading fee (admin only)
+    pub fn update_platform_fee(
+        ctx: Context<UpdatePlatformFee>,
+        platform_fee_bps: u16,
+    ) -> Result<()> {
+        let old_platform_fee_bps = ctx.accounts.config.platform_fee_bps;
+        ctx.accounts.update_platform_fee(platform_fee_bps)?;
+        
+        let clock = Clock::get()?;
+        emit!(PlatformFeeUpdated {
+            authority: ctx.accounts.authority.key(),
+            old_platform_fee_bps,
+            new_platform_fee_bps: platform_fee_bps,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+
     /// Update admin authority (admin only)
     pub fn update_admin(
         ctx: Context<UpdateAdmin>,
@@ -95,43 +129,458 @@ pub mod notmarket_solana {
         Ok(())
     }
 
+    /// Toggle accrual of trading fees in the SOL vault (admin only)
+    pub fn update_fee_accrual(
+        ctx: Context<UpdateFeeAccrual>,
+        accrue_fees: bool,
+    ) -> Result<()> {
+        ctx.accounts.update_fee_accrual(accrue_fees)?;
+        
+        let clock = Clock::get()?;
+        emit!(FeeAccrualUpdated {
+            authority: ctx.accounts.authority.key(),
+            accrue_fees,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+
+    /// Update the minimum SOL reserve required to graduate (admin only)
+    pub fn update_min_graduation_sol(
+        ctx: Context<UpdateMinGraduationSol>,
+        min_graduation_sol_lamports: u64,
+    ) -> Result<()> {
+        let old_min_graduation_sol_lamports = ctx.accounts.config.min_graduation_sol_lamports;
+        ctx.accounts.update_min_graduation_sol(min_graduation_sol_lamports)?;
+        
+        let clock = Clock::get()?;
+        emit!(MinGraduationSolUpdated {
+            authority: ctx.accounts.authority.key(),
+            old_min_graduation_sol_lamports,
+            new_min_graduation_sol_lamports: min_graduation_sol_lamports,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+
+    /// Update the minimum trade size in tokens (admin only)
+    pub fn update_min_trade_tokens(
+        ctx: Context<UpdateMinTradeTokens>,
+        min_trade_tokens: u64,
+    ) -> Result<()> {
+        let old_min_trade_tokens = ctx.accounts.config.min_trade_tokens;
+        ctx.accounts.update_min_trade_tokens(min_trade_tokens)?;
+        
+        let clock = Clock::get()?;
+        emit!(MinTradeTokensUpdated {
+            authority: ctx.accounts.authority.key(),
+            old_min_trade_tokens,
+            new_min_trade_tokens: min_trade_tokens,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+
+    /// Update the minimum time between any two trades on a curve (admin only)
+    pub fn update_min_inter_trade_seconds(
+        ctx: Context<UpdateMinInterTradeSeconds>,
+        min_inter_trade_seconds: u32,
+    ) -> Result<()> {
+        let old_min_inter_trade_seconds = ctx.accounts.config.min_inter_trade_seconds;
+        ctx.accounts.update_min_inter_trade_seconds(min_inter_trade_seconds)?;
+        
+        let clock = Clock::get()?;
+        emit!(MinInterTradeSecondsUpdated {
+            authority: ctx.accounts.authority.key(),
+            old_min_inter_trade_seconds,
+            new_min_inter_trade_seconds: min_inter_trade_seconds,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+
+    /// Update how long sells stay open on a curve after it graduates (admin only)
+    pub fn update_graduation_grace(
+        ctx: Context<UpdateGraduationGrace>,
+        graduation_grace_seconds: u32,
+    ) -> Result<()> {
+        let old_graduation_grace_seconds = ctx.accounts.config.graduation_grace_seconds;
+        ctx.accounts.update_graduation_grace(graduation_grace_seconds)?;
+        
+        let clock = Clock::get()?;
+        emit!(GraduationGraceUpdated {
+            authority: ctx.accounts.authority.key(),
+            old_graduation_grace_seconds,
+            new_graduation_grace_seconds: graduation_grace_seconds,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+
+    /// Update how much notice a creator pause gives in-flight trades (admin only)
+    pub fn update_deactivation_delay(
+        ctx: Context<UpdateDeactivationDelay>,
+        deactivation_delay_seconds: u32,
+    ) -> Result<()> {
+        let old_deactivation_delay_seconds = ctx.accounts.config.deactivation_delay_seconds;
+        ctx.accounts.update_deactivation_delay(deactivation_delay_seconds)?;
+        
+        let clock = Clock::get()?;
+        emit!(DeactivationDelayUpdated {
+            authority: ctx.accounts.authority.key(),
+            old_deactivation_delay_seconds,
+            new_deactivation_delay_seconds: deactivation_delay_seconds,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+
+    /// Update how long graduated liquidity must stay in the vaults before withdraw_liquidity (admin only)
+    /// Applies to every graduated curve, including ones that graduated before the change
+    pub fn update_min_liquidity_lock(
+        ctx: Context<UpdateMinLiquidityLock>,
+        min_lock_after_graduation_seconds: u32,
+    ) -> Result<()> {
+        let old_min_lock_after_graduation_seconds = ctx.accounts.config.min_lock_after_graduation_seconds;
+        ctx.accounts.update_min_liquidity_lock(min_lock_after_graduation_seconds)?;
+        
+        let clock = Clock::get()?;
+        emit!(MinLiquidityLockUpdated {
+            authority: ctx.accounts.authority.key(),
+            old_min_lock_after_graduation_seconds,
+            new_min_lock_after_graduation_seconds: min_lock_after_graduation_seconds,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+
+    /// Update how old a Pyth update may be before trades price off the stored backup (admin only)
+    /// Also bounds refresh_price, which rejects updates outside the window
+    pub fn update_max_price_staleness(
+        ctx: Context<UpdateMaxPriceStaleness>,
+        max_price_staleness_seconds: u32,
+    ) -> Result<()> {
+        let old_max_price_staleness_seconds = ctx.accounts.config.max_price_staleness_seconds;
+        ctx.accounts.update_max_price_staleness(max_price_staleness_seconds)?;
+        
+        let clock = Clock::get()?;
+        emit!(MaxPriceStalenessUpdated {
+            authority: ctx.accounts.authority.key(),
+            old_max_price_staleness_seconds,
+            new_max_price_staleness_seconds: max_price_staleness_seconds,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+
+    /// Update how many distinct holders a curve needs before it can graduate (admin only)
+    /// Checked alongside the token/USD/SOL conditions; 0 disables the requirement
+    pub fn update_min_holders_for_graduation(
+        ctx: Context<UpdateMinHoldersForGraduation>,
+        min_holders_for_graduation: u32,
+    ) -> Result<()> {
+        let old_min_holders_for_graduation = ctx.accounts.config.min_holders_for_graduation;
+        ctx.accounts.update_min_holders_for_graduation(min_holders_for_graduation)?;
+        
+        let clock = Clock::get()?;
+        emit!(MinHoldersForGraduationUpdated {
+            authority: ctx.accounts.authority.key(),
+            old_min_holders_for_graduation,
+            new_min_holders_for_graduation: min_holders_for_graduation,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+
+    /// Update the vesting period for creator allocations (admin only)
+    /// Applies to launches created afterwards; existing launches keep their schedule
+    pub fn update_creator_vesting(
+        ctx: Context<UpdateCreatorVesting>,
+        creator_vesting_seconds: u32,
+    ) -> Result<()> {
+        let old_creator_vesting_seconds = ctx.accounts.config.creator_vesting_seconds;
+        ctx.accounts.update_creator_vesting(creator_vesting_seconds)?;
+        
+        let clock = Clock::get()?;
+        emit!(CreatorVestingUpdated {
+            authority: ctx.accounts.authority.key(),
+            old_creator_vesting_seconds,
+            new_creator_vesting_seconds: creator_vesting_seconds,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+
+    /// Update the largest buy as a share of a curve's remaining token_reserve (admin only)
+    /// Unlike a fixed size it tightens as the curve fills; 0 disables the cap
+    pub fn update_max_buy_fraction(
+        ctx: Context<UpdateMaxBuyFraction>,
+        max_buy_fraction_bps: u16,
+    ) -> Result<()> {
+        let old_max_buy_fraction_bps = ctx.accounts.config.max_buy_fraction_bps;
+        ctx.accounts.update_max_buy_fraction(max_buy_fraction_bps)?;
+        
+        let clock = Clock::get()?;
+        emit!(MaxBuyFractionUpdated {
+            authority: ctx.accounts.authority.key(),
+            old_max_buy_fraction_bps,
+            new_max_buy_fraction_bps: max_buy_fraction_bps,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+
+    /// Update the minimum time between metadata URI / description updates (admin only)
+    pub fn update_metadata_update_cooldown(
+        ctx: Context<UpdateMetadataUpdateCooldown>,
+        cooldown_seconds: u32,
+    ) -> Result<()> {
+        let old_cooldown_seconds = ctx.accounts.config.metadata_update_cooldown_seconds;
+        ctx.accounts.update_metadata_update_cooldown(cooldown_seconds)?;
+        
+        let clock = Clock::get()?;
+        emit!(MetadataUpdateCooldownUpdated {
+            authority: ctx.accounts.authority.key(),
+            old_cooldown_seconds,
+            new_cooldown_seconds: cooldown_seconds,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+
+    /// Update the success fee skimmed from the SOL vault at graduation (admin only)
+    pub fn update_graduation_fee(
+        ctx: Context<UpdateGraduationFee>,
+        graduation_fee_bps: u16,
+    ) -> Result<()> {
+        let old_graduation_fee_bps = ctx.accounts.config.graduation_fee_bps;
+        ctx.accounts.update_graduation_fee(graduation_fee_bps)?;
+        
+        let clock = Clock::get()?;
+        emit!(GraduationFeeUpdated {
+            authority: ctx.accounts.authority.key(),
+            old_graduation_fee_bps,
+            new_graduation_fee_bps: graduation_fee_bps,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+
+    /// Update the platform fee skimmed from each liquidity withdrawal (admin only)
+    pub fn update_withdraw_fee(
+        ctx: Context<UpdateWithdrawFee>,
+        withdraw_fee_bps: u16,
+    ) -> Result<()> {
+        let old_withdraw_fee_bps = ctx.accounts.config.withdraw_fee_bps;
+        ctx.accounts.update_withdraw_fee(withdraw_fee_bps)?;
+        
+        let clock = Clock::get()?;
+        emit!(WithdrawFeeUpdated {
+            authority: ctx.accounts.authority.key(),
+            old_withdraw_fee_bps,
+            new_withdraw_fee_bps: withdraw_fee_bps,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+
+    /// Split per-trade platform fees across weighted recipients, empty restores
+    /// the single fee recipient (admin only)
+    pub fn update_fee_splits(
+        ctx: Context<UpdateFeeSplits>,
+        fee_splits: Vec<FeeSplit>,
+    ) -> Result<()> {
+        let old_fee_splits = ctx.accounts.config.fee_splits.clone();
+        ctx.accounts.update_fee_splits(fee_splits.clone())?;
+        
+        let clock = Clock::get()?;
+        emit!(FeeSplitsUpdated {
+            authority: ctx.accounts.authority.key(),
+            old_fee_splits,
+            new_fee_splits: fee_splits,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+
+    /// Configure the extra sell fee charged on sells that take a large share of the reserve (admin only)
+    pub fn update_sell_fee_ramp(
+        ctx: Context<UpdateSellFeeRamp>,
+        max_bps: u16,
+        full_share_bps: u16,
+    ) -> Result<()> {
+        let old_max_bps = ctx.accounts.config.sell_fee_ramp_max_bps;
+        let old_full_share_bps = ctx.accounts.config.sell_fee_ramp_full_share_bps;
+        ctx.accounts.update_sell_fee_ramp(max_bps, full_share_bps)?;
+        
+        let clock = Clock::get()?;
+        emit!(SellFeeRampUpdated {
+            authority: ctx.accounts.authority.key(),
+            old_max_bps,
+            new_max_bps: max_bps,
+            old_full_share_bps,
+            new_full_share_bps: full_share_bps,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+
+    /// Restrict metadata URIs to the given prefixes, empty allows any (admin only)
+    pub fn update_allowed_uri_prefixes(
+        ctx: Context<UpdateAllowedUriPrefixes>,
+        allowed_uri_prefixes: Vec<String>,
+    ) -> Result<()> {
+        let old_allowed_uri_prefixes = ctx.accounts.config.allowed_uri_prefixes.clone();
+        ctx.accounts.update_allowed_uri_prefixes(allowed_uri_prefixes.clone())?;
+        
+        let clock = Clock::get()?;
+        emit!(AllowedUriPrefixesUpdated {
+            authority: ctx.accounts.authority.key(),
+            old_allowed_uri_prefixes,
+            new_allowed_uri_prefixes: allowed_uri_prefixes,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+
+    /// Toggle burning of unsold curve tokens at graduation (admin only)
+    pub fn update_burn_unsold_on_graduation(
+        ctx: Context<UpdateBurnUnsoldOnGraduation>,
+        burn_unsold_on_graduation: bool,
+    ) -> Result<()> {
+        ctx.accounts.update_burn_unsold_on_graduation(burn_unsold_on_graduation)?;
+        
+        let clock = Clock::get()?;
+        emit!(BurnUnsoldOnGraduationUpdated {
+            authority: ctx.accounts.authority.key(),
+            burn_unsold_on_graduation,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+
+    /// Toggle trading off the stored backup price when no Pyth feed is passed (admin only)
+    pub fn update_manual_price_allowed(
+        ctx: Context<UpdateManualPriceAllowed>,
+        manual_price_allowed: bool,
+    ) -> Result<()> {
+        ctx.accounts.update_manual_price_allowed(manual_price_allowed)?;
+        
+        let clock = Clock::get()?;
+        emit!(ManualPriceAllowedUpdated {
+            authority: ctx.accounts.authority.key(),
+            manual_price_allowed,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+
+    /// Set the loyalty token whose holders get a platform fee discount (admin only)
+    pub fn update_fee_discount(
+        ctx: Context<UpdateFeeDiscount>,
+        fee_discount_mint: Pubkey,
+        fee_discount_bps: u16,
+    ) -> Result<()> {
+        ctx.accounts.update_fee_discount(fee_discount_mint, fee_discount_bps)?;
+        
+        let clock = Clock::get()?;
+        emit!(FeeDiscountUpdated {
+            authority: ctx.accounts.authority.key(),
+            fee_discount_mint,
+            fee_discount_bps,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+
+    /// Register the DEX program and treasury allowed to receive graduation liquidity (admin only)
+    pub fn update_liquidity_destinations(
+        ctx: Context<UpdateLiquidityDestinations>,
+        liquidity_program: Pubkey,
+        liquidity_treasury: Pubkey,
+    ) -> Result<()> {
+        ctx.accounts.update_liquidity_destinations(liquidity_program, liquidity_treasury)?;
+        
+        let clock = Clock::get()?;
+        emit!(LiquidityDestinationsUpdated {
+            authority: ctx.accounts.authority.key(),
+            liquidity_program,
+            liquidity_treasury,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+
     /// Create a new token launch with bonding curve
     /// Fixed parameters: 1B supply, 800M on curve, 200M for LP
     /// Price range: $0.00000420 → $0.00006900
     pub fn create_token_launch(
         ctx: Context<CreateTokenLaunch>,
-        name: String,
-        symbol: String,
-        metadata_uri: String,
-        description: String,
-        sol_price_usd: u64, // Current SOL price in USD (scaled by 1e8, e.g., $150 = 15_000_000_000)
-    ) -> Result<()> {
-        ctx.accounts.create(
-            name.clone(),
-            symbol.clone(),
-            metadata_uri.clone(),
-            description.clone(),
-            sol_price_usd,
-            &ctx.bumps,
-        )?;
+        params: CreateLaunchParams,
+    ) -> Result<()> {
+        ctx.accounts.create(&params, &ctx.bumps)?;
         
-        // Mint full supply (1B tokens) to bonding curve
+        // Mint full supply (1B tokens): 800M less any creator allocation to the curve,
+        // the creator allocation to the creator vault, 200M to the LP vault
         ctx.accounts.mint_initial_supply()?;
         
+        // Report allocations from the real token balances, not the constants
+        ctx.accounts.curve_token_account.reload()?;
+        ctx.accounts.lp_vault.reload()?;
+        ctx.accounts.creator_vault.reload()?;
+        let minted_to_curve_account = ctx.accounts.curve_token_account.amount;
+        let sellable_on_curve = ctx.accounts.bonding_curve.token_reserve;
+        let lp_reserved = ctx.accounts.lp_vault.amount;
+        let creator_allocation = ctx.accounts.creator_vault.amount;
+        
         let clock = Clock::get()?;
         emit!(TokenLaunchCreated {
             launch: ctx.accounts.token_launch.key(),
             mint: ctx.accounts.mint.key(),
             creator: ctx.accounts.creator.key(),
             bonding_curve: ctx.accounts.bonding_curve.key(),
-            name,
-            symbol,
-            uri: metadata_uri,
-            description,
+            name: params.name,
+            symbol: params.symbol,
+            uri: params.metadata_uri,
+            description: params.description,
             total_supply: ctx.accounts.token_launch.total_supply,
-            curve_supply: ctx.accounts.bonding_curve.token_reserve,
-            creator_allocation: ctx.accounts.token_launch.total_supply - ctx.accounts.bonding_curve.token_reserve,
-            initial_price_usd: sol_price_usd,
+            minted_to_curve_account,
+            sellable_on_curve,
+            lp_reserved,
+            creator_allocation,
+            creator_vesting_seconds: ctx.accounts.bonding_curve.creator_vesting_seconds,
+            initial_price_usd: params.sol_price_usd,
+            trading_starts_at: params.trading_starts_at,
+            is_mutable: params.is_mutable,
+            sells_enabled: params.sells_enabled,
+            max_sol_raise_lamports: params.max_sol_raise_lamports,
+            target_sol_vault_lamports: params.target_sol_vault_lamports,
+            max_trades: params.max_trades,
+            expected_price_feed: params.expected_price_feed,
+            graduation_action: params.graduation_action,
+            curve_type: params.curve_type,
+            launch_slot: ctx.accounts.token_launch.launch_slot,
             timestamp: clock.unix_timestamp,
         });
         
@@ -139,15 +588,22 @@ pub mod notmarket_solana {
     }
 
     /// Toggle active status of a token launch
+    /// With a deactivation delay configured, pausing only schedules the pause and
+    /// toggling again before it takes effect cancels it
     pub fn toggle_token_launch_active(
         ctx: Context<UpdateTokenLaunch>,
     ) -> Result<()> {
-        ctx.accounts.toggle_active()?;
+        let (change, paused_seconds) = ctx.accounts.toggle_active()?;
         
         let clock = Clock::get()?;
+        let token_launch = &ctx.accounts.token_launch;
         emit!(LaunchStatusToggled {
-            launch: ctx.accounts.token_launch.key(),
-            is_active: ctx.accounts.token_launch.is_active,
+            launch: token_launch.key(),
+            is_active: token_launch.is_active,
+            change,
+            paused_seconds,
+            total_paused_seconds: token_launch.total_paused_seconds,
+            deactivates_at: token_launch.deactivates_at,
             toggled_by: ctx.accounts.creator.key(),
             timestamp: clock.unix_timestamp,
         });
@@ -155,6 +611,81 @@ pub mod notmarket_solana {
         Ok(())
     }
 
+    /// Freeze trading on a launch; the creator cannot re-activate it (admin only)
+    pub fn admin_freeze_launch(
+        ctx: Context<AdminFreezeLaunch>,
+    ) -> Result<()> {
+        ctx.accounts.set_frozen(true)?;
+        
+        let clock = Clock::get()?;
+        emit!(LaunchFreezeUpdated {
+            launch: ctx.accounts.token_launch.key(),
+            admin_frozen: true,
+            authority: ctx.accounts.authority.key(),
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+
+    /// Lift an admin freeze on a launch (admin only)
+    pub fn admin_unfreeze_launch(
+        ctx: Context<AdminFreezeLaunch>,
+    ) -> Result<()> {
+        ctx.accounts.set_frozen(false)?;
+        
+        let clock = Clock::get()?;
+        emit!(LaunchFreezeUpdated {
+            launch: ctx.accounts.token_launch.key(),
+            admin_frozen: false,
+            authority: ctx.accounts.authority.key(),
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+
+    /// Migrate a launch to a different Pyth price feed account (admin only)
+    pub fn update_price_feed(
+        ctx: Context<UpdatePriceFeed>,
+        new_price_feed: Pubkey,
+    ) -> Result<()> {
+        let old_price_feed = ctx.accounts.bonding_curve.expected_price_feed;
+        ctx.accounts.update_price_feed(new_price_feed)?;
+        
+        let clock = Clock::get()?;
+        emit!(PriceFeedUpdated {
+            launch: ctx.accounts.token_launch.key(),
+            old_price_feed,
+            new_price_feed,
+            authority: ctx.accounts.authority.key(),
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+
+    /// Give a launch a platform fee that moves linearly from `start_bps` to `end_bps`
+    /// as its curve fills, or pass None to go back to the flat fee (admin only)
+    pub fn update_fee_schedule(
+        ctx: Context<UpdateFeeSchedule>,
+        fee_schedule: Option<FeeSchedule>,
+    ) -> Result<()> {
+        let old_fee_schedule = ctx.accounts.bonding_curve.fee_schedule_by_progress;
+        ctx.accounts.update_fee_schedule(fee_schedule)?;
+        
+        let clock = Clock::get()?;
+        emit!(FeeScheduleUpdated {
+            launch: ctx.accounts.token_launch.key(),
+            old_fee_schedule,
+            new_fee_schedule: fee_schedule,
+            authority: ctx.accounts.authority.key(),
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+
     /// Update metadata URI for a token launch
     pub fn update_metadata_uri(
         ctx: Context<UpdateTokenLaunch>,
@@ -193,20 +724,65 @@ pub mod notmarket_solana {
         Ok(())
     }
 
+    /// Correct a mis-set creation SOL/USD price (creator only)
+    /// Only allowed while the curve has no trades; locked from the first trade on
+    pub fn fix_initial_price(
+        ctx: Context<FixInitialPrice>,
+        new_price: u64,
+    ) -> Result<()> {
+        let (old_price_usd, new_price_usd) = ctx.accounts.fix_initial_price(new_price)?;
+        
+        let clock = Clock::get()?;
+        emit!(InitialPriceFixed {
+            launch: ctx.accounts.token_launch.key(),
+            bonding_curve: ctx.accounts.bonding_curve.key(),
+            creator: ctx.accounts.creator.key(),
+            old_price_usd,
+            new_price_usd,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+
+    /// Claim the vested part of the launch's creator allocation (creator only)
+    /// Vests linearly from launch over the period fixed at creation
+    pub fn claim_creator_allocation(ctx: Context<ClaimCreatorAllocation>) -> Result<()> {
+        let amount = ctx.accounts.claim()?;
+        
+        let bonding_curve = &ctx.accounts.bonding_curve;
+        let clock = Clock::get()?;
+        emit!(CreatorAllocationClaimed {
+            launch: ctx.accounts.token_launch.key(),
+            creator: ctx.accounts.creator.key(),
+            amount,
+            total_claimed: bonding_curve.creator_allocation_claimed,
+            remaining: bonding_curve.creator_allocation.saturating_sub(bonding_curve.creator_allocation_claimed),
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+
     /// Buy tokens from the bonding curve
-    pub fn buy_tokens(
-        ctx: Context<BuyTokens>,
+    /// A nonzero `deadline` rejects the buy if it lands after that unix timestamp.
+    /// With fee splits configured, pass the split recipients as remaining accounts
+    pub fn buy_tokens<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BuyTokens<'info>>,
         amount: u64,
         max_sol_cost: u64,
+        deadline: i64, // Unix timestamp; 0 disables the check
     ) -> Result<()> {
         // Execute buy and get actual cost and fee from bonding curve calculation
-        let (cost, fee) = ctx.accounts.execute(amount, max_sol_cost, &ctx.bumps)?;
+        let (cost, fee, slippage_bps) = ctx.accounts.execute(
+            amount,
+            max_sol_cost,
+            deadline,
+            ctx.remaining_accounts,
+            &ctx.bumps,
+        )?;
         
-        let price_per_token = if amount > 0 {
-            cost.checked_mul(1_000_000_000).unwrap_or(0) / amount
-        } else {
-            0
-        };
+        let price_per_token = TradeRecord::price_per_token(cost, amount);
         
         let clock = Clock::get()?;
         emit!(TokensPurchased {
@@ -219,6 +795,7 @@ pub mod notmarket_solana {
             tokens_sold_after: ctx.accounts.bonding_curve.tokens_sold,
             sol_reserve_after: ctx.accounts.bonding_curve.sol_reserve,
             price_per_token,
+            slippage_bps,
             timestamp: clock.unix_timestamp,
         });
         
@@ -226,19 +803,23 @@ pub mod notmarket_solana {
     }
 
     /// Sell tokens back to the bonding curve
-    pub fn sell_tokens(
-        ctx: Context<SellTokens>,
+    /// A nonzero `deadline` rejects the sell if it lands after that unix timestamp.
+    /// With fee splits configured, pass the split recipients as remaining accounts
+    pub fn sell_tokens<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SellTokens<'info>>,
         amount: u64,
         min_sol_output: u64,
+        deadline: i64, // Unix timestamp; 0 disables the check
     ) -> Result<()> {
         // Execute sell and get actual proceeds and fee from bonding curve calculation
-        let (proceeds, fee) = ctx.accounts.execute(amount, min_sol_output, &ctx.bumps)?;
+        let (proceeds, fee, slippage_bps) = ctx.accounts.execute(
+            amount,
+            min_sol_output,
+            deadline,
+            ctx.remaining_accounts,
+        )?;
         
-        let price_per_token = if amount > 0 {
-            proceeds.checked_mul(1_000_000_000).unwrap_or(0) / amount
-        } else {
-            0
-        };
+        let price_per_token = TradeRecord::price_per_token(proceeds, amount);
         
         let clock = Clock::get()?;
         emit!(TokensSold {
@@ -251,21 +832,47 @@ pub mod notmarket_solana {
             tokens_sold_after: ctx.accounts.bonding_curve.tokens_sold,
             sol_reserve_after: ctx.accounts.bonding_curve.sol_reserve,
             price_per_token,
+            slippage_bps,
             timestamp: clock.unix_timestamp,
         });
         
         Ok(())
     }
 
+    /// Sell tokens with a percentage slippage tolerance instead of an absolute minimum
+    /// `expected_sol_output` is the client's quote of net proceeds (e.g. `simulate_trade`'s
+    /// `net_sol`), so trades landing between the quote and the sell count against the tolerance
+    pub fn sell_tokens_with_slippage<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SellTokens<'info>>,
+        amount: u64,
+        expected_sol_output: u64,
+        max_slippage_bps: u16,
+        deadline: i64, // Unix timestamp; 0 disables the check
+    ) -> Result<()> {
+        let min_sol_output = bonding_curve::BondingCurveCalculator::min_output_after_slippage(
+            expected_sol_output,
+            max_slippage_bps,
+        )?;
+        sell_tokens(ctx, amount, min_sol_output, deadline)
+    }
+
+    /// Refresh the stored SOL/USD price from Pyth without trading (permissionless)
+    pub fn refresh_price(
+        ctx: Context<RefreshPrice>,
+    ) -> Result<()> {
+        // The stored price's writer emits SolPriceUpdated if it changed
+        ctx.accounts.refresh()?;
+        
+        Ok(())
+    }
+
     /// Get a price quote for buying tokens (view function)
     pub fn get_buy_quote(
         ctx: Context<GetBuyQuote>,
         amount: u64,
+        slippage_reference: Option<SlippageReference>, // Defaults to CurrentSpot
     ) -> Result<BuyQuote> {
-        let quote = ctx.accounts.get_quote(amount)?;
-        
-        // Calculate fee for logging
-        let fee = quote.cost.checked_mul(100).unwrap_or(0) / 10000; // 1% platform fee
+        let quote = ctx.accounts.get_quote(amount, slippage_reference.unwrap_or_default())?;
         
         let clock = Clock::get()?;
         emit!(PriceQuoteRequested {
@@ -273,7 +880,7 @@ pub mod notmarket_solana {
             bonding_curve: ctx.accounts.bonding_curve.key(),
             token_amount: amount,
             estimated_cost: quote.cost,
-            estimated_fee: fee,
+            estimated_fee: quote.fee, // From the live platform_fee_bps
             tokens_sold_current: ctx.accounts.bonding_curve.tokens_sold,
             timestamp: clock.unix_timestamp,
         });
@@ -289,10 +896,192 @@ pub mod notmarket_solana {
         ctx.accounts.get_current_price()
     }
 
+    /// Get launch metadata, curve state and derived stats in one call (view function)
+    pub fn get_launch_summary(
+        ctx: Context<GetLaunchSummary>,
+    ) -> Result<LaunchSummary> {
+        ctx.accounts.summarize()
+    }
+
+    /// Reconcile SOL vault lamports against the curve's reserve and accrued fees (view function)
+    /// Non-zero unattributed lamports indicate drift from direct transfers or accounting bugs
+    pub fn get_vault_reconciliation(
+        ctx: Context<GetVaultReconciliation>,
+    ) -> Result<VaultRecon> {
+        ctx.accounts.reconcile()
+    }
+
+    /// Break the token supply down into circulating, on-curve and LP-reserved amounts (view function)
+    /// Absent direct transfers to the curve and until liquidity is withdrawn,
+    /// total == circulating + on_curve + lp_reserved
+    pub fn get_supply_breakdown(
+        ctx: Context<GetSupplyBreakdown>,
+    ) -> Result<SupplyBreakdown> {
+        ctx.accounts.breakdown()
+    }
+
+    /// Protocol-wide totals: launches created, curve volume, graduations and trade fees (view function)
+    pub fn get_global_stats(
+        ctx: Context<GetGlobalStats>,
+    ) -> Result<GlobalTotals> {
+        Ok(ctx.accounts.totals())
+    }
+
+    /// Check curve solvency: the SOL reserve against the proceeds owed if every
+    /// holder sold back at the stored SOL price (view function)
+    pub fn get_curve_health(
+        ctx: Context<GetCurveHealth>,
+    ) -> Result<CurveHealth> {
+        ctx.accounts.curve_health()
+    }
+
+    /// Diagnose why a curve hasn't graduated: each condition, how far short it is,
+    /// and the first one blocking (view function, state is not changed)
+    pub fn get_graduation_status(
+        ctx: Context<GetGraduationStatus>,
+    ) -> Result<GraduationStatus> {
+        ctx.accounts.status()
+    }
+
+    /// Get up to `limit` of the launch's last trades, newest first, skipping the
+    /// `offset` most recent (view function). Only the last 32 trades are kept
+    /// on-chain; page with offsets 0, 16, ... until an empty page
+    pub fn get_recent_trades(
+        ctx: Context<GetRecentTrades>,
+        offset: u32,
+        limit: u8,
+    ) -> Result<RecentTrades> {
+        ctx.accounts.recent_trades(offset, limit)
+    }
+
+    /// Get a position's return marked to the current spot price, plus a simple
+    /// annualized figure over the time since its first buy (view function)
+    pub fn get_position_return(
+        ctx: Context<GetPositionReturn>,
+    ) -> Result<PositionReturn> {
+        ctx.accounts.position_return()
+    }
+
+    /// Total tokens and per-holder shares across the UserPosition accounts passed
+    /// as remaining accounts, the basis for pro-rata distribution (view function)
+    pub fn get_all_positions_for_launch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, GetAllPositionsForLaunch<'info>>,
+    ) -> Result<LaunchPositions> {
+        ctx.accounts.all_positions(ctx.remaining_accounts)
+    }
+
+    /// Snapshot the balances of the positions passed as remaining accounts for
+    /// off-curve airdrops; returns the new snapshot id (admin or creator)
+    pub fn snapshot_positions<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SnapshotPositions<'info>>,
+    ) -> Result<u64> {
+        ctx.accounts.execute(ctx.remaining_accounts)
+    }
+
+    /// Get the SOL reserve expected at graduation, for sizing DEX liquidity (view function)
+    /// Changes with SOL price: the curve raises a fixed USD amount
+    pub fn get_expected_graduation_sol(
+        ctx: Context<GetExpectedGraduationSol>,
+    ) -> Result<u64> {
+        ctx.accounts.expected_graduation_sol()
+    }
+
+    /// Get the DEX pool a graduated launch's liquidity was sent to, so clients can
+    /// redirect traders (view function). None until withdraw_liquidity has run
+    pub fn get_pool_address(
+        ctx: Context<GetPoolAddress>,
+    ) -> Result<Option<Pubkey>> {
+        ctx.accounts.pool_address()
+    }
+
+    /// Get the spot price at a hypothetical supply level (view function)
+    /// Uses the launch's stored SOL price so the UI can chart the full curve
+    pub fn get_price_at_supply(
+        ctx: Context<GetPriceAtSupply>,
+        hypothetical_tokens_sold: u64,
+    ) -> Result<u64> {
+        ctx.accounts.price_at(hypothetical_tokens_sold)
+    }
+
+    /// Batch variant of get_price_at_supply (view function)
+    pub fn get_prices_at_supplies(
+        ctx: Context<GetPriceAtSupply>,
+        hypothetical_tokens_sold: Vec<u64>,
+    ) -> Result<Vec<u64>> {
+        ctx.accounts.prices_at(&hypothetical_tokens_sold)
+    }
+
+    /// Simulate a buy or sell and return the projected post-trade state (view function)
+    pub fn simulate_trade(
+        ctx: Context<SimulateTrade>,
+        side: TradeSide,
+        amount: u64,
+    ) -> Result<TradeSimulation> {
+        ctx.accounts.simulate(side, amount)
+    }
+
+    /// Resolve all launch PDAs for a mint, validating they exist (view function)
+    pub fn resolve_launch(
+        ctx: Context<ResolveLaunch>,
+    ) -> Result<LaunchAddresses> {
+        ctx.accounts.resolve()
+    }
+
+    /// Verify a mint was launched by this program and by which creator (view function)
+    pub fn verify_launch(
+        ctx: Context<VerifyLaunch>,
+    ) -> Result<VerifiedLaunch> {
+        ctx.accounts.verify()
+    }
+
     /// Withdraw liquidity after graduation (for LP creation)
     pub fn withdraw_liquidity(
         ctx: Context<WithdrawLiquidity>,
     ) -> Result<()> {
-        ctx.accounts.execute(&ctx.bumps)
+        ctx.accounts.execute()
+    }
+
+    /// Complete a graduated launch's liquidity withdrawal to a corrected, registered
+    /// recipient (admin only); never moves more than is still owed, so it can't double-withdraw
+    pub fn recover_withdraw(
+        ctx: Context<WithdrawLiquidity>,
+    ) -> Result<()> {
+        let (sol_amount, token_amount) = ctx.accounts.recover()?;
+        
+        let clock = Clock::get()?;
+        emit!(LiquidityRecovered {
+            launch: ctx.accounts.token_launch.key(),
+            bonding_curve: ctx.accounts.bonding_curve.key(),
+            authority: ctx.accounts.authority.key(),
+            sol_recipient: ctx.accounts.sol_recipient.key(),
+            sol_amount,
+            token_amount,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+
+    /// Sweep accrued platform fees from the SOL vault (admin or creator)
+    pub fn withdraw_fees(
+        ctx: Context<WithdrawFees>,
+    ) -> Result<()> {
+        ctx.accounts.execute()
+    }
+
+    /// Sweep tokens sent straight to the curve token account, beyond the tracked
+    /// token reserve, to a recipient token account (admin only); returns the amount swept
+    pub fn reconcile_curve_tokens(
+        ctx: Context<ReconcileCurveTokens>,
+    ) -> Result<u64> {
+        ctx.accounts.execute()
+    }
+
+    /// Close a wound-down launch, burning leftover curve tokens and returning
+    /// all account rent to the creator (creator only)
+    pub fn close_launch(
+        ctx: Context<CloseLaunch>,
+    ) -> Result<()> {
+        ctx.accounts.execute()
     }
 }
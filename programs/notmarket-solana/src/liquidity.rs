@@ -1,21 +1,28 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer as TokenTransfer};
+use anchor_spl::token::{self, Burn, CloseAccount, Mint, Token, TokenAccount, Transfer as TokenTransfer};
 use anchor_lang::system_program::{transfer, Transfer};
 use crate::state::*;
 use crate::errors::LaunchpadError;
+use crate::events::*;
 
 /// Withdraw liquidity after graduation to create DEX pool
-/// This transfers SOL and tokens from PDAs to specified recipient
+/// This transfers SOL and tokens from PDAs to the DEX pool or registered treasury.
+/// Shared by withdraw_liquidity (creator) and recover_withdraw (admin)
 #[derive(Accounts)]
 pub struct WithdrawLiquidity<'info> {
+    #[account(
+        seeds = [b"launchpad_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, LaunchpadConfig>,
+    
     #[account(
         mut,
         seeds = [
             b"token_launch",
             token_launch.mint.as_ref()
         ],
-        bump = token_launch.bump,
-        constraint = token_launch.creator == authority.key() @ LaunchpadError::Unauthorized
+        bump = token_launch.bump
     )]
     pub token_launch: Account<'info, TokenLaunch>,
     
@@ -30,36 +37,60 @@ pub struct WithdrawLiquidity<'info> {
     )]
     pub bonding_curve: Account<'info, BondingCurve>,
     
-    /// SOL vault PDA - holds all SOL from trades
-    /// CHECK: PDA verified through seeds constraint. No data stored, just holds SOL.
+    /// SOL vault PDA: no data, just holds SOL, and must stay system-owned
     #[account(
         mut,
         seeds = [
             b"sol_vault",
             bonding_curve.key().as_ref()
         ],
-        bump
+        bump = bonding_curve.vault_bump(&bonding_curve.key())
     )]
-    pub sol_vault: UncheckedAccount<'info>,
+    pub sol_vault: SystemAccount<'info>,
     
-    /// Token account owned by bonding curve - holds remaining tokens
+    /// LP vault owned by bonding curve - holds the 200M LP allocation
+    /// Unsold curve tokens stay in the curve token account and are never pooled
     #[account(
         mut,
-        associated_token::mint = token_launch.mint,
-        associated_token::authority = bonding_curve
+        seeds = [
+            b"lp_vault",
+            bonding_curve.key().as_ref()
+        ],
+        bump
     )]
-    pub curve_token_account: Account<'info, TokenAccount>,
+    pub lp_vault: Account<'info, TokenAccount>,
     
-    /// Recipient for SOL (e.g., DEX pool or treasury)
-    /// CHECK: Can be any account, verified by creator authority
-    #[account(mut)]
+    /// Recipient for SOL - the registered treasury or an account owned by the DEX program
+    /// CHECK: Verified against the liquidity destinations in config
+    #[account(
+        mut,
+        constraint = config.is_approved_liquidity_recipient(
+            &sol_recipient.key(),
+            sol_recipient.owner
+        ) @ LaunchpadError::Unauthorized
+    )]
     pub sol_recipient: UncheckedAccount<'info>,
     
-    /// Recipient for tokens (e.g., DEX pool or treasury)
-    #[account(mut)]
+    /// Recipient for tokens - must be held by the same destination as the SOL
+    #[account(
+        mut,
+        constraint = token_recipient.mint == token_launch.mint @ LaunchpadError::TokenMintMismatch,
+        constraint = token_recipient.owner == sol_recipient.key() @ LaunchpadError::Unauthorized
+    )]
     pub token_recipient: Account<'info, TokenAccount>,
     
-    /// Authority (creator) who can withdraw
+    /// CHECK: Fee recipient from config, receives the graduation fee
+    #[account(
+        mut,
+        constraint = fee_recipient.key() == config.fee_recipient @ LaunchpadError::InvalidFeeRecipient
+    )]
+    pub fee_recipient: UncheckedAccount<'info>,
+    
+    /// Creator (withdraw) or launchpad admin (recovery); each path checks which it needs
+    #[account(
+        constraint = authority.key() == token_launch.creator
+            || authority.key() == config.authority @ LaunchpadError::Unauthorized
+    )]
     pub authority: Signer<'info>,
     
     pub token_program: Program<'info, Token>,
@@ -67,29 +98,159 @@ pub struct WithdrawLiquidity<'info> {
 }
 
 impl<'info> WithdrawLiquidity<'info> {
-    pub fn execute(&mut self, bumps: &WithdrawLiquidityBumps) -> Result<()> {
+    /// Creator path: move the whole graduation liquidity out, once
+    pub fn execute(&mut self) -> Result<()> {
+        require_keys_eq!(
+            self.authority.key(),
+            self.token_launch.creator,
+            LaunchpadError::Unauthorized
+        );
+        // Anything a withdrawal left behind is only reachable through recover_withdraw
+        require!(
+            !self.bonding_curve.has_withdrawn_liquidity(),
+            LaunchpadError::LiquidityAlreadyWithdrawn
+        );
+        
+        // Get balances to transfer (accrued fees stay behind for withdraw_fees)
+        let sol_balance = self.sol_vault.lamports()
+            .saturating_sub(self.bonding_curve.accrued_fees);
+        let token_balance = self.lp_vault.amount;
+        
+        msg!("Withdrawing liquidity - SOL: {} lamports, Tokens: {}", sol_balance, token_balance);
+        self.release(sol_balance, token_balance)?;
+        msg!("🎉 Liquidity withdrawal complete!");
+        
+        Ok(())
+    }
+    
+    /// Admin path: complete a stuck or never-made withdrawal to a corrected, registered
+    /// recipient. Only the SOL reserve and LP allocation not yet withdrawn can move.
+    /// Returns (SOL including fees, tokens) moved
+    pub fn recover(&mut self) -> Result<(u64, u64)> {
+        require_keys_eq!(
+            self.authority.key(),
+            self.config.authority,
+            LaunchpadError::Unauthorized
+        );
+        
+        let vault_sol = self.sol_vault.lamports()
+            .saturating_sub(self.bonding_curve.accrued_fees);
+        let (sol_balance, token_balance) = self.bonding_curve
+            .outstanding_liquidity(vault_sol, self.lp_vault.amount);
+        require!(
+            sol_balance > 0 || token_balance > 0,
+            LaunchpadError::NothingToRecover
+        );
+        
+        msg!("Recovering liquidity - SOL: {} lamports, Tokens: {}", sol_balance, token_balance);
+        self.release(sol_balance, token_balance)?;
+        msg!("🎉 Liquidity recovery complete!");
+        
+        Ok((sol_balance, token_balance))
+    }
+    
+    /// Pay `sol_balance` (less platform fees) and `token_balance` LP tokens to the
+    /// recipients and record them as withdrawn; all-or-nothing within the instruction
+    fn release(&mut self, sol_balance: u64, token_balance: u64) -> Result<()> {
         // Ensure curve is graduated
         require!(
             self.bonding_curve.is_graduated,
             LaunchpadError::NotGraduated
         );
+        // Holders may still be selling back to the curve, so its reserve isn't final yet
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            !self.bonding_curve.is_in_graduation_grace(now),
+            LaunchpadError::GraduationGraceActive
+        );
+        // Liquidity must sit in the vaults for the configured lock before it can reach the DEX
+        require!(
+            !self.bonding_curve.is_liquidity_locked(now, self.config.min_lock_after_graduation_seconds),
+            LaunchpadError::LiquidityLockActive
+        );
         
-        // Get balances to transfer
-        let sol_balance = self.sol_vault.lamports();
-        let token_balance = self.curve_token_account.amount;
+        // Only pooled launches release funds; locked ones keep them in the vaults
+        match self.bonding_curve.graduation_action {
+            GraduationAction::Pool => {}
+            GraduationAction::Lock => return err!(LaunchpadError::LiquidityLocked),
+            GraduationAction::Distribute => return err!(LaunchpadError::GraduationActionNotSupported),
+        }
         
-        msg!("Withdrawing liquidity - SOL: {} lamports, Tokens: {}", sol_balance, token_balance);
+        // Let clients redirect traders from the graduated curve to the pool
+        self.bonding_curve.pool_address = Some(self.sol_recipient.key());
         
-        // Transfer all SOL from vault to recipient using PDA signer
-        if sol_balance > 0 {
-            let bonding_curve_key = self.bonding_curve.key();
-            let vault_seeds = &[
-                b"sol_vault",
-                bonding_curve_key.as_ref(),
-                &[bumps.sol_vault],
-            ];
-            let vault_signer_seeds = &[&vault_seeds[..]];
+        // Platform success and withdrawal fees come off the top; the rest goes to liquidity
+        let graduation_fee = self.config.graduation_fee(sol_balance)?;
+        let withdraw_fee = self.config.withdraw_fee(sol_balance)?;
+        let liquidity_sol = sol_balance
+            .checked_sub(graduation_fee)
+            .and_then(|remaining| remaining.checked_sub(withdraw_fee))
+            .ok_or(LaunchpadError::MathOverflow)?;
+        
+        let bonding_curve_key = self.bonding_curve.key();
+        let vault_seeds = &[
+            b"sol_vault",
+            bonding_curve_key.as_ref(),
+            &[self.bonding_curve.vault_bump(&bonding_curve_key)],
+        ];
+        let vault_signer_seeds = &[&vault_seeds[..]];
+        
+        if graduation_fee > 0 {
+            let transfer_fee = Transfer {
+                from: self.sol_vault.to_account_info(),
+                to: self.fee_recipient.to_account_info(),
+            };
+            
+            transfer(
+                CpiContext::new_with_signer(
+                    self.system_program.to_account_info(),
+                    transfer_fee,
+                    vault_signer_seeds,
+                ),
+                graduation_fee,
+            )?;
+            
+            emit!(GraduationFeeCollected {
+                launch: self.token_launch.key(),
+                bonding_curve: bonding_curve_key,
+                fee_recipient: self.fee_recipient.key(),
+                sol_withdrawn: sol_balance,
+                fee: graduation_fee,
+                timestamp: Clock::get()?.unix_timestamp,
+            });
+            
+            msg!("✅ Transferred {} lamports graduation fee to fee recipient", graduation_fee);
+        }
+        
+        if withdraw_fee > 0 {
+            let transfer_fee = Transfer {
+                from: self.sol_vault.to_account_info(),
+                to: self.fee_recipient.to_account_info(),
+            };
+            
+            transfer(
+                CpiContext::new_with_signer(
+                    self.system_program.to_account_info(),
+                    transfer_fee,
+                    vault_signer_seeds,
+                ),
+                withdraw_fee,
+            )?;
+            
+            emit!(WithdrawFeeCollected {
+                launch: self.token_launch.key(),
+                bonding_curve: bonding_curve_key,
+                fee_recipient: self.fee_recipient.key(),
+                sol_withdrawn: sol_balance,
+                fee: withdraw_fee,
+                timestamp: Clock::get()?.unix_timestamp,
+            });
             
+            msg!("✅ Transferred {} lamports withdraw fee to fee recipient", withdraw_fee);
+        }
+        
+        // Transfer remaining SOL from vault to recipient using PDA signer
+        if liquidity_sol > 0 {
             let transfer_sol = Transfer {
                 from: self.sol_vault.to_account_info(),
                 to: self.sol_recipient.to_account_info(),
@@ -101,13 +262,13 @@ impl<'info> WithdrawLiquidity<'info> {
                     transfer_sol,
                     vault_signer_seeds,
                 ),
-                sol_balance,
+                liquidity_sol,
             )?;
             
-            msg!("✅ Transferred {} lamports to SOL recipient", sol_balance);
+            msg!("✅ Transferred {} lamports to SOL recipient", liquidity_sol);
         }
         
-        // Transfer all tokens from curve to recipient using PDA signer
+        // Transfer the LP allocation to recipient using PDA signer
         if token_balance > 0 {
             let token_launch_key = self.token_launch.key();
             let bonding_seeds = &[
@@ -118,7 +279,7 @@ impl<'info> WithdrawLiquidity<'info> {
             let bonding_signer_seeds = &[&bonding_seeds[..]];
             
             let transfer_tokens = TokenTransfer {
-                from: self.curve_token_account.to_account_info(),
+                from: self.lp_vault.to_account_info(),
                 to: self.token_recipient.to_account_info(),
                 authority: self.bonding_curve.to_account_info(),
             };
@@ -132,10 +293,411 @@ impl<'info> WithdrawLiquidity<'info> {
                 token_balance,
             )?;
             
-            msg!("✅ Transferred {} tokens to token recipient", token_balance);
+            msg!("✅ Transferred {} LP tokens to token recipient", token_balance);
         }
         
-        msg!("🎉 Liquidity withdrawal complete!");
+        self.bonding_curve.record_liquidity_withdrawal(sol_balance, token_balance)
+    }
+}
+
+/// Sweep platform fees accrued in the SOL vault to the fee recipient
+/// Callable by the launchpad admin or the launch creator
+#[derive(Accounts)]
+pub struct WithdrawFees<'info> {
+    #[account(
+        seeds = [
+            b"token_launch",
+            token_launch.mint.as_ref()
+        ],
+        bump = token_launch.bump
+    )]
+    pub token_launch: Account<'info, TokenLaunch>,
+    
+    #[account(
+        mut,
+        seeds = [
+            b"bonding_curve",
+            token_launch.key().as_ref()
+        ],
+        bump = bonding_curve.bump
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
+    /// SOL vault PDA: no data, just holds SOL, and must stay system-owned
+    #[account(
+        mut,
+        seeds = [
+            b"sol_vault",
+            bonding_curve.key().as_ref()
+        ],
+        bump = bonding_curve.vault_bump(&bonding_curve.key())
+    )]
+    pub sol_vault: SystemAccount<'info>,
+    
+    #[account(
+        seeds = [b"launchpad_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, LaunchpadConfig>,
+    
+    /// CHECK: Fee recipient from config
+    #[account(
+        mut,
+        constraint = fee_recipient.key() == config.fee_recipient @ LaunchpadError::InvalidFeeRecipient
+    )]
+    pub fee_recipient: UncheckedAccount<'info>,
+    
+    #[account(
+        constraint = authority.key() == config.authority
+            || authority.key() == token_launch.creator @ LaunchpadError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> WithdrawFees<'info> {
+    pub fn execute(&mut self) -> Result<()> {
+        let amount = self.bonding_curve.accrued_fees;
+        require!(amount > 0, LaunchpadError::InvalidAmount);
+        
+        let bonding_curve_key = self.bonding_curve.key();
+        let vault_seeds = &[
+            b"sol_vault",
+            bonding_curve_key.as_ref(),
+            &[self.bonding_curve.vault_bump(&bonding_curve_key)],
+        ];
+        let vault_signer_seeds = &[&vault_seeds[..]];
+        
+        let transfer_fees = Transfer {
+            from: self.sol_vault.to_account_info(),
+            to: self.fee_recipient.to_account_info(),
+        };
+        transfer(
+            CpiContext::new_with_signer(
+                self.system_program.to_account_info(),
+                transfer_fees,
+                vault_signer_seeds,
+            ),
+            amount,
+        )?;
+        
+        // Only the fee bucket is cleared; sol_reserve is untouched
+        self.bonding_curve.accrued_fees = 0;
+        
+        emit!(FeesWithdrawn {
+            launch: self.token_launch.key(),
+            bonding_curve: bonding_curve_key,
+            fee_recipient: self.fee_recipient.key(),
+            amount,
+            withdrawn_by: self.authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        msg!("Withdrew {} lamports of accrued fees", amount);
+        
+        Ok(())
+    }
+}
+
+/// Move tokens sent directly to the curve token account, beyond the tracked
+/// token reserve, to a recipient of the admin's choosing (admin only)
+#[derive(Accounts)]
+pub struct ReconcileCurveTokens<'info> {
+    #[account(
+        seeds = [
+            b"token_launch",
+            token_launch.mint.as_ref()
+        ],
+        bump = token_launch.bump
+    )]
+    pub token_launch: Account<'info, TokenLaunch>,
+    
+    #[account(
+        seeds = [
+            b"bonding_curve",
+            token_launch.key().as_ref()
+        ],
+        bump = bonding_curve.bump
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
+    #[account(
+        mut,
+        associated_token::mint = token_launch.mint,
+        associated_token::authority = bonding_curve
+    )]
+    pub curve_token_account: Account<'info, TokenAccount>,
+    
+    /// Any token account for the launch mint
+    #[account(
+        mut,
+        constraint = recipient_token_account.mint == token_launch.mint @ LaunchpadError::TokenMintMismatch
+    )]
+    pub recipient_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        seeds = [b"launchpad_config"],
+        bump = config.bump,
+        constraint = config.authority == authority.key() @ LaunchpadError::Unauthorized
+    )]
+    pub config: Account<'info, LaunchpadConfig>,
+    
+    pub authority: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> ReconcileCurveTokens<'info> {
+    pub fn execute(&mut self) -> Result<u64> {
+        let stray = self.bonding_curve.stray_curve_tokens(self.curve_token_account.amount);
+        require!(stray > 0, LaunchpadError::InvalidAmount);
+        
+        let token_launch_key = self.token_launch.key();
+        let bonding_seeds = &[
+            b"bonding_curve",
+            token_launch_key.as_ref(),
+            &[self.bonding_curve.bump],
+        ];
+        let bonding_signer_seeds = &[&bonding_seeds[..]];
+        
+        let transfer_tokens = TokenTransfer {
+            from: self.curve_token_account.to_account_info(),
+            to: self.recipient_token_account.to_account_info(),
+            authority: self.bonding_curve.to_account_info(),
+        };
+        token::transfer(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                transfer_tokens,
+                bonding_signer_seeds,
+            ),
+            stray,
+        )?;
+        
+        // The curve account is back to exactly the reserve it tracks
+        self.curve_token_account.reload()?;
+        self.bonding_curve.check_reserve_invariant(self.curve_token_account.amount)?;
+        
+        emit!(StrayCurveTokensSwept {
+            launch: token_launch_key,
+            mint: self.token_launch.mint,
+            recipient_token_account: self.recipient_token_account.key(),
+            amount: stray,
+            swept_by: self.authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        msg!("Swept {} stray tokens from the curve token account", stray);
+        
+        Ok(stray)
+    }
+}
+
+/// Close a wound-down launch and return all account rent to the creator
+/// Wound down means inactive, and either never holding anyone's tokens or
+/// graduated with liquidity and fees already withdrawn
+#[derive(Accounts)]
+pub struct CloseLaunch<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"token_launch",
+            token_launch.mint.as_ref()
+        ],
+        bump = token_launch.bump,
+        constraint = token_launch.creator == creator.key() @ LaunchpadError::Unauthorized,
+        close = creator
+    )]
+    pub token_launch: Account<'info, TokenLaunch>,
+    
+    #[account(
+        mut,
+        constraint = mint.key() == token_launch.mint @ LaunchpadError::TokenMintMismatch
+    )]
+    pub mint: Account<'info, Mint>,
+    
+    #[account(
+        mut,
+        seeds = [
+            b"bonding_curve",
+            token_launch.key().as_ref()
+        ],
+        bump = bonding_curve.bump,
+        close = creator
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = bonding_curve
+    )]
+    pub curve_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        seeds = [
+            b"lp_vault",
+            bonding_curve.key().as_ref()
+        ],
+        bump
+    )]
+    pub lp_vault: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        seeds = [
+            b"creator_vault",
+            bonding_curve.key().as_ref()
+        ],
+        bump
+    )]
+    pub creator_vault: Account<'info, TokenAccount>,
+    
+    /// SOL vault PDA: no data, just holds SOL, and must stay system-owned
+    #[account(
+        mut,
+        seeds = [
+            b"sol_vault",
+            bonding_curve.key().as_ref()
+        ],
+        bump = bonding_curve.vault_bump(&bonding_curve.key())
+    )]
+    pub sol_vault: SystemAccount<'info>,
+    
+    /// Recent-trade ring buffer; launches created before it run
+    /// `initialize_trade_history` first, which this close refunds
+    #[account(
+        mut,
+        seeds = [
+            b"trade_history",
+            token_launch.key().as_ref()
+        ],
+        bump = trade_history.bump,
+        close = creator
+    )]
+    pub trade_history: Box<Account<'info, TradeHistory>>,
+    
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CloseLaunch<'info> {
+    pub fn execute(&mut self) -> Result<()> {
+        let curve = &self.bonding_curve;
+        require!(
+            !self.token_launch.is_trading_active(Clock::get()?.unix_timestamp),
+            LaunchpadError::LaunchStillLive
+        );
+        require!(curve.accrued_fees == 0, LaunchpadError::LaunchStillFunded);
+        
+        let rent_exempt_minimum = Rent::get()?.minimum_balance(0);
+        if curve.is_graduated {
+            // Pooled liquidity must have been withdrawn; locked liquidity never is
+            require!(
+                self.lp_vault.amount == 0 && self.sol_vault.lamports() <= rent_exempt_minimum,
+                LaunchpadError::LaunchStillFunded
+            );
+        } else {
+            // Holders can still sell against the reserve until every token is back on the curve
+            require!(curve.tokens_sold == 0, LaunchpadError::LaunchStillFunded);
+        }
+        
+        let lamports_before = self.creator.lamports();
+        let closing_lamports = self.token_launch.to_account_info().lamports()
+            + self.bonding_curve.to_account_info().lamports()
+            + self.trade_history.to_account_info().lamports();
+        
+        let token_launch_key = self.token_launch.key();
+        let bonding_seeds = &[
+            b"bonding_curve",
+            token_launch_key.as_ref(),
+            &[self.bonding_curve.bump],
+        ];
+        let bonding_signer_seeds = &[&bonding_seeds[..]];
+        
+        // Nobody holds a claim on what's left in the curve-owned token accounts;
+        // the closing creator forfeits any creator allocation they haven't claimed
+        let mut tokens_burned = 0u64;
+        for token_account in [&self.curve_token_account, &self.lp_vault, &self.creator_vault] {
+            let balance = token_account.amount;
+            if balance > 0 {
+                token::burn(
+                    CpiContext::new_with_signer(
+                        self.token_program.to_account_info(),
+                        Burn {
+                            mint: self.mint.to_account_info(),
+                            from: token_account.to_account_info(),
+                            authority: self.bonding_curve.to_account_info(),
+                        },
+                        bonding_signer_seeds,
+                    ),
+                    balance,
+                )?;
+                tokens_burned = tokens_burned
+                    .checked_add(balance)
+                    .ok_or(LaunchpadError::MathOverflow)?;
+            }
+            
+            token::close_account(CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                CloseAccount {
+                    account: token_account.to_account_info(),
+                    destination: self.creator.to_account_info(),
+                    authority: self.bonding_curve.to_account_info(),
+                },
+                bonding_signer_seeds,
+            ))?;
+        }
+        
+        // Sweep the vault's rent (and any rounding dust) back to the creator
+        let vault_lamports = self.sol_vault.lamports();
+        if vault_lamports > 0 {
+            let bonding_curve_key = self.bonding_curve.key();
+            let vault_seeds = &[
+                b"sol_vault",
+                bonding_curve_key.as_ref(),
+                &[self.bonding_curve.vault_bump(&bonding_curve_key)],
+            ];
+            let vault_signer_seeds = &[&vault_seeds[..]];
+            
+            transfer(
+                CpiContext::new_with_signer(
+                    self.system_program.to_account_info(),
+                    Transfer {
+                        from: self.sol_vault.to_account_info(),
+                        to: self.creator.to_account_info(),
+                    },
+                    vault_signer_seeds,
+                ),
+                vault_lamports,
+            )?;
+        }
+        
+        // token_launch, bonding_curve and trade_history are closed to the creator on exit
+        let lamports_reclaimed = self.creator.lamports()
+            .checked_sub(lamports_before)
+            .and_then(|reclaimed| reclaimed.checked_add(closing_lamports))
+            .ok_or(LaunchpadError::MathOverflow)?;
+        
+        emit!(LaunchClosed {
+            launch: token_launch_key,
+            mint: self.mint.key(),
+            creator: self.creator.key(),
+            tokens_burned,
+            lamports_reclaimed,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        msg!(
+            "Closed launch: burned {} tokens, reclaimed {} lamports",
+            tokens_burned,
+            lamports_reclaimed
+        );
         
         Ok(())
     }
@@ -2,6 +2,23 @@ use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
 use anchor_lang::prelude::*;
 use crate::errors::LaunchpadError;
 
+/// Default maximum age of a Pyth price update before it is considered stale;
+/// admins tune it per deployment through `LaunchpadConfig::max_price_staleness_seconds`
+pub const DEFAULT_PRICE_STALENESS_SECONDS: u32 = 60;
+
+/// Largest move a fresh price may make against a recently stored one (20%)
+pub const MAX_PRICE_DEVIATION_BPS: u64 = 2_000;
+/// Window after an oracle write during which the deviation clamp applies
+pub const PRICE_DEVIATION_WINDOW_SECONDS: i64 = 300;
+
+/// Sane range of Pyth exponents for a USD price feed
+pub const MIN_PYTH_EXPONENT: i32 = -12;
+pub const MAX_PYTH_EXPONENT: i32 = 0;
+
+/// Sane range for a creator-supplied SOL/USD price (scaled by 1e8): $1 to $100,000
+pub const MIN_SOL_PRICE_USD: u64 = 100_000_000;
+pub const MAX_SOL_PRICE_USD: u64 = 10_000_000_000_000;
+
 /// Pyth price feed integration for SOL/USD price
 pub struct PythPriceReader;
 
@@ -17,7 +34,7 @@ impl PythPriceReader {
     /// 
     /// # Example
     /// If SOL = $100.50, returns 10_050_000_000 (100.50 * 1e8)
-    pub fn get_sol_price_usd(price_update: &Account<PriceUpdateV2>) -> Result<u64> {
+    pub fn get_sol_price_usd(price_update: &PriceUpdateV2) -> Result<u64> {
         let price_message = &price_update.price_message;
         
         // Log price feed information for debugging
@@ -33,83 +50,328 @@ impl PythPriceReader {
             LaunchpadError::InvalidPrice
         );
         
-        // Get the price and exponent
-        let price = price_message.price;
-        let exponent = price_message.exponent;
-        
         // Pyth prices are represented as price * 10^exponent
-        // We need to scale it to our USD_SCALE (1e8)
-        // 
         // Example: If Pyth returns price=10050 with exponent=-2
         // Actual price = 10050 * 10^-2 = 100.50
         // We need: 100.50 * 1e8 = 10_050_000_000
+        let sol_price_usd = Self::scale_to_usd(price_message.price, price_message.exponent)?;
+        
+        msg!("Calculated SOL/USD price (scaled 1e8): {}", sol_price_usd);
         
-        let sol_price_usd = if exponent >= 0 {
-            // Positive exponent: multiply
-            let multiplier = 10_u64.pow(exponent as u32);
+        Ok(sol_price_usd)
+    }
+    
+    /// Rescale a Pyth `price * 10^exponent` value to USD_SCALE (1e8)
+    /// 
+    /// Performs a single u128 rescale by `10^(exponent + 8)`, rounding half up
+    /// when precision has to be dropped. Non-positive prices and exponents
+    /// outside MIN_PYTH_EXPONENT..=MAX_PYTH_EXPONENT are rejected.
+    /// 
+    /// # Arguments
+    /// * `price` - Raw Pyth price
+    /// * `exponent` - Pyth exponent
+    /// 
+    /// # Returns
+    /// * `Result<u64>` - Price scaled by 1e8
+    pub fn scale_to_usd(price: i64, exponent: i32) -> Result<u64> {
+        require!(price > 0, LaunchpadError::InvalidPrice);
+        require!(
+            (MIN_PYTH_EXPONENT..=MAX_PYTH_EXPONENT).contains(&exponent),
+            LaunchpadError::InvalidPrice
+        );
+        let price = u128::try_from(price).map_err(|_| LaunchpadError::InvalidPrice)?;
+        
+        // value * 1e8 = price * 10^(exponent + 8)
+        let shift = exponent
+            .checked_add(8)
+            .ok_or(LaunchpadError::InvalidPrice)?;
+        
+        let scaled = if shift >= 0 {
+            let multiplier = 10u128
+                .checked_pow(shift as u32)
+                .ok_or(LaunchpadError::MathOverflow)?;
             price
-                .checked_mul(multiplier as i64)
-                .ok_or(LaunchpadError::MathOverflow)?
-                .checked_mul(100_000_000)
+                .checked_mul(multiplier)
                 .ok_or(LaunchpadError::MathOverflow)?
         } else {
-            // Negative exponent: we need to adjust the scaling
-            // Target scale: 1e8
-            // Current scale: 10^exponent
-            // Adjustment: 1e8 / 10^exponent = 10^(8 - |exponent|)
-            
-            let abs_exponent = exponent.abs() as u32;
-            
-            if abs_exponent <= 8 {
-                // Scale up to reach 1e8
-                let scale_factor = 10_u64.pow(8 - abs_exponent);
-                price
-                    .checked_mul(scale_factor as i64)
-                    .ok_or(LaunchpadError::MathOverflow)?
-            } else {
-                // Scale down from higher precision
-                let scale_divisor = 10_u64.pow(abs_exponent - 8);
-                price
-                    .checked_div(scale_divisor as i64)
-                    .ok_or(LaunchpadError::MathOverflow)?
-            }
+            let divisor = 10u128
+                .checked_pow(shift.unsigned_abs())
+                .ok_or(LaunchpadError::MathOverflow)?;
+            price
+                .checked_add(divisor / 2)
+                .ok_or(LaunchpadError::MathOverflow)?
+                / divisor
         };
         
-        // Convert to u64 and validate
-        let sol_price_usd = u64::try_from(sol_price_usd)
-            .map_err(|_| LaunchpadError::InvalidPrice)?;
+        let scaled = u64::try_from(scaled).map_err(|_| LaunchpadError::MathOverflow)?;
+        require!(scaled > 0, LaunchpadError::InvalidPrice);
         
-        require!(
-            sol_price_usd > 0,
-            LaunchpadError::InvalidPrice
-        );
+        Ok(scaled)
+    }
+    
+    /// Reject a fresh price that jumps more than MAX_PRICE_DEVIATION_BPS away from
+    /// a price stored within the last PRICE_DEVIATION_WINDOW_SECONDS, so a spiked
+    /// update can't be written as the backup price that later stale-window trades use
+    /// 
+    /// # Arguments
+    /// * `stored_price` - Currently stored SOL price (scaled by 1e8)
+    /// * `stored_at` - When the stored price was read from the oracle (0 = never)
+    /// * `fresh_price` - Newly read SOL price (scaled by 1e8)
+    /// * `now` - Current unix timestamp
+    pub fn check_price_deviation(
+        stored_price: u64,
+        stored_at: i64,
+        fresh_price: u64,
+        now: i64,
+    ) -> Result<()> {
+        // Nothing recent to compare against (creator-supplied or expired price)
+        if stored_price == 0
+            || stored_at == 0
+            || now.saturating_sub(stored_at) >= PRICE_DEVIATION_WINDOW_SECONDS
+        {
+            return Ok(());
+        }
         
-        msg!("Calculated SOL/USD price (scaled 1e8): {}", sol_price_usd);
+        // Compare cross-multiplied so a move just past the bound isn't rounded down onto it
+        let deviation = (fresh_price.abs_diff(stored_price) as u128) * 10_000;
+        let deviation_bps = deviation / (stored_price as u128);
         
-        Ok(sol_price_usd)
+        if deviation > (MAX_PRICE_DEVIATION_BPS as u128) * (stored_price as u128) {
+            msg!(
+                "Fresh price {} deviates {} bps from stored {} (max {})",
+                fresh_price,
+                deviation_bps,
+                stored_price,
+                MAX_PRICE_DEVIATION_BPS
+            );
+            return err!(LaunchpadError::PriceDeviationTooHigh);
+        }
+        
+        Ok(())
+    }
+    
+    /// Whether a SOL/USD price (scaled by 1e8) falls within
+    /// MIN_SOL_PRICE_USD..=MAX_SOL_PRICE_USD; outside it the curve is untradeable
+    pub fn is_sane_sol_price(sol_price_usd: u64) -> bool {
+        (MIN_SOL_PRICE_USD..=MAX_SOL_PRICE_USD).contains(&sol_price_usd)
     }
     
     /// Check if the price update is recent (within acceptable staleness threshold)
-    /// Returns true if fresh, false if stale (but doesn't error)
+    /// Returns true if fresh, false if stale (but doesn't error); a price dated
+    /// in the future errors with FuturePriceTimestamp
     /// 
     /// # Arguments
     /// * `price_update` - Pyth PriceUpdateV2 account
-    /// * `max_staleness_seconds` - Maximum age of price data in seconds (default: 60)
+    /// * `max_staleness_seconds` - Maximum age of price data in seconds (see `LaunchpadConfig::max_price_staleness_seconds`)
     pub fn is_price_fresh(
         price_update: &Account<PriceUpdateV2>,
         max_staleness_seconds: i64,
     ) -> Result<bool> {
-        let current_time = Clock::get()?.unix_timestamp;
-        let publish_time = price_update.price_message.publish_time;
+        Self::is_price_fresh_at(price_update, max_staleness_seconds, Clock::get()?.unix_timestamp)
+    }
+    
+    /// `is_price_fresh` against an explicit `now`
+    pub fn is_price_fresh_at(
+        price_update: &PriceUpdateV2,
+        max_staleness_seconds: i64,
+        now: i64,
+    ) -> Result<bool> {
+        let age = Self::price_age(price_update.price_message.publish_time, now)?;
+        let is_fresh = age <= max_staleness_seconds;
         
-        let age = current_time
+        msg!("Price age: {} seconds (max: {}), fresh: {}", age, max_staleness_seconds, is_fresh);
+        
+        Ok(is_fresh)
+    }
+    
+    /// Whether a trade can execute at this update's price at `now`. A future-dated
+    /// update counts as not fresh here, so the trade falls back to the stored price
+    /// instead of failing; `is_price_fresh_at` still rejects it for refreshes
+    pub fn is_trade_price_fresh_at(
+        price_update: &PriceUpdateV2,
+        max_staleness_seconds: i64,
+        now: i64,
+    ) -> bool {
+        Self::is_price_fresh_at(price_update, max_staleness_seconds, now).unwrap_or(false)
+    }
+    
+    /// Seconds elapsed between `publish_time` and `now`. A price published after
+    /// `now` (clock skew) is neither fresh nor stale, so it is rejected outright
+    /// rather than read as stale and silently replaced by the stored price
+    pub fn price_age(publish_time: i64, now: i64) -> Result<i64> {
+        if publish_time > now {
+            msg!("Price published at {} is ahead of the clock ({})", publish_time, now);
+            return err!(LaunchpadError::FuturePriceTimestamp);
+        }
+        
+        let age = now
             .checked_sub(publish_time)
             .ok_or(LaunchpadError::InvalidPrice)?;
         
-        let is_fresh = age >= 0 && age <= max_staleness_seconds;
+        Ok(age)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pyth_solana_receiver_sdk::price_update::{PriceFeedMessage, VerificationLevel};
+    
+    fn price_update_published_at(publish_time: i64) -> PriceUpdateV2 {
+        PriceUpdateV2 {
+            write_authority: Pubkey::default(),
+            verification_level: VerificationLevel::Full,
+            price_message: PriceFeedMessage {
+                feed_id: [0; 32],
+                price: 15_000_000_000,
+                conf: 0,
+                exponent: -8,
+                publish_time,
+                prev_publish_time: publish_time - 1,
+                ema_price: 15_000_000_000,
+                ema_conf: 0,
+            },
+            posted_slot: 0,
+        }
+    }
+    
+    #[test]
+    fn test_scale_exponent_minus_6() {
+        // $150.123456 with 6 decimals
+        let scaled = PythPriceReader::scale_to_usd(150_123_456, -6).unwrap();
+        assert_eq!(scaled, 15_012_345_600);
+    }
+    
+    #[test]
+    fn test_scale_exponent_minus_8() {
+        // $150.12345678 is already at USD_SCALE
+        let scaled = PythPriceReader::scale_to_usd(15_012_345_678, -8).unwrap();
+        assert_eq!(scaled, 15_012_345_678);
+    }
+    
+    #[test]
+    fn test_scale_exponent_minus_10_rounds() {
+        // $150.1234567850 rounds half up to $150.12345679
+        let scaled = PythPriceReader::scale_to_usd(1_501_234_567_850, -10).unwrap();
+        assert_eq!(scaled, 15_012_345_679);
         
-        msg!("Price age: {} seconds (max: {}), fresh: {}", age, max_staleness_seconds, is_fresh);
+        // $150.1234567849 rounds down to $150.12345678
+        let scaled = PythPriceReader::scale_to_usd(1_501_234_567_849, -10).unwrap();
+        assert_eq!(scaled, 15_012_345_678);
+    }
+    
+    #[test]
+    fn test_scale_exponent_zero() {
+        // 150 * 10^0 = $150
+        let scaled = PythPriceReader::scale_to_usd(150, 0).unwrap();
+        assert_eq!(scaled, 15_000_000_000);
+    }
+    
+    #[test]
+    fn test_scale_rejects_malformed_exponents() {
+        for exponent in [1, 10, -13, i32::MIN, i32::MAX] {
+            assert_eq!(
+                PythPriceReader::scale_to_usd(15_000_000_000, exponent).unwrap_err(),
+                LaunchpadError::InvalidPrice.into(),
+                "exponent {} should be rejected",
+                exponent
+            );
+        }
+    }
+    
+    #[test]
+    fn test_scale_rejects_non_positive_prices() {
+        for price in [0, -1, -15_000_000_000, i64::MIN] {
+            assert_eq!(
+                PythPriceReader::scale_to_usd(price, -8).unwrap_err(),
+                LaunchpadError::InvalidPrice.into(),
+                "price {} should be rejected",
+                price
+            );
+        }
+    }
+    
+    #[test]
+    fn test_price_deviation_clamp() {
+        let stored = 15_000_000_000; // $150
+        let stored_at = 1_700_000_000;
+        let now = stored_at + 30;
         
-        Ok(is_fresh)
+        // Within 20% is accepted
+        assert!(PythPriceReader::check_price_deviation(stored, stored_at, 18_000_000_000, now).is_ok());
+        assert!(PythPriceReader::check_price_deviation(stored, stored_at, 12_000_000_000, now).is_ok());
+        
+        // A spike inside the window is rejected
+        for spiked in [18_000_000_001, 11_999_999_999, 150_000_000_000, 1] {
+            assert_eq!(
+                PythPriceReader::check_price_deviation(stored, stored_at, spiked, now).unwrap_err(),
+                LaunchpadError::PriceDeviationTooHigh.into(),
+                "price {} should be clamped",
+                spiked
+            );
+        }
+        
+        // The same move is accepted once the window has passed
+        let later = stored_at + PRICE_DEVIATION_WINDOW_SECONDS;
+        assert!(PythPriceReader::check_price_deviation(stored, stored_at, 150_000_000_000, later).is_ok());
+        
+        // A price never read from the oracle is not clamped
+        assert!(PythPriceReader::check_price_deviation(stored, 0, 150_000_000_000, now).is_ok());
+    }
+    
+    #[test]
+    fn test_scale_rounding_to_zero_is_rejected() {
+        // 0.00000000004 USD rounds to 0 at 1e8 scale
+        assert_eq!(
+            PythPriceReader::scale_to_usd(4, -12).unwrap_err(),
+            LaunchpadError::InvalidPrice.into()
+        );
+    }
+    
+    #[test]
+    fn test_sane_sol_price_range() {
+        assert!(!PythPriceReader::is_sane_sol_price(0));
+        assert!(!PythPriceReader::is_sane_sol_price(1));
+        assert!(!PythPriceReader::is_sane_sol_price(MIN_SOL_PRICE_USD - 1));
+        assert!(PythPriceReader::is_sane_sol_price(MIN_SOL_PRICE_USD));
+        assert!(PythPriceReader::is_sane_sol_price(15_000_000_000)); // $150
+        assert!(PythPriceReader::is_sane_sol_price(MAX_SOL_PRICE_USD));
+        assert!(!PythPriceReader::is_sane_sol_price(MAX_SOL_PRICE_USD + 1));
+    }
+    
+    #[test]
+    fn test_price_freshness_by_age() {
+        let now = 1_700_000_000;
+        let max_staleness = DEFAULT_PRICE_STALENESS_SECONDS as i64;
+        
+        for age in [0, 1, max_staleness] {
+            let update = price_update_published_at(now - age);
+            assert!(PythPriceReader::is_price_fresh_at(&update, max_staleness, now).unwrap());
+        }
+        
+        let update = price_update_published_at(now - max_staleness - 1);
+        assert!(!PythPriceReader::is_price_fresh_at(&update, max_staleness, now).unwrap());
+    }
+    
+    #[test]
+    fn test_future_dated_price_is_rejected_distinctly() {
+        let now = 1_700_000_000;
+        
+        // Even a one-second skew is neither fresh nor stale
+        for ahead in [1, 30, i64::MAX - now] {
+            let update = price_update_published_at(now + ahead);
+            assert_eq!(
+                PythPriceReader::is_price_fresh_at(&update, 60, now).unwrap_err(),
+                LaunchpadError::FuturePriceTimestamp.into(),
+                "price {} seconds ahead should be rejected",
+                ahead
+            );
+        }
+        
+        // An age too large for i64 is still an invalid price
+        assert_eq!(
+            PythPriceReader::price_age(i64::MIN, now).unwrap_err(),
+            LaunchpadError::InvalidPrice.into()
+        );
     }
 }
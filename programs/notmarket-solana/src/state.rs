@@ -1,18 +1,27 @@
 use anchor_lang::prelude::*;
+use crate::errors::LaunchpadError;
+use crate::events::SolPriceUpdated;
+use crate::pyth_price::{PythPriceReader, DEFAULT_PRICE_STALENESS_SECONDS};
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
 
 /// Fixed tokenomics constants
+pub const TOKEN_DECIMALS: u8 = 9;                        // Mint decimals used for pricing math
 pub const TOTAL_SUPPLY: u64 = 1_000_000_000_000_000_000; // 1 billion tokens (with 9 decimals)
 pub const CURVE_SUPPLY: u64 = 800_000_000_000_000_000;   // 800 million on bonding curve
 pub const LP_SUPPLY: u64 = 200_000_000_000_000_000;      // 200 million for LP
 pub const GRADUATION_USD: u64 = 12_000;                  // $12,000 USD threshold
+pub const NEAR_GRADUATION_BPS: u64 = 9_500;              // 95% of curve sold triggers NearGraduation
 
 // Bonding curve price range (in lamports per token with decimals)
 // Starting price: $0.00000420 
 // Ending price: $0.00006900
 // Assuming SOL = $150 USD (can be adjusted)
-pub const START_PRICE_USD: u64 = 420;        // $0.00000420 * 100_000_000 (scaled)
-pub const END_PRICE_USD: u64 = 6_900;        // $0.00006900 * 100_000_000 (scaled)
-pub const USD_SCALE: u64 = 100_000_000;      // Scale factor for USD calculations
+pub const START_PRICE_USD: u64 = 4_200_000;  // $0.00000420 * PRICE_SCALE
+pub const END_PRICE_USD: u64 = 69_000_000;   // $0.00006900 * PRICE_SCALE
+pub const PRICE_SCALE: u64 = 1_000_000_000_000; // Scale factor for per-token USD prices (1e12)
+pub const USD_SCALE: u64 = 100_000_000;      // Scale factor for SOL/USD prices and USD totals (1e8)
+
+pub const SECONDS_PER_YEAR: u64 = 365 * 24 * 60 * 60; // For simple annualized returns
 
 /// Main configuration account for the launchpad
 #[account]
@@ -27,18 +36,147 @@ pub struct LaunchpadConfig {
     pub whitelisted_wallet_1: Pubkey,
     /// Second whitelisted wallet that can launch tokens
     pub whitelisted_wallet_2: Pubkey,
+    /// Accrue trading fees in the SOL vault instead of transferring per trade
+    pub accrue_fees: bool,
+    /// Minimum SOL reserve (lamports) required to graduate, regardless of USD value
+    pub min_graduation_sol_lamports: u64,
+    /// Minimum tokens per trade (with decimals); sells of the full balance are exempt
+    pub min_trade_tokens: u64,
+    /// DEX program whose accounts may receive graduation liquidity
+    pub liquidity_program: Pubkey,
+    /// Pre-registered treasury that may receive graduation liquidity
+    pub liquidity_treasury: Pubkey,
+    /// Loyalty token whose holders get a platform fee discount (default = disabled)
+    pub fee_discount_mint: Pubkey,
+    /// Discount on the platform fee for loyalty token holders, in basis points of the fee
+    pub fee_discount_bps: u16,
+    /// Burn whatever is left in the curve token account when a curve graduates
+    pub burn_unsold_on_graduation: bool,
+    /// Success fee skimmed from the SOL vault at withdraw_liquidity, in basis points
+    pub graduation_fee_bps: u16,
+    /// URI prefixes (scheme + host + '/') metadata must be hosted under; empty allows any
+    pub allowed_uri_prefixes: Vec<String>,
+    /// Extra sell fee at full ramp, in basis points on top of the platform fee (0 = flat fee)
+    pub sell_fee_ramp_max_bps: u16,
+    /// Share of the SOL reserve (bps) a sell must take to pay the full ramp
+    pub sell_fee_ramp_full_share_bps: u16,
+    /// Minimum seconds between any two trades on the same curve (0 = no cooldown)
+    pub min_inter_trade_seconds: u32,
+    /// Minimum seconds between metadata URI / description updates per launch (0 = no cooldown)
+    pub metadata_update_cooldown_seconds: u32,
+    /// Let trades price off the stored backup SOL price when no Pyth feed account is passed
+    pub manual_price_allowed: bool,
+    /// Platform fee skimmed from every withdraw_liquidity SOL transfer, in basis points
+    pub withdraw_fee_bps: u16,
+    /// Weighted recipients sharing each per-trade platform fee; empty sends it all to fee_recipient
+    pub fee_splits: Vec<FeeSplit>,
     /// Bump seed for PDA
     pub bump: u8,
+    /// Zeroed headroom; new fields are carved out of it so existing accounts need no realloc
+    pub reserved: [u8; 64],
+    /// Seconds after graduation during which sells (not buys) still hit the curve (0 = none),
+    /// fixed on each curve when it graduates
+    pub graduation_grace_seconds: u32,
+    /// Notice a creator pause must give before trading stops (0 = pauses take effect immediately)
+    pub deactivation_delay_seconds: u32,
+    /// Minimum time LP liquidity stays in the vaults after graduation before it can be withdrawn (0 = none)
+    pub min_lock_after_graduation_seconds: u32,
+    /// Maximum age of a Pyth update before trades fall back to the stored backup price
+    /// (0 = DEFAULT_PRICE_STALENESS_SECONDS)
+    pub max_price_staleness_seconds: u32,
+    /// Distinct wallets that must hold before a curve can graduate (0 = no minimum)
+    pub min_holders_for_graduation: u32,
+    /// Linear vesting period for creator allocations, fixed on each launch at creation (0 = unlocked)
+    pub creator_vesting_seconds: u32,
+    /// Largest buy as a share of the curve's remaining token_reserve, in basis points (0 = no cap)
+    pub max_buy_fraction_bps: u16,
+}
+
+/// One recipient's share of the platform trading fee
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct FeeSplit {
+    pub recipient: Pubkey,
+    /// Share of the fee in basis points; a config's splits sum to 10_000
+    pub weight_bps: u16,
+}
+
+/// Per-launch platform fee interpolated linearly over curve progress:
+/// `start_bps` with nothing sold, `end_bps` once the launch's curve supply is sold
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct FeeSchedule {
+    pub start_bps: u16,
+    pub end_bps: u16,
+}
+
+impl FeeSchedule {
+    /// Cap on either end of the schedule, matching the platform fee cap (10%)
+    pub const MAX_BPS: u16 = 1_000;
+    
+    pub fn is_valid(&self) -> bool {
+        self.start_bps <= Self::MAX_BPS && self.end_bps <= Self::MAX_BPS
+    }
+    
+    /// Fee in basis points with `tokens_sold` of `curve_supply` sold
+    pub fn fee_bps_at(&self, tokens_sold: u64, curve_supply: u64) -> u16 {
+        if curve_supply == 0 {
+            return self.end_bps;
+        }
+        let sold = tokens_sold.min(curve_supply) as i128;
+        let span = self.end_bps as i128 - self.start_bps as i128;
+        (self.start_bps as i128 + span * sold / curve_supply as i128) as u16
+    }
 }
 
 impl LaunchpadConfig {
+    /// Maximum graduation success fee (10%)
+    pub const MAX_GRADUATION_FEE_BPS: u16 = 1_000;
+    /// Maximum number of allowed metadata URI prefixes
+    pub const MAX_URI_PREFIXES: usize = 4;
+    /// Maximum length of a single allowed metadata URI prefix
+    pub const MAX_URI_PREFIX_LEN: usize = 64;
+    /// Maximum extra sell fee from the size ramp (10%)
+    pub const MAX_SELL_FEE_RAMP_BPS: u16 = 1_000;
+    /// Maximum platform fee on withdrawn liquidity (10%)
+    pub const MAX_WITHDRAW_FEE_BPS: u16 = 1_000;
+    /// Maximum number of weighted platform fee recipients
+    pub const MAX_FEE_SPLITS: usize = 4;
+    /// Shortest accepted Pyth staleness window; tighter rejects most updates in practice
+    pub const MIN_PRICE_STALENESS_SECONDS: u32 = 5;
+    /// Longest accepted Pyth staleness window (1 hour)
+    pub const MAX_PRICE_STALENESS_SECONDS: u32 = 3_600;
+    
     pub const LEN: usize = 8 + // discriminator
         32 + // authority
         32 + // fee_recipient
         2 +  // platform_fee_bps
         32 + // whitelisted_wallet_1
         32 + // whitelisted_wallet_2
-        1;   // bump
+        1 +  // accrue_fees
+        8 +  // min_graduation_sol_lamports
+        8 +  // min_trade_tokens
+        32 + // liquidity_program
+        32 + // liquidity_treasury
+        32 + // fee_discount_mint
+        2 +  // fee_discount_bps
+        1 +  // burn_unsold_on_graduation
+        2 +  // graduation_fee_bps
+        4 + Self::MAX_URI_PREFIXES * (4 + Self::MAX_URI_PREFIX_LEN) + // allowed_uri_prefixes (Vec<String>)
+        2 +  // sell_fee_ramp_max_bps
+        2 +  // sell_fee_ramp_full_share_bps
+        4 +  // min_inter_trade_seconds
+        4 +  // metadata_update_cooldown_seconds
+        1 +  // manual_price_allowed
+        2 +  // withdraw_fee_bps
+        4 + Self::MAX_FEE_SPLITS * (32 + 2) + // fee_splits (Vec<FeeSplit>)
+        1 +  // bump
+        64 + // reserved
+        4 +  // graduation_grace_seconds
+        4 +  // deactivation_delay_seconds
+        4 +  // min_lock_after_graduation_seconds
+        4 +  // max_price_staleness_seconds
+        4 +  // min_holders_for_graduation
+        4 +  // creator_vesting_seconds
+        2;  // max_buy_fraction_bps
     
     /// Check if a wallet is authorized to create token launches
     /// Returns true if wallet is admin or a non-default whitelisted wallet
@@ -53,6 +191,110 @@ impl LaunchpadConfig {
         (wallet == &self.whitelisted_wallet_1 && self.whitelisted_wallet_1 != default_pubkey)
             || (wallet == &self.whitelisted_wallet_2 && self.whitelisted_wallet_2 != default_pubkey)
     }
+    
+    /// Pyth staleness window trades apply, defaulting when none has been set
+    pub fn price_staleness_seconds(&self) -> u32 {
+        if self.max_price_staleness_seconds == 0 {
+            DEFAULT_PRICE_STALENESS_SECONDS
+        } else {
+            self.max_price_staleness_seconds
+        }
+    }
+    
+    /// Graduation success fee owed on `sol_amount` lamports withdrawn for liquidity
+    pub fn graduation_fee(&self, sol_amount: u64) -> Result<u64> {
+        let fee = (sol_amount as u128)
+            .checked_mul(self.graduation_fee_bps as u128)
+            .ok_or(LaunchpadError::MathOverflow)?
+            / 10_000;
+        Ok(fee as u64)
+    }
+    
+    /// Platform withdrawal fee owed on `sol_amount` lamports moved by a single
+    /// withdraw_liquidity call, charged on top of any graduation fee
+    pub fn withdraw_fee(&self, sol_amount: u64) -> Result<u64> {
+        let fee = (sol_amount as u128)
+            .checked_mul(self.withdraw_fee_bps as u128)
+            .ok_or(LaunchpadError::MathOverflow)?
+            / 10_000;
+        Ok(fee as u64)
+    }
+    
+    /// Platform fee in basis points for a trader, after any loyalty discount off
+    /// `base_fee_bps` (the launch's fee, see `BondingCurve::platform_fee_bps`)
+    pub fn effective_fee_bps(&self, base_fee_bps: u16, holds_discount_token: bool) -> u16 {
+        if !holds_discount_token || self.fee_discount_mint == Pubkey::default() {
+            return base_fee_bps;
+        }
+        let discount = (base_fee_bps as u32) * (self.fee_discount_bps as u32) / 10_000;
+        base_fee_bps - discount as u16
+    }
+    
+    /// Sell fee in basis points, ramping linearly with the share of the reserve
+    /// the sell takes out: `base_fee_bps` for tiny sells, up to `base_fee_bps +
+    /// sell_fee_ramp_max_bps` once the sell takes `sell_fee_ramp_full_share_bps`
+    pub fn sell_fee_bps(&self, base_fee_bps: u16, proceeds: u64, sol_reserve: u64) -> u16 {
+        if self.sell_fee_ramp_max_bps == 0 || self.sell_fee_ramp_full_share_bps == 0 || sol_reserve == 0 {
+            return base_fee_bps;
+        }
+        let full_share = self.sell_fee_ramp_full_share_bps as u128;
+        let share_bps = ((proceeds as u128) * 10_000 / (sol_reserve as u128)).min(full_share);
+        let extra = (self.sell_fee_ramp_max_bps as u128) * share_bps / full_share;
+        base_fee_bps + extra as u16
+    }
+    
+    /// Check if graduation liquidity may be sent to `recipient` (owned by `recipient_owner`)
+    /// Only the registered treasury or accounts owned by the DEX program qualify;
+    /// unset (default) destinations never match
+    pub fn is_approved_liquidity_recipient(&self, recipient: &Pubkey, recipient_owner: &Pubkey) -> bool {
+        let default_pubkey = Pubkey::default();
+        (recipient == &self.liquidity_treasury && self.liquidity_treasury != default_pubkey)
+            || (recipient_owner == &self.liquidity_program && self.liquidity_program != default_pubkey)
+    }
+    
+    /// Check a fee split table: empty, or up to MAX_FEE_SPLITS distinct, non-default
+    /// recipients with non-zero weights summing to exactly 10_000 bps
+    pub fn is_valid_fee_split(fee_splits: &[FeeSplit]) -> bool {
+        if fee_splits.is_empty() {
+            return true;
+        }
+        let total_bps: u32 = fee_splits.iter().map(|split| split.weight_bps as u32).sum();
+        fee_splits.len() <= Self::MAX_FEE_SPLITS
+            && total_bps == 10_000
+            && fee_splits.iter().enumerate().all(|(i, split)| {
+                split.weight_bps > 0
+                    && split.recipient != Pubkey::default()
+                    && fee_splits[..i].iter().all(|earlier| earlier.recipient != split.recipient)
+            })
+    }
+    
+    /// Divide `fee` across fee_splits by weight, in the same order
+    /// Shares round down and the last recipient takes the remainder, so the
+    /// shares always sum to exactly `fee`
+    pub fn split_fee(&self, fee: u64) -> Result<Vec<u64>> {
+        let mut shares = Vec::with_capacity(self.fee_splits.len());
+        let mut remaining = fee;
+        for (i, split) in self.fee_splits.iter().enumerate() {
+            let share = if i + 1 == self.fee_splits.len() {
+                remaining
+            } else {
+                ((fee as u128)
+                    .checked_mul(split.weight_bps as u128)
+                    .ok_or(LaunchpadError::MathOverflow)?
+                    / 10_000) as u64
+            };
+            remaining = remaining.checked_sub(share).ok_or(LaunchpadError::MathOverflow)?;
+            shares.push(share);
+        }
+        Ok(shares)
+    }
+    
+    /// Check if a metadata URI is hosted under one of the allowed prefixes
+    /// An empty list allows any URI
+    pub fn is_allowed_uri(&self, uri: &str) -> bool {
+        self.allowed_uri_prefixes.is_empty()
+            || self.allowed_uri_prefixes.iter().any(|prefix| uri.starts_with(prefix.as_str()))
+    }
 }
 
 /// Represents a token launch on the platform
@@ -78,18 +320,52 @@ pub struct TokenLaunch {
     pub circulating_supply: u64,
     /// Timestamp of launch
     pub launch_timestamp: i64,
+    /// Slot the launch was created in; orders launches that share a timestamp
+    pub launch_slot: u64,
+    /// Timestamp when trading opens (0 = immediately)
+    pub trading_starts_at: i64,
     /// Whether trading is active
     pub is_active: bool,
+    /// Whether the creator can still update metadata URI / description
+    pub is_mutable: bool,
+    /// Admin freeze, blocks trading regardless of is_active (creator can't lift it)
+    pub admin_frozen: bool,
+    /// When the creator last paused trading (0 = not currently paused)
+    pub paused_at: i64,
+    /// Seconds spent paused across all completed pause/resume cycles
+    pub total_paused_seconds: u64,
+    /// Timestamp of the last metadata URI or description update (0 = never updated)
+    pub last_metadata_update: i64,
     /// Bump seed for PDA
     pub bump: u8,
+    /// Zeroed headroom; new fields are carved out of it so existing accounts need no realloc
+    pub reserved: [u8; 64],
+    /// Buy-only launch: holders can't sell back to the curve and exit on the DEX after graduation
+    /// (false = sells allowed; read through `sells_enabled`)
+    pub sells_disabled: bool,
+    /// When a scheduled creator pause takes effect (0 = none pending)
+    pub deactivates_at: i64,
+    /// Why trading last stopped (None while active)
+    pub deactivation_reason: Option<LaunchStatusChange>,
 }
 
 impl TokenLaunch {
-    pub const MAX_URI_LEN: usize = 200;
-    pub const MAX_NAME_LEN: usize = 32;
+    // String limits are in UTF-8 bytes, which is what account space is sized by
+    
+    /// 200 bytes by default, 512 when built with the `long-metadata-uri` feature.
+    /// `LEN` grows with it, so the larger bound costs every new launch ~6,960 lamports
+    /// of rent per extra byte (~0.0022 SOL), and launches created under the smaller
+    /// bound keep their smaller accounts: they need a realloc before storing a longer
+    /// URI. The 1232-byte create_token_launch transaction is the practical ceiling.
+    pub const MAX_URI_LEN: usize = if cfg!(feature = "long-metadata-uri") { 512 } else { 200 };
+    pub const MAX_NAME_LEN: usize = 32;          // Also the max PDA seed length (name seeds the mint)
     pub const MAX_SYMBOL_LEN: usize = 10;
     pub const MAX_DESCRIPTION_LEN: usize = 500;
     
+    // Display limits in characters, enforced alongside the byte limits
+    pub const MAX_NAME_CHARS: usize = 32;
+    pub const MAX_SYMBOL_CHARS: usize = 10;
+    
     pub const LEN: usize = 8 + // discriminator
         32 + // creator
         32 + // mint
@@ -101,8 +377,95 @@ impl TokenLaunch {
         8 +  // total_supply
         8 +  // circulating_supply
         8 +  // launch_timestamp
+        8 +  // launch_slot
+        8 +  // trading_starts_at
         1 +  // is_active
-        1;   // bump
+        1 +  // is_mutable
+        1 +  // admin_frozen
+        8 +  // paused_at
+        8 +  // total_paused_seconds
+        8 +  // last_metadata_update
+        1 +  // bump
+        64 + // reserved
+        1 +  // sells_disabled
+        8 +  // deactivates_at
+        1 + 1;  // deactivation_reason (Option<LaunchStatusChange>)
+    
+    /// Flip is_active and account for pause time. Returns the length of the pause
+    /// that just ended (0 when pausing).
+    pub fn toggle_paused(&mut self, now: i64) -> Result<u64> {
+        self.is_active = !self.is_active;
+        
+        if !self.is_active {
+            self.paused_at = now;
+            self.deactivation_reason = Some(LaunchStatusChange::Pause);
+            return Ok(0);
+        }
+        
+        let paused_for = u64::try_from(now.saturating_sub(self.paused_at)).unwrap_or(0);
+        self.total_paused_seconds = self.total_paused_seconds
+            .checked_add(paused_for)
+            .ok_or(LaunchpadError::MathOverflow)?;
+        self.paused_at = 0;
+        self.deactivation_reason = None;
+        Ok(paused_for)
+    }
+    
+    /// Whether trades are accepted at `now`: active, with no scheduled pause that has come due
+    pub fn is_trading_active(&self, now: i64) -> bool {
+        self.is_active && (self.deactivates_at == 0 || now < self.deactivates_at)
+    }
+    
+    /// Apply a scheduled pause whose notice has run out, dating the pause from
+    /// when it took effect rather than from when it was noticed
+    pub fn settle_scheduled_pause(&mut self, now: i64) {
+        if self.deactivates_at > 0 && now >= self.deactivates_at {
+            self.is_active = false;
+            self.paused_at = self.deactivates_at;
+            self.deactivation_reason = Some(LaunchStatusChange::Pause);
+            self.deactivates_at = 0;
+        }
+    }
+    
+    /// Check if a metadata update at `now` falls within the cooldown after the last one
+    pub fn is_in_metadata_cooldown(&self, now: i64, cooldown_seconds: u32) -> bool {
+        cooldown_seconds > 0
+            && self.last_metadata_update > 0
+            && now.saturating_sub(self.last_metadata_update) < cooldown_seconds as i64
+    }
+    
+    /// Whether holders can sell back to the curve
+    pub fn sells_enabled(&self) -> bool {
+        !self.sells_disabled
+    }
+    
+    /// Check if trading has opened for this launch at the given time
+    pub fn has_trading_started(&self, now: i64) -> bool {
+        self.trading_starts_at == 0 || now >= self.trading_starts_at
+    }
+    
+    /// Check a name against both the byte limit (account space, mint seed) and the character limit
+    pub fn name_fits(name: &str) -> bool {
+        name.len() <= Self::MAX_NAME_LEN && name.chars().count() <= Self::MAX_NAME_CHARS
+    }
+    
+    /// Check a symbol against both the byte limit and the character limit
+    pub fn symbol_fits(symbol: &str) -> bool {
+        symbol.len() <= Self::MAX_SYMBOL_LEN && symbol.chars().count() <= Self::MAX_SYMBOL_CHARS
+    }
+    
+    /// Names must be non-empty printable ASCII, which rules out control,
+    /// zero-width and homoglyph characters used to impersonate other tokens
+    pub fn is_valid_name(name: &str) -> bool {
+        !name.is_empty()
+            && name.bytes().all(|b| b.is_ascii_graphic() || b == b' ')
+            && name.trim() == name
+    }
+    
+    /// Symbols must be non-empty printable ASCII without whitespace
+    pub fn is_valid_symbol(symbol: &str) -> bool {
+        !symbol.is_empty() && symbol.bytes().all(|b| b.is_ascii_graphic())
+    }
 }
 
 /// Bonding curve state for pricing
@@ -118,14 +481,64 @@ pub struct BondingCurve {
     pub tokens_sold: u64,
     /// SOL price in USD (scaled by 1e8) - updated via oracle
     pub sol_price_usd: u64,
+    /// When sol_price_usd was last read from the oracle (0 = creator-supplied)
+    pub sol_price_updated_at: i64,
     /// Total volume traded (in lamports)
     pub total_volume: u64,
     /// Number of trades
     pub trade_count: u64,
     /// Whether the curve has graduated to DEX
     pub is_graduated: bool,
+    /// Whether the NearGraduation event has already been emitted (sticky)
+    pub near_graduation_notified: bool,
+    /// Platform fees held in the SOL vault awaiting withdraw_fees (not part of sol_reserve)
+    pub accrued_fees: u64,
+    /// Number of wallets currently holding a non-zero position
+    pub holder_count: u64,
+    /// Number of distinct wallets that have ever bought (never decremented)
+    pub unique_buyers: u64,
+    /// Hard cap on sol_reserve in lamports, independent of graduation (0 = no cap)
+    pub max_sol_raise_lamports: u64,
+    /// SOL reserve at which the curve graduates regardless of tokens sold (0 = token/USD graduation)
+    pub target_sol_vault_lamports: u64,
+    /// Trades allowed on this curve before buys and sells stop (0 = unlimited)
+    pub max_trades: u64,
+    /// Pyth PriceUpdateV2 account trades and refreshes must read the SOL/USD price from
+    pub expected_price_feed: Pubkey,
+    /// Timestamp of the last buy or sell on this curve (0 = never traded)
+    pub last_trade_time: i64,
+    /// Post-graduation handling of the raised SOL and LP allocation, fixed at creation
+    pub graduation_action: GraduationAction,
+    /// Where withdraw_liquidity sent the graduation liquidity (None until withdrawn)
+    pub pool_address: Option<Pubkey>,
     /// Bump seed for PDA
     pub bump: u8,
+    /// Zeroed headroom; new fields are carved out of it so existing accounts need no realloc
+    pub reserved: [u8; 64],
+    /// Id of the latest position snapshot taken for airdrops (0 = none yet)
+    pub snapshot_id: u64,
+    /// Timestamp the curve graduated (0 = not graduated)
+    pub graduated_at: i64,
+    /// Sell-only grace window copied from the config at graduation, so later config
+    /// changes can't reopen or cut short a running window
+    pub graduation_grace_seconds: u32,
+    /// Shape of the price curve between the start and end price, fixed at creation
+    pub curve_type: CurveType,
+    /// Lamports moved out of the SOL vault by liquidity withdrawals, including their fees
+    pub liquidity_sol_withdrawn: u64,
+    /// LP allocation tokens moved out of the LP vault by liquidity withdrawals
+    pub liquidity_tokens_withdrawn: u64,
+    /// Platform fee schedule over curve progress, replacing the config's flat fee (None = flat)
+    pub fee_schedule_by_progress: Option<FeeSchedule>,
+    /// Bump seed of the sol_vault PDA, used for its signer seeds (0 = not stored; read
+    /// through `vault_bump`)
+    pub sol_vault_bump: u8,
+    /// Tokens minted to the creator vault at creation instead of the curve
+    pub creator_allocation: u64,
+    /// Creator allocation tokens already claimed from the creator vault
+    pub creator_allocation_claimed: u64,
+    /// Vesting period of the creator allocation from launch, copied from the config at creation
+    pub creator_vesting_seconds: u32,
 }
 
 impl BondingCurve {
@@ -135,31 +548,399 @@ impl BondingCurve {
         8 +  // token_reserve
         8 +  // tokens_sold
         8 +  // sol_price_usd
+        8 +  // sol_price_updated_at
         8 +  // total_volume
         8 +  // trade_count
         1 +  // is_graduated
-        1;   // bump
+        1 +  // near_graduation_notified
+        8 +  // accrued_fees
+        8 +  // holder_count
+        8 +  // unique_buyers
+        8 +  // max_sol_raise_lamports
+        8 +  // target_sol_vault_lamports
+        8 +  // max_trades
+        32 + // expected_price_feed
+        8 +  // last_trade_time
+        1 +  // graduation_action (enum)
+        1 + 32 + // pool_address (Option<Pubkey>)
+        1 +  // bump
+        64 + // reserved
+        8 +  // snapshot_id
+        8 +  // graduated_at
+        4 +  // graduation_grace_seconds
+        1 +  // curve_type (enum)
+        8 +  // liquidity_sol_withdrawn
+        8 +  // liquidity_tokens_withdrawn
+        1 + 2 + 2 + // fee_schedule_by_progress (Option<FeeSchedule>)
+        1 +  // sol_vault_bump
+        8 +  // creator_allocation
+        8 +  // creator_allocation_claimed
+        4;  // creator_vesting_seconds
     
-    /// Check if curve has reached graduation (800M tokens sold, $12k raised)
-    pub fn should_graduate(&self) -> bool {
-        if self.is_graduated {
-            return false;
+    /// Single writer for the stored SOL/USD price: stores `new_price` and emits
+    /// SolPriceUpdated if it changed. Only Pyth prices count as oracle reads;
+    /// creator and manual prices leave `sol_price_updated_at` at 0 so the next
+    /// oracle read is not deviation-clamped against them
+    pub fn set_sol_price(&mut self, new_price: u64, source: SolPriceSource, now: i64) {
+        let old_price = self.sol_price_usd;
+        self.sol_price_usd = new_price;
+        self.sol_price_updated_at = if source == SolPriceSource::Pyth { now } else { 0 };
+        if new_price != old_price {
+            emit!(SolPriceUpdated {
+                launch: self.token_launch,
+                old_price,
+                new_price,
+                source,
+                timestamp: now,
+            });
         }
+    }
+    
+    /// Store a fresh oracle price as the backup price, rejecting spikes
+    /// against a recently stored value (see `PythPriceReader::check_price_deviation`)
+    pub fn record_oracle_price(&mut self, fresh_price: u64, now: i64) -> Result<()> {
+        PythPriceReader::check_price_deviation(
+            self.sol_price_usd,
+            self.sol_price_updated_at,
+            fresh_price,
+            now,
+        )?;
+        self.set_sol_price(fresh_price, SolPriceSource::Pyth, now);
+        Ok(())
+    }
+    
+    /// Replace a mis-set creator price before anyone has traded against it.
+    /// The new price counts as creator-supplied, so the next oracle read is
+    /// not deviation-clamped against it. Returns the replaced price
+    pub fn fix_initial_price(&mut self, new_price: u64, now: i64) -> Result<u64> {
+        require!(self.trade_count == 0, LaunchpadError::TradingStarted);
+        require!(
+            PythPriceReader::is_sane_sol_price(new_price),
+            LaunchpadError::InvalidPrice
+        );
+        let old_price = self.sol_price_usd;
+        self.set_sol_price(new_price, SolPriceSource::Manual, now);
+        Ok(old_price)
+    }
+    
+    /// SOL/USD price a trade executes at: a Pyth price no older than
+    /// `max_staleness_seconds` (recorded as the new backup), otherwise the stored
+    /// backup price. With no feed account at all the backup is only used if the
+    /// admin has enabled `manual_price_allowed`. A future-dated Pyth price falls
+    /// back to the backup like a stale one
+    pub fn resolve_trade_price(
+        &mut self,
+        price_feed: Option<&PriceUpdateV2>,
+        manual_price_allowed: bool,
+        max_staleness_seconds: u32,
+        now: i64,
+    ) -> Result<u64> {
+        match price_feed {
+            Some(feed) if PythPriceReader::is_trade_price_fresh_at(feed, max_staleness_seconds as i64, now) => {
+                let fresh_price = PythPriceReader::get_sol_price_usd(feed)?;
+                msg!("Using fresh Pyth price: {}", fresh_price);
+                // Update bonding curve with fresh price, unless it spikes against the stored one
+                self.record_oracle_price(fresh_price, now)?;
+                Ok(fresh_price)
+            }
+            Some(_) => {
+                msg!("⚠️  Pyth price is not fresh, using last known price: {}", self.sol_price_usd);
+                require!(self.sol_price_usd > 0, LaunchpadError::InvalidPrice);
+                Ok(self.sol_price_usd)
+            }
+            None => {
+                require!(manual_price_allowed, LaunchpadError::PriceFeedMissing);
+                msg!("⚠️  No Pyth feed provided, using last known price: {}", self.sol_price_usd);
+                require!(self.sol_price_usd > 0, LaunchpadError::InvalidPrice);
+                Ok(self.sol_price_usd)
+            }
+        }
+    }
+    
+    /// Reserve invariant, checked after every trade:
+    /// - `token_reserve + tokens_sold == curve_supply()` (the 200M LP allocation and any
+    ///   creator allocation live in separate vaults and are never part of `token_reserve`;
+    ///   on a graduated curve this only rules out a sum above the original supply, since
+    ///   graduation may have burned the unsold part)
+    /// - the curve token account holds at least `token_reserve`; a surplus only means
+    ///   stray tokens were sent in
+    pub fn check_reserve_invariant(&self, curve_token_balance: u64) -> Result<()> {
+        let accounted_supply = self.token_reserve
+            .checked_add(self.tokens_sold)
+            .ok_or(LaunchpadError::ReserveCalculationError)?;
+        require!(
+            accounted_supply == self.curve_supply(),
+            LaunchpadError::ReserveCalculationError
+        );
         
-        // Check if 800M tokens sold
-        let tokens_sold_check = self.tokens_sold >= CURVE_SUPPLY;
-        
-        // Check if $12k USD raised (sol_reserve * sol_price_usd / scale >= 12000 * scale)
-        let usd_raised = (self.sol_reserve as u128)
+        require!(
+            curve_token_balance >= self.token_reserve,
+            LaunchpadError::ReserveCalculationError
+        );
+        Ok(())
+    }
+    
+    /// Bump of the sol_vault PDA for the curve at `bonding_curve`: the stored one, or
+    /// derived when none is stored
+    pub fn vault_bump(&self, bonding_curve: &Pubkey) -> u8 {
+        if self.sol_vault_bump != 0 {
+            return self.sol_vault_bump;
+        }
+        Pubkey::find_program_address(&[b"sol_vault", bonding_curve.as_ref()], &crate::ID).1
+    }
+    
+    /// Tokens in the curve token account beyond the tracked `token_reserve`,
+    /// i.e. stray tokens sent in directly rather than sold back
+    pub fn stray_curve_tokens(&self, curve_token_balance: u64) -> u64 {
+        curve_token_balance.saturating_sub(self.token_reserve)
+    }
+    
+    /// Check if a trade at `now` falls within the curve-wide cooldown after the last trade
+    pub fn is_in_trade_cooldown(&self, now: i64, min_inter_trade_seconds: u32) -> bool {
+        min_inter_trade_seconds > 0
+            && self.last_trade_time > 0
+            && now.saturating_sub(self.last_trade_time) < min_inter_trade_seconds as i64
+    }
+    
+    /// Check if a graduated curve is still inside the sell-only grace window at `now`.
+    /// The window closes early once any liquidity has left the vaults
+    pub fn is_in_graduation_grace(&self, now: i64) -> bool {
+        self.is_graduated
+            && self.graduation_grace_seconds > 0
+            && now.saturating_sub(self.graduated_at) <= self.graduation_grace_seconds as i64
+            && !self.has_withdrawn_liquidity()
+    }
+    
+    /// Check if a graduated curve's liquidity is still inside the post-graduation lock at `now`
+    pub fn is_liquidity_locked(&self, now: i64, min_lock_after_graduation_seconds: u32) -> bool {
+        self.is_graduated
+            && now.saturating_sub(self.graduated_at) < min_lock_after_graduation_seconds as i64
+    }
+    
+    /// Whether any graduation liquidity has already left the vaults
+    pub fn has_withdrawn_liquidity(&self) -> bool {
+        self.liquidity_sol_withdrawn > 0 || self.liquidity_tokens_withdrawn > 0
+    }
+    
+    /// (SOL, tokens) a recovery may still move: what the vaults hold now, capped at the
+    /// SOL reserve and LP allocation less what earlier withdrawals already took, so
+    /// nothing is paid out twice. `vault_sol` excludes accrued fees
+    pub fn outstanding_liquidity(&self, vault_sol: u64, lp_vault_tokens: u64) -> (u64, u64) {
+        let sol = vault_sol.min(self.sol_reserve.saturating_sub(self.liquidity_sol_withdrawn));
+        let tokens = lp_vault_tokens.min(LP_SUPPLY.saturating_sub(self.liquidity_tokens_withdrawn));
+        (sol, tokens)
+    }
+    
+    /// Add a withdrawal's SOL (including fees) and LP tokens to the running totals
+    pub fn record_liquidity_withdrawal(&mut self, sol: u64, tokens: u64) -> Result<()> {
+        self.liquidity_sol_withdrawn = self.liquidity_sol_withdrawn
+            .checked_add(sol)
+            .ok_or(LaunchpadError::MathOverflow)?;
+        self.liquidity_tokens_withdrawn = self.liquidity_tokens_withdrawn
+            .checked_add(tokens)
+            .ok_or(LaunchpadError::MathOverflow)?;
+        Ok(())
+    }
+    
+    /// Check if the curve has used up its trade allowance
+    pub fn has_reached_trade_limit(&self) -> bool {
+        self.max_trades > 0 && self.trade_count >= self.max_trades
+    }
+    
+    /// Largest buy allowed under a `max_buy_fraction_bps` cap on the remaining
+    /// token_reserve. Never below one whole token or `min_trade_tokens`: the cap
+    /// shrinks with the reserve, and without a floor the curve could never sell out
+    pub fn max_buy_tokens(&self, max_buy_fraction_bps: u16, min_trade_tokens: u64) -> u64 {
+        if max_buy_fraction_bps == 0 {
+            return self.token_reserve;
+        }
+        let fraction = (self.token_reserve as u128) * (max_buy_fraction_bps as u128) / 10_000;
+        (fraction as u64)
+            .max(min_trade_tokens)
+            .max(10u64.pow(TOKEN_DECIMALS as u32))
+    }
+    
+    /// Check if adding `cost` lamports to the reserve would pass the raise cap
+    pub fn exceeds_raise_cap(&self, cost: u64) -> bool {
+        self.max_sol_raise_lamports > 0
+            && self.sol_reserve.saturating_add(cost) > self.max_sol_raise_lamports
+    }
+    
+    /// Vault lamports not explained by the reserve, accrued fees and rent minimum
+    /// Positive means stray deposits; negative means the vault can't cover the reserve
+    pub fn unattributed_vault_lamports(&self, vault_lamports: u64, rent_exempt_minimum: u64) -> Result<i64> {
+        let attributed = (self.sol_reserve as i128)
+            + (self.accrued_fees as i128)
+            + (rent_exempt_minimum as i128);
+        i64::try_from(vault_lamports as i128 - attributed).map_err(|_| LaunchpadError::MathOverflow.into())
+    }
+    
+    /// Compare the reserve with `liability_lamports`, the curve proceeds owed if
+    /// every sold token were sold back at once
+    /// Each trade truncates its cost to whole lamports, so a fully backed reserve
+    /// can trail the liability by up to a lamport per trade without being insolvent
+    pub fn health(&self, liability_lamports: u64) -> CurveHealth {
+        let coverage_bps = if liability_lamports == 0 {
+            10_000
+        } else {
+            ((self.sol_reserve as u128) * 10_000 / (liability_lamports as u128)).min(u64::MAX as u128) as u64
+        };
+        CurveHealth {
+            is_solvent: self.sol_reserve.saturating_add(self.trade_count) >= liability_lamports,
+            reserve_lamports: self.sol_reserve,
+            liability_lamports,
+            coverage_bps,
+        }
+    }
+    
+    /// Tokens sellable on this curve: the 800M curve supply less any creator allocation.
+    /// Graduation may burn whatever was left unsold, after which only the tokens sold
+    /// (and any sold back during the grace window) remain
+    pub fn curve_supply(&self) -> u64 {
+        let supply = CURVE_SUPPLY.saturating_sub(self.creator_allocation);
+        if self.is_graduated {
+            supply.min(self.token_reserve.saturating_add(self.tokens_sold))
+        } else {
+            supply
+        }
+    }
+    
+    /// Creator allocation tokens vested at `now`, linearly over `creator_vesting_seconds`
+    /// from `launch_timestamp`
+    pub fn creator_allocation_vested(&self, launch_timestamp: i64, now: i64) -> u64 {
+        let elapsed = now.saturating_sub(launch_timestamp).max(0) as u128;
+        let vesting_seconds = self.creator_vesting_seconds as u128;
+        if elapsed >= vesting_seconds {
+            return self.creator_allocation;
+        }
+        ((self.creator_allocation as u128) * elapsed / vesting_seconds) as u64
+    }
+    
+    /// Vested creator allocation not yet claimed at `now`
+    pub fn claimable_creator_allocation(&self, launch_timestamp: i64, now: i64) -> u64 {
+        self.creator_allocation_vested(launch_timestamp, now)
+            .saturating_sub(self.creator_allocation_claimed)
+    }
+    
+    pub fn record_creator_claim(&mut self, amount: u64) -> Result<()> {
+        self.creator_allocation_claimed = self.creator_allocation_claimed
+            .checked_add(amount)
+            .ok_or(LaunchpadError::MathOverflow)?;
+        Ok(())
+    }
+    
+    /// Tokens left on the curve before its graduation supply is reached
+    pub fn tokens_until_graduation(&self) -> u64 {
+        self.curve_supply().saturating_sub(self.tokens_sold)
+    }
+    
+    /// Platform fee in basis points before any loyalty discount: the launch's
+    /// schedule at the current progress, or the config's flat `platform_fee_bps`
+    pub fn platform_fee_bps(&self, config_fee_bps: u16) -> u16 {
+        match self.fee_schedule_by_progress {
+            Some(schedule) => schedule.fee_bps_at(self.tokens_sold, self.curve_supply()),
+            None => config_fee_bps,
+        }
+    }
+    
+    /// Progress towards the curve supply in basis points (10_000 = fully sold)
+    pub fn graduation_progress_bps(&self) -> u64 {
+        let curve_supply = self.curve_supply();
+        ((self.tokens_sold.min(curve_supply) as u128) * 10_000 / (curve_supply.max(1) as u128)) as u64
+    }
+    
+    /// USD value of the SOL reserve at the stored SOL price (scaled by USD_SCALE)
+    pub fn usd_raised(&self) -> u128 {
+        (self.sol_reserve as u128)
             .checked_mul(self.sol_price_usd as u128)
             .unwrap_or(0)
-            / (1_000_000_000u128); // Divide by 1e9 (SOL decimals)
+            / (1_000_000_000u128) // Divide by 1e9 (SOL decimals)
+    }
+    
+    /// Check if tokens sold has crossed NEAR_GRADUATION_BPS of the curve supply
+    pub fn is_near_graduation(&self) -> bool {
+        (self.tokens_sold as u128) * 10_000
+            >= (self.curve_supply() as u128) * (NEAR_GRADUATION_BPS as u128)
+    }
+    
+    /// Check if curve has reached graduation (curve supply sold, $12k raised,
+    /// at least `min_graduation_sol_lamports` in reserve for LP depth, and at
+    /// least `min_holders_for_graduation` distinct wallets holding)
+    pub fn should_graduate(&self, min_graduation_sol_lamports: u64, min_holders_for_graduation: u32) -> bool {
+        self.graduation_status(min_graduation_sol_lamports, min_holders_for_graduation).blocking_reason
+            == GraduationBlocker::None
+    }
+    
+    /// Break graduation down into its individual conditions, for diagnostics.
+    /// `blocking_reason` is the first unmet condition; `None` means the next
+    /// trade graduates the curve.
+    pub fn graduation_status(
+        &self,
+        min_graduation_sol_lamports: u64,
+        min_holders_for_graduation: u32,
+    ) -> GraduationStatus {
+        // Check if the curve supply (800M less any creator allocation) is sold
+        let tokens_met = self.tokens_sold >= self.curve_supply();
         
+        // Check if $12k USD raised (sol_reserve * sol_price_usd / scale >= 12000 * scale)
         let usd_threshold = (GRADUATION_USD as u128)
             .checked_mul(USD_SCALE as u128)
             .unwrap_or(0);
+        let usd_met = self.usd_raised() >= usd_threshold;
+        
+        // Reserve needed for the USD threshold at the stored SOL price, rounded up
+        let usd_reserve_needed = (usd_threshold * 1_000_000_000)
+            .div_ceil((self.sol_price_usd as u128).max(1))
+            .min(u64::MAX as u128) as u64;
+        
+        // Require a meaningful absolute SOL reserve for the resulting pool
+        let min_sol_met = self.sol_reserve >= min_graduation_sol_lamports;
+        
+        // Target-vault launches graduate on SOL raised alone, however many tokens are left
+        let target_mode = self.target_sol_vault_lamports > 0;
+        let target_sol_met = self.sol_reserve >= self.target_sol_vault_lamports;
+        
+        // Keep a single wallet from buying out the curve and graduating with no distribution
+        let min_holders = min_holders_for_graduation as u64;
+        let holders_met = self.holder_count >= min_holders;
         
-        tokens_sold_check && usd_raised >= usd_threshold
+        let blocking_reason = if self.is_graduated {
+            GraduationBlocker::AlreadyGraduated
+        } else if target_mode {
+            if !target_sol_met {
+                GraduationBlocker::SolTargetNotReached
+            } else if !min_sol_met {
+                GraduationBlocker::SolReserveBelowMinimum
+            } else if !holders_met {
+                GraduationBlocker::HoldersBelowMinimum
+            } else {
+                GraduationBlocker::None
+            }
+        } else if !tokens_met {
+            GraduationBlocker::TokensNotSold
+        } else if !usd_met {
+            GraduationBlocker::UsdNotRaised
+        } else if !min_sol_met {
+            GraduationBlocker::SolReserveBelowMinimum
+        } else if !holders_met {
+            GraduationBlocker::HoldersBelowMinimum
+        } else {
+            GraduationBlocker::None
+        };
+        
+        GraduationStatus {
+            is_graduated: self.is_graduated,
+            tokens_met,
+            usd_met,
+            min_sol_met,
+            holders_met,
+            tokens_short: self.tokens_until_graduation(),
+            usd_short_lamports: usd_reserve_needed.saturating_sub(self.sol_reserve),
+            min_sol_short_lamports: min_graduation_sol_lamports.saturating_sub(self.sol_reserve),
+            target_sol_short_lamports: self.target_sol_vault_lamports.saturating_sub(self.sol_reserve),
+            holders_short: min_holders.saturating_sub(self.holder_count),
+            blocking_reason,
+        }
     }
 }
 
@@ -182,8 +963,12 @@ pub struct UserPosition {
     pub sell_count: u32,
     /// Last interaction timestamp
     pub last_interaction: i64,
+    /// Timestamp of the position's first buy (0 = never bought)
+    pub first_buy_timestamp: i64,
     /// Bump seed for PDA
     pub bump: u8,
+    /// Zeroed headroom; new fields are carved out of it so existing accounts need no realloc
+    pub reserved: [u8; 64],
 }
 
 impl UserPosition {
@@ -196,17 +981,359 @@ impl UserPosition {
         4 +  // buy_count
         4 +  // sell_count
         8 +  // last_interaction
-        1;   // bump
+        8 +  // first_buy_timestamp
+        1 +  // bump
+        64;  // reserved
+    
+    /// Mark-to-spot return on the position and a simple (non-compounded)
+    /// annualization over the time since the first buy
+    /// 
+    /// # Arguments
+    /// * `spot_price` - Current spot price in lamports per whole token
+    /// * `now` - Current unix timestamp
+    pub fn position_return(&self, spot_price: u64, now: i64) -> Result<PositionReturn> {
+        let current_value = (self.token_amount as u128)
+            .checked_mul(spot_price as u128)
+            .ok_or(LaunchpadError::MathOverflow)?
+            / 10u128.pow(TOKEN_DECIMALS as u32);
+        let current_value = u64::try_from(current_value).map_err(|_| LaunchpadError::MathOverflow)?;
+        
+        // Realized proceeds count towards the return alongside the held tokens
+        let absolute_return = (current_value as i128)
+            + (self.sol_received as i128)
+            - (self.sol_invested as i128);
+        let return_bps = if self.sol_invested == 0 {
+            0
+        } else {
+            absolute_return * 10_000 / (self.sol_invested as i128)
+        };
+        
+        let held_seconds = if self.first_buy_timestamp == 0 {
+            0
+        } else {
+            now.saturating_sub(self.first_buy_timestamp).max(0)
+        };
+        let annualized_return_bps = if held_seconds == 0 {
+            0
+        } else {
+            return_bps * (SECONDS_PER_YEAR as i128) / (held_seconds as i128)
+        };
+        
+        let to_i64 = |value: i128| i64::try_from(value).map_err(|_| LaunchpadError::MathOverflow);
+        Ok(PositionReturn {
+            token_amount: self.token_amount,
+            current_value,
+            sol_invested: self.sol_invested,
+            sol_received: self.sol_received,
+            absolute_return: to_i64(absolute_return)?,
+            return_bps: to_i64(return_bps)?,
+            held_seconds,
+            annualized_return_bps: to_i64(annualized_return_bps)?,
+        })
+    }
+}
+
+/// Protocol-wide totals across every launch, so dashboards need not sum each curve
+/// 
+/// A single PDA (seeds = [b"global_stats"]) that every create writes to. Solana
+/// serializes transactions that lock the same writable account, so buys and sells
+/// take it as an optional account: only trades that pass it add to the volume,
+/// fee and graduation totals
+#[account]
+pub struct GlobalStats {
+    /// Launches created
+    pub total_launches: u64,
+    /// Curve-side SOL traded across all launches, in lamports (buy cost + sell proceeds)
+    pub total_volume: u64,
+    /// Curves that reached graduation
+    pub total_graduated: u64,
+    /// Platform fees charged on trades, in lamports (accrued or paid out)
+    pub total_fees: u64,
+    /// Bump seed for PDA
+    pub bump: u8,
+    /// Zeroed headroom; new fields are carved out of it so existing accounts need no realloc
+    pub reserved: [u8; 64],
+}
+
+impl GlobalStats {
+    pub const LEN: usize = 8 + // discriminator
+        8 +  // total_launches
+        8 +  // total_volume
+        8 +  // total_graduated
+        8 +  // total_fees
+        1 +  // bump
+        64;  // reserved
+    
+    pub fn record_launch(&mut self) -> Result<()> {
+        self.total_launches = self.total_launches
+            .checked_add(1)
+            .ok_or(LaunchpadError::MathOverflow)?;
+        Ok(())
+    }
+    
+    /// Add one trade's curve-side SOL and platform fee to the totals
+    pub fn record_trade(&mut self, volume: u64, fee: u64) -> Result<()> {
+        self.total_volume = self.total_volume
+            .checked_add(volume)
+            .ok_or(LaunchpadError::MathOverflow)?;
+        self.total_fees = self.total_fees
+            .checked_add(fee)
+            .ok_or(LaunchpadError::MathOverflow)?;
+        Ok(())
+    }
+    
+    pub fn record_graduation(&mut self) -> Result<()> {
+        self.total_graduated = self.total_graduated
+            .checked_add(1)
+            .ok_or(LaunchpadError::MathOverflow)?;
+        Ok(())
+    }
+    
+    pub fn totals(&self) -> GlobalTotals {
+        GlobalTotals {
+            total_launches: self.total_launches,
+            total_volume: self.total_volume,
+            total_graduated: self.total_graduated,
+            total_fees: self.total_fees,
+        }
+    }
+}
+
+/// Slots in each launch's TradeHistory ring buffer
+pub const TRADE_HISTORY_CAPACITY: usize = 32;
+
+/// One fill kept in a launch's on-chain trade history
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub struct TradeRecord {
+    pub side: TradeSide,
+    pub trader: Pubkey,
+    /// Tokens bought or sold (with decimals)
+    pub token_amount: u64,
+    /// Average fill in lamports per whole token, as in TokensPurchased/TokensSold
+    pub price_per_token: u64,
+    pub timestamp: i64,
+}
+
+impl TradeRecord {
+    pub const LEN: usize = 1 + // side
+        32 + // trader
+        8 +  // token_amount
+        8 +  // price_per_token
+        8;   // timestamp
+    
+    /// Curve-side SOL per whole token (1e9 base units); 0 for an empty fill
+    pub fn price_per_token(sol_amount: u64, token_amount: u64) -> u64 {
+        // Widened so fills above ~18 SOL don't overflow the scaling
+        (sol_amount as u128 * 1_000_000_000)
+            .checked_div(token_amount as u128)
+            .map_or(0, |price| price.min(u64::MAX as u128) as u64)
+    }
+}
+
+/// The last TRADE_HISTORY_CAPACITY trades on a launch, readable without an indexer
+/// 
+/// A fixed ring buffer (seeds = [b"trade_history", token_launch]) written by every
+/// buy and sell that passes it: trade n lands in slot n % TRADE_HISTORY_CAPACITY, overwriting the
+/// oldest once full. Events remain the complete history
+#[account]
+pub struct TradeHistory {
+    pub token_launch: Pubkey,
+    /// Trades ever recorded; the next one is written to `total_trades % TRADE_HISTORY_CAPACITY`
+    pub total_trades: u64,
+    pub trades: [TradeRecord; TRADE_HISTORY_CAPACITY],
+    /// Bump seed for PDA
+    pub bump: u8,
+    /// Zeroed headroom; new fields are carved out of it so existing accounts need no realloc
+    pub reserved: [u8; 32],
+}
+
+impl TradeHistory {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // token_launch
+        8 +  // total_trades
+        TradeRecord::LEN * TRADE_HISTORY_CAPACITY + // trades
+        1 +  // bump
+        32;  // reserved
+    
+    /// Maximum trades per get_recent_trades page (bounded by the 1024-byte return data limit)
+    pub const MAX_PAGE_SIZE: u8 = 16;
+    
+    pub fn initialize(&mut self, token_launch: Pubkey, bump: u8) {
+        self.token_launch = token_launch;
+        self.total_trades = 0;
+        self.trades = [TradeRecord::default(); TRADE_HISTORY_CAPACITY];
+        self.bump = bump;
+        self.reserved = [0; 32];
+    }
+    
+    pub fn record(
+        &mut self,
+        side: TradeSide,
+        trader: Pubkey,
+        token_amount: u64,
+        sol_amount: u64,
+        timestamp: i64,
+    ) -> Result<()> {
+        let slot = (self.total_trades % TRADE_HISTORY_CAPACITY as u64) as usize;
+        self.trades[slot] = TradeRecord {
+            side,
+            trader,
+            token_amount,
+            price_per_token: TradeRecord::price_per_token(sol_amount, token_amount),
+            timestamp,
+        };
+        self.total_trades = self.total_trades
+            .checked_add(1)
+            .ok_or(LaunchpadError::MathOverflow)?;
+        Ok(())
+    }
+    
+    /// Up to `limit` stored trades, newest first, skipping the `offset` most recent
+    pub fn recent_trades(&self, offset: u32, limit: u8) -> Result<RecentTrades> {
+        require!(
+            limit > 0 && limit <= Self::MAX_PAGE_SIZE,
+            LaunchpadError::InvalidAmount
+        );
+        
+        let stored = self.total_trades.min(TRADE_HISTORY_CAPACITY as u64);
+        let start = (offset as u64).min(stored);
+        let end = start.saturating_add(limit as u64).min(stored);
+        let trades = (start..end)
+            .map(|back| {
+                let index = (self.total_trades - 1 - back) % TRADE_HISTORY_CAPACITY as u64;
+                self.trades[index as usize]
+            })
+            .collect();
+        
+        Ok(RecentTrades {
+            total_trades: self.total_trades,
+            trades,
+        })
+    }
+}
+
+/// Arguments to `create_token_launch`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct CreateLaunchParams {
+    pub name: String,
+    pub symbol: String,
+    pub metadata_uri: String,
+    pub description: String,
+    /// Current SOL price in USD (scaled by 1e8, e.g., $150 = 15_000_000_000)
+    pub sol_price_usd: u64,
+    /// Unix timestamp when trading opens (0 = immediately)
+    pub trading_starts_at: i64,
+    /// false locks metadata URI and description at creation
+    pub is_mutable: bool,
+    /// false makes the launch buy-only until graduation
+    pub sells_enabled: bool,
+    /// Hard cap on SOL raised, independent of graduation (0 = no cap)
+    pub max_sol_raise_lamports: u64,
+    /// Graduate once the SOL reserve reaches this (0 = token/USD graduation)
+    pub target_sol_vault_lamports: u64,
+    /// Buys and sells allowed before trading stops (0 = unlimited)
+    pub max_trades: u64,
+    /// Pyth SOL/USD PriceUpdateV2 account trades must read from
+    pub expected_price_feed: Pubkey,
+    /// Pool or Lock; Distribute is rejected for now
+    pub graduation_action: GraduationAction,
+    /// Price curve shape between the fixed start and end prices
+    pub curve_type: CurveType,
+    /// Share of the 1B supply vesting to the creator, taken from the curve (0 = none)
+    pub creator_allocation_bps: u16,
+}
+
+/// What happens to the raised SOL and LP allocation once a curve graduates
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum GraduationAction {
+    /// withdraw_liquidity moves SOL and LP tokens to the approved DEX destination
+    #[default]
+    Pool,
+    /// Pro-rata SOL distribution to holders (not yet supported, rejected at creation)
+    Distribute,
+    /// SOL and LP tokens stay locked in the program vaults permanently
+    Lock,
+}
+
+/// Price curve shape between START_PRICE_USD and END_PRICE_USD over the curve supply
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum CurveType {
+    /// Price grows by the same factor per token across the whole range
+    #[default]
+    Exponential,
+    /// Logistic S-curve: flat at the start, steepest mid-curve, flattening into
+    /// the end price so the final buys before graduation see little slippage
+    Sigmoid,
+}
+
+/// First unmet graduation condition; every variant but `None` and
+/// `AlreadyGraduated` is a specific case of GraduationThresholdNotReached
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum GraduationBlocker {
+    /// All conditions met, the next trade graduates the curve
+    None,
+    /// The curve has already graduated
+    AlreadyGraduated,
+    /// The curve supply (800M less any creator allocation) is not sold out
+    TokensNotSold,
+    /// SOL reserve is worth less than GRADUATION_USD at the stored SOL price
+    UsdNotRaised,
+    /// SOL reserve is below the config's min_graduation_sol_lamports
+    SolReserveBelowMinimum,
+    /// SOL reserve is below the launch's target_sol_vault_lamports (target mode only)
+    SolTargetNotReached,
+    /// Fewer distinct wallets hold than the config's min_holders_for_graduation
+    HoldersBelowMinimum,
+}
+
+/// Why a launch's active status changed, carried on LaunchStatusToggled
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum LaunchStatusChange {
+    /// Creator paused trading
+    Pause,
+    /// Creator resumed trading after a pause
+    Resume,
+    /// Curve graduated and trading moved to the DEX (permanent)
+    Graduation,
+    /// Creator asked to pause; trading continues until the notice delay runs out
+    PauseScheduled,
+    /// Creator called off a scheduled pause before it took effect
+    PauseCancelled,
+}
+
+/// Where a change to a curve's stored SOL/USD price came from, carried on SolPriceUpdated
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SolPriceSource {
+    /// Seeded from the creator's argument when the launch was created
+    Creator,
+    /// Read from a fresh Pyth update by a trade or refresh_price
+    Pyth,
+    /// Corrected by the creator through fix_initial_price before the first trade
+    Manual,
+}
+
+/// Reference price that quote slippage is measured against
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SlippageReference {
+    /// Current spot price on the curve
+    #[default]
+    CurrentSpot,
+    /// Launch start price (shows total appreciation)
+    StartPrice,
 }
 
 /// Return type for buy quote view function
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct BuyQuote {
-    /// Total cost in lamports to buy the tokens
+    /// Curve cost in lamports to buy the tokens, before the platform fee
     pub cost: u64,
+    /// Platform fee in lamports at the launch's platform fee (before any loyalty discount)
+    pub fee: u64,
+    /// cost + fee: what the buy charges, and the value to pass as max_sol_cost
+    pub total_cost: u64,
     /// Current spot price per token in lamports
     pub spot_price: u64,
-    /// Slippage in basis points (e.g., 100 = 1%)
+    /// Slippage in basis points (e.g., 100 = 1%) against the requested reference
     pub slippage: u16,
 }
 
@@ -219,4 +1346,1408 @@ pub struct SpotPrice {
     pub tokens_sold: u64,
     /// Current SOL reserve in the bonding curve
     pub sol_reserve: u64,
+    /// Tokens remaining on the curve until graduation
+    pub tokens_until_graduation: u64,
+    /// Number of wallets currently holding tokens
+    pub holder_count: u64,
+}
+
+/// Return type for position return view function
+#[derive(AnchorSerialize, AnchorDeserialize, Debug)]
+pub struct PositionReturn {
+    /// Tokens currently held
+    pub token_amount: u64,
+    /// Held tokens valued at the current spot price, in lamports
+    pub current_value: u64,
+    /// Total SOL invested across all buys
+    pub sol_invested: u64,
+    /// Total SOL received from sells
+    pub sol_received: u64,
+    /// current_value + sol_received - sol_invested, in lamports
+    pub absolute_return: i64,
+    /// absolute_return relative to sol_invested, in basis points
+    pub return_bps: i64,
+    /// Seconds since the first buy
+    pub held_seconds: i64,
+    /// return_bps scaled linearly to a year (0 until time has passed)
+    pub annualized_return_bps: i64,
+}
+
+/// One holder's slice of a launch, for pro-rata distribution
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct PositionShare {
+    /// Wallet that owns the position
+    pub user: Pubkey,
+    /// Tokens held by the position
+    pub token_amount: u64,
+    /// token_amount relative to the total across all supplied positions, in basis points
+    pub share_bps: u16,
+}
+
+/// Return type for the launch positions aggregate view function
+#[derive(AnchorSerialize, AnchorDeserialize, Debug)]
+pub struct LaunchPositions {
+    /// Tokens held across all supplied positions
+    pub total_tokens: u64,
+    /// Per-position breakdown, in the order the positions were supplied
+    pub positions: Vec<PositionShare>,
+}
+
+impl LaunchPositions {
+    /// Cap on positions per query so the result fits in the 1024-byte return data
+    pub const MAX_POSITIONS: usize = 20;
+    
+    /// Total the (user, token_amount) holdings and each one's pro-rata share
+    pub fn from_holdings(holdings: &[(Pubkey, u64)]) -> Result<Self> {
+        let total_tokens = holdings.iter().try_fold(0u64, |total, (_, amount)| {
+            total.checked_add(*amount).ok_or(LaunchpadError::MathOverflow)
+        })?;
+        
+        let positions = holdings
+            .iter()
+            .map(|(user, token_amount)| {
+                // Floored, so shares never sum past 10,000 bps
+                let share_bps = if total_tokens == 0 {
+                    0
+                } else {
+                    ((*token_amount as u128) * 10_000 / (total_tokens as u128)) as u16
+                };
+                PositionShare {
+                    user: *user,
+                    token_amount: *token_amount,
+                    share_bps,
+                }
+            })
+            .collect();
+        
+        Ok(Self { total_tokens, positions })
+    }
+}
+
+/// Return type for graduation status view function
+#[derive(AnchorSerialize, AnchorDeserialize, Debug)]
+pub struct GraduationStatus {
+    /// Whether the curve has already graduated
+    pub is_graduated: bool,
+    /// Whether the full curve supply has been sold
+    pub tokens_met: bool,
+    /// Whether the SOL reserve is worth at least GRADUATION_USD
+    pub usd_met: bool,
+    /// Whether the SOL reserve meets the config's minimum for LP depth
+    pub min_sol_met: bool,
+    /// Whether enough distinct wallets hold to meet the config's holder minimum
+    pub holders_met: bool,
+    /// Tokens left to sell before the token threshold is met
+    pub tokens_short: u64,
+    /// Additional reserve lamports needed to meet the USD threshold at the stored SOL price
+    pub usd_short_lamports: u64,
+    /// Additional reserve lamports needed to meet the minimum graduation reserve
+    pub min_sol_short_lamports: u64,
+    /// Additional reserve lamports needed to hit the target SOL vault (0 outside target mode)
+    pub target_sol_short_lamports: u64,
+    /// Additional holders needed to meet the config's holder minimum
+    pub holders_short: u64,
+    /// First unmet condition (None = graduation is due)
+    pub blocking_reason: GraduationBlocker,
+}
+
+/// Return type for SOL vault reconciliation view function
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct VaultRecon {
+    /// Lamports actually held by the SOL vault
+    pub vault_lamports: u64,
+    /// SOL reserve recorded on the bonding curve
+    pub sol_reserve: u64,
+    /// Platform fees accrued in the vault and not yet swept
+    pub accrued_fees: u64,
+    /// Rent-exempt minimum the vault keeps for a 0-byte account
+    pub rent_exempt_minimum: u64,
+    /// Lamports not explained by the fields above (negative = vault is short)
+    pub unattributed: i64,
+}
+
+/// Return type for supply breakdown view function
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SupplyBreakdown {
+    /// Mint supply, net of any tokens burned at graduation
+    pub total: u64,
+    /// Tokens bought off the curve and not sold back, per the launch's accounting
+    pub circulating: u64,
+    /// Tokens held by the curve token account
+    pub on_curve: u64,
+    /// Tokens held by the LP vault (0 once liquidity is withdrawn)
+    pub lp_reserved: u64,
+    /// Creator allocation still in the creator vault, vested or not
+    pub creator_unclaimed: u64,
+}
+
+/// Return type for global stats view function
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct GlobalTotals {
+    /// Launches created
+    pub total_launches: u64,
+    /// Curve-side SOL traded across all launches, in lamports (trades that passed the stats account)
+    pub total_volume: u64,
+    /// Curves that reached graduation on a trade that passed the stats account
+    pub total_graduated: u64,
+    /// Platform fees charged on trades that passed the stats account, in lamports
+    pub total_fees: u64,
+}
+
+/// Return type for the recent trades view function
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RecentTrades {
+    /// Trades ever recorded on the launch, including ones since overwritten
+    pub total_trades: u64,
+    /// Newest first
+    pub trades: Vec<TradeRecord>,
+}
+
+/// Return type for curve health view function
+#[derive(AnchorSerialize, AnchorDeserialize, Debug)]
+pub struct CurveHealth {
+    /// Whether the reserve covers the liability (allowing per-trade lamport rounding)
+    pub is_solvent: bool,
+    /// SOL reserve recorded on the bonding curve
+    pub reserve_lamports: u64,
+    /// Curve proceeds owed if all of tokens_sold were sold back, at the stored SOL price
+    pub liability_lamports: u64,
+    /// reserve / liability in basis points (10_000 = exactly covered, and when nothing is owed)
+    pub coverage_bps: u64,
+}
+
+/// Return type for launch summary view function
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LaunchSummary {
+    /// Token mint
+    pub mint: Pubkey,
+    /// Creator of the token
+    pub creator: Pubkey,
+    /// Token name
+    pub name: String,
+    /// Token symbol
+    pub symbol: String,
+    /// Token metadata URI
+    pub metadata_uri: String,
+    /// Total supply
+    pub total_supply: u64,
+    /// Current circulating supply
+    pub circulating_supply: u64,
+    /// Total tokens sold on the curve
+    pub tokens_sold: u64,
+    /// Tokens remaining on the curve until graduation
+    pub tokens_until_graduation: u64,
+    /// Current spot price per token in lamports
+    pub spot_price: u64,
+    /// Current SOL reserve in the bonding curve
+    pub sol_reserve: u64,
+    /// USD value of the SOL reserve (scaled by 1e8)
+    pub usd_raised: u64,
+    /// Progress towards graduation in basis points (10_000 = graduated supply)
+    pub graduation_progress_bps: u16,
+    /// Number of wallets currently holding tokens
+    pub holder_count: u64,
+    /// Number of distinct wallets that have ever bought
+    pub unique_buyers: u64,
+    /// Number of trades executed
+    pub trade_count: u64,
+    /// Total trading volume in lamports
+    pub total_volume: u64,
+    /// Whether trading is active
+    pub is_active: bool,
+    /// Whether the curve has graduated
+    pub is_graduated: bool,
+}
+
+/// Return type for launch verification view function
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct VerifiedLaunch {
+    /// Creator of the token
+    pub creator: Pubkey,
+    /// Whether trading is active
+    pub is_active: bool,
+    /// Whether the curve has graduated
+    pub is_graduated: bool,
+    /// BondingCurve PDA for the launch
+    pub bonding_curve: Pubkey,
+}
+
+/// Return type for launch address resolution view function
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LaunchAddresses {
+    /// Token mint
+    pub mint: Pubkey,
+    /// TokenLaunch PDA (["token_launch", mint])
+    pub token_launch: Pubkey,
+    /// BondingCurve PDA (["bonding_curve", token_launch])
+    pub bonding_curve: Pubkey,
+    /// SOL vault PDA (["sol_vault", bonding_curve])
+    pub sol_vault: Pubkey,
+    /// Bonding curve's associated token account (sellable supply)
+    pub curve_token_account: Pubkey,
+    /// LP vault PDA (["lp_vault", bonding_curve]) holding the 200M LP allocation
+    pub lp_vault: Pubkey,
+}
+
+/// Side of a trade for simulation and trade history
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum TradeSide {
+    #[default]
+    Buy,
+    Sell,
+}
+
+/// Return type for trade simulation view function
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct TradeSimulation {
+    /// Curve cost (buy) or gross proceeds (sell) in lamports, before fees
+    pub sol_amount: u64,
+    /// Platform fee in lamports
+    pub fee: u64,
+    /// SOL paid by the buyer (cost + fee) or received by the seller (proceeds - fee)
+    pub net_sol: u64,
+    /// Tokens sold on the curve after the trade
+    pub tokens_sold_after: u64,
+    /// Token reserve on the curve after the trade
+    pub token_reserve_after: u64,
+    /// SOL reserve on the curve after the trade
+    pub sol_reserve_after: u64,
+    /// Spot price per token in lamports after the trade
+    pub spot_price_after: u64,
+    /// Whether the trade would trigger graduation
+    pub triggers_graduation: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pyth_solana_receiver_sdk::price_update::{PriceFeedMessage, VerificationLevel};
+    
+    fn config_with_liquidity_destinations(program: Pubkey, treasury: Pubkey) -> LaunchpadConfig {
+        LaunchpadConfig {
+            authority: Pubkey::new_unique(),
+            fee_recipient: Pubkey::new_unique(),
+            platform_fee_bps: 100,
+            whitelisted_wallet_1: Pubkey::default(),
+            whitelisted_wallet_2: Pubkey::default(),
+            accrue_fees: false,
+            min_graduation_sol_lamports: 0,
+            min_trade_tokens: 0,
+            liquidity_program: program,
+            liquidity_treasury: treasury,
+            fee_discount_mint: Pubkey::default(),
+            fee_discount_bps: 0,
+            burn_unsold_on_graduation: false,
+            graduation_fee_bps: 0,
+            allowed_uri_prefixes: Vec::new(),
+            sell_fee_ramp_max_bps: 0,
+            sell_fee_ramp_full_share_bps: 0,
+            min_inter_trade_seconds: 0,
+            metadata_update_cooldown_seconds: 0,
+            manual_price_allowed: false,
+            withdraw_fee_bps: 0,
+            fee_splits: Vec::new(),
+            bump: 0,
+            reserved: [0; 64],
+            graduation_grace_seconds: 0,
+            deactivation_delay_seconds: 0,
+            min_lock_after_graduation_seconds: 0,
+            max_price_staleness_seconds: DEFAULT_PRICE_STALENESS_SECONDS,
+            min_holders_for_graduation: 0,
+            creator_vesting_seconds: 0,
+            max_buy_fraction_bps: 0,
+        }
+    }
+    
+    #[test]
+    fn test_liquidity_recipient_approval() {
+        let dex_program = Pubkey::new_unique();
+        let treasury = Pubkey::new_unique();
+        let config = config_with_liquidity_destinations(dex_program, treasury);
+        let system_program = Pubkey::default();
+        
+        // Treasury itself, or any account owned by the DEX program
+        assert!(config.is_approved_liquidity_recipient(&treasury, &system_program));
+        assert!(config.is_approved_liquidity_recipient(&Pubkey::new_unique(), &dex_program));
+        
+        // Arbitrary wallet (e.g. the creator) is rejected
+        assert!(!config.is_approved_liquidity_recipient(&Pubkey::new_unique(), &system_program));
+        
+        // Unset destinations never match, even for system-owned wallets
+        let unset = config_with_liquidity_destinations(Pubkey::default(), Pubkey::default());
+        assert!(!unset.is_approved_liquidity_recipient(&Pubkey::default(), &system_program));
+        assert!(!unset.is_approved_liquidity_recipient(&Pubkey::new_unique(), &system_program));
+    }
+    
+    #[test]
+    fn test_sell_fee_ramp() {
+        let mut config = config_with_liquidity_destinations(Pubkey::default(), Pubkey::default());
+        let reserve = 10_000_000_000;
+        
+        // Disabled ramp keeps the flat fee regardless of size
+        assert_eq!(config.sell_fee_bps(100, reserve, reserve), 100);
+        
+        // +5% at 50% of the reserve
+        config.sell_fee_ramp_max_bps = 500;
+        config.sell_fee_ramp_full_share_bps = 5_000;
+        
+        // Tiny sell pays (effectively) the base fee
+        assert_eq!(config.sell_fee_bps(100, reserve / 100_000, reserve), 100);
+        // 10% of the reserve pays a fifth of the ramp
+        assert_eq!(config.sell_fee_bps(100, reserve / 10, reserve), 200);
+        // 25% pays half
+        assert_eq!(config.sell_fee_bps(100, reserve / 4, reserve), 350);
+        // At and beyond the full share the ramp is capped
+        assert_eq!(config.sell_fee_bps(100, reserve / 2, reserve), 600);
+        assert_eq!(config.sell_fee_bps(100, reserve, reserve), 600);
+        
+        // Ramp applies on top of a discounted base
+        assert_eq!(config.sell_fee_bps(50, reserve, reserve), 550);
+    }
+    
+    #[test]
+    fn test_allowed_uri_prefixes() {
+        let mut config = config_with_liquidity_destinations(Pubkey::default(), Pubkey::default());
+        
+        // Empty list allows anything
+        assert!(config.is_allowed_uri("https://phishing.example/meta.json"));
+        
+        config.allowed_uri_prefixes = vec![
+            "https://arweave.net/".to_string(),
+            "ipfs://".to_string(),
+        ];
+        assert!(config.is_allowed_uri("https://arweave.net/abc123"));
+        assert!(config.is_allowed_uri("ipfs://bafy123/meta.json"));
+        assert!(!config.is_allowed_uri("https://phishing.example/meta.json"));
+        
+        // Trailing slash on the prefix pins the host
+        assert!(!config.is_allowed_uri("https://arweave.net.phishing.example/abc123"));
+        assert!(!config.is_allowed_uri("http://arweave.net/abc123"));
+    }
+    
+    #[test]
+    fn test_price_staleness_defaults_when_unset() {
+        let mut config = config_with_liquidity_destinations(Pubkey::default(), Pubkey::default());
+        assert_eq!(config.price_staleness_seconds(), DEFAULT_PRICE_STALENESS_SECONDS);
+        
+        config.max_price_staleness_seconds = 300;
+        assert_eq!(config.price_staleness_seconds(), 300);
+        
+        // Zero means unset rather than "always stale"
+        config.max_price_staleness_seconds = 0;
+        assert_eq!(config.price_staleness_seconds(), DEFAULT_PRICE_STALENESS_SECONDS);
+    }
+    
+    fn curve_with_tokens_sold(tokens_sold: u64) -> BondingCurve {
+        BondingCurve {
+            token_launch: Pubkey::default(),
+            sol_reserve: 0,
+            token_reserve: CURVE_SUPPLY - tokens_sold,
+            tokens_sold,
+            sol_price_usd: 15_000_000_000,
+            sol_price_updated_at: 0,
+            total_volume: 0,
+            trade_count: 0,
+            is_graduated: false,
+            near_graduation_notified: false,
+            accrued_fees: 0,
+            holder_count: 0,
+            unique_buyers: 0,
+            max_sol_raise_lamports: 0,
+            target_sol_vault_lamports: 0,
+            max_trades: 0,
+            expected_price_feed: Pubkey::default(),
+            last_trade_time: 0,
+            graduation_action: GraduationAction::Pool,
+            pool_address: None,
+            bump: 0,
+            reserved: [0; 64],
+            snapshot_id: 0,
+            graduated_at: 0,
+            graduation_grace_seconds: 0,
+            curve_type: CurveType::Exponential,
+            liquidity_sol_withdrawn: 0,
+            liquidity_tokens_withdrawn: 0,
+            fee_schedule_by_progress: None,
+            sol_vault_bump: 0,
+            creator_allocation: 0,
+            creator_allocation_claimed: 0,
+            creator_vesting_seconds: 0,
+        }
+    }
+    
+    #[test]
+    fn test_name_and_symbol_length_in_bytes_and_chars() {
+        // ASCII at the boundary: bytes == chars
+        assert!(TokenLaunch::name_fits(&"a".repeat(32)));
+        assert!(!TokenLaunch::name_fits(&"a".repeat(33)));
+        assert!(TokenLaunch::symbol_fits(&"A".repeat(10)));
+        assert!(!TokenLaunch::symbol_fits(&"A".repeat(11)));
+        
+        // Two-byte characters hit the byte limit at half the character count
+        assert!(TokenLaunch::name_fits(&"é".repeat(16)));
+        assert!(!TokenLaunch::name_fits(&"é".repeat(17)));
+        assert!(TokenLaunch::symbol_fits(&"é".repeat(5)));
+        assert!(!TokenLaunch::symbol_fits(&"é".repeat(6)));
+        
+        // Four-byte characters: 8 fit a name, 9 don't
+        assert!(TokenLaunch::name_fits(&"🚀".repeat(8)));
+        assert!(!TokenLaunch::name_fits(&"🚀".repeat(9)));
+    }
+    
+    #[test]
+    fn test_token_launch_len_holds_max_strings() {
+        let launch = TokenLaunch {
+            creator: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            bonding_curve: Pubkey::new_unique(),
+            metadata_uri: "u".repeat(TokenLaunch::MAX_URI_LEN),
+            name: "n".repeat(TokenLaunch::MAX_NAME_LEN),
+            symbol: "S".repeat(TokenLaunch::MAX_SYMBOL_LEN),
+            description: "d".repeat(TokenLaunch::MAX_DESCRIPTION_LEN),
+            total_supply: TOTAL_SUPPLY,
+            circulating_supply: 0,
+            launch_timestamp: 0,
+            launch_slot: 0,
+            trading_starts_at: 0,
+            is_active: true,
+            is_mutable: true,
+            admin_frozen: false,
+            paused_at: 0,
+            total_paused_seconds: 0,
+            last_metadata_update: 0,
+            bump: 255,
+            reserved: [0; 64],
+            sells_disabled: false,
+            deactivates_at: 0,
+            deactivation_reason: Some(LaunchStatusChange::Pause),
+        };
+        
+        let mut serialized = Vec::new();
+        launch.serialize(&mut serialized).unwrap();
+        
+        // 8-byte discriminator + borsh body must fit the allocated space exactly
+        assert_eq!(8 + serialized.len(), TokenLaunch::LEN);
+    }
+    
+    #[test]
+    fn test_account_lens_include_reserved_headroom() {
+        // Config at its largest: every URI prefix and fee split slot filled
+        let mut config = config_with_liquidity_destinations(Pubkey::default(), Pubkey::default());
+        config.allowed_uri_prefixes = vec!["p".repeat(LaunchpadConfig::MAX_URI_PREFIX_LEN); LaunchpadConfig::MAX_URI_PREFIXES];
+        config.fee_splits = vec![
+            FeeSplit { recipient: Pubkey::new_unique(), weight_bps: 2_500 };
+            LaunchpadConfig::MAX_FEE_SPLITS
+        ];
+        let mut serialized = Vec::new();
+        config.serialize(&mut serialized).unwrap();
+        assert_eq!(8 + serialized.len(), LaunchpadConfig::LEN);
+        
+        let mut curve = curve_with_tokens_sold(0);
+        curve.pool_address = Some(Pubkey::new_unique());
+        curve.fee_schedule_by_progress = Some(FeeSchedule { start_bps: 300, end_bps: 100 });
+        let mut serialized = Vec::new();
+        curve.serialize(&mut serialized).unwrap();
+        assert_eq!(8 + serialized.len(), BondingCurve::LEN);
+        
+        let position = UserPosition {
+            user: Pubkey::new_unique(),
+            token_launch: Pubkey::new_unique(),
+            token_amount: 0,
+            sol_invested: 0,
+            sol_received: 0,
+            buy_count: 0,
+            sell_count: 0,
+            last_interaction: 0,
+            first_buy_timestamp: 0,
+            bump: 255,
+            reserved: [0; 64],
+        };
+        let mut serialized = Vec::new();
+        position.serialize(&mut serialized).unwrap();
+        assert_eq!(8 + serialized.len(), UserPosition::LEN);
+        
+        // Headroom sits after every real field, so new fields can be carved from its front
+        assert_eq!(&serialized[serialized.len() - 64..], &[0u8; 64][..]);
+        
+        let stats = GlobalStats {
+            total_launches: 0,
+            total_volume: 0,
+            total_graduated: 0,
+            total_fees: 0,
+            bump: 255,
+            reserved: [0; 64],
+        };
+        let mut serialized = Vec::new();
+        stats.serialize(&mut serialized).unwrap();
+        assert_eq!(8 + serialized.len(), GlobalStats::LEN);
+        
+        let mut history = TradeHistory {
+            token_launch: Pubkey::default(),
+            total_trades: 0,
+            trades: [TradeRecord::default(); TRADE_HISTORY_CAPACITY],
+            bump: 0,
+            reserved: [0; 32],
+        };
+        history.initialize(Pubkey::new_unique(), 255);
+        let mut serialized = Vec::new();
+        history.serialize(&mut serialized).unwrap();
+        assert_eq!(8 + serialized.len(), TradeHistory::LEN);
+        // Created through CPI at launch creation, so it must stay under the 10KB init limit
+        const _: () = assert!(TradeHistory::LEN <= 10_240);
+    }
+    
+    #[test]
+    fn test_trade_history_wraps_after_capacity() {
+        let mut history = TradeHistory {
+            token_launch: Pubkey::default(),
+            total_trades: 0,
+            trades: [TradeRecord::default(); TRADE_HISTORY_CAPACITY],
+            bump: 0,
+            reserved: [0; 32],
+        };
+        let trader = Pubkey::new_unique();
+        
+        // Nothing recorded yet
+        assert!(history.recent_trades(0, 16).unwrap().trades.is_empty());
+        assert!(history.recent_trades(0, 0).is_err());
+        assert!(history.recent_trades(0, TradeHistory::MAX_PAGE_SIZE + 1).is_err());
+        
+        // Trade n buys n tokens for 2n lamports
+        let total = TRADE_HISTORY_CAPACITY as u64 + 5;
+        for n in 1..=total {
+            let side = if n % 2 == 0 { TradeSide::Sell } else { TradeSide::Buy };
+            history.record(side, trader, n, 2 * n, n as i64).unwrap();
+        }
+        assert_eq!(history.total_trades, total);
+        
+        // Pages walk back from the newest trade until the oldest surviving one
+        let mut seen = Vec::new();
+        let mut offset = 0;
+        loop {
+            let page = history.recent_trades(offset, 16).unwrap();
+            assert_eq!(page.total_trades, total);
+            if page.trades.is_empty() {
+                break;
+            }
+            offset += page.trades.len() as u32;
+            seen.extend(page.trades);
+        }
+        assert_eq!(seen.len(), TRADE_HISTORY_CAPACITY);
+        let amounts: Vec<u64> = seen.iter().map(|trade| trade.token_amount).collect();
+        let expected: Vec<u64> = (6..=total).rev().collect();
+        assert_eq!(amounts, expected);
+        
+        let newest = seen[0];
+        assert_eq!(newest.side, TradeSide::Buy);
+        assert_eq!(newest.trader, trader);
+        assert_eq!(newest.timestamp, total as i64);
+        assert_eq!(newest.price_per_token, 2_000_000_000);
+        assert_eq!(seen[1].side, TradeSide::Sell);
+        // 100 SOL for 1,000 tokens: 0.1 SOL each, past where u64 scaling overflowed
+        assert_eq!(TradeRecord::price_per_token(100_000_000_000, 1_000_000_000_000), 100_000_000);
+        assert_eq!(TradeRecord::price_per_token(1_000_000_000, 0), 0);
+        
+        // Offsets past the stored trades return an empty page
+        assert!(history.recent_trades(TRADE_HISTORY_CAPACITY as u32, 16).unwrap().trades.is_empty());
+        assert!(history.recent_trades(u32::MAX, 16).unwrap().trades.is_empty());
+    }
+    
+    #[test]
+    fn test_position_return_over_time() {
+        let position = UserPosition {
+            user: Pubkey::default(),
+            token_launch: Pubkey::default(),
+            token_amount: 1_000_000_000_000_000, // 1M tokens
+            sol_invested: 1_000_000_000,         // 1 SOL
+            sol_received: 0,
+            buy_count: 1,
+            sell_count: 0,
+            last_interaction: 1_000,
+            first_buy_timestamp: 1_000,
+            bump: 0,
+            reserved: [0; 64],
+        };
+        
+        // Spot of 1_100 lamports per token values the 1M tokens at 1.1 SOL
+        let held_for = (SECONDS_PER_YEAR / 4) as i64;
+        let result = position.position_return(1_100, 1_000 + held_for).unwrap();
+        assert_eq!(result.current_value, 1_100_000_000);
+        assert_eq!(result.absolute_return, 100_000_000);
+        assert_eq!(result.return_bps, 1_000);
+        assert_eq!(result.held_seconds, held_for);
+        // 10% over a quarter is 40% simple annualized
+        assert_eq!(result.annualized_return_bps, 4_000);
+        
+        // Losses are negative, and no elapsed time means no annualized figure
+        let result = position.position_return(900, 1_000).unwrap();
+        assert_eq!(result.absolute_return, -100_000_000);
+        assert_eq!(result.return_bps, -1_000);
+        assert_eq!(result.annualized_return_bps, 0);
+    }
+    
+    #[test]
+    fn test_launch_positions_pro_rata() {
+        let (alice, bob, carol) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let holdings = [(alice, 500), (bob, 300), (carol, 200)];
+        
+        let result = LaunchPositions::from_holdings(&holdings).unwrap();
+        assert_eq!(result.total_tokens, 1_000);
+        let shares: Vec<u16> = result.positions.iter().map(|p| p.share_bps).collect();
+        assert_eq!(shares, vec![5_000, 3_000, 2_000]);
+        assert_eq!(result.positions[1].user, bob);
+        
+        // Uneven splits floor, never handing out more than the whole
+        let result = LaunchPositions::from_holdings(&[(alice, 1), (bob, 1), (carol, 1)]).unwrap();
+        let total_bps: u16 = result.positions.iter().map(|p| p.share_bps).sum();
+        assert_eq!(total_bps, 9_999);
+        
+        // Empty positions have no share rather than dividing by zero
+        let result = LaunchPositions::from_holdings(&[(alice, 0)]).unwrap();
+        assert_eq!(result.total_tokens, 0);
+        assert_eq!(result.positions[0].share_bps, 0);
+        
+        assert!(LaunchPositions::from_holdings(&[(alice, u64::MAX), (bob, 1)]).is_err());
+    }
+    
+    #[test]
+    fn test_global_stats_accumulate() {
+        let mut stats = GlobalStats {
+            total_launches: 0,
+            total_volume: 0,
+            total_graduated: 0,
+            total_fees: 0,
+            bump: 0,
+            reserved: [0; 64],
+        };
+        
+        stats.record_launch().unwrap();
+        stats.record_launch().unwrap();
+        stats.record_trade(1_000_000_000, 10_000_000).unwrap();
+        stats.record_trade(500_000_000, 5_000_000).unwrap();
+        stats.record_graduation().unwrap();
+        
+        let totals = stats.totals();
+        assert_eq!(totals.total_launches, 2);
+        assert_eq!(totals.total_volume, 1_500_000_000);
+        assert_eq!(totals.total_fees, 15_000_000);
+        assert_eq!(totals.total_graduated, 1);
+        
+        // An overflowing trade is rejected rather than wrapping the totals
+        assert!(stats.record_trade(u64::MAX, 0).is_err());
+        assert_eq!(stats.total_volume, 1_500_000_000);
+    }
+    
+    #[test]
+    fn test_toggle_paused_accumulates_pause_time() {
+        let mut launch = TokenLaunch {
+            creator: Pubkey::default(),
+            mint: Pubkey::default(),
+            bonding_curve: Pubkey::default(),
+            metadata_uri: String::new(),
+            name: String::new(),
+            symbol: String::new(),
+            description: String::new(),
+            total_supply: TOTAL_SUPPLY,
+            circulating_supply: 0,
+            launch_timestamp: 0,
+            launch_slot: 0,
+            trading_starts_at: 0,
+            is_active: true,
+            is_mutable: true,
+            admin_frozen: false,
+            paused_at: 0,
+            total_paused_seconds: 0,
+            last_metadata_update: 0,
+            bump: 0,
+            reserved: [0; 64],
+            sells_disabled: false,
+            deactivates_at: 0,
+            deactivation_reason: None,
+        };
+        
+        assert_eq!(launch.toggle_paused(1_000).unwrap(), 0);
+        assert!(!launch.is_active);
+        assert_eq!(launch.paused_at, 1_000);
+        
+        assert_eq!(launch.toggle_paused(1_060).unwrap(), 60);
+        assert!(launch.is_active);
+        assert_eq!(launch.paused_at, 0);
+        
+        // Second cycle accumulates on top of the first
+        launch.toggle_paused(2_000).unwrap();
+        assert_eq!(launch.toggle_paused(2_030).unwrap(), 30);
+        assert_eq!(launch.total_paused_seconds, 90);
+        assert_eq!(launch.deactivation_reason, None);
+        
+        // A scheduled pause leaves trading open until it comes due
+        launch.deactivates_at = 3_000;
+        assert!(launch.is_trading_active(2_999));
+        assert!(!launch.is_trading_active(3_000));
+        
+        // Settling before it is due changes nothing
+        launch.settle_scheduled_pause(2_999);
+        assert!(launch.is_active);
+        assert_eq!(launch.deactivates_at, 3_000);
+        
+        // Once due, the pause is dated from when it took effect
+        launch.settle_scheduled_pause(3_050);
+        assert!(!launch.is_active);
+        assert_eq!(launch.paused_at, 3_000);
+        assert_eq!(launch.deactivates_at, 0);
+        assert_eq!(launch.deactivation_reason, Some(LaunchStatusChange::Pause));
+        assert_eq!(launch.toggle_paused(3_100).unwrap(), 100);
+    }
+    
+    #[test]
+    fn test_name_and_symbol_validation() {
+        assert!(TokenLaunch::is_valid_name("Doge Coin 2.0"));
+        assert!(TokenLaunch::is_valid_symbol("DOGE"));
+        
+        // Control and zero-width characters
+        assert!(!TokenLaunch::is_valid_name("Doge\nCoin"));
+        assert!(!TokenLaunch::is_valid_name("Doge\u{200B}Coin"));
+        assert!(!TokenLaunch::is_valid_symbol("DO\u{0}GE"));
+        
+        // Cyrillic homoglyphs
+        assert!(!TokenLaunch::is_valid_name("D\u{043E}ge Coin"));
+        assert!(!TokenLaunch::is_valid_symbol("D\u{041E}GE"));
+        
+        // Empty, padded, or spaced
+        assert!(!TokenLaunch::is_valid_name(""));
+        assert!(!TokenLaunch::is_valid_name(" Doge"));
+        assert!(!TokenLaunch::is_valid_symbol(""));
+        assert!(!TokenLaunch::is_valid_symbol("DO GE"));
+    }
+    
+    #[test]
+    fn test_tokens_until_graduation() {
+        assert_eq!(curve_with_tokens_sold(0).tokens_until_graduation(), CURVE_SUPPLY);
+        assert_eq!(
+            curve_with_tokens_sold(CURVE_SUPPLY / 2).tokens_until_graduation(),
+            CURVE_SUPPLY / 2
+        );
+        assert_eq!(curve_with_tokens_sold(CURVE_SUPPLY).tokens_until_graduation(), 0);
+    }
+    
+    #[test]
+    fn test_graduation_fee_split() {
+        let mut config = config_with_liquidity_destinations(Pubkey::default(), Pubkey::default());
+        assert_eq!(config.graduation_fee(123_000_000_000).unwrap(), 0);
+        
+        config.graduation_fee_bps = 500; // 5%
+        assert_eq!(config.graduation_fee(100_000_000_000).unwrap(), 5_000_000_000);
+        
+        config.graduation_fee_bps = LaunchpadConfig::MAX_GRADUATION_FEE_BPS;
+        assert_eq!(config.graduation_fee(u64::MAX).unwrap(), u64::MAX / 10);
+    }
+    
+    #[test]
+    fn test_withdraw_fee_is_proportional() {
+        let mut config = config_with_liquidity_destinations(Pubkey::default(), Pubkey::default());
+        assert_eq!(config.withdraw_fee(50_000_000_000).unwrap(), 0);
+        
+        // 2% of each withdrawal, whatever its size
+        config.withdraw_fee_bps = 200;
+        assert_eq!(config.withdraw_fee(50_000_000_000).unwrap(), 1_000_000_000);
+        assert_eq!(config.withdraw_fee(5_000_000_000).unwrap(), 100_000_000);
+        
+        // Both fees at their caps still leave most of the withdrawal
+        config.graduation_fee_bps = LaunchpadConfig::MAX_GRADUATION_FEE_BPS;
+        config.withdraw_fee_bps = LaunchpadConfig::MAX_WITHDRAW_FEE_BPS;
+        let amount = 100_000_000_000;
+        let fees = config.graduation_fee(amount).unwrap() + config.withdraw_fee(amount).unwrap();
+        assert_eq!(fees, 20_000_000_000);
+    }
+    
+    #[test]
+    fn test_split_fee_loses_no_lamports() {
+        let mut config = config_with_liquidity_destinations(Pubkey::default(), Pubkey::default());
+        config.fee_splits = vec![
+            FeeSplit { recipient: Pubkey::new_unique(), weight_bps: 6_000 },
+            FeeSplit { recipient: Pubkey::new_unique(), weight_bps: 3_000 },
+            FeeSplit { recipient: Pubkey::new_unique(), weight_bps: 1_000 },
+        ];
+        assert!(LaunchpadConfig::is_valid_fee_split(&config.fee_splits));
+        
+        assert_eq!(config.split_fee(1_000_000).unwrap(), vec![600_000, 300_000, 100_000]);
+        
+        // Fees that don't divide evenly leave the dust with the last recipient
+        for fee in [1, 7, 999, 1_234_567, u64::MAX] {
+            let shares = config.split_fee(fee).unwrap();
+            assert_eq!(shares.iter().map(|&s| s as u128).sum::<u128>(), fee as u128);
+        }
+        assert_eq!(config.split_fee(7).unwrap(), vec![4, 2, 1]);
+    }
+    
+    #[test]
+    fn test_fee_split_validation() {
+        let split = |weight_bps| FeeSplit { recipient: Pubkey::new_unique(), weight_bps };
+        
+        assert!(LaunchpadConfig::is_valid_fee_split(&[]));
+        assert!(LaunchpadConfig::is_valid_fee_split(&[split(10_000)]));
+        
+        // Weights must sum to exactly 100%
+        assert!(!LaunchpadConfig::is_valid_fee_split(&[split(6_000), split(3_000)]));
+        assert!(!LaunchpadConfig::is_valid_fee_split(&[split(6_000), split(5_000)]));
+        // No zero weights, unset or repeated recipients, or oversized tables
+        assert!(!LaunchpadConfig::is_valid_fee_split(&[split(10_000), split(0)]));
+        let unset = FeeSplit { recipient: Pubkey::default(), weight_bps: 10_000 };
+        assert!(!LaunchpadConfig::is_valid_fee_split(&[unset]));
+        let repeated = split(5_000);
+        assert!(!LaunchpadConfig::is_valid_fee_split(&[repeated, repeated]));
+        let five_way = [split(2_000), split(2_000), split(2_000), split(2_000), split(2_000)];
+        assert!(!LaunchpadConfig::is_valid_fee_split(&five_way));
+    }
+    
+    #[test]
+    fn test_effective_fee_bps_with_loyalty_discount() {
+        let mut config = config_with_liquidity_destinations(Pubkey::default(), Pubkey::default());
+        config.fee_discount_bps = 5_000; // 50% off the platform fee
+        
+        let base = config.platform_fee_bps;
+        
+        // No discount mint configured -> full fee even for "holders"
+        assert_eq!(config.effective_fee_bps(base, true), 100);
+        
+        config.fee_discount_mint = Pubkey::new_unique();
+        assert_eq!(config.effective_fee_bps(base, false), 100);
+        assert_eq!(config.effective_fee_bps(base, true), 50);
+        // The discount applies to a launch's scheduled fee just the same
+        assert_eq!(config.effective_fee_bps(300, true), 150);
+        
+        config.fee_discount_bps = 10_000;
+        assert_eq!(config.effective_fee_bps(base, true), 0);
+    }
+    
+    #[test]
+    fn test_fee_schedule_by_progress() {
+        let decreasing = FeeSchedule { start_bps: 300, end_bps: 100 };
+        assert_eq!(decreasing.fee_bps_at(0, CURVE_SUPPLY), 300);
+        assert_eq!(decreasing.fee_bps_at(CURVE_SUPPLY / 2, CURVE_SUPPLY), 200);
+        assert_eq!(decreasing.fee_bps_at(CURVE_SUPPLY, CURVE_SUPPLY), 100);
+        // Sells during a graduation grace window can't push past the end
+        assert_eq!(decreasing.fee_bps_at(u64::MAX, CURVE_SUPPLY), 100);
+        
+        let increasing = FeeSchedule { start_bps: 0, end_bps: 1_000 };
+        assert_eq!(increasing.fee_bps_at(0, CURVE_SUPPLY), 0);
+        assert_eq!(increasing.fee_bps_at(CURVE_SUPPLY / 2, CURVE_SUPPLY), 500);
+        assert_eq!(increasing.fee_bps_at(CURVE_SUPPLY, CURVE_SUPPLY), 1_000);
+        
+        assert!(increasing.is_valid());
+        assert!(!FeeSchedule { start_bps: 1_001, end_bps: 0 }.is_valid());
+        assert!(!FeeSchedule { start_bps: 0, end_bps: 1_001 }.is_valid());
+        
+        // Without a schedule the launch pays the config's flat fee
+        let mut curve = curve_with_tokens_sold(CURVE_SUPPLY / 2);
+        assert_eq!(curve.platform_fee_bps(100), 100);
+        curve.fee_schedule_by_progress = Some(decreasing);
+        assert_eq!(curve.platform_fee_bps(100), 200);
+    }
+    
+    #[test]
+    fn test_trade_cooldown() {
+        let mut curve = curve_with_tokens_sold(0);
+        
+        // Never traded, or no cooldown configured
+        assert!(!curve.is_in_trade_cooldown(1_000, 10));
+        curve.last_trade_time = 1_000;
+        assert!(!curve.is_in_trade_cooldown(1_000, 0));
+        
+        assert!(curve.is_in_trade_cooldown(1_000, 10));
+        assert!(curve.is_in_trade_cooldown(1_009, 10));
+        assert!(!curve.is_in_trade_cooldown(1_010, 10));
+    }
+    
+    #[test]
+    fn test_trade_limit() {
+        let mut curve = curve_with_tokens_sold(0);
+        
+        // Zero means unlimited
+        curve.trade_count = 1_000;
+        assert!(!curve.has_reached_trade_limit());
+        
+        curve.max_trades = 3;
+        curve.trade_count = 2;
+        assert!(!curve.has_reached_trade_limit());
+        curve.trade_count = 3;
+        assert!(curve.has_reached_trade_limit());
+    }
+    
+    #[test]
+    fn test_graduation_grace() {
+        let mut curve = curve_with_tokens_sold(800_000_000 * 1_000_000_000);
+        
+        curve.graduation_grace_seconds = 60;
+        
+        // Not graduated yet: the grace window doesn't apply
+        assert!(!curve.is_in_graduation_grace(1_000));
+        
+        curve.is_graduated = true;
+        curve.graduated_at = 1_000;
+        assert!(curve.is_in_graduation_grace(1_000));
+        assert!(curve.is_in_graduation_grace(1_060));
+        assert!(!curve.is_in_graduation_grace(1_061));
+        
+        // Liquidity leaving the vaults closes the window early
+        curve.record_liquidity_withdrawal(1, 0).unwrap();
+        assert!(!curve.is_in_graduation_grace(1_000));
+        
+        // Zero disables the window entirely
+        curve.liquidity_sol_withdrawn = 0;
+        curve.graduation_grace_seconds = 0;
+        assert!(!curve.is_in_graduation_grace(1_000));
+    }
+    
+    #[test]
+    fn test_liquidity_lock_after_graduation() {
+        let mut curve = curve_with_tokens_sold(800_000_000 * 1_000_000_000);
+        
+        // The lock only starts counting once the curve graduates
+        assert!(!curve.is_liquidity_locked(1_000, 3_600));
+        
+        curve.is_graduated = true;
+        curve.graduated_at = 1_000;
+        assert!(curve.is_liquidity_locked(1_000, 3_600));
+        assert!(curve.is_liquidity_locked(4_599, 3_600));
+        assert!(!curve.is_liquidity_locked(4_600, 3_600));
+        
+        // Zero means no lock
+        assert!(!curve.is_liquidity_locked(1_000, 0));
+    }
+    
+    #[test]
+    fn test_outstanding_liquidity_never_pays_twice() {
+        let mut curve = curve_with_tokens_sold(CURVE_SUPPLY);
+        curve.sol_reserve = 80_000_000_000;
+        assert!(!curve.has_withdrawn_liquidity());
+        
+        // Nothing withdrawn yet: everything the vaults hold, up to the reserve and allocation
+        assert_eq!(curve.outstanding_liquidity(80_000_000_000, LP_SUPPLY), (80_000_000_000, LP_SUPPLY));
+        assert_eq!(curve.outstanding_liquidity(90_000_000_000, LP_SUPPLY), (80_000_000_000, LP_SUPPLY));
+        
+        // A withdrawal that moved the SOL but not the tokens leaves only the tokens owed
+        curve.record_liquidity_withdrawal(80_000_000_000, 0).unwrap();
+        assert!(curve.has_withdrawn_liquidity());
+        assert_eq!(curve.outstanding_liquidity(5_000_000, LP_SUPPLY), (0, LP_SUPPLY));
+        
+        // Once both are out, later deposits into the vaults are not claimable as liquidity
+        curve.record_liquidity_withdrawal(0, LP_SUPPLY).unwrap();
+        assert_eq!(curve.outstanding_liquidity(5_000_000, 1_000), (0, 0));
+    }
+    
+    #[test]
+    fn test_metadata_update_cooldown() {
+        let mut launch = TokenLaunch {
+            creator: Pubkey::default(),
+            mint: Pubkey::default(),
+            bonding_curve: Pubkey::default(),
+            metadata_uri: String::new(),
+            name: String::new(),
+            symbol: String::new(),
+            description: String::new(),
+            total_supply: TOTAL_SUPPLY,
+            circulating_supply: 0,
+            launch_timestamp: 0,
+            launch_slot: 0,
+            trading_starts_at: 0,
+            is_active: true,
+            is_mutable: true,
+            admin_frozen: false,
+            paused_at: 0,
+            total_paused_seconds: 0,
+            last_metadata_update: 0,
+            bump: 0,
+            reserved: [0; 64],
+            sells_disabled: false,
+            deactivates_at: 0,
+            deactivation_reason: None,
+        };
+        
+        // Never updated, or no cooldown configured
+        assert!(!launch.is_in_metadata_cooldown(1_000, 60));
+        launch.last_metadata_update = 1_000;
+        assert!(!launch.is_in_metadata_cooldown(1_000, 0));
+        
+        assert!(launch.is_in_metadata_cooldown(1_059, 60));
+        assert!(!launch.is_in_metadata_cooldown(1_060, 60));
+    }
+    
+    #[test]
+    fn test_raise_cap() {
+        let mut curve = curve_with_tokens_sold(0);
+        curve.sol_reserve = 9_000_000_000;
+        
+        // Zero disables the cap
+        assert!(!curve.exceeds_raise_cap(u64::MAX));
+        
+        curve.max_sol_raise_lamports = 10_000_000_000;
+        assert!(!curve.exceeds_raise_cap(1_000_000_000));
+        assert!(curve.exceeds_raise_cap(1_000_000_001));
+        assert!(curve.exceeds_raise_cap(u64::MAX));
+    }
+    
+    #[test]
+    fn test_max_buy_fraction_tightens_as_curve_fills() {
+        let million = 1_000_000 * 10u64.pow(TOKEN_DECIMALS as u32);
+        
+        // Zero disables the cap: the whole reserve can be bought at once
+        let curve = curve_with_tokens_sold(0);
+        assert_eq!(curve.max_buy_tokens(0, 0), CURVE_SUPPLY);
+        
+        // 10% of a fresh 800M reserve is 80M
+        assert_eq!(curve.max_buy_tokens(1_000, 0), 80 * million);
+        
+        // Near the end only 5M remain, so the same cap allows 0.5M
+        let curve = curve_with_tokens_sold(CURVE_SUPPLY - 5 * million);
+        assert_eq!(curve.max_buy_tokens(1_000, 0), million / 2);
+        
+        // ...but never less than the minimum trade size, which would lock the curve
+        assert_eq!(curve.max_buy_tokens(1_000, million), million);
+        
+        // The last few tokens can still be bought, so the curve can sell out
+        let one_token = 10u64.pow(TOKEN_DECIMALS as u32);
+        let curve = curve_with_tokens_sold(CURVE_SUPPLY - 3 * one_token);
+        assert_eq!(curve.max_buy_tokens(1_000, 0), one_token);
+    }
+    
+    #[test]
+    fn test_curve_health() {
+        let mut curve = curve_with_tokens_sold(100_000_000_000_000_000); // 100M sold
+        curve.sol_reserve = 10_000_000_000;
+        curve.trade_count = 5;
+        
+        // Exactly covered, or short only by per-trade rounding dust
+        let health = curve.health(10_000_000_000);
+        assert!(health.is_solvent);
+        assert_eq!(health.coverage_bps, 10_000);
+        assert!(curve.health(10_000_000_005).is_solvent);
+        
+        // Synthetically under-reserved: only 80% of the liability is backed
+        let health = curve.health(12_500_000_000);
+        assert!(!health.is_solvent);
+        assert_eq!(health.reserve_lamports, 10_000_000_000);
+        assert_eq!(health.liability_lamports, 12_500_000_000);
+        assert_eq!(health.coverage_bps, 8_000);
+        
+        // Nothing sold means nothing owed
+        curve.tokens_sold = 0;
+        curve.sol_reserve = 0;
+        let health = curve.health(0);
+        assert!(health.is_solvent);
+        assert_eq!(health.coverage_bps, 10_000);
+    }
+    
+    #[test]
+    fn test_unattributed_vault_lamports() {
+        let mut curve = curve_with_tokens_sold(0);
+        curve.sol_reserve = 5_000_000_000;
+        curve.accrued_fees = 50_000_000;
+        let rent = 890_880;
+        
+        let balanced = curve.sol_reserve + curve.accrued_fees + rent;
+        assert_eq!(curve.unattributed_vault_lamports(balanced, rent).unwrap(), 0);
+        
+        // Direct transfer into the vault shows up as a surplus
+        assert_eq!(curve.unattributed_vault_lamports(balanced + 1_000, rent).unwrap(), 1_000);
+        
+        // Vault short of what the curve records shows up as a deficit
+        assert_eq!(curve.unattributed_vault_lamports(balanced - 1_000, rent).unwrap(), -1_000);
+    }
+    
+    #[test]
+    fn test_reserve_invariant() {
+        let curve = curve_with_tokens_sold(CURVE_SUPPLY / 4);
+        let expected_balance = curve.token_reserve;
+        
+        assert!(curve.check_reserve_invariant(expected_balance).is_ok());
+        // Stray tokens sent to the curve account are tolerated
+        assert!(curve.check_reserve_invariant(expected_balance + 1).is_ok());
+        // Tokens missing from the curve account are not
+        assert!(curve.check_reserve_invariant(expected_balance - 1).is_err());
+        
+        assert_eq!(curve.stray_curve_tokens(expected_balance), 0);
+        assert_eq!(curve.stray_curve_tokens(expected_balance + 7), 7);
+        assert_eq!(curve.stray_curve_tokens(expected_balance - 1), 0);
+        
+        // State fields drifting apart is a discrepancy
+        let mut drifted = curve_with_tokens_sold(CURVE_SUPPLY / 4);
+        drifted.tokens_sold += 1;
+        assert!(drifted.check_reserve_invariant(CURVE_SUPPLY).is_err());
+    }
+    
+    #[test]
+    fn test_vault_bump_derived_when_not_stored() {
+        let mut curve = curve_with_tokens_sold(0);
+        let curve_key = Pubkey::new_unique();
+        let (_, derived) = Pubkey::find_program_address(&[b"sol_vault", curve_key.as_ref()], &crate::ID);
+        
+        // Curves from before the bump was stored read zero and fall back to deriving it
+        assert_eq!(curve.vault_bump(&curve_key), derived);
+        
+        curve.sol_vault_bump = derived;
+        assert_eq!(curve.vault_bump(&curve_key), derived);
+    }
+    
+    #[test]
+    fn test_reserve_invariant_after_graduation_burn() {
+        // Graduated on a SOL target with a quarter of the curve unsold, which was burned
+        let mut curve = curve_with_tokens_sold(CURVE_SUPPLY * 3 / 4);
+        curve.is_graduated = true;
+        curve.token_reserve = 0;
+        assert_eq!(curve.curve_supply(), CURVE_SUPPLY * 3 / 4);
+        assert!(curve.check_reserve_invariant(0).is_ok());
+        assert_eq!(curve.graduation_progress_bps(), 10_000);
+        
+        // A grace-window sell moves tokens back onto the curve without breaking it
+        curve.tokens_sold -= 1_000;
+        curve.token_reserve += 1_000;
+        assert_eq!(curve.curve_supply(), CURVE_SUPPLY * 3 / 4);
+        assert!(curve.check_reserve_invariant(1_000).is_ok());
+        assert!(curve.check_reserve_invariant(999).is_err());
+        
+        // Nothing can push the accounted supply above the original curve supply
+        curve.token_reserve = CURVE_SUPPLY;
+        assert!(curve.check_reserve_invariant(CURVE_SUPPLY).is_err());
+    }
+    
+    #[test]
+    fn test_trade_price_without_feed_uses_backup() {
+        let mut curve = curve_with_tokens_sold(0);
+        let backup = curve.sol_price_usd;
+        
+        // Without a feed account the backup is only usable when manual pricing is on
+        assert!(curve.resolve_trade_price(None, false, DEFAULT_PRICE_STALENESS_SECONDS, 1_000).is_err());
+        assert_eq!(curve.resolve_trade_price(None, true, DEFAULT_PRICE_STALENESS_SECONDS, 1_000).unwrap(), backup);
+        // The backup is used as-is, not recorded as a fresh oracle read
+        assert_eq!(curve.sol_price_updated_at, 0);
+        
+        // A missing backup price still can't be traded against
+        curve.sol_price_usd = 0;
+        assert!(curve.resolve_trade_price(None, true, DEFAULT_PRICE_STALENESS_SECONDS, 1_000).is_err());
+    }
+    
+    #[test]
+    fn test_trade_price_falls_back_on_future_dated_feed() {
+        let mut curve = curve_with_tokens_sold(0);
+        let backup = curve.sol_price_usd;
+        let now = 1_000;
+        let feed_published_at = |publish_time: i64| PriceUpdateV2 {
+            write_authority: Pubkey::default(),
+            verification_level: VerificationLevel::Full,
+            price_message: PriceFeedMessage {
+                feed_id: [0; 32],
+                price: 16_000_000_000,
+                conf: 0,
+                exponent: -8,
+                publish_time,
+                prev_publish_time: publish_time - 1,
+                ema_price: 16_000_000_000,
+                ema_conf: 0,
+            },
+            posted_slot: 0,
+        };
+        
+        // Clock skew: the trade prices off the backup instead of failing
+        let ahead = feed_published_at(now + 30);
+        assert_eq!(curve.resolve_trade_price(Some(&ahead), false, 60, now).unwrap(), backup);
+        assert_eq!(curve.sol_price_updated_at, 0);
+        
+        // Same for a stale update
+        let stale = feed_published_at(now - 61);
+        assert_eq!(curve.resolve_trade_price(Some(&stale), false, 60, now).unwrap(), backup);
+        
+        // A fresh update is used and recorded as the new backup
+        let fresh = feed_published_at(now - 10);
+        assert_eq!(curve.resolve_trade_price(Some(&fresh), false, 60, now).unwrap(), 16_000_000_000);
+        assert_eq!(curve.sol_price_usd, 16_000_000_000);
+        assert_eq!(curve.sol_price_updated_at, now);
+    }
+    
+    #[test]
+    fn test_fix_initial_price_only_before_first_trade() {
+        let mut curve = curve_with_tokens_sold(0);
+        let original = curve.sol_price_usd;
+        
+        // Out-of-range prices are rejected and leave the price untouched
+        assert!(curve.fix_initial_price(0, 1_000).is_err());
+        assert!(curve.fix_initial_price(u64::MAX, 1_000).is_err());
+        assert_eq!(curve.sol_price_usd, original);
+        
+        // A fix after an oracle refresh is creator-supplied again
+        curve.sol_price_updated_at = 1_000;
+        assert_eq!(curve.fix_initial_price(20_000_000_000, 1_000).unwrap(), original);
+        assert_eq!(curve.sol_price_usd, 20_000_000_000);
+        assert_eq!(curve.sol_price_updated_at, 0);
+        
+        curve.trade_count = 1;
+        assert!(curve.fix_initial_price(15_000_000_000, 1_000).is_err());
+        assert_eq!(curve.sol_price_usd, 20_000_000_000);
+    }
+    
+    #[test]
+    fn test_graduation_progress_and_usd_raised() {
+        assert_eq!(curve_with_tokens_sold(0).graduation_progress_bps(), 0);
+        assert_eq!(curve_with_tokens_sold(CURVE_SUPPLY / 4).graduation_progress_bps(), 2_500);
+        assert_eq!(curve_with_tokens_sold(CURVE_SUPPLY).graduation_progress_bps(), 10_000);
+        
+        // 80 SOL at $150/SOL = $12,000
+        let mut curve = curve_with_tokens_sold(0);
+        curve.sol_reserve = 80_000_000_000;
+        assert_eq!(curve.usd_raised(), (GRADUATION_USD as u128) * (USD_SCALE as u128));
+    }
+    
+    #[test]
+    fn test_graduation_withheld_below_min_sol_reserve() {
+        let mut curve = curve_with_tokens_sold(CURVE_SUPPLY);
+        // 20 SOL at $1,000/SOL = $20k, comfortably above the USD threshold
+        curve.sol_reserve = 20_000_000_000;
+        curve.sol_price_usd = 100_000_000_000;
+        
+        assert!(curve.should_graduate(0, 0));
+        assert!(curve.should_graduate(20_000_000_000, 0));
+        assert!(!curve.should_graduate(20_000_000_001, 0));
+        assert!(!curve.should_graduate(80_000_000_000, 0));
+    }
+    
+    #[test]
+    fn test_graduation_status_reports_each_blocker() {
+        // Nothing sold: tokens block first, and the whole USD threshold is missing
+        let curve = curve_with_tokens_sold(0);
+        let status = curve.graduation_status(0, 0);
+        assert_eq!(status.blocking_reason, GraduationBlocker::TokensNotSold);
+        assert!(!status.tokens_met && !status.usd_met);
+        assert_eq!(status.tokens_short, CURVE_SUPPLY);
+        // $12,000 at $150/SOL = 80 SOL
+        assert_eq!(status.usd_short_lamports, 80_000_000_000);
+        
+        // Sold out but 79 SOL at $150/SOL is $11,850
+        let mut curve = curve_with_tokens_sold(CURVE_SUPPLY);
+        curve.sol_reserve = 79_000_000_000;
+        let status = curve.graduation_status(0, 0);
+        assert_eq!(status.blocking_reason, GraduationBlocker::UsdNotRaised);
+        assert!(status.tokens_met && !status.usd_met);
+        assert_eq!(status.tokens_short, 0);
+        assert_eq!(status.usd_short_lamports, 1_000_000_000);
+        
+        // USD met, but the admin minimum reserve is not
+        curve.sol_reserve = 80_000_000_000;
+        let status = curve.graduation_status(100_000_000_000, 0);
+        assert_eq!(status.blocking_reason, GraduationBlocker::SolReserveBelowMinimum);
+        assert!(status.usd_met && !status.min_sol_met);
+        assert_eq!(status.usd_short_lamports, 0);
+        assert_eq!(status.min_sol_short_lamports, 20_000_000_000);
+        
+        let status = curve.graduation_status(0, 0);
+        assert_eq!(status.blocking_reason, GraduationBlocker::None);
+        assert!(curve.should_graduate(0, 0));
+        
+        curve.is_graduated = true;
+        assert_eq!(curve.graduation_status(0, 0).blocking_reason, GraduationBlocker::AlreadyGraduated);
+        assert!(!curve.should_graduate(0, 0));
+    }
+    
+    #[test]
+    fn test_target_sol_vault_graduates_before_sell_out() {
+        let mut curve = curve_with_tokens_sold(CURVE_SUPPLY / 10);
+        curve.target_sol_vault_lamports = 5_000_000_000;
+        curve.sol_reserve = 4_000_000_000;
+        
+        let status = curve.graduation_status(0, 0);
+        assert_eq!(status.blocking_reason, GraduationBlocker::SolTargetNotReached);
+        assert_eq!(status.target_sol_short_lamports, 1_000_000_000);
+        
+        // Hitting the target graduates with most of the supply unsold and the USD threshold unmet
+        curve.sol_reserve = 5_000_000_000;
+        let status = curve.graduation_status(0, 0);
+        assert!(!status.tokens_met && !status.usd_met);
+        assert_eq!(status.target_sol_short_lamports, 0);
+        assert!(curve.should_graduate(0, 0));
+        
+        // The config's absolute reserve floor still applies
+        assert_eq!(
+            curve.graduation_status(6_000_000_000, 0).blocking_reason,
+            GraduationBlocker::SolReserveBelowMinimum
+        );
+        
+        // Without a target, selling out is still required
+        curve.target_sol_vault_lamports = 0;
+        assert_eq!(curve.graduation_status(0, 0).blocking_reason, GraduationBlocker::TokensNotSold);
+    }
+    
+    #[test]
+    fn test_creator_allocation_shrinks_curve_and_vests() {
+        let allocation = TOTAL_SUPPLY / 20; // 5%
+        let mut curve = curve_with_tokens_sold(0);
+        curve.creator_allocation = allocation;
+        curve.token_reserve = CURVE_SUPPLY - allocation;
+        curve.creator_vesting_seconds = 1_000;
+        assert_eq!(curve.curve_supply(), CURVE_SUPPLY - allocation);
+        assert!(curve.check_reserve_invariant(curve.token_reserve).is_ok());
+        
+        // Selling out the smaller curve meets the token condition
+        curve.tokens_sold = curve.curve_supply();
+        curve.token_reserve = 0;
+        assert!(curve.check_reserve_invariant(0).is_ok());
+        assert!(curve.graduation_status(0, 0).tokens_met);
+        assert_eq!(curve.tokens_until_graduation(), 0);
+        assert_eq!(curve.graduation_progress_bps(), 10_000);
+        
+        // Linear from launch, capped at the allocation
+        let launched = 5_000;
+        assert_eq!(curve.creator_allocation_vested(launched, launched - 10), 0);
+        assert_eq!(curve.creator_allocation_vested(launched, launched), 0);
+        assert_eq!(curve.creator_allocation_vested(launched, launched + 250), allocation / 4);
+        assert_eq!(curve.creator_allocation_vested(launched, launched + 5_000), allocation);
+        
+        // Claims only release what vested since the last one
+        curve.record_creator_claim(allocation / 4).unwrap();
+        assert_eq!(curve.claimable_creator_allocation(launched, launched + 250), 0);
+        assert_eq!(curve.claimable_creator_allocation(launched, launched + 500), allocation / 4);
+        
+        // No vesting period unlocks everything at once
+        curve.creator_vesting_seconds = 0;
+        assert_eq!(curve.claimable_creator_allocation(launched, launched), allocation - allocation / 4);
+    }
+    
+    #[test]
+    fn test_graduation_withheld_below_min_holders() {
+        // One whale bought out the curve: every other condition is met
+        let mut curve = curve_with_tokens_sold(CURVE_SUPPLY);
+        curve.sol_reserve = 80_000_000_000;
+        curve.holder_count = 1;
+        assert!(curve.should_graduate(0, 0));
+        assert!(curve.should_graduate(0, 1));
+        
+        let status = curve.graduation_status(0, 3);
+        assert_eq!(status.blocking_reason, GraduationBlocker::HoldersBelowMinimum);
+        assert!(!status.holders_met);
+        assert_eq!(status.holders_short, 2);
+        
+        curve.holder_count = 3;
+        let status = curve.graduation_status(0, 3);
+        assert!(status.holders_met);
+        assert_eq!(status.holders_short, 0);
+        assert!(curve.should_graduate(0, 3));
+        
+        // Target-vault launches need the same distribution
+        let mut curve = curve_with_tokens_sold(CURVE_SUPPLY / 10);
+        curve.target_sol_vault_lamports = 5_000_000_000;
+        curve.sol_reserve = 5_000_000_000;
+        curve.holder_count = 1;
+        assert_eq!(
+            curve.graduation_status(0, 2).blocking_reason,
+            GraduationBlocker::HoldersBelowMinimum
+        );
+    }
+    
+    #[test]
+    fn test_near_graduation_boundary() {
+        let threshold = CURVE_SUPPLY / 10_000 * NEAR_GRADUATION_BPS;
+        
+        assert!(!curve_with_tokens_sold(threshold - 1).is_near_graduation());
+        assert!(curve_with_tokens_sold(threshold).is_near_graduation());
+        assert!(curve_with_tokens_sold(threshold + 1).is_near_graduation());
+        assert!(curve_with_tokens_sold(CURVE_SUPPLY).is_near_graduation());
+    }
 }
@@ -1,8 +1,10 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Mint, Token, TokenAccount, MintTo};
+use anchor_spl::token::{self, Mint, Token, TokenAccount, MintTo, Transfer};
 use anchor_spl::associated_token::AssociatedToken;
 use crate::state::*;
 use crate::errors::LaunchpadError;
+use crate::pyth_price::{PythPriceReader, DEFAULT_PRICE_STALENESS_SECONDS};
+use crate::bonding_curve::BondingCurveCalculator;
 
 /// Initialize the launchpad configuration (admin only)
 #[derive(Accounts)]
@@ -16,6 +18,15 @@ pub struct InitializeLaunchpad<'info> {
     )]
     pub config: Account<'info, LaunchpadConfig>,
     
+    #[account(
+        init,
+        payer = authority,
+        space = GlobalStats::LEN,
+        seeds = [b"global_stats"],
+        bump
+    )]
+    pub global_stats: Account<'info, GlobalStats>,
+    
     #[account(mut)]
     pub authority: Signer<'info>,
     
@@ -25,6 +36,45 @@ pub struct InitializeLaunchpad<'info> {
     pub system_program: Program<'info, System>,
 }
 
+/// Create the global stats account for a launchpad initialized before it existed (admin only)
+#[derive(Accounts)]
+pub struct InitializeGlobalStats<'info> {
+    #[account(
+        seeds = [b"launchpad_config"],
+        bump = config.bump,
+        constraint = config.authority == authority.key() @ LaunchpadError::Unauthorized
+    )]
+    pub config: Account<'info, LaunchpadConfig>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = GlobalStats::LEN,
+        seeds = [b"global_stats"],
+        bump
+    )]
+    pub global_stats: Account<'info, GlobalStats>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitializeGlobalStats<'info> {
+    /// Totals start from zero; launches and trades before this call are not backfilled
+    pub fn initialize(&mut self, bump: u8) -> Result<()> {
+        let global_stats = &mut self.global_stats;
+        global_stats.total_launches = 0;
+        global_stats.total_volume = 0;
+        global_stats.total_graduated = 0;
+        global_stats.total_fees = 0;
+        global_stats.bump = bump;
+        global_stats.reserved = [0; 64];
+        Ok(())
+    }
+}
+
 /// Update fee recipient (admin only)
 #[derive(Accounts)]
 pub struct UpdateFeeRecipient<'info> {
@@ -47,9 +97,34 @@ impl<'info> UpdateFeeRecipient<'info> {
     }
 }
 
+/// Update the platform trading fee (admin only)
+#[derive(Accounts)]
+pub struct UpdatePlatformFee<'info> {
+    #[account(
+        mut,
+        seeds = [b"launchpad_config"],
+        bump = config.bump,
+        constraint = config.authority == authority.key() @ LaunchpadError::Unauthorized
+    )]
+    pub config: Account<'info, LaunchpadConfig>,
+    
+    pub authority: Signer<'info>,
+}
+
+impl<'info> UpdatePlatformFee<'info> {
+    pub fn update_platform_fee(&mut self, platform_fee_bps: u16) -> Result<()> {
+        // Same 10% ceiling as initialize
+        require!(platform_fee_bps <= 1000, LaunchpadError::InvalidFee);
+        
+        self.config.platform_fee_bps = platform_fee_bps;
+        msg!("Platform fee updated to: {} bps", platform_fee_bps);
+        Ok(())
+    }
+}
+
 /// Create a new token launch
 #[derive(Accounts)]
-#[instruction(name: String, symbol: String)]
+#[instruction(params: CreateLaunchParams)]
 pub struct CreateTokenLaunch<'info> {
     #[account(
         seeds = [b"launchpad_config"],
@@ -57,27 +132,30 @@ pub struct CreateTokenLaunch<'info> {
     )]
     pub config: Account<'info, LaunchpadConfig>,
     
+    /// `init_if_needed` so a double-submitted create hits the friendly
+    /// AlreadyInitialized constraint below instead of a raw "already in use"
     #[account(
-        init,
+        init_if_needed,
         payer = creator,
         space = TokenLaunch::LEN,
         seeds = [
             b"token_launch",
             mint.key().as_ref()
         ],
-        bump
+        bump,
+        constraint = token_launch.creator == Pubkey::default() @ LaunchpadError::AlreadyInitialized
     )]
     pub token_launch: Account<'info, TokenLaunch>,
     
     #[account(
         init,
         payer = creator,
-        mint::decimals = 9,
+        mint::decimals = TOKEN_DECIMALS,
         mint::authority = bonding_curve,
         seeds = [
             b"mint",
             creator.key().as_ref(),
-            name.as_bytes()
+            params.name.as_bytes()
         ],
         bump
     )]
@@ -103,7 +181,42 @@ pub struct CreateTokenLaunch<'info> {
     )]
     pub curve_token_account: Account<'info, TokenAccount>,
     
-    /// CHECK: Vault to hold SOL for the bonding curve
+    #[account(
+        mut,
+        seeds = [b"global_stats"],
+        bump = global_stats.bump
+    )]
+    pub global_stats: Account<'info, GlobalStats>,
+    
+    /// Holds the 200M LP allocation apart from the sellable curve supply
+    #[account(
+        init,
+        payer = creator,
+        token::mint = mint,
+        token::authority = bonding_curve,
+        seeds = [
+            b"lp_vault",
+            bonding_curve.key().as_ref()
+        ],
+        bump
+    )]
+    pub lp_vault: Account<'info, TokenAccount>,
+    
+    /// Holds the creator allocation until it vests and is claimed (empty without one)
+    #[account(
+        init,
+        payer = creator,
+        token::mint = mint,
+        token::authority = bonding_curve,
+        seeds = [
+            b"creator_vault",
+            bonding_curve.key().as_ref()
+        ],
+        bump
+    )]
+    pub creator_vault: Account<'info, TokenAccount>,
+    
+    /// Vault to hold SOL for the bonding curve (a data-less, system-owned PDA)
     #[account(
         mut,
         seeds = [
@@ -112,7 +225,20 @@ pub struct CreateTokenLaunch<'info> {
         ],
         bump
     )]
-    pub sol_vault: UncheckedAccount<'info>,
+    pub sol_vault: SystemAccount<'info>,
+    
+    /// Recent-trade ring buffer; boxed to keep its ~1.8KB off the BPF stack
+    #[account(
+        init,
+        payer = creator,
+        space = TradeHistory::LEN,
+        seeds = [
+            b"trade_history",
+            token_launch.key().as_ref()
+        ],
+        bump
+    )]
+    pub trade_history: Box<Account<'info, TradeHistory>>,
     
     #[account(mut)]
     pub creator: Signer<'info>,
@@ -169,6 +295,7 @@ impl<'info> InitializeLaunchpad<'info> {
         &mut self,
         platform_fee_bps: u16,
         bump: u8,
+        global_stats_bump: u8,
     ) -> Result<()> {
         require!(platform_fee_bps <= 1000, LaunchpadError::InvalidFee);
         
@@ -180,7 +307,41 @@ impl<'info> InitializeLaunchpad<'info> {
         // They can be set later using update_whitelisted_wallets instruction
         config.whitelisted_wallet_1 = Pubkey::default();
         config.whitelisted_wallet_2 = Pubkey::default();
+        config.accrue_fees = false;
+        config.min_graduation_sol_lamports = 0;
+        config.min_trade_tokens = 0;
+        // Liquidity destinations must be registered before any launch can withdraw
+        config.liquidity_program = Pubkey::default();
+        config.liquidity_treasury = Pubkey::default();
+        config.fee_discount_mint = Pubkey::default();
+        config.fee_discount_bps = 0;
+        config.burn_unsold_on_graduation = false;
+        config.graduation_fee_bps = 0;
+        config.allowed_uri_prefixes = Vec::new();
+        config.sell_fee_ramp_max_bps = 0;
+        config.sell_fee_ramp_full_share_bps = 0;
+        config.min_inter_trade_seconds = 0;
+        config.metadata_update_cooldown_seconds = 0;
+        config.manual_price_allowed = false;
+        config.withdraw_fee_bps = 0;
+        config.fee_splits = Vec::new();
         config.bump = bump;
+        config.reserved = [0; 64];
+        config.graduation_grace_seconds = 0;
+        config.deactivation_delay_seconds = 0;
+        config.min_lock_after_graduation_seconds = 0;
+        config.max_price_staleness_seconds = DEFAULT_PRICE_STALENESS_SECONDS;
+        config.min_holders_for_graduation = 0;
+        config.creator_vesting_seconds = 0;
+        config.max_buy_fraction_bps = 0;
+        
+        let global_stats = &mut self.global_stats;
+        global_stats.total_launches = 0;
+        global_stats.total_volume = 0;
+        global_stats.total_graduated = 0;
+        global_stats.total_fees = 0;
+        global_stats.bump = global_stats_bump;
+        global_stats.reserved = [0; 64];
         
         msg!("Launchpad initialized with fee: {} bps", platform_fee_bps);
         msg!("Whitelisted wallets can be set later using update_whitelisted_wallets");
@@ -189,17 +350,27 @@ impl<'info> InitializeLaunchpad<'info> {
 }
 
 impl<'info> CreateTokenLaunch<'info> {
-    pub fn create(
-        &mut self,
-        name: String,
-        symbol: String,
-        metadata_uri: String,
-        description: String,
-        sol_price_usd: u64, // Current SOL price in USD (scaled by 1e8)
-        bumps: &CreateTokenLaunchBumps,
-    ) -> Result<()> {
+    pub fn create(&mut self, params: &CreateLaunchParams, bumps: &CreateTokenLaunchBumps) -> Result<()> {
         use crate::state::{TOTAL_SUPPLY, CURVE_SUPPLY};
         
+        let CreateLaunchParams {
+            name,
+            symbol,
+            metadata_uri,
+            description,
+            sol_price_usd,
+            trading_starts_at,
+            is_mutable,
+            sells_enabled,
+            max_sol_raise_lamports,
+            target_sol_vault_lamports,
+            max_trades,
+            expected_price_feed,
+            graduation_action,
+            curve_type,
+            creator_allocation_bps,
+        } = params.clone();
+        
         // Check if creator is authorized (admin or whitelisted wallet)
         require!(
             self.config.is_authorized_launcher(&self.creator.key()),
@@ -208,25 +379,65 @@ impl<'info> CreateTokenLaunch<'info> {
         
         // Validate inputs
         require!(
-            name.len() <= TokenLaunch::MAX_NAME_LEN,
+            TokenLaunch::name_fits(&name),
             LaunchpadError::NameTooLong
         );
         require!(
-            symbol.len() <= TokenLaunch::MAX_SYMBOL_LEN,
+            TokenLaunch::symbol_fits(&symbol),
             LaunchpadError::SymbolTooLong
         );
+        require!(
+            TokenLaunch::is_valid_name(&name),
+            LaunchpadError::InvalidTokenName
+        );
+        require!(
+            TokenLaunch::is_valid_symbol(&symbol),
+            LaunchpadError::InvalidTokenSymbol
+        );
         require!(
             metadata_uri.len() <= TokenLaunch::MAX_URI_LEN,
             LaunchpadError::UriTooLong
         );
+        require!(
+            self.config.is_allowed_uri(&metadata_uri),
+            LaunchpadError::InvalidUri
+        );
         require!(
             description.len() <= TokenLaunch::MAX_DESCRIPTION_LEN,
             LaunchpadError::DescriptionTooLong
         );
+        // Fat-finger guard: a price outside $1..$100,000 makes the curve untradeable
         require!(
-            sol_price_usd > 0,
+            PythPriceReader::is_sane_sol_price(sol_price_usd),
             LaunchpadError::InvalidPrice
         );
+        require!(
+            expected_price_feed != Pubkey::default(),
+            LaunchpadError::InvalidPriceFeed
+        );
+        // Pro-rata distribution has no withdraw path yet; don't strand funds behind it
+        require!(
+            graduation_action != GraduationAction::Distribute,
+            LaunchpadError::GraduationActionNotSupported
+        );
+        require!(
+            trading_starts_at >= 0,
+            LaunchpadError::InvalidTimestamp
+        );
+        // A target above the raise cap could never be reached
+        require!(
+            max_sol_raise_lamports == 0 || target_sol_vault_lamports <= max_sol_raise_lamports,
+            LaunchpadError::InvalidAmount
+        );
+        // The allocation comes off the top of the curve; what's left must still sell for $12k
+        let creator_allocation = ((TOTAL_SUPPLY as u128) * (creator_allocation_bps as u128) / 10_000) as u64;
+        require!(
+            BondingCurveCalculator::can_raise_graduation_usd(
+                curve_type,
+                CURVE_SUPPLY.saturating_sub(creator_allocation),
+            ),
+            LaunchpadError::InvalidCreatorAllocation
+        );
         
         let clock = Clock::get()?;
         
@@ -245,20 +456,59 @@ impl<'info> CreateTokenLaunch<'info> {
         token_launch.total_supply = TOTAL_SUPPLY;
         token_launch.circulating_supply = 0;
         token_launch.launch_timestamp = clock.unix_timestamp;
+        token_launch.launch_slot = clock.slot;
+        token_launch.trading_starts_at = trading_starts_at;
         token_launch.is_active = true;
+        token_launch.is_mutable = is_mutable;
+        token_launch.admin_frozen = false;
+        token_launch.paused_at = 0;
+        token_launch.total_paused_seconds = 0;
+        token_launch.last_metadata_update = 0;
         token_launch.bump = bumps.token_launch;
+        token_launch.reserved = [0; 64];
+        token_launch.sells_disabled = !sells_enabled;
+        token_launch.deactivates_at = 0;
+        token_launch.deactivation_reason = None;
         
         // Initialize BondingCurve with fixed parameters
         let bonding_curve = &mut self.bonding_curve;
         bonding_curve.token_launch = token_launch_key;
         bonding_curve.sol_reserve = 0;
-        bonding_curve.token_reserve = CURVE_SUPPLY; // 800M tokens for curve
+        bonding_curve.token_reserve = CURVE_SUPPLY - creator_allocation; // 800M less any creator allocation
         bonding_curve.tokens_sold = 0;
-        bonding_curve.sol_price_usd = sol_price_usd;
+        // Creator-supplied, so the first oracle read is never deviation-clamped
+        bonding_curve.set_sol_price(sol_price_usd, SolPriceSource::Creator, clock.unix_timestamp);
         bonding_curve.total_volume = 0;
         bonding_curve.trade_count = 0;
         bonding_curve.is_graduated = false;
+        bonding_curve.near_graduation_notified = false;
+        bonding_curve.accrued_fees = 0;
+        bonding_curve.holder_count = 0;
+        bonding_curve.unique_buyers = 0;
+        bonding_curve.max_sol_raise_lamports = max_sol_raise_lamports;
+        bonding_curve.target_sol_vault_lamports = target_sol_vault_lamports;
+        bonding_curve.max_trades = max_trades;
+        bonding_curve.expected_price_feed = expected_price_feed;
+        bonding_curve.last_trade_time = 0;
+        bonding_curve.graduation_action = graduation_action;
+        bonding_curve.pool_address = None;
         bonding_curve.bump = bumps.bonding_curve;
+        bonding_curve.reserved = [0; 64];
+        bonding_curve.snapshot_id = 0;
+        bonding_curve.graduated_at = 0;
+        bonding_curve.graduation_grace_seconds = 0;
+        bonding_curve.curve_type = curve_type;
+        bonding_curve.liquidity_sol_withdrawn = 0;
+        bonding_curve.liquidity_tokens_withdrawn = 0;
+        bonding_curve.fee_schedule_by_progress = None;
+        bonding_curve.sol_vault_bump = bumps.sol_vault;
+        bonding_curve.creator_allocation = creator_allocation;
+        bonding_curve.creator_allocation_claimed = 0;
+        bonding_curve.creator_vesting_seconds = self.config.creator_vesting_seconds;
+        
+        self.trade_history.initialize(token_launch_key, bumps.trade_history);
+        
+        self.global_stats.record_launch()?;
         
         msg!(
             "Token launch created: {} ({}) - Fixed supply: 1B tokens, 800M on curve, price: $0.00000420 → $0.00006900",
@@ -270,7 +520,7 @@ impl<'info> CreateTokenLaunch<'info> {
     }
     
     pub fn mint_initial_supply(&mut self) -> Result<()> {
-        use crate::state::TOTAL_SUPPLY;
+        use crate::state::LP_SUPPLY;
         
         let token_launch_key = self.token_launch.key();
         let seeds = &[
@@ -280,8 +530,7 @@ impl<'info> CreateTokenLaunch<'info> {
         ];
         let signer_seeds = &[&seeds[..]];
         
-        // Mint full supply (1B tokens) to bonding curve
-        // The curve will hold 800M for sale, and 200M reserved for LP
+        // Mint the sellable supply to the curve's token account
         let cpi_accounts = MintTo {
             mint: self.mint.to_account_info(),
             to: self.curve_token_account.to_account_info(),
@@ -290,16 +539,175 @@ impl<'info> CreateTokenLaunch<'info> {
         let cpi_program = self.token_program.to_account_info();
         let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
         
-        token::mint_to(cpi_ctx, TOTAL_SUPPLY)?;
+        token::mint_to(cpi_ctx, self.bonding_curve.curve_supply())?;
+        
+        // Mint the creator allocation, carved out of the 800M, to the creator vault
+        let creator_allocation = self.bonding_curve.creator_allocation;
+        if creator_allocation > 0 {
+            let cpi_accounts = MintTo {
+                mint: self.mint.to_account_info(),
+                to: self.creator_vault.to_account_info(),
+                authority: self.bonding_curve.to_account_info(),
+            };
+            let cpi_program = self.token_program.to_account_info();
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+            
+            token::mint_to(cpi_ctx, creator_allocation)?;
+        }
+        
+        // Mint the 200M LP allocation to the separate LP vault
+        let cpi_accounts = MintTo {
+            mint: self.mint.to_account_info(),
+            to: self.lp_vault.to_account_info(),
+            authority: self.bonding_curve.to_account_info(),
+        };
+        let cpi_program = self.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+        
+        token::mint_to(cpi_ctx, LP_SUPPLY)?;
         
-        msg!("Minted 1B tokens to bonding curve (800M for sale, 200M reserved for LP)");
+        msg!(
+            "Minted 1B tokens: {} to the bonding curve for sale, {} to the creator vault, 200M to the LP vault",
+            self.bonding_curve.curve_supply(),
+            creator_allocation
+        );
+        Ok(())
+    }
+}
+
+/// Create the trade history account for a launch created before it existed (permissionless)
+#[derive(Accounts)]
+pub struct InitializeTradeHistory<'info> {
+    #[account(
+        seeds = [
+            b"token_launch",
+            token_launch.mint.as_ref()
+        ],
+        bump = token_launch.bump
+    )]
+    pub token_launch: Account<'info, TokenLaunch>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = TradeHistory::LEN,
+        seeds = [
+            b"trade_history",
+            token_launch.key().as_ref()
+        ],
+        bump
+    )]
+    pub trade_history: Box<Account<'info, TradeHistory>>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitializeTradeHistory<'info> {
+    /// History starts empty; trades before this call are not backfilled
+    pub fn initialize(&mut self, bump: u8) -> Result<()> {
+        self.trade_history.initialize(self.token_launch.key(), bump);
         Ok(())
     }
 }
 
+/// Claim the vested part of the creator allocation (creator only)
+#[derive(Accounts)]
+pub struct ClaimCreatorAllocation<'info> {
+    #[account(
+        seeds = [
+            b"token_launch",
+            token_launch.mint.as_ref()
+        ],
+        bump = token_launch.bump,
+        constraint = token_launch.creator == creator.key() @ LaunchpadError::Unauthorized
+    )]
+    pub token_launch: Account<'info, TokenLaunch>,
+    
+    #[account(
+        mut,
+        seeds = [
+            b"bonding_curve",
+            token_launch.key().as_ref()
+        ],
+        bump = bonding_curve.bump
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
+    #[account(
+        mut,
+        seeds = [
+            b"creator_vault",
+            bonding_curve.key().as_ref()
+        ],
+        bump
+    )]
+    pub creator_vault: Account<'info, TokenAccount>,
+    
+    #[account(
+        constraint = mint.key() == token_launch.mint @ LaunchpadError::TokenMintMismatch
+    )]
+    pub mint: Account<'info, Mint>,
+    
+    #[account(
+        init_if_needed,
+        payer = creator,
+        associated_token::mint = mint,
+        associated_token::authority = creator
+    )]
+    pub creator_token_account: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimCreatorAllocation<'info> {
+    /// Transfer everything vested and not yet claimed; returns the amount claimed
+    pub fn claim(&mut self) -> Result<u64> {
+        let now = Clock::get()?.unix_timestamp;
+        let amount = self.bonding_curve
+            .claimable_creator_allocation(self.token_launch.launch_timestamp, now);
+        require!(amount > 0, LaunchpadError::NothingToClaim);
+        
+        let token_launch_key = self.token_launch.key();
+        let seeds = &[
+            b"bonding_curve",
+            token_launch_key.as_ref(),
+            &[self.bonding_curve.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+        
+        let cpi_accounts = Transfer {
+            from: self.creator_vault.to_account_info(),
+            to: self.creator_token_account.to_account_info(),
+            authority: self.bonding_curve.to_account_info(),
+        };
+        let cpi_program = self.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+        
+        token::transfer(cpi_ctx, amount)?;
+        
+        self.bonding_curve.record_creator_claim(amount)?;
+        msg!("Claimed {} creator allocation tokens", amount);
+        Ok(amount)
+    }
+}
+
 /// Update token launch status
 #[derive(Accounts)]
 pub struct UpdateTokenLaunch<'info> {
+    #[account(
+        seeds = [b"launchpad_config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, LaunchpadConfig>,
+    
     #[account(
         mut,
         seeds = [
@@ -311,51 +719,162 @@ pub struct UpdateTokenLaunch<'info> {
     )]
     pub token_launch: Account<'info, TokenLaunch>,
     
+    #[account(
+        seeds = [
+            b"bonding_curve",
+            token_launch.key().as_ref()
+        ],
+        bump = bonding_curve.bump
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
     pub creator: Signer<'info>,
 }
 
 impl<'info> UpdateTokenLaunch<'info> {
-    pub fn toggle_active(&mut self) -> Result<()> {
-        self.token_launch.is_active = !self.token_launch.is_active;
+    /// Returns what changed and the length of the pause that just ended (0 unless resuming)
+    pub fn toggle_active(&mut self) -> Result<(LaunchStatusChange, u64)> {
+        // A graduated launch stays inactive; trading has moved to the DEX
+        require!(
+            !self.bonding_curve.is_graduated,
+            LaunchpadError::CurveGraduated
+        );
+        // Only the admin can lift a freeze
+        require!(
+            !self.token_launch.admin_frozen,
+            LaunchpadError::LaunchFrozen
+        );
+        
+        let now = Clock::get()?.unix_timestamp;
+        self.token_launch.settle_scheduled_pause(now);
+        
+        // Toggling again before the notice runs out calls the pause off
+        if self.token_launch.deactivates_at > 0 {
+            self.token_launch.deactivates_at = 0;
+            msg!("Scheduled pause cancelled");
+            return Ok((LaunchStatusChange::PauseCancelled, 0));
+        }
+        
+        // Give in-flight trades notice before a pause takes effect
+        let delay = self.config.deactivation_delay_seconds;
+        if self.token_launch.is_active && delay > 0 {
+            self.token_launch.deactivates_at = now
+                .checked_add(delay as i64)
+                .ok_or(LaunchpadError::MathOverflow)?;
+            msg!("Trading pauses at {}", self.token_launch.deactivates_at);
+            return Ok((LaunchStatusChange::PauseScheduled, 0));
+        }
+        
+        let paused_for = self.token_launch.toggle_paused(now)?;
         msg!("Token launch active status: {}", self.token_launch.is_active);
-        Ok(())
+        let change = if self.token_launch.is_active {
+            LaunchStatusChange::Resume
+        } else {
+            LaunchStatusChange::Pause
+        };
+        Ok((change, paused_for))
     }
     
     pub fn update_metadata_uri(&mut self, new_uri: String) -> Result<()> {
+        require!(
+            self.token_launch.is_mutable,
+            LaunchpadError::MetadataFrozen
+        );
         require!(
             new_uri.len() <= TokenLaunch::MAX_URI_LEN,
             LaunchpadError::UriTooLong
         );
+        require!(
+            self.config.is_allowed_uri(&new_uri),
+            LaunchpadError::InvalidUri
+        );
+        self.touch_metadata()?;
         self.token_launch.metadata_uri = new_uri;
         msg!("Updated metadata URI");
         Ok(())
     }
     
     pub fn update_description(&mut self, new_description: String) -> Result<()> {
+        require!(
+            self.token_launch.is_mutable,
+            LaunchpadError::MetadataFrozen
+        );
         require!(
             new_description.len() <= TokenLaunch::MAX_DESCRIPTION_LEN,
             LaunchpadError::DescriptionTooLong
         );
+        self.touch_metadata()?;
         self.token_launch.description = new_description;
         msg!("Updated token description");
         Ok(())
     }
+    
+    /// Enforce the shared URI/description update cooldown and record this update
+    fn touch_metadata(&mut self) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let cooldown_seconds = self.config.metadata_update_cooldown_seconds;
+        if self.token_launch.is_in_metadata_cooldown(now, cooldown_seconds) {
+            msg!(
+                "Metadata last updated at {}, next update allowed after {}s",
+                self.token_launch.last_metadata_update,
+                cooldown_seconds
+            );
+            return err!(LaunchpadError::CooldownActive);
+        }
+        self.token_launch.last_metadata_update = now;
+        Ok(())
+    }
 }
 
-/// Update admin authority (admin only)
+/// Correct the creation-time SOL/USD price before the first trade (creator only)
 #[derive(Accounts)]
-pub struct UpdateAdmin<'info> {
+pub struct FixInitialPrice<'info> {
     #[account(
-        mut,
-        seeds = [b"launchpad_config"],
-        bump = config.bump,
-        constraint = config.authority == authority.key() @ LaunchpadError::Unauthorized
+        seeds = [
+            b"token_launch",
+            token_launch.mint.as_ref()
+        ],
+        bump = token_launch.bump,
+        constraint = token_launch.creator == creator.key() @ LaunchpadError::Unauthorized
     )]
-    pub config: Account<'info, LaunchpadConfig>,
-    
-    pub authority: Signer<'info>,
+    pub token_launch: Account<'info, TokenLaunch>,
     
-    /// CHECK: New authority can be any account
+    #[account(
+        mut,
+        seeds = [
+            b"bonding_curve",
+            token_launch.key().as_ref()
+        ],
+        bump = bonding_curve.bump
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
+    pub creator: Signer<'info>,
+}
+
+impl<'info> FixInitialPrice<'info> {
+    /// Returns (old_price, new_price)
+    pub fn fix_initial_price(&mut self, new_price: u64) -> Result<(u64, u64)> {
+        let old_price = self.bonding_curve.fix_initial_price(new_price, Clock::get()?.unix_timestamp)?;
+        msg!("Initial SOL/USD price fixed: {} -> {}", old_price, new_price);
+        Ok((old_price, new_price))
+    }
+}
+
+/// Update admin authority (admin only)
+#[derive(Accounts)]
+pub struct UpdateAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [b"launchpad_config"],
+        bump = config.bump,
+        constraint = config.authority == authority.key() @ LaunchpadError::Unauthorized
+    )]
+    pub config: Account<'info, LaunchpadConfig>,
+    
+    pub authority: Signer<'info>,
+    
+    /// CHECK: New authority can be any account
     pub new_authority: UncheckedAccount<'info>,
 }
 
@@ -397,3 +916,632 @@ impl<'info> UpdateWhitelistedWallets<'info> {
         Ok(())
     }
 }
+
+/// Toggle fee accrual in the SOL vault (admin only)
+#[derive(Accounts)]
+pub struct UpdateFeeAccrual<'info> {
+    #[account(
+        mut,
+        seeds = [b"launchpad_config"],
+        bump = config.bump,
+        constraint = config.authority == authority.key() @ LaunchpadError::Unauthorized
+    )]
+    pub config: Account<'info, LaunchpadConfig>,
+    
+    pub authority: Signer<'info>,
+}
+
+impl<'info> UpdateFeeAccrual<'info> {
+    pub fn update_fee_accrual(&mut self, accrue_fees: bool) -> Result<()> {
+        self.config.accrue_fees = accrue_fees;
+        msg!("Fee accrual enabled: {}", accrue_fees);
+        Ok(())
+    }
+}
+
+/// Update the minimum SOL reserve required to graduate (admin only)
+#[derive(Accounts)]
+pub struct UpdateMinGraduationSol<'info> {
+    #[account(
+        mut,
+        seeds = [b"launchpad_config"],
+        bump = config.bump,
+        constraint = config.authority == authority.key() @ LaunchpadError::Unauthorized
+    )]
+    pub config: Account<'info, LaunchpadConfig>,
+    
+    pub authority: Signer<'info>,
+}
+
+impl<'info> UpdateMinGraduationSol<'info> {
+    pub fn update_min_graduation_sol(&mut self, min_graduation_sol_lamports: u64) -> Result<()> {
+        self.config.min_graduation_sol_lamports = min_graduation_sol_lamports;
+        msg!("Minimum graduation SOL reserve: {} lamports", min_graduation_sol_lamports);
+        Ok(())
+    }
+}
+
+/// Update the minimum trade size in tokens (admin only)
+#[derive(Accounts)]
+pub struct UpdateMinTradeTokens<'info> {
+    #[account(
+        mut,
+        seeds = [b"launchpad_config"],
+        bump = config.bump,
+        constraint = config.authority == authority.key() @ LaunchpadError::Unauthorized
+    )]
+    pub config: Account<'info, LaunchpadConfig>,
+    
+    pub authority: Signer<'info>,
+}
+
+impl<'info> UpdateMinTradeTokens<'info> {
+    pub fn update_min_trade_tokens(&mut self, min_trade_tokens: u64) -> Result<()> {
+        self.config.min_trade_tokens = min_trade_tokens;
+        msg!("Minimum trade size: {} tokens (with decimals)", min_trade_tokens);
+        Ok(())
+    }
+}
+
+
+/// Update the curve-wide minimum time between trades (admin only)
+#[derive(Accounts)]
+pub struct UpdateMinInterTradeSeconds<'info> {
+    #[account(
+        mut,
+        seeds = [b"launchpad_config"],
+        bump = config.bump,
+        constraint = config.authority == authority.key() @ LaunchpadError::Unauthorized
+    )]
+    pub config: Account<'info, LaunchpadConfig>,
+    
+    pub authority: Signer<'info>,
+}
+
+impl<'info> UpdateMinInterTradeSeconds<'info> {
+    pub fn update_min_inter_trade_seconds(&mut self, min_inter_trade_seconds: u32) -> Result<()> {
+        self.config.min_inter_trade_seconds = min_inter_trade_seconds;
+        msg!("Minimum time between trades per curve: {}s", min_inter_trade_seconds);
+        Ok(())
+    }
+}
+
+/// Update the sell-only grace window after graduation (admin only)
+#[derive(Accounts)]
+pub struct UpdateGraduationGrace<'info> {
+    #[account(
+        mut,
+        seeds = [b"launchpad_config"],
+        bump = config.bump,
+        constraint = config.authority == authority.key() @ LaunchpadError::Unauthorized
+    )]
+    pub config: Account<'info, LaunchpadConfig>,
+    
+    pub authority: Signer<'info>,
+}
+
+impl<'info> UpdateGraduationGrace<'info> {
+    pub fn update_graduation_grace(&mut self, graduation_grace_seconds: u32) -> Result<()> {
+        self.config.graduation_grace_seconds = graduation_grace_seconds;
+        msg!("Sells allowed for {}s after graduation", graduation_grace_seconds);
+        Ok(())
+    }
+}
+
+/// Update the notice creators must give before a pause stops trading (admin only)
+#[derive(Accounts)]
+pub struct UpdateDeactivationDelay<'info> {
+    #[account(
+        mut,
+        seeds = [b"launchpad_config"],
+        bump = config.bump,
+        constraint = config.authority == authority.key() @ LaunchpadError::Unauthorized
+    )]
+    pub config: Account<'info, LaunchpadConfig>,
+    
+    pub authority: Signer<'info>,
+}
+
+impl<'info> UpdateDeactivationDelay<'info> {
+    pub fn update_deactivation_delay(&mut self, deactivation_delay_seconds: u32) -> Result<()> {
+        self.config.deactivation_delay_seconds = deactivation_delay_seconds;
+        msg!("Creator pauses take effect after {}s", deactivation_delay_seconds);
+        Ok(())
+    }
+}
+
+/// Update how long liquidity stays locked after graduation (admin only)
+#[derive(Accounts)]
+pub struct UpdateMinLiquidityLock<'info> {
+    #[account(
+        mut,
+        seeds = [b"launchpad_config"],
+        bump = config.bump,
+        constraint = config.authority == authority.key() @ LaunchpadError::Unauthorized
+    )]
+    pub config: Account<'info, LaunchpadConfig>,
+    
+    pub authority: Signer<'info>,
+}
+
+impl<'info> UpdateMinLiquidityLock<'info> {
+    pub fn update_min_liquidity_lock(&mut self, min_lock_after_graduation_seconds: u32) -> Result<()> {
+        self.config.min_lock_after_graduation_seconds = min_lock_after_graduation_seconds;
+        msg!("Liquidity locked for {}s after graduation", min_lock_after_graduation_seconds);
+        Ok(())
+    }
+}
+
+/// Update how old a Pyth price may be before trades use the backup price (admin only)
+#[derive(Accounts)]
+pub struct UpdateMaxPriceStaleness<'info> {
+    #[account(
+        mut,
+        seeds = [b"launchpad_config"],
+        bump = config.bump,
+        constraint = config.authority == authority.key() @ LaunchpadError::Unauthorized
+    )]
+    pub config: Account<'info, LaunchpadConfig>,
+    
+    pub authority: Signer<'info>,
+}
+
+impl<'info> UpdateMaxPriceStaleness<'info> {
+    pub fn update_max_price_staleness(&mut self, max_price_staleness_seconds: u32) -> Result<()> {
+        require!(
+            (LaunchpadConfig::MIN_PRICE_STALENESS_SECONDS..=LaunchpadConfig::MAX_PRICE_STALENESS_SECONDS)
+                .contains(&max_price_staleness_seconds),
+            LaunchpadError::InvalidPriceStaleness
+        );
+        
+        self.config.max_price_staleness_seconds = max_price_staleness_seconds;
+        msg!("Pyth prices older than {}s now fall back to the backup price", max_price_staleness_seconds);
+        Ok(())
+    }
+}
+
+/// Update how many distinct holders a curve needs before it can graduate (admin only)
+#[derive(Accounts)]
+pub struct UpdateMinHoldersForGraduation<'info> {
+    #[account(
+        mut,
+        seeds = [b"launchpad_config"],
+        bump = config.bump,
+        constraint = config.authority == authority.key() @ LaunchpadError::Unauthorized
+    )]
+    pub config: Account<'info, LaunchpadConfig>,
+    
+    pub authority: Signer<'info>,
+}
+
+impl<'info> UpdateMinHoldersForGraduation<'info> {
+    pub fn update_min_holders_for_graduation(&mut self, min_holders_for_graduation: u32) -> Result<()> {
+        self.config.min_holders_for_graduation = min_holders_for_graduation;
+        msg!("Graduation now requires {} distinct holders", min_holders_for_graduation);
+        Ok(())
+    }
+}
+
+/// Update the vesting period applied to creator allocations of new launches (admin only)
+#[derive(Accounts)]
+pub struct UpdateCreatorVesting<'info> {
+    #[account(
+        mut,
+        seeds = [b"launchpad_config"],
+        bump = config.bump,
+        constraint = config.authority == authority.key() @ LaunchpadError::Unauthorized
+    )]
+    pub config: Account<'info, LaunchpadConfig>,
+    
+    pub authority: Signer<'info>,
+}
+
+impl<'info> UpdateCreatorVesting<'info> {
+    pub fn update_creator_vesting(&mut self, creator_vesting_seconds: u32) -> Result<()> {
+        self.config.creator_vesting_seconds = creator_vesting_seconds;
+        msg!("Creator allocations of new launches vest over {}s", creator_vesting_seconds);
+        Ok(())
+    }
+}
+
+/// Update the largest buy as a share of a curve's remaining supply (admin only)
+#[derive(Accounts)]
+pub struct UpdateMaxBuyFraction<'info> {
+    #[account(
+        mut,
+        seeds = [b"launchpad_config"],
+        bump = config.bump,
+        constraint = config.authority == authority.key() @ LaunchpadError::Unauthorized
+    )]
+    pub config: Account<'info, LaunchpadConfig>,
+    
+    pub authority: Signer<'info>,
+}
+
+impl<'info> UpdateMaxBuyFraction<'info> {
+    pub fn update_max_buy_fraction(&mut self, max_buy_fraction_bps: u16) -> Result<()> {
+        require!(max_buy_fraction_bps <= 10_000, LaunchpadError::InvalidConfiguration);
+        
+        self.config.max_buy_fraction_bps = max_buy_fraction_bps;
+        msg!("Maximum buy: {} bps of the remaining curve supply", max_buy_fraction_bps);
+        Ok(())
+    }
+}
+
+/// Update the minimum time between metadata updates on a launch (admin only)
+#[derive(Accounts)]
+pub struct UpdateMetadataUpdateCooldown<'info> {
+    #[account(
+        mut,
+        seeds = [b"launchpad_config"],
+        bump = config.bump,
+        constraint = config.authority == authority.key() @ LaunchpadError::Unauthorized
+    )]
+    pub config: Account<'info, LaunchpadConfig>,
+    
+    pub authority: Signer<'info>,
+}
+
+impl<'info> UpdateMetadataUpdateCooldown<'info> {
+    pub fn update_metadata_update_cooldown(&mut self, cooldown_seconds: u32) -> Result<()> {
+        self.config.metadata_update_cooldown_seconds = cooldown_seconds;
+        msg!("Minimum time between metadata updates per launch: {}s", cooldown_seconds);
+        Ok(())
+    }
+}
+
+/// Toggle burning of unsold curve tokens at graduation (admin only)
+#[derive(Accounts)]
+pub struct UpdateBurnUnsoldOnGraduation<'info> {
+    #[account(
+        mut,
+        seeds = [b"launchpad_config"],
+        bump = config.bump,
+        constraint = config.authority == authority.key() @ LaunchpadError::Unauthorized
+    )]
+    pub config: Account<'info, LaunchpadConfig>,
+    
+    pub authority: Signer<'info>,
+}
+
+impl<'info> UpdateBurnUnsoldOnGraduation<'info> {
+    pub fn update_burn_unsold_on_graduation(&mut self, burn_unsold_on_graduation: bool) -> Result<()> {
+        self.config.burn_unsold_on_graduation = burn_unsold_on_graduation;
+        msg!("Burn unsold tokens on graduation: {}", burn_unsold_on_graduation);
+        Ok(())
+    }
+}
+
+/// Toggle trading off the stored backup price when no Pyth feed is passed (admin only)
+#[derive(Accounts)]
+pub struct UpdateManualPriceAllowed<'info> {
+    #[account(
+        mut,
+        seeds = [b"launchpad_config"],
+        bump = config.bump,
+        constraint = config.authority == authority.key() @ LaunchpadError::Unauthorized
+    )]
+    pub config: Account<'info, LaunchpadConfig>,
+    
+    pub authority: Signer<'info>,
+}
+
+impl<'info> UpdateManualPriceAllowed<'info> {
+    pub fn update_manual_price_allowed(&mut self, manual_price_allowed: bool) -> Result<()> {
+        self.config.manual_price_allowed = manual_price_allowed;
+        msg!("Manual price fallback allowed: {}", manual_price_allowed);
+        Ok(())
+    }
+}
+
+/// Update the graduation success fee (admin only)
+#[derive(Accounts)]
+pub struct UpdateGraduationFee<'info> {
+    #[account(
+        mut,
+        seeds = [b"launchpad_config"],
+        bump = config.bump,
+        constraint = config.authority == authority.key() @ LaunchpadError::Unauthorized
+    )]
+    pub config: Account<'info, LaunchpadConfig>,
+    
+    pub authority: Signer<'info>,
+}
+
+impl<'info> UpdateGraduationFee<'info> {
+    pub fn update_graduation_fee(&mut self, graduation_fee_bps: u16) -> Result<()> {
+        require!(
+            graduation_fee_bps <= LaunchpadConfig::MAX_GRADUATION_FEE_BPS,
+            LaunchpadError::InvalidFee
+        );
+        
+        self.config.graduation_fee_bps = graduation_fee_bps;
+        msg!("Graduation fee updated to: {} bps", graduation_fee_bps);
+        Ok(())
+    }
+}
+
+/// Update the platform fee on withdrawn liquidity (admin only)
+#[derive(Accounts)]
+pub struct UpdateWithdrawFee<'info> {
+    #[account(
+        mut,
+        seeds = [b"launchpad_config"],
+        bump = config.bump,
+        constraint = config.authority == authority.key() @ LaunchpadError::Unauthorized
+    )]
+    pub config: Account<'info, LaunchpadConfig>,
+    
+    pub authority: Signer<'info>,
+}
+
+impl<'info> UpdateWithdrawFee<'info> {
+    pub fn update_withdraw_fee(&mut self, withdraw_fee_bps: u16) -> Result<()> {
+        require!(
+            withdraw_fee_bps <= LaunchpadConfig::MAX_WITHDRAW_FEE_BPS,
+            LaunchpadError::InvalidFee
+        );
+        
+        self.config.withdraw_fee_bps = withdraw_fee_bps;
+        msg!("Withdraw fee updated to: {} bps", withdraw_fee_bps);
+        Ok(())
+    }
+}
+
+/// Split the platform trading fee across weighted recipients (admin only)
+#[derive(Accounts)]
+pub struct UpdateFeeSplits<'info> {
+    #[account(
+        mut,
+        seeds = [b"launchpad_config"],
+        bump = config.bump,
+        constraint = config.authority == authority.key() @ LaunchpadError::Unauthorized
+    )]
+    pub config: Account<'info, LaunchpadConfig>,
+    
+    pub authority: Signer<'info>,
+}
+
+impl<'info> UpdateFeeSplits<'info> {
+    pub fn update_fee_splits(&mut self, fee_splits: Vec<FeeSplit>) -> Result<()> {
+        require!(
+            LaunchpadConfig::is_valid_fee_split(&fee_splits),
+            LaunchpadError::InvalidFeeSplit
+        );
+        
+        self.config.fee_splits = fee_splits;
+        msg!("Fee splits updated: {:?}", self.config.fee_splits);
+        Ok(())
+    }
+}
+
+/// Configure the size-based sell fee ramp (admin only)
+#[derive(Accounts)]
+pub struct UpdateSellFeeRamp<'info> {
+    #[account(
+        mut,
+        seeds = [b"launchpad_config"],
+        bump = config.bump,
+        constraint = config.authority == authority.key() @ LaunchpadError::Unauthorized
+    )]
+    pub config: Account<'info, LaunchpadConfig>,
+    
+    pub authority: Signer<'info>,
+}
+
+impl<'info> UpdateSellFeeRamp<'info> {
+    pub fn update_sell_fee_ramp(&mut self, max_bps: u16, full_share_bps: u16) -> Result<()> {
+        require!(
+            max_bps <= LaunchpadConfig::MAX_SELL_FEE_RAMP_BPS,
+            LaunchpadError::InvalidFee
+        );
+        require!(
+            full_share_bps > 0 && full_share_bps <= 10_000,
+            LaunchpadError::InvalidFee
+        );
+        
+        self.config.sell_fee_ramp_max_bps = max_bps;
+        self.config.sell_fee_ramp_full_share_bps = full_share_bps;
+        msg!("Sell fee ramp: up to +{} bps at {} bps of the reserve", max_bps, full_share_bps);
+        Ok(())
+    }
+}
+
+/// Restrict metadata URIs to approved hosts (admin only)
+#[derive(Accounts)]
+pub struct UpdateAllowedUriPrefixes<'info> {
+    #[account(
+        mut,
+        seeds = [b"launchpad_config"],
+        bump = config.bump,
+        constraint = config.authority == authority.key() @ LaunchpadError::Unauthorized
+    )]
+    pub config: Account<'info, LaunchpadConfig>,
+    
+    pub authority: Signer<'info>,
+}
+
+impl<'info> UpdateAllowedUriPrefixes<'info> {
+    pub fn update_allowed_uri_prefixes(&mut self, allowed_uri_prefixes: Vec<String>) -> Result<()> {
+        require!(
+            allowed_uri_prefixes.len() <= LaunchpadConfig::MAX_URI_PREFIXES,
+            LaunchpadError::InvalidUri
+        );
+        // Prefixes must end at a path separator so "https://arweave.net/" can't
+        // be satisfied by "https://arweave.net.attacker.com"
+        for prefix in allowed_uri_prefixes.iter() {
+            require!(
+                prefix.len() <= LaunchpadConfig::MAX_URI_PREFIX_LEN && prefix.ends_with('/'),
+                LaunchpadError::InvalidUri
+            );
+        }
+        
+        self.config.allowed_uri_prefixes = allowed_uri_prefixes;
+        msg!("Allowed metadata URI prefixes: {:?}", self.config.allowed_uri_prefixes);
+        Ok(())
+    }
+}
+
+/// Register where graduation liquidity may be sent (admin only)
+#[derive(Accounts)]
+pub struct UpdateLiquidityDestinations<'info> {
+    #[account(
+        mut,
+        seeds = [b"launchpad_config"],
+        bump = config.bump,
+        constraint = config.authority == authority.key() @ LaunchpadError::Unauthorized
+    )]
+    pub config: Account<'info, LaunchpadConfig>,
+    
+    pub authority: Signer<'info>,
+}
+
+impl<'info> UpdateLiquidityDestinations<'info> {
+    pub fn update_liquidity_destinations(
+        &mut self,
+        liquidity_program: Pubkey,
+        liquidity_treasury: Pubkey,
+    ) -> Result<()> {
+        self.config.liquidity_program = liquidity_program;
+        self.config.liquidity_treasury = liquidity_treasury;
+        msg!(
+            "Liquidity destinations updated - DEX program: {}, treasury: {}",
+            liquidity_program,
+            liquidity_treasury
+        );
+        Ok(())
+    }
+}
+
+/// Update the loyalty token fee discount (admin only)
+#[derive(Accounts)]
+pub struct UpdateFeeDiscount<'info> {
+    #[account(
+        mut,
+        seeds = [b"launchpad_config"],
+        bump = config.bump,
+        constraint = config.authority == authority.key() @ LaunchpadError::Unauthorized
+    )]
+    pub config: Account<'info, LaunchpadConfig>,
+    
+    pub authority: Signer<'info>,
+}
+
+impl<'info> UpdateFeeDiscount<'info> {
+    pub fn update_fee_discount(&mut self, fee_discount_mint: Pubkey, fee_discount_bps: u16) -> Result<()> {
+        require!(fee_discount_bps <= 10_000, LaunchpadError::InvalidFee);
+        
+        self.config.fee_discount_mint = fee_discount_mint;
+        self.config.fee_discount_bps = fee_discount_bps;
+        msg!("Fee discount: {} bps for holders of {}", fee_discount_bps, fee_discount_mint);
+        Ok(())
+    }
+}
+
+/// Point a launch at a different Pyth price feed account (admin only)
+#[derive(Accounts)]
+pub struct UpdatePriceFeed<'info> {
+    #[account(
+        seeds = [b"launchpad_config"],
+        bump = config.bump,
+        constraint = config.authority == authority.key() @ LaunchpadError::Unauthorized
+    )]
+    pub config: Account<'info, LaunchpadConfig>,
+    
+    pub token_launch: Account<'info, TokenLaunch>,
+    
+    #[account(
+        mut,
+        seeds = [
+            b"bonding_curve",
+            token_launch.key().as_ref()
+        ],
+        bump = bonding_curve.bump
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
+    pub authority: Signer<'info>,
+}
+
+impl<'info> UpdatePriceFeed<'info> {
+    pub fn update_price_feed(&mut self, new_price_feed: Pubkey) -> Result<()> {
+        require!(
+            new_price_feed != Pubkey::default(),
+            LaunchpadError::InvalidPriceFeed
+        );
+        
+        self.bonding_curve.expected_price_feed = new_price_feed;
+        msg!("Expected price feed updated to: {}", new_price_feed);
+        Ok(())
+    }
+}
+
+/// Set or clear a launch's platform fee schedule over curve progress (admin only)
+#[derive(Accounts)]
+pub struct UpdateFeeSchedule<'info> {
+    #[account(
+        seeds = [b"launchpad_config"],
+        bump = config.bump,
+        constraint = config.authority == authority.key() @ LaunchpadError::Unauthorized
+    )]
+    pub config: Account<'info, LaunchpadConfig>,
+    
+    pub token_launch: Account<'info, TokenLaunch>,
+    
+    #[account(
+        mut,
+        seeds = [
+            b"bonding_curve",
+            token_launch.key().as_ref()
+        ],
+        bump = bonding_curve.bump
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
+    pub authority: Signer<'info>,
+}
+
+impl<'info> UpdateFeeSchedule<'info> {
+    pub fn update_fee_schedule(&mut self, fee_schedule: Option<FeeSchedule>) -> Result<()> {
+        if let Some(schedule) = fee_schedule {
+            require!(schedule.is_valid(), LaunchpadError::InvalidFee);
+            msg!("Platform fee scheduled from {} to {} bps", schedule.start_bps, schedule.end_bps);
+        } else {
+            msg!("Platform fee schedule cleared, using the flat platform fee");
+        }
+        
+        self.bonding_curve.fee_schedule_by_progress = fee_schedule;
+        Ok(())
+    }
+}
+
+/// Freeze or unfreeze trading on a specific launch (admin only)
+#[derive(Accounts)]
+pub struct AdminFreezeLaunch<'info> {
+    #[account(
+        seeds = [b"launchpad_config"],
+        bump = config.bump,
+        constraint = config.authority == authority.key() @ LaunchpadError::Unauthorized
+    )]
+    pub config: Account<'info, LaunchpadConfig>,
+    
+    #[account(
+        mut,
+        seeds = [
+            b"token_launch",
+            token_launch.mint.as_ref()
+        ],
+        bump = token_launch.bump
+    )]
+    pub token_launch: Account<'info, TokenLaunch>,
+    
+    pub authority: Signer<'info>,
+}
+
+impl<'info> AdminFreezeLaunch<'info> {
+    pub fn set_frozen(&mut self, frozen: bool) -> Result<()> {
+        self.token_launch.admin_frozen = frozen;
+        msg!("Token launch admin frozen: {}", frozen);
+        Ok(())
+    }
+}
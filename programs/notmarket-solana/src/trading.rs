@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 use anchor_lang::system_program::{transfer, Transfer};
-use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer as TokenTransfer};
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount, Transfer as TokenTransfer};
 use anchor_spl::associated_token::AssociatedToken;
 use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
 use crate::state::*;
@@ -20,7 +20,7 @@ pub struct BuyTokens<'info> {
             token_launch.mint.as_ref()
         ],
         bump = token_launch.bump,
-        constraint = token_launch.is_active @ LaunchpadError::TradingInactive
+        constraint = !token_launch.admin_frozen @ LaunchpadError::LaunchFrozen
     )]
     pub token_launch: Account<'info, TokenLaunch>,
     
@@ -42,16 +42,38 @@ pub struct BuyTokens<'info> {
     )]
     pub curve_token_account: Account<'info, TokenAccount>,
     
-    /// CHECK: SOL vault for the bonding curve
+    /// SOL vault for the bonding curve (a data-less, system-owned PDA)
     #[account(
         mut,
         seeds = [
             b"sol_vault",
             bonding_curve.key().as_ref()
         ],
-        bump
+        bump = bonding_curve.vault_bump(&bonding_curve.key())
+    )]
+    pub sol_vault: SystemAccount<'info>,
+    
+    /// Optional protocol-wide totals; a trade that passes it locks the shared account,
+    /// so indexers can leave it out and keep trades on different launches parallel
+    #[account(
+        mut,
+        seeds = [b"global_stats"],
+        bump = global_stats.bump
+    )]
+    pub global_stats: Option<Account<'info, GlobalStats>>,
+    
+    /// Recent-trade ring buffer; boxed to keep its ~1.8KB off the BPF stack.
+    /// Optional so launches created before it existed keep trading until
+    /// `initialize_trade_history` runs; pass it whenever it exists
+    #[account(
+        mut,
+        seeds = [
+            b"trade_history",
+            token_launch.key().as_ref()
+        ],
+        bump = trade_history.bump
     )]
-    pub sol_vault: UncheckedAccount<'info>,
+    pub trade_history: Option<Box<Account<'info, TradeHistory>>>,
     
     #[account(
         init_if_needed,
@@ -66,7 +88,10 @@ pub struct BuyTokens<'info> {
     )]
     pub user_position: Account<'info, UserPosition>,
     
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = mint.key() == token_launch.mint @ LaunchpadError::TokenMintMismatch
+    )]
     pub mint: Account<'info, Mint>,
     
     #[account(
@@ -82,6 +107,13 @@ pub struct BuyTokens<'info> {
     
     pub config: Account<'info, LaunchpadConfig>,
     
+    /// Optional loyalty token account proving the buyer qualifies for the fee discount
+    #[account(
+        constraint = discount_token_account.owner == buyer.key() @ LaunchpadError::Unauthorized,
+        constraint = discount_token_account.mint == config.fee_discount_mint @ LaunchpadError::TokenMintMismatch
+    )]
+    pub discount_token_account: Option<Account<'info, TokenAccount>>,
+    
     /// CHECK: Fee recipient from config
     #[account(
         mut,
@@ -89,8 +121,12 @@ pub struct BuyTokens<'info> {
     )]
     pub fee_recipient: UncheckedAccount<'info>,
     
-    /// Pyth SOL/USD price feed
-    pub sol_price_feed: Account<'info, PriceUpdateV2>,
+    /// Pyth SOL/USD price feed, must be the one registered for this launch.
+    /// Optional so trading survives a Pyth outage when manual pricing is allowed
+    #[account(
+        constraint = sol_price_feed.key() == bonding_curve.expected_price_feed @ LaunchpadError::InvalidPriceFeed
+    )]
+    pub sol_price_feed: Option<Account<'info, PriceUpdateV2>>,
     
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
@@ -108,7 +144,7 @@ pub struct SellTokens<'info> {
             token_launch.mint.as_ref()
         ],
         bump = token_launch.bump,
-        constraint = token_launch.is_active @ LaunchpadError::TradingInactive
+        constraint = !token_launch.admin_frozen @ LaunchpadError::LaunchFrozen
     )]
     pub token_launch: Account<'info, TokenLaunch>,
     
@@ -118,8 +154,7 @@ pub struct SellTokens<'info> {
             b"bonding_curve",
             token_launch.key().as_ref()
         ],
-        bump = bonding_curve.bump,
-        constraint = !bonding_curve.is_graduated @ LaunchpadError::CurveGraduated
+        bump = bonding_curve.bump
     )]
     pub bonding_curve: Account<'info, BondingCurve>,
     
@@ -130,16 +165,38 @@ pub struct SellTokens<'info> {
     )]
     pub curve_token_account: Account<'info, TokenAccount>,
     
-    /// CHECK: SOL vault for the bonding curve
+    /// SOL vault for the bonding curve (a data-less, system-owned PDA)
     #[account(
         mut,
         seeds = [
             b"sol_vault",
             bonding_curve.key().as_ref()
         ],
-        bump
+        bump = bonding_curve.vault_bump(&bonding_curve.key())
+    )]
+    pub sol_vault: SystemAccount<'info>,
+    
+    /// Optional protocol-wide totals; a trade that passes it locks the shared account,
+    /// so indexers can leave it out and keep trades on different launches parallel
+    #[account(
+        mut,
+        seeds = [b"global_stats"],
+        bump = global_stats.bump
+    )]
+    pub global_stats: Option<Account<'info, GlobalStats>>,
+    
+    /// Recent-trade ring buffer; boxed to keep its ~1.8KB off the BPF stack.
+    /// Optional so launches created before it existed keep trading until
+    /// `initialize_trade_history` runs; pass it whenever it exists
+    #[account(
+        mut,
+        seeds = [
+            b"trade_history",
+            token_launch.key().as_ref()
+        ],
+        bump = trade_history.bump
     )]
-    pub sol_vault: UncheckedAccount<'info>,
+    pub trade_history: Option<Box<Account<'info, TradeHistory>>>,
     
     #[account(
         mut,
@@ -165,6 +222,13 @@ pub struct SellTokens<'info> {
     
     pub config: Account<'info, LaunchpadConfig>,
     
+    /// Optional loyalty token account proving the seller qualifies for the fee discount
+    #[account(
+        constraint = discount_token_account.owner == seller.key() @ LaunchpadError::Unauthorized,
+        constraint = discount_token_account.mint == config.fee_discount_mint @ LaunchpadError::TokenMintMismatch
+    )]
+    pub discount_token_account: Option<Account<'info, TokenAccount>>,
+    
     /// CHECK: Fee recipient from config
     #[account(
         mut,
@@ -172,47 +236,115 @@ pub struct SellTokens<'info> {
     )]
     pub fee_recipient: UncheckedAccount<'info>,
     
-    /// Pyth SOL/USD price feed
-    pub sol_price_feed: Account<'info, PriceUpdateV2>,
+    /// Pyth SOL/USD price feed, must be the one registered for this launch.
+    /// Optional so trading survives a Pyth outage when manual pricing is allowed
+    #[account(
+        constraint = sol_price_feed.key() == bonding_curve.expected_price_feed @ LaunchpadError::InvalidPriceFeed
+    )]
+    pub sol_price_feed: Option<Account<'info, PriceUpdateV2>>,
     
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
 }
 
+/// Pair each platform fee payee with its share of `fee`. With fee splits set, the
+/// split recipients must be passed as remaining accounts in config order;
+/// otherwise the whole fee goes to the config's fee recipient
+fn fee_payees<'info>(
+    config: &LaunchpadConfig,
+    fee_recipient: AccountInfo<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    fee: u64,
+) -> Result<Vec<(AccountInfo<'info>, u64)>> {
+    if config.fee_splits.is_empty() {
+        return Ok(vec![(fee_recipient, fee)]);
+    }
+    require!(
+        remaining_accounts.len() >= config.fee_splits.len(),
+        LaunchpadError::InvalidFeeRecipient
+    );
+    
+    let shares = config.split_fee(fee)?;
+    let mut payees = Vec::with_capacity(shares.len());
+    for ((split, share), account) in config.fee_splits.iter().zip(shares).zip(remaining_accounts) {
+        require_keys_eq!(account.key(), split.recipient, LaunchpadError::InvalidFeeRecipient);
+        payees.push((account.clone(), share));
+    }
+    Ok(payees)
+}
+
 impl<'info> BuyTokens<'info> {
-    pub fn execute(&mut self, amount: u64, max_sol_cost: u64, bumps: &BuyTokensBumps) -> Result<(u64, u64)> {
+    /// Returns (cost, fee, slippage in bps against the pre-trade spot price)
+    pub fn execute(
+        &mut self,
+        amount: u64,
+        max_sol_cost: u64,
+        deadline: i64,
+        remaining_accounts: &[AccountInfo<'info>],
+        bumps: &BuyTokensBumps,
+    ) -> Result<(u64, u64, u16)> {
+        let now = Clock::get()?.unix_timestamp;
+        // Not a constraint: a pause still within its notice delay keeps trading open
+        require!(self.token_launch.is_trading_active(now), LaunchpadError::TradingInactive);
         require!(amount > 0, LaunchpadError::InvalidAmount);
+        // A zero deadline means the caller doesn't care when the trade lands
+        require!(deadline == 0 || now <= deadline, LaunchpadError::DeadlineExceeded);
+        require!(
+            self.token_launch.has_trading_started(now),
+            LaunchpadError::TradingNotStarted
+        );
+        // Curve-wide throttle, regardless of which wallet traded last
+        require!(
+            !self.bonding_curve.is_in_trade_cooldown(now, self.config.min_inter_trade_seconds),
+            LaunchpadError::CooldownActive
+        );
+        require!(
+            !self.bonding_curve.has_reached_trade_limit(),
+            LaunchpadError::TradeLimitReached
+        );
+        require!(
+            amount >= self.config.min_trade_tokens,
+            LaunchpadError::MinimumTradeAmount
+        );
         require!(
             self.bonding_curve.token_reserve >= amount,
             LaunchpadError::InsufficientLiquidity
         );
+        // Relative to what's left, so a single buy can't sweep most of the remaining supply
+        require!(
+            amount <= self.bonding_curve.max_buy_tokens(
+                self.config.max_buy_fraction_bps,
+                self.config.min_trade_tokens,
+            ),
+            LaunchpadError::MaximumTradeAmount
+        );
         
-        // Try to read fresh SOL/USD price from Pyth, fallback to last known price if stale
-        let is_fresh = PythPriceReader::is_price_fresh(&self.sol_price_feed, 60)?;
-        let sol_price_usd = if is_fresh {
-            let fresh_price = PythPriceReader::get_sol_price_usd(&self.sol_price_feed)?;
-            msg!("Using fresh Pyth price: {}", fresh_price);
-            // Update bonding curve with fresh price
-            self.bonding_curve.sol_price_usd = fresh_price;
-            fresh_price
-        } else {
-            // Use last known price from bonding curve state
-            let backup_price = self.bonding_curve.sol_price_usd;
-            msg!("⚠️  Pyth price is stale, using last known price: {}", backup_price);
-            require!(backup_price > 0, LaunchpadError::InvalidPrice);
-            backup_price
-        };
+        // Try to read fresh SOL/USD price from Pyth, fallback to last known price if stale or absent
+        let sol_price_usd = self.bonding_curve.resolve_trade_price(
+            self.sol_price_feed.as_deref(),
+            self.config.manual_price_allowed,
+            self.config.price_staleness_seconds(),
+            Clock::get()?.unix_timestamp,
+        )?;
         
         // Calculate cost using bonding curve with current/backup price
         let cost = BondingCurveCalculator::calculate_buy_price(
+            self.bonding_curve.curve_type,
             self.bonding_curve.tokens_sold,
             amount,
             sol_price_usd,
         )?;
         
-        // Calculate platform fee
+        // Calculate platform fee at the launch's pre-trade progress, discounted for loyalty token holders
+        let holds_discount_token = self.discount_token_account
+            .as_ref()
+            .is_some_and(|account| account.amount > 0);
+        let fee_bps = self.config.effective_fee_bps(
+            self.bonding_curve.platform_fee_bps(self.config.platform_fee_bps),
+            holds_discount_token,
+        );
         let fee = cost
-            .checked_mul(self.config.platform_fee_bps as u64)
+            .checked_mul(fee_bps as u64)
             .ok_or(LaunchpadError::MathOverflow)?
             .checked_div(10000)
             .ok_or(LaunchpadError::MathOverflow)?;
@@ -225,18 +357,52 @@ impl<'info> BuyTokens<'info> {
             total_cost <= max_sol_cost,
             LaunchpadError::SlippageExceeded
         );
+        require!(
+            !self.bonding_curve.exceeds_raise_cap(cost),
+            LaunchpadError::RaiseCapReached
+        );
         
-        // Ensure sol_vault has rent-exempt minimum (890880 lamports for 0-byte account)
-        const RENT_EXEMPT_MINIMUM: u64 = 890_880;
+        // When accruing, the fee rides along with the cost into the vault
+        let accrue_fees = self.config.accrue_fees;
+        let vault_deposit = if accrue_fees {
+            cost.checked_add(fee).ok_or(LaunchpadError::MathOverflow)?
+        } else {
+            cost
+        };
+        
+        // Ensure sol_vault has the live rent-exempt minimum for a 0-byte account.
+        // Lamports already in the vault (e.g. sent directly before the first buy) only
+        // offset the rent; they never enter sol_reserve, and any excess over the
+        // minimum is reported as unattributed by get_vault_reconciliation
+        let rent_exempt_minimum = Rent::get()?.minimum_balance(0);
         let vault_lamports = self.sol_vault.lamports();
-        let amount_to_transfer = if vault_lamports < RENT_EXEMPT_MINIMUM {
+        let amount_to_transfer = if vault_lamports < rent_exempt_minimum {
             // First transfer: ensure vault becomes rent-exempt
-            cost.checked_add(RENT_EXEMPT_MINIMUM - vault_lamports)
+            vault_deposit.checked_add(rent_exempt_minimum - vault_lamports)
                 .ok_or(LaunchpadError::MathOverflow)?
         } else {
-            cost
+            vault_deposit
         };
         
+        // Check the buyer can cover everything up front, so an underfunded first
+        // buyer gets a clear error instead of a failed system transfer
+        let fee_to_recipient = if accrue_fees { 0 } else { fee };
+        let total_debit = amount_to_transfer
+            .checked_add(fee_to_recipient)
+            .ok_or(LaunchpadError::MathOverflow)?;
+        let buyer_lamports = self.buyer.lamports();
+        if buyer_lamports < total_debit {
+            msg!(
+                "Buy requires {} lamports ({} cost + {} fee + {} vault rent), buyer has {}",
+                total_debit,
+                cost,
+                fee,
+                amount_to_transfer - vault_deposit,
+                buyer_lamports
+            );
+            return err!(LaunchpadError::InsufficientSolForRent);
+        }
+        
         // Transfer SOL from buyer to vault
         let transfer_to_vault = Transfer {
             from: self.buyer.to_account_info(),
@@ -250,19 +416,38 @@ impl<'info> BuyTokens<'info> {
             amount_to_transfer,
         )?;
         
-        // Transfer fee to fee recipient
-        if fee > 0 {
-            let transfer_fee = Transfer {
-                from: self.buyer.to_account_info(),
-                to: self.fee_recipient.to_account_info(),
-            };
-            transfer(
-                CpiContext::new(
-                    self.system_program.to_account_info(),
-                    transfer_fee,
-                ),
+        // Transfer fee to the fee recipient, or split it across the weighted recipients
+        if fee > 0 && !accrue_fees {
+            let payees = fee_payees(
+                &self.config,
+                self.fee_recipient.to_account_info(),
+                remaining_accounts,
                 fee,
             )?;
+            for (recipient, share) in payees {
+                if share == 0 {
+                    continue;
+                }
+                let transfer_fee = Transfer {
+                    from: self.buyer.to_account_info(),
+                    to: recipient.clone(),
+                };
+                transfer(
+                    CpiContext::new(
+                        self.system_program.to_account_info(),
+                        transfer_fee,
+                    ),
+                    share,
+                )?;
+                
+                emit!(PlatformFeeCollected {
+                    launch: self.token_launch.key(),
+                    payer: self.buyer.key(),
+                    amount: share,
+                    recipient: recipient.key(),
+                    timestamp: Clock::get()?.unix_timestamp,
+                });
+            }
         }
         
         // Transfer tokens from curve to buyer
@@ -288,6 +473,16 @@ impl<'info> BuyTokens<'info> {
             amount,
         )?;
         
+        // Measured against the spot price before this buy moves the curve
+        let slippage_bps = BondingCurveCalculator::realized_slippage(
+            self.bonding_curve.curve_type,
+            TradeSide::Buy,
+            self.bonding_curve.tokens_sold,
+            amount,
+            cost,
+            sol_price_usd,
+        )?;
+        
         // Update bonding curve state
         self.bonding_curve.sol_reserve = self.bonding_curve.sol_reserve
             .checked_add(cost)
@@ -301,15 +496,31 @@ impl<'info> BuyTokens<'info> {
         self.bonding_curve.total_volume = self.bonding_curve.total_volume
             .checked_add(cost)
             .ok_or(LaunchpadError::MathOverflow)?;
+        if let Some(global_stats) = self.global_stats.as_mut() {
+            global_stats.record_trade(cost, fee)?;
+        }
+        if let Some(trade_history) = self.trade_history.as_mut() {
+            trade_history.record(TradeSide::Buy, self.buyer.key(), amount, cost, now)?;
+        }
         self.bonding_curve.trade_count = self.bonding_curve.trade_count
             .checked_add(1)
             .ok_or(LaunchpadError::MathOverflow)?;
+        self.bonding_curve.last_trade_time = now;
+        if accrue_fees {
+            self.bonding_curve.accrued_fees = self.bonding_curve.accrued_fees
+                .checked_add(fee)
+                .ok_or(LaunchpadError::MathOverflow)?;
+        }
         
         // Update token launch circulating supply
         self.token_launch.circulating_supply = self.token_launch.circulating_supply
             .checked_add(amount)
             .ok_or(LaunchpadError::MathOverflow)?;
         
+        // Reload so the invariant sees the post-transfer curve token balance
+        self.curve_token_account.reload()?;
+        self.bonding_curve.check_reserve_invariant(self.curve_token_account.amount)?;
+        
         // Update or initialize user position
         if self.user_position.user == Pubkey::default() {
             self.user_position.user = self.buyer.key();
@@ -319,7 +530,24 @@ impl<'info> BuyTokens<'info> {
             self.user_position.sol_received = 0;
             self.user_position.buy_count = 0;
             self.user_position.sell_count = 0;
+            self.user_position.first_buy_timestamp = 0;
             self.user_position.bump = bumps.user_position;
+            self.user_position.reserved = [0; 64];
+        }
+        
+        // A position going 0 -> positive is a new holder (covers re-entry after a full sell)
+        if self.user_position.token_amount == 0 {
+            self.bonding_curve.holder_count = self.bonding_curve.holder_count
+                .checked_add(1)
+                .ok_or(LaunchpadError::MathOverflow)?;
+        }
+        
+        // First buy on this position is a new unique buyer; re-entry after a full sell is not
+        if self.user_position.buy_count == 0 {
+            self.user_position.first_buy_timestamp = now;
+            self.bonding_curve.unique_buyers = self.bonding_curve.unique_buyers
+                .checked_add(1)
+                .ok_or(LaunchpadError::MathOverflow)?;
         }
         
         self.user_position.token_amount = self.user_position.token_amount
@@ -353,10 +581,37 @@ impl<'info> BuyTokens<'info> {
             self.bonding_curve.tokens_sold / 1_000_000_000
         );
         
-        // Check if graduation threshold reached (800M tokens sold + $12k raised)
-        if self.bonding_curve.should_graduate() {
-            msg!("🎓 Graduation threshold reached! 800M tokens sold and $12k raised!");
+        // Warn once when the curve first crosses 95% sold
+        if !self.bonding_curve.near_graduation_notified && self.bonding_curve.is_near_graduation() {
+            self.bonding_curve.near_graduation_notified = true;
+            
+            emit!(NearGraduation {
+                launch: self.token_launch.key(),
+                bonding_curve: self.bonding_curve.key(),
+                tokens_sold: self.bonding_curve.tokens_sold,
+                tokens_until_graduation: self.bonding_curve.tokens_until_graduation(),
+                timestamp: Clock::get()?.unix_timestamp,
+            });
+        }
+        
+        // Check if graduation threshold reached (curve supply sold + $12k raised, or the SOL target)
+        if self.bonding_curve.should_graduate(
+            self.config.min_graduation_sol_lamports,
+            self.config.min_holders_for_graduation,
+        ) {
+            msg!("🎓 Graduation threshold reached with {} lamports raised!", self.bonding_curve.sol_reserve);
+            let timestamp = Clock::get()?.unix_timestamp;
+            
             self.bonding_curve.is_graduated = true;
+            self.bonding_curve.graduated_at = timestamp;
+            self.bonding_curve.graduation_grace_seconds = self.config.graduation_grace_seconds;
+            if let Some(global_stats) = self.global_stats.as_mut() {
+                global_stats.record_graduation()?;
+            }
+            // Graduated curves are untradeable, so mirror that on the launch
+            self.token_launch.is_active = false;
+            self.token_launch.deactivates_at = 0;
+            self.token_launch.deactivation_reason = Some(LaunchStatusChange::Graduation);
             
             // Emit graduation event
             emit!(CurveGraduated {
@@ -364,50 +619,197 @@ impl<'info> BuyTokens<'info> {
                 bonding_curve: self.bonding_curve.key(),
                 tokens_sold: self.bonding_curve.tokens_sold,
                 sol_raised: self.bonding_curve.sol_reserve,
-                timestamp: Clock::get()?.unix_timestamp,
+                graduation_action: self.bonding_curve.graduation_action,
+                timestamp,
+            });
+            
+            emit!(LaunchStatusToggled {
+                launch: self.token_launch.key(),
+                is_active: false,
+                change: LaunchStatusChange::Graduation,
+                paused_seconds: 0,
+                total_paused_seconds: self.token_launch.total_paused_seconds,
+                deactivates_at: 0,
+                toggled_by: self.buyer.key(),
+                timestamp,
             });
             
+            // Keep the listing clean: unsold (or stray) curve tokens never reach the pool.
+            // Target-vault launches graduate with supply left, so they always burn it
+            if self.config.burn_unsold_on_graduation
+                || self.bonding_curve.target_sol_vault_lamports > 0
+            {
+                let burned = self.burn_unsold_tokens()?;
+                if burned > 0 {
+                    emit!(UnsoldTokensBurned {
+                        launch: self.token_launch.key(),
+                        mint: self.mint.key(),
+                        amount: burned,
+                        timestamp,
+                    });
+                }
+            }
+            
             // Note: Actual LP creation logic would be implemented in a separate instruction
         }
         
-        Ok((cost, fee))
+        Ok((cost, fee, slippage_bps))
+    }
+    
+    /// Burn everything left in the curve token account and zero the token reserve,
+    /// returning the amount burned
+    fn burn_unsold_tokens(&mut self) -> Result<u64> {
+        self.curve_token_account.reload()?;
+        let unsold = self.curve_token_account.amount;
+        if unsold == 0 {
+            return Ok(0);
+        }
+        
+        let token_launch_key = self.token_launch.key();
+        let seeds = &[
+            b"bonding_curve",
+            token_launch_key.as_ref(),
+            &[self.bonding_curve.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+        
+        let burn_tokens = Burn {
+            mint: self.mint.to_account_info(),
+            from: self.curve_token_account.to_account_info(),
+            authority: self.bonding_curve.to_account_info(),
+        };
+        token::burn(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                burn_tokens,
+                signer_seeds,
+            ),
+            unsold,
+        )?;
+        // The burned tokens are gone from the curve; only grace-window sells refill it
+        self.bonding_curve.token_reserve = 0;
+        
+        msg!("🔥 Burned {} unsold curve tokens at graduation", unsold);
+        Ok(unsold)
     }
 }
 
 impl<'info> SellTokens<'info> {
-    pub fn execute(&mut self, amount: u64, min_sol_output: u64, bumps: &SellTokensBumps) -> Result<(u64, u64)> {
+    /// Returns (proceeds, fee, slippage in bps against the pre-trade spot price)
+    pub fn execute(
+        &mut self,
+        amount: u64,
+        min_sol_output: u64,
+        deadline: i64,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<(u64, u64, u16)> {
+        let now = Clock::get()?.unix_timestamp;
+        // Graduation deactivates the launch, so sells in the grace window skip that check
+        if self.bonding_curve.is_graduated {
+            require!(
+                self.bonding_curve.is_in_graduation_grace(now),
+                LaunchpadError::CurveGraduated
+            );
+        } else {
+            require!(self.token_launch.is_trading_active(now), LaunchpadError::TradingInactive);
+        }
         require!(amount > 0, LaunchpadError::InvalidAmount);
+        require!(self.token_launch.sells_enabled(), LaunchpadError::SellsDisabled);
+        // Only tokens bought from the curve can be sold back to it
+        require!(
+            amount <= self.bonding_curve.tokens_sold,
+            LaunchpadError::SellExceedsTokensSold
+        );
+        // A zero deadline means the caller doesn't care when the trade lands
+        require!(deadline == 0 || now <= deadline, LaunchpadError::DeadlineExceeded);
+        require!(
+            self.token_launch.has_trading_started(now),
+            LaunchpadError::TradingNotStarted
+        );
+        // Curve-wide throttle, regardless of which wallet traded last
+        require!(
+            !self.bonding_curve.is_in_trade_cooldown(now, self.config.min_inter_trade_seconds),
+            LaunchpadError::CooldownActive
+        );
+        require!(
+            !self.bonding_curve.has_reached_trade_limit(),
+            LaunchpadError::TradeLimitReached
+        );
         require!(
             self.user_position.token_amount >= amount,
             LaunchpadError::InsufficientBalance
         );
         
-        // Try to read fresh SOL/USD price from Pyth, fallback to last known price if stale
-        let is_fresh = PythPriceReader::is_price_fresh(&self.sol_price_feed, 60)?;
-        let sol_price_usd = if is_fresh {
-            let fresh_price = PythPriceReader::get_sol_price_usd(&self.sol_price_feed)?;
-            msg!("Using fresh Pyth price: {}", fresh_price);
-            // Update bonding curve with fresh price
-            self.bonding_curve.sol_price_usd = fresh_price;
-            fresh_price
-        } else {
-            // Use last known price from bonding curve state
-            let backup_price = self.bonding_curve.sol_price_usd;
-            msg!("⚠️  Pyth price is stale, using last known price: {}", backup_price);
-            require!(backup_price > 0, LaunchpadError::InvalidPrice);
-            backup_price
-        };
+        // Selling the full balance is always allowed; otherwise the trade and
+        // the remainder must both meet the minimum so no unsellable dust is left
+        let remaining = self.user_position.token_amount - amount;
+        if remaining > 0 {
+            let min_trade_tokens = self.config.min_trade_tokens;
+            require!(
+                amount >= min_trade_tokens,
+                LaunchpadError::MinimumTradeAmount
+            );
+            if remaining < min_trade_tokens {
+                msg!(
+                    "Sell would leave {} tokens, below the {} minimum. Sell the full balance of {} instead.",
+                    remaining,
+                    min_trade_tokens,
+                    self.user_position.token_amount
+                );
+                return err!(LaunchpadError::MinimumTradeAmount);
+            }
+        }
+        
+        // Try to read fresh SOL/USD price from Pyth, fallback to last known price if stale or absent
+        let sol_price_usd = self.bonding_curve.resolve_trade_price(
+            self.sol_price_feed.as_deref(),
+            self.config.manual_price_allowed,
+            self.config.price_staleness_seconds(),
+            Clock::get()?.unix_timestamp,
+        )?;
         
         // Calculate proceeds using bonding curve with current/backup price
-        let proceeds = BondingCurveCalculator::calculate_sell_price(
+        let mut proceeds = BondingCurveCalculator::calculate_sell_price(
+            self.bonding_curve.curve_type,
             self.bonding_curve.tokens_sold,
             amount,
             sol_price_usd,
         )?;
         
-        // Calculate platform fee
+        // Never pay out more than the reserve backs, even if it has drifted below the curve.
+        // Applied before any other check: the clamp scales every sell by the backing ratio,
+        // so the proceeds it leaves always fit within the reserve
+        let backed_proceeds = BondingCurveCalculator::apply_reserve_backing(
+            self.bonding_curve.curve_type,
+            self.bonding_curve.tokens_sold,
+            proceeds,
+            self.bonding_curve.sol_reserve,
+            sol_price_usd,
+        )?;
+        if backed_proceeds < proceeds {
+            msg!(
+                "Reserve under-backs the curve: proceeds clamped from {} to {} lamports",
+                proceeds,
+                backed_proceeds
+            );
+            proceeds = backed_proceeds;
+        }
+        
+        // Calculate platform fee at the launch's pre-trade progress, discounted for
+        // loyalty token holders and ramped up for sells that take a large share of the reserve
+        let holds_discount_token = self.discount_token_account
+            .as_ref()
+            .is_some_and(|account| account.amount > 0);
+        let fee_bps = self.config.sell_fee_bps(
+            self.config.effective_fee_bps(
+                self.bonding_curve.platform_fee_bps(self.config.platform_fee_bps),
+                holds_discount_token,
+            ),
+            proceeds,
+            self.bonding_curve.sol_reserve,
+        );
         let fee = proceeds
-            .checked_mul(self.config.platform_fee_bps as u64)
+            .checked_mul(fee_bps as u64)
             .ok_or(LaunchpadError::MathOverflow)?
             .checked_div(10000)
             .ok_or(LaunchpadError::MathOverflow)?;
@@ -420,10 +822,6 @@ impl<'info> SellTokens<'info> {
             net_proceeds >= min_sol_output,
             LaunchpadError::SlippageExceeded
         );
-        require!(
-            self.bonding_curve.sol_reserve >= proceeds,
-            LaunchpadError::InsufficientLiquidity
-        );
         
         // Transfer tokens from seller to curve
         let transfer_tokens = TokenTransfer {
@@ -444,7 +842,7 @@ impl<'info> SellTokens<'info> {
         let vault_seeds = &[
             b"sol_vault",
             bonding_curve_key.as_ref(),
-            &[bumps.sol_vault],
+            &[self.bonding_curve.vault_bump(&bonding_curve_key)],
         ];
         let vault_signer_seeds = &[&vault_seeds[..]];
         
@@ -462,22 +860,54 @@ impl<'info> SellTokens<'info> {
             net_proceeds,
         )?;
         
-        // Transfer fee to fee recipient
-        if fee > 0 {
-            let transfer_fee = Transfer {
-                from: self.sol_vault.to_account_info(),
-                to: self.fee_recipient.to_account_info(),
-            };
-            transfer(
-                CpiContext::new_with_signer(
-                    self.system_program.to_account_info(),
-                    transfer_fee,
-                    vault_signer_seeds,
-                ),
+        // Transfer fee to the fee recipient (or split it across the weighted
+        // recipients), or leave it in the vault when accruing
+        let accrue_fees = self.config.accrue_fees;
+        if fee > 0 && !accrue_fees {
+            let payees = fee_payees(
+                &self.config,
+                self.fee_recipient.to_account_info(),
+                remaining_accounts,
                 fee,
             )?;
+            for (recipient, share) in payees {
+                if share == 0 {
+                    continue;
+                }
+                let transfer_fee = Transfer {
+                    from: self.sol_vault.to_account_info(),
+                    to: recipient.clone(),
+                };
+                transfer(
+                    CpiContext::new_with_signer(
+                        self.system_program.to_account_info(),
+                        transfer_fee,
+                        vault_signer_seeds,
+                    ),
+                    share,
+                )?;
+                
+                emit!(PlatformFeeCollected {
+                    launch: self.token_launch.key(),
+                    payer: self.seller.key(),
+                    amount: share,
+                    recipient: recipient.key(),
+                    timestamp: Clock::get()?.unix_timestamp,
+                });
+            }
         }
         
+        // Measured against the spot price before this sell moves the curve,
+        // using the proceeds actually paid (after any reserve clamp)
+        let slippage_bps = BondingCurveCalculator::realized_slippage(
+            self.bonding_curve.curve_type,
+            TradeSide::Sell,
+            self.bonding_curve.tokens_sold,
+            amount,
+            proceeds,
+            sol_price_usd,
+        )?;
+        
         // Update bonding curve state
         self.bonding_curve.sol_reserve = self.bonding_curve.sol_reserve
             .checked_sub(proceeds)
@@ -491,19 +921,42 @@ impl<'info> SellTokens<'info> {
         self.bonding_curve.total_volume = self.bonding_curve.total_volume
             .checked_add(proceeds)
             .ok_or(LaunchpadError::MathOverflow)?;
+        if let Some(global_stats) = self.global_stats.as_mut() {
+            global_stats.record_trade(proceeds, fee)?;
+        }
+        if let Some(trade_history) = self.trade_history.as_mut() {
+            trade_history.record(TradeSide::Sell, self.seller.key(), amount, proceeds, now)?;
+        }
         self.bonding_curve.trade_count = self.bonding_curve.trade_count
             .checked_add(1)
             .ok_or(LaunchpadError::MathOverflow)?;
+        self.bonding_curve.last_trade_time = now;
+        if accrue_fees {
+            self.bonding_curve.accrued_fees = self.bonding_curve.accrued_fees
+                .checked_add(fee)
+                .ok_or(LaunchpadError::MathOverflow)?;
+        }
         
         // Update token launch circulating supply
         self.token_launch.circulating_supply = self.token_launch.circulating_supply
             .checked_sub(amount)
             .ok_or(LaunchpadError::MathOverflow)?;
         
+        // Reload so the invariant sees the post-transfer curve token balance
+        self.curve_token_account.reload()?;
+        self.bonding_curve.check_reserve_invariant(self.curve_token_account.amount)?;
+        
         // Update user position
         self.user_position.token_amount = self.user_position.token_amount
             .checked_sub(amount)
             .ok_or(LaunchpadError::MathOverflow)?;
+        
+        // Fully exited positions no longer count as holders
+        if self.user_position.token_amount == 0 {
+            self.bonding_curve.holder_count = self.bonding_curve.holder_count
+                .checked_sub(1)
+                .ok_or(LaunchpadError::MathOverflow)?;
+        }
         self.user_position.sol_received = self.user_position.sol_received
             .checked_add(net_proceeds)
             .ok_or(LaunchpadError::MathOverflow)?;
@@ -531,38 +984,124 @@ impl<'info> SellTokens<'info> {
             fee
         );
         
-        Ok((proceeds, fee))
+        Ok((proceeds, fee, slippage_bps))
+    }
+}
+
+/// Refresh the stored SOL/USD backup price from Pyth (permissionless)
+#[derive(Accounts)]
+pub struct RefreshPrice<'info> {
+    #[account(
+        seeds = [b"launchpad_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, LaunchpadConfig>,
+    
+    #[account(
+        seeds = [
+            b"token_launch",
+            token_launch.mint.as_ref()
+        ],
+        bump = token_launch.bump
+    )]
+    pub token_launch: Account<'info, TokenLaunch>,
+    
+    #[account(
+        mut,
+        seeds = [
+            b"bonding_curve",
+            token_launch.key().as_ref()
+        ],
+        bump = bonding_curve.bump,
+        constraint = !bonding_curve.is_graduated @ LaunchpadError::CurveGraduated
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
+    /// Pyth SOL/USD price feed, must be the one registered for this launch
+    #[account(
+        constraint = sol_price_feed.key() == bonding_curve.expected_price_feed @ LaunchpadError::InvalidPriceFeed
+    )]
+    pub sol_price_feed: Account<'info, PriceUpdateV2>,
+}
+
+impl<'info> RefreshPrice<'info> {
+    /// Returns (old_price, new_price)
+    pub fn refresh(&mut self) -> Result<(u64, u64)> {
+        require!(
+            PythPriceReader::is_price_fresh(
+                &self.sol_price_feed,
+                self.config.price_staleness_seconds() as i64,
+            )?,
+            LaunchpadError::PriceStale
+        );
+        
+        let old_price = self.bonding_curve.sol_price_usd;
+        let new_price = PythPriceReader::get_sol_price_usd(&self.sol_price_feed)?;
+        self.bonding_curve.record_oracle_price(new_price, Clock::get()?.unix_timestamp)?;
+        
+        msg!("Refreshed SOL/USD price: {} -> {}", old_price, new_price);
+        
+        Ok((old_price, new_price))
     }
 }
 
 /// Get current price quote for buying tokens (view function)
 #[derive(Accounts)]
 pub struct GetBuyQuote<'info> {
+    #[account(
+        seeds = [b"launchpad_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, LaunchpadConfig>,
+    
     pub token_launch: Account<'info, TokenLaunch>,
     pub bonding_curve: Account<'info, BondingCurve>,
 }
 
 impl<'info> GetBuyQuote<'info> {
-    pub fn get_quote(&self, amount: u64) -> Result<BuyQuote> {
+    pub fn get_quote(&self, amount: u64, slippage_reference: SlippageReference) -> Result<BuyQuote> {
         let cost = BondingCurveCalculator::calculate_buy_price(
+            self.bonding_curve.curve_type,
             self.bonding_curve.tokens_sold,
             amount,
             self.bonding_curve.sol_price_usd,
         )?;
         
-        let spot_price = BondingCurveCalculator::get_spot_price(
+        let spot_price = BondingCurveCalculator::get_spot_price_lite(
+            self.bonding_curve.curve_type,
             self.bonding_curve.tokens_sold,
             self.bonding_curve.sol_price_usd,
         )?;
         
-        let slippage = BondingCurveCalculator::calculate_slippage(
-            self.bonding_curve.tokens_sold,
-            amount,
-            self.bonding_curve.sol_price_usd,
-        )?;
+        let slippage = match slippage_reference {
+            SlippageReference::CurrentSpot => BondingCurveCalculator::calculate_slippage(
+                self.bonding_curve.curve_type,
+                self.bonding_curve.tokens_sold,
+                amount,
+                self.bonding_curve.sol_price_usd,
+            )?,
+            SlippageReference::StartPrice => BondingCurveCalculator::calculate_slippage_from_start(
+                self.bonding_curve.curve_type,
+                self.bonding_curve.tokens_sold,
+                amount,
+                self.bonding_curve.sol_price_usd,
+            )?,
+        };
+        
+        // Undiscounted platform fee, so total_cost is always enough for max_sol_cost
+        let fee = cost
+            .checked_mul(self.bonding_curve.platform_fee_bps(self.config.platform_fee_bps) as u64)
+            .ok_or(LaunchpadError::MathOverflow)?
+            .checked_div(10000)
+            .ok_or(LaunchpadError::MathOverflow)?;
+        let total_cost = cost
+            .checked_add(fee)
+            .ok_or(LaunchpadError::MathOverflow)?;
         
         Ok(BuyQuote {
             cost,
+            fee,
+            total_cost,
             spot_price,
             slippage,
         })
@@ -578,7 +1117,8 @@ pub struct GetSpotPrice<'info> {
 
 impl<'info> GetSpotPrice<'info> {
     pub fn get_current_price(&self) -> Result<SpotPrice> {
-        let spot_price = BondingCurveCalculator::get_spot_price(
+        let spot_price = BondingCurveCalculator::get_spot_price_lite(
+            self.bonding_curve.curve_type,
             self.bonding_curve.tokens_sold,
             self.bonding_curve.sol_price_usd,
         )?;
@@ -587,6 +1127,666 @@ impl<'info> GetSpotPrice<'info> {
             spot_price,
             tokens_sold: self.bonding_curve.tokens_sold,
             sol_reserve: self.bonding_curve.sol_reserve,
+            tokens_until_graduation: self.bonding_curve.tokens_until_graduation(),
+            holder_count: self.bonding_curve.holder_count,
+        })
+    }
+}
+
+/// Get the spot price at an arbitrary supply level for charting (view function)
+#[derive(Accounts)]
+pub struct GetPriceAtSupply<'info> {
+    pub token_launch: Account<'info, TokenLaunch>,
+    
+    #[account(
+        constraint = bonding_curve.token_launch == token_launch.key() @ LaunchpadError::BondingCurveNotFound
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+}
+
+impl<'info> GetPriceAtSupply<'info> {
+    /// Maximum samples per batch call (bounded by the 1024-byte return data limit)
+    pub const MAX_SAMPLES: usize = 100;
+    
+    pub fn price_at(&self, tokens_sold: u64) -> Result<u64> {
+        require!(tokens_sold <= CURVE_SUPPLY, LaunchpadError::InsufficientSupply);
+        
+        BondingCurveCalculator::get_spot_price_lite(
+            self.bonding_curve.curve_type,
+            tokens_sold,
+            self.bonding_curve.sol_price_usd,
+        )
+    }
+    
+    pub fn prices_at(&self, supplies: &[u64]) -> Result<Vec<u64>> {
+        require!(
+            !supplies.is_empty() && supplies.len() <= Self::MAX_SAMPLES,
+            LaunchpadError::InvalidAmount
+        );
+        
+        supplies.iter().map(|tokens_sold| self.price_at(*tokens_sold)).collect()
+    }
+}
+
+/// Simulate a trade against the bonding curve without mutating state (view function)
+#[derive(Accounts)]
+pub struct SimulateTrade<'info> {
+    pub token_launch: Account<'info, TokenLaunch>,
+    
+    #[account(
+        constraint = bonding_curve.token_launch == token_launch.key() @ LaunchpadError::BondingCurveNotFound
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
+    #[account(
+        seeds = [b"launchpad_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, LaunchpadConfig>,
+}
+
+impl<'info> SimulateTrade<'info> {
+    pub fn simulate(&self, side: TradeSide, amount: u64) -> Result<TradeSimulation> {
+        require!(amount > 0, LaunchpadError::InvalidAmount);
+        require!(!self.bonding_curve.is_graduated, LaunchpadError::CurveGraduated);
+        
+        // Work on an in-memory copy so nothing is written back
+        let mut curve = (*self.bonding_curve).clone();
+        let sol_price_usd = curve.sol_price_usd;
+        
+        let (sol_amount, fee, net_sol) = match side {
+            TradeSide::Buy => {
+                require!(curve.token_reserve >= amount, LaunchpadError::InsufficientLiquidity);
+                
+                let cost = BondingCurveCalculator::calculate_buy_price(
+                    curve.curve_type,
+                    curve.tokens_sold,
+                    amount,
+                    sol_price_usd,
+                )?;
+                let fee = cost
+                    .checked_mul(curve.platform_fee_bps(self.config.platform_fee_bps) as u64)
+                    .ok_or(LaunchpadError::MathOverflow)?
+                    .checked_div(10000)
+                    .ok_or(LaunchpadError::MathOverflow)?;
+                let total_cost = cost
+                    .checked_add(fee)
+                    .ok_or(LaunchpadError::MathOverflow)?;
+                
+                curve.sol_reserve = curve.sol_reserve
+                    .checked_add(cost)
+                    .ok_or(LaunchpadError::MathOverflow)?;
+                curve.token_reserve = curve.token_reserve
+                    .checked_sub(amount)
+                    .ok_or(LaunchpadError::MathOverflow)?;
+                curve.tokens_sold = curve.tokens_sold
+                    .checked_add(amount)
+                    .ok_or(LaunchpadError::MathOverflow)?;
+                
+                (cost, fee, total_cost)
+            }
+            TradeSide::Sell => {
+                require!(self.token_launch.sells_enabled(), LaunchpadError::SellsDisabled);
+                require!(amount <= curve.tokens_sold, LaunchpadError::SellExceedsTokensSold);
+                let proceeds = BondingCurveCalculator::apply_reserve_backing(
+                    curve.curve_type,
+                    curve.tokens_sold,
+                    BondingCurveCalculator::calculate_sell_price(curve.curve_type, curve.tokens_sold, amount, sol_price_usd)?,
+                    curve.sol_reserve,
+                    sol_price_usd,
+                )?;
+                
+                let fee_bps = self.config.sell_fee_bps(
+                    curve.platform_fee_bps(self.config.platform_fee_bps),
+                    proceeds,
+                    curve.sol_reserve,
+                );
+                let fee = proceeds
+                    .checked_mul(fee_bps as u64)
+                    .ok_or(LaunchpadError::MathOverflow)?
+                    .checked_div(10000)
+                    .ok_or(LaunchpadError::MathOverflow)?;
+                let net_proceeds = proceeds
+                    .checked_sub(fee)
+                    .ok_or(LaunchpadError::MathOverflow)?;
+                
+                curve.sol_reserve = curve.sol_reserve
+                    .checked_sub(proceeds)
+                    .ok_or(LaunchpadError::MathOverflow)?;
+                curve.token_reserve = curve.token_reserve
+                    .checked_add(amount)
+                    .ok_or(LaunchpadError::MathOverflow)?;
+                curve.tokens_sold = curve.tokens_sold
+                    .checked_sub(amount)
+                    .ok_or(LaunchpadError::MathOverflow)?;
+                
+                (proceeds, fee, net_proceeds)
+            }
+        };
+        
+        let spot_price_after = BondingCurveCalculator::get_spot_price_lite(
+            curve.curve_type,
+            curve.tokens_sold,
+            sol_price_usd,
+        )?;
+        
+        Ok(TradeSimulation {
+            sol_amount,
+            fee,
+            net_sol,
+            tokens_sold_after: curve.tokens_sold,
+            token_reserve_after: curve.token_reserve,
+            sol_reserve_after: curve.sol_reserve,
+            spot_price_after,
+            triggers_graduation: side == TradeSide::Buy && curve.should_graduate(
+                self.config.min_graduation_sol_lamports,
+                self.config.min_holders_for_graduation,
+            ),
+        })
+    }
+}
+
+/// Resolve every PDA of a launch from its mint (view function)
+/// Seeds are enforced here so integrators don't need to know the scheme
+#[derive(Accounts)]
+pub struct ResolveLaunch<'info> {
+    pub mint: Account<'info, Mint>,
+    
+    #[account(
+        seeds = [
+            b"token_launch",
+            mint.key().as_ref()
+        ],
+        bump = token_launch.bump,
+        constraint = token_launch.mint == mint.key() @ LaunchpadError::TokenMintMismatch
+    )]
+    pub token_launch: Account<'info, TokenLaunch>,
+    
+    #[account(
+        seeds = [
+            b"bonding_curve",
+            token_launch.key().as_ref()
+        ],
+        bump = bonding_curve.bump,
+        constraint = token_launch.bonding_curve == bonding_curve.key() @ LaunchpadError::BondingCurveNotFound
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
+    /// SOL vault for the bonding curve (a data-less, system-owned PDA)
+    #[account(
+        seeds = [
+            b"sol_vault",
+            bonding_curve.key().as_ref()
+        ],
+        bump = bonding_curve.vault_bump(&bonding_curve.key())
+    )]
+    pub sol_vault: SystemAccount<'info>,
+    
+    #[account(
+        associated_token::mint = mint,
+        associated_token::authority = bonding_curve
+    )]
+    pub curve_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        seeds = [
+            b"lp_vault",
+            bonding_curve.key().as_ref()
+        ],
+        bump
+    )]
+    pub lp_vault: Account<'info, TokenAccount>,
+}
+
+impl<'info> ResolveLaunch<'info> {
+    pub fn resolve(&self) -> Result<LaunchAddresses> {
+        Ok(LaunchAddresses {
+            mint: self.mint.key(),
+            token_launch: self.token_launch.key(),
+            bonding_curve: self.bonding_curve.key(),
+            sol_vault: self.sol_vault.key(),
+            curve_token_account: self.curve_token_account.key(),
+            lp_vault: self.lp_vault.key(),
+        })
+    }
+}
+
+/// Summarize a launch's metadata, curve state and derived stats in one call (view function)
+#[derive(Accounts)]
+pub struct GetLaunchSummary<'info> {
+    pub token_launch: Account<'info, TokenLaunch>,
+    
+    #[account(
+        constraint = bonding_curve.token_launch == token_launch.key() @ LaunchpadError::BondingCurveNotFound
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+}
+
+impl<'info> GetLaunchSummary<'info> {
+    pub fn summarize(&self) -> Result<LaunchSummary> {
+        let launch = &self.token_launch;
+        let curve = &self.bonding_curve;
+        
+        let spot_price = BondingCurveCalculator::get_spot_price_lite(
+            curve.curve_type,
+            curve.tokens_sold,
+            curve.sol_price_usd,
+        )?;
+        
+        Ok(LaunchSummary {
+            mint: launch.mint,
+            creator: launch.creator,
+            name: launch.name.clone(),
+            symbol: launch.symbol.clone(),
+            metadata_uri: launch.metadata_uri.clone(),
+            total_supply: launch.total_supply,
+            circulating_supply: launch.circulating_supply,
+            tokens_sold: curve.tokens_sold,
+            tokens_until_graduation: curve.tokens_until_graduation(),
+            spot_price,
+            sol_reserve: curve.sol_reserve,
+            usd_raised: u64::try_from(curve.usd_raised()).map_err(|_| LaunchpadError::MathOverflow)?,
+            graduation_progress_bps: curve.graduation_progress_bps() as u16,
+            holder_count: curve.holder_count,
+            unique_buyers: curve.unique_buyers,
+            trade_count: curve.trade_count,
+            total_volume: curve.total_volume,
+            is_active: launch.is_trading_active(Clock::get()?.unix_timestamp),
+            is_graduated: curve.is_graduated,
+        })
+    }
+}
+
+/// Compare the SOL vault balance with what the curve says it should hold (view function)
+#[derive(Accounts)]
+pub struct GetVaultReconciliation<'info> {
+    pub token_launch: Account<'info, TokenLaunch>,
+    
+    #[account(
+        constraint = bonding_curve.token_launch == token_launch.key() @ LaunchpadError::BondingCurveNotFound
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
+    /// SOL vault for the bonding curve, only its lamports are read
+    #[account(
+        seeds = [
+            b"sol_vault",
+            bonding_curve.key().as_ref()
+        ],
+        bump = bonding_curve.vault_bump(&bonding_curve.key())
+    )]
+    pub sol_vault: SystemAccount<'info>,
+}
+
+impl<'info> GetVaultReconciliation<'info> {
+    pub fn reconcile(&self) -> Result<VaultRecon> {
+        let curve = &self.bonding_curve;
+        let vault_lamports = self.sol_vault.lamports();
+        let rent_exempt_minimum = Rent::get()?.minimum_balance(0);
+        
+        Ok(VaultRecon {
+            vault_lamports,
+            sol_reserve: curve.sol_reserve,
+            accrued_fees: curve.accrued_fees,
+            rent_exempt_minimum,
+            unattributed: curve.unattributed_vault_lamports(vault_lamports, rent_exempt_minimum)?,
+        })
+    }
+}
+
+/// Split the mint supply into circulating, curve and LP holdings (view function)
+#[derive(Accounts)]
+pub struct GetSupplyBreakdown<'info> {
+    pub token_launch: Account<'info, TokenLaunch>,
+    
+    #[account(
+        constraint = bonding_curve.token_launch == token_launch.key() @ LaunchpadError::BondingCurveNotFound
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
+    #[account(
+        constraint = mint.key() == token_launch.mint @ LaunchpadError::TokenMintMismatch
+    )]
+    pub mint: Account<'info, Mint>,
+    
+    #[account(
+        associated_token::mint = token_launch.mint,
+        associated_token::authority = bonding_curve
+    )]
+    pub curve_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        seeds = [
+            b"lp_vault",
+            bonding_curve.key().as_ref()
+        ],
+        bump
+    )]
+    pub lp_vault: Account<'info, TokenAccount>,
+}
+
+impl<'info> GetSupplyBreakdown<'info> {
+    pub fn breakdown(&self) -> Result<SupplyBreakdown> {
+        Ok(SupplyBreakdown {
+            total: self.mint.supply,
+            circulating: self.token_launch.circulating_supply,
+            on_curve: self.curve_token_account.amount,
+            lp_reserved: self.lp_vault.amount,
+            creator_unclaimed: self.bonding_curve.creator_allocation
+                .saturating_sub(self.bonding_curve.creator_allocation_claimed),
+        })
+    }
+}
+
+/// Read the protocol-wide totals (view function)
+#[derive(Accounts)]
+pub struct GetGlobalStats<'info> {
+    #[account(
+        seeds = [b"global_stats"],
+        bump = global_stats.bump
+    )]
+    pub global_stats: Account<'info, GlobalStats>,
+}
+
+impl<'info> GetGlobalStats<'info> {
+    pub fn totals(&self) -> GlobalTotals {
+        self.global_stats.totals()
+    }
+}
+
+/// Check whether the reserve could pay out every holder at once (view function)
+#[derive(Accounts)]
+pub struct GetCurveHealth<'info> {
+    pub token_launch: Account<'info, TokenLaunch>,
+    
+    #[account(
+        constraint = bonding_curve.token_launch == token_launch.key() @ LaunchpadError::BondingCurveNotFound
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+}
+
+impl<'info> GetCurveHealth<'info> {
+    pub fn curve_health(&self) -> Result<CurveHealth> {
+        let curve = &self.bonding_curve;
+        // Selling the whole sold supply back walks the curve from tokens_sold down to 0
+        let liability_lamports = if curve.tokens_sold == 0 {
+            0
+        } else {
+            BondingCurveCalculator::calculate_sell_price(
+                curve.curve_type,
+                curve.tokens_sold,
+                curve.tokens_sold,
+                curve.sol_price_usd,
+            )?
+        };
+        
+        Ok(curve.health(liability_lamports))
+    }
+}
+
+/// Report which graduation conditions are met and which one is blocking (view function)
+#[derive(Accounts)]
+pub struct GetGraduationStatus<'info> {
+    #[account(
+        seeds = [b"launchpad_config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, LaunchpadConfig>,
+    
+    pub token_launch: Account<'info, TokenLaunch>,
+    
+    #[account(
+        constraint = bonding_curve.token_launch == token_launch.key() @ LaunchpadError::BondingCurveNotFound
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+}
+
+impl<'info> GetGraduationStatus<'info> {
+    pub fn status(&self) -> Result<GraduationStatus> {
+        Ok(self.bonding_curve.graduation_status(
+            self.config.min_graduation_sol_lamports,
+            self.config.min_holders_for_graduation,
+        ))
+    }
+}
+
+/// Page through a launch's most recent trades without an indexer (view function)
+#[derive(Accounts)]
+pub struct GetRecentTrades<'info> {
+    pub token_launch: Account<'info, TokenLaunch>,
+    
+    #[account(
+        seeds = [
+            b"trade_history",
+            token_launch.key().as_ref()
+        ],
+        bump = trade_history.bump
+    )]
+    pub trade_history: Box<Account<'info, TradeHistory>>,
+}
+
+impl<'info> GetRecentTrades<'info> {
+    pub fn recent_trades(&self, offset: u32, limit: u8) -> Result<RecentTrades> {
+        self.trade_history.recent_trades(offset, limit)
+    }
+}
+
+/// Get a position's mark-to-spot return and simple annualized return (view function)
+#[derive(Accounts)]
+pub struct GetPositionReturn<'info> {
+    pub token_launch: Account<'info, TokenLaunch>,
+    
+    #[account(
+        constraint = bonding_curve.token_launch == token_launch.key() @ LaunchpadError::BondingCurveNotFound
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
+    #[account(
+        constraint = user_position.token_launch == token_launch.key() @ LaunchpadError::UserPositionNotFound
+    )]
+    pub user_position: Account<'info, UserPosition>,
+}
+
+impl<'info> GetPositionReturn<'info> {
+    pub fn position_return(&self) -> Result<PositionReturn> {
+        let spot_price = BondingCurveCalculator::get_spot_price_lite(
+            self.bonding_curve.curve_type,
+            self.bonding_curve.tokens_sold,
+            self.bonding_curve.sol_price_usd,
+        )?;
+        self.user_position.position_return(spot_price, Clock::get()?.unix_timestamp)
+    }
+}
+
+/// Total and per-holder breakdown of the positions passed as remaining accounts (view function)
+#[derive(Accounts)]
+pub struct GetAllPositionsForLaunch<'info> {
+    pub token_launch: Account<'info, TokenLaunch>,
+}
+
+impl<'info> GetAllPositionsForLaunch<'info> {
+    pub fn all_positions(&self, position_infos: &'info [AccountInfo<'info>]) -> Result<LaunchPositions> {
+        let holdings = load_holdings(self.token_launch.key(), position_infos)?;
+        LaunchPositions::from_holdings(&holdings)
+    }
+}
+
+/// Read `(user, token_amount)` from UserPosition accounts of a single launch
+fn load_holdings<'info>(
+    token_launch: Pubkey,
+    position_infos: &'info [AccountInfo<'info>],
+) -> Result<Vec<(Pubkey, u64)>> {
+    require!(
+        position_infos.len() <= LaunchPositions::MAX_POSITIONS,
+        LaunchpadError::TooManyPositions
+    );
+    
+    let mut holdings: Vec<(Pubkey, u64)> = Vec::with_capacity(position_infos.len());
+    for info in position_infos {
+        // Checks the owner and discriminator, so only real positions are counted
+        let position = Account::<UserPosition>::try_from(info)?;
+        require!(
+            position.token_launch == token_launch,
+            LaunchpadError::UserPositionNotFound
+        );
+        // A repeated position would inflate its pro-rata share
+        require!(
+            !holdings.iter().any(|(user, _)| *user == position.user),
+            LaunchpadError::DuplicatePosition
+        );
+        holdings.push((position.user, position.token_amount));
+    }
+    
+    Ok(holdings)
+}
+
+/// Emit the balances of the positions passed as remaining accounts under a new snapshot id
+/// Callable by the launchpad admin or the launch creator
+#[derive(Accounts)]
+pub struct SnapshotPositions<'info> {
+    pub token_launch: Account<'info, TokenLaunch>,
+    
+    #[account(
+        mut,
+        seeds = [
+            b"bonding_curve",
+            token_launch.key().as_ref()
+        ],
+        bump = bonding_curve.bump
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
+    #[account(
+        seeds = [b"launchpad_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, LaunchpadConfig>,
+    
+    #[account(
+        constraint = authority.key() == config.authority
+            || authority.key() == token_launch.creator @ LaunchpadError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+impl<'info> SnapshotPositions<'info> {
+    pub fn execute(&mut self, position_infos: &'info [AccountInfo<'info>]) -> Result<u64> {
+        let holdings = load_holdings(self.token_launch.key(), position_infos)?;
+        
+        // Every position in this call shares the id, so indexers can group them
+        let snapshot_id = self.bonding_curve.snapshot_id
+            .checked_add(1)
+            .ok_or(LaunchpadError::MathOverflow)?;
+        self.bonding_curve.snapshot_id = snapshot_id;
+        
+        let timestamp = Clock::get()?.unix_timestamp;
+        for (user, token_amount) in holdings {
+            emit!(PositionSnapshot {
+                user,
+                token_amount,
+                launch: self.token_launch.key(),
+                snapshot_id,
+                timestamp,
+            });
+        }
+        
+        Ok(snapshot_id)
+    }
+}
+
+/// Get the SOL the curve will hold at graduation at the stored SOL price (view function)
+#[derive(Accounts)]
+pub struct GetExpectedGraduationSol<'info> {
+    pub token_launch: Account<'info, TokenLaunch>,
+    
+    #[account(
+        constraint = bonding_curve.token_launch == token_launch.key() @ LaunchpadError::BondingCurveNotFound
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+}
+
+impl<'info> GetExpectedGraduationSol<'info> {
+    pub fn expected_graduation_sol(&self) -> Result<u64> {
+        BondingCurveCalculator::calculate_expected_graduation_sol(
+            self.bonding_curve.curve_type,
+            self.bonding_curve.curve_supply(),
+            self.bonding_curve.sol_price_usd,
+        )
+    }
+}
+
+/// Where a graduated launch's liquidity went, for redirecting traders (view function)
+#[derive(Accounts)]
+pub struct GetPoolAddress<'info> {
+    pub token_launch: Account<'info, TokenLaunch>,
+    
+    #[account(
+        constraint = bonding_curve.token_launch == token_launch.key() @ LaunchpadError::BondingCurveNotFound
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+}
+
+impl<'info> GetPoolAddress<'info> {
+    pub fn pool_address(&self) -> Result<Option<Pubkey>> {
+        require!(self.bonding_curve.is_graduated, LaunchpadError::NotGraduated);
+        Ok(self.bonding_curve.pool_address)
+    }
+}
+
+/// Verify a mint was launched by this program and return its creator (view function)
+/// Accounts are unchecked so a foreign mint fails with TokenLaunchNotFound
+/// instead of an account deserialization error
+#[derive(Accounts)]
+pub struct VerifyLaunch<'info> {
+    /// CHECK: Any mint; verification fails cleanly if it has no launch
+    pub mint: UncheckedAccount<'info>,
+    
+    /// CHECK: TokenLaunch PDA for the mint, may not exist
+    #[account(
+        seeds = [
+            b"token_launch",
+            mint.key().as_ref()
+        ],
+        bump
+    )]
+    pub token_launch: UncheckedAccount<'info>,
+    
+    /// CHECK: BondingCurve PDA for the launch, may not exist
+    #[account(
+        seeds = [
+            b"bonding_curve",
+            token_launch.key().as_ref()
+        ],
+        bump
+    )]
+    pub bonding_curve: UncheckedAccount<'info>,
+}
+
+impl<'info> VerifyLaunch<'info> {
+    pub fn verify(&self) -> Result<VerifiedLaunch> {
+        require!(
+            self.token_launch.owner == &crate::ID,
+            LaunchpadError::TokenLaunchNotFound
+        );
+        let token_launch = TokenLaunch::try_deserialize(&mut &self.token_launch.try_borrow_data()?[..])
+            .map_err(|_| LaunchpadError::TokenLaunchNotFound)?;
+        require!(
+            token_launch.mint == self.mint.key(),
+            LaunchpadError::TokenMintMismatch
+        );
+        
+        require!(
+            self.bonding_curve.owner == &crate::ID,
+            LaunchpadError::BondingCurveNotFound
+        );
+        let bonding_curve = BondingCurve::try_deserialize(&mut &self.bonding_curve.try_borrow_data()?[..])
+            .map_err(|_| LaunchpadError::BondingCurveNotFound)?;
+        
+        Ok(VerifiedLaunch {
+            creator: token_launch.creator,
+            is_active: token_launch.is_trading_active(Clock::get()?.unix_timestamp),
+            is_graduated: bonding_curve.is_graduated,
+            bonding_curve: self.bonding_curve.key(),
         })
     }
 }
@@ -1,11 +1,11 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program, BN } from "@coral-xyz/anchor";
 import { NotmarketSolana } from "../target/types/notmarket_solana";
-import { 
-  PublicKey, 
-  Keypair, 
+import {
+  PublicKey,
+  Keypair,
   SystemProgram,
-  LAMPORTS_PER_SOL 
+  LAMPORTS_PER_SOL,
 } from "@solana/web3.js";
 import {
   TOKEN_PROGRAM_ID,
@@ -13,6 +13,7 @@ import {
   getAssociatedTokenAddressSync,
 } from "@solana/spl-token";
 import { assert } from "chai";
+import { TestLaunch, airdrop, setManualPriceAllowed, MILLION } from "./helpers";
 
 describe("Bonding Curve - Large Scale Trades", () => {
   const provider = anchor.AnchorProvider.env();
@@ -99,6 +100,9 @@ describe("Bonding Curve - Large Scale Trades", () => {
       console.log(`   Fee Recipient: ${feeRecipient.toString()}`);
     }
 
+    // No Pyth feed on the test validator: trades price off the stored SOL price
+    await setManualPriceAllowed(program, true);
+
     // Airdrop reasonable amounts of SOL to whales
     const whaleAirdropAmount = 50_000 * LAMPORTS_PER_SOL; // 50k SOL each
     
@@ -211,6 +215,7 @@ describe("Bonding Curve - Large Scale Trades", () => {
         const quote = await program.methods
           .getBuyQuote(buyAmount)
           .accounts({
+            config: configPda,
             bondingCurve: bondingCurvePda,
             tokenLaunch: tokenLaunchPda,
           })
@@ -242,7 +247,7 @@ describe("Bonding Curve - Large Scale Trades", () => {
       // Execute the trade
       console.log("\n💸 Executing 10M token purchase...");
       const txSignature = await program.methods
-        .buyTokens(buyAmount, maxSolCost)
+        .buyTokens(buyAmount, maxSolCost, new BN(0))
         .accounts({
           config: configPda,
           tokenLaunch: tokenLaunchPda,
@@ -311,6 +316,7 @@ describe("Bonding Curve - Large Scale Trades", () => {
         const quote = await program.methods
           .getBuyQuote(buyAmount)
           .accounts({
+            config: configPda,
             bondingCurve: bondingCurvePda,
             tokenLaunch: tokenLaunchPda,
           })
@@ -343,7 +349,7 @@ describe("Bonding Curve - Large Scale Trades", () => {
       // Execute the trade
       console.log("\n💸 Executing 100M token purchase...");
       const txSignature = await program.methods
-        .buyTokens(buyAmount, maxSolCost)
+        .buyTokens(buyAmount, maxSolCost, new BN(0))
         .accounts({
           config: configPda,
           tokenLaunch: tokenLaunchPda,
@@ -412,6 +418,7 @@ describe("Bonding Curve - Large Scale Trades", () => {
         const quote = await program.methods
           .getBuyQuote(buyAmount)
           .accounts({
+            config: configPda,
             bondingCurve: bondingCurvePda,
             tokenLaunch: tokenLaunchPda,
           })
@@ -444,7 +451,7 @@ describe("Bonding Curve - Large Scale Trades", () => {
       // Execute the trade
       console.log("\n💸 Executing 200M token purchase...");
       const txSignature = await program.methods
-        .buyTokens(buyAmount, maxSolCost)
+        .buyTokens(buyAmount, maxSolCost, new BN(0))
         .accounts({
           config: configPda,
           tokenLaunch: tokenLaunchPda,
@@ -499,6 +506,171 @@ describe("Bonding Curve - Large Scale Trades", () => {
       assert.ok(bondingCurveAfter.tokensSold.eq(expectedTotal), "Should have sold 310M tokens total");
       assert.ok(Number(whale3TokenBalance.value.amount) >= Number(TWO_HUNDRED_MILLION.toString()), "Whale should have received tokens");
     });
+
+    /**
+     * Test the sigmoid price curve against the default exponential curve
+     */
+    describe("Sigmoid Curve", () => {
+      const trader = Keypair.generate();
+
+      const CURVE_SUPPLY = MILLION.muln(800);
+      const TAIL_START = MILLION.muln(700);
+
+      let exponential: TestLaunch;
+      let sigmoid: TestLaunch;
+
+      const create = async (tokenName: string, symbol: string, curveType: object) => {
+        const launch = new TestLaunch(program, authority.publicKey, tokenName);
+        await launch.create({
+          symbol,
+          metadataUri: "https://example.com/sigmoid.json",
+          description: "Launch used to test the sigmoid curve",
+          curveType,
+        });
+        return launch;
+      };
+
+      const buy = (launch: TestLaunch, amount: BN) =>
+        launch.buy(trader, amount, { maxSolCost: new BN(1_000 * LAMPORTS_PER_SOL) });
+
+      const quote = (launch: TestLaunch, amount: BN) =>
+        program.methods
+          .getBuyQuote(amount, { currentSpot: {} })
+          .accounts({ config: launch.config, tokenLaunch: launch.tokenLaunch, bondingCurve: launch.bondingCurve })
+          .view();
+
+      const priceAt = (launch: TestLaunch, tokensSold: BN) =>
+        program.methods
+          .getPriceAtSupply(tokensSold)
+          .accounts({ tokenLaunch: launch.tokenLaunch, bondingCurve: launch.bondingCurve })
+          .view();
+
+      before(async () => {
+        await airdrop(provider, trader.publicKey, 1_000);
+
+        exponential = await create("Exponential Curve Token", "EXPC", { exponential: {} });
+        sigmoid = await create("Sigmoid Curve Token", "SIGC", { sigmoid: {} });
+      });
+
+      it("Stores the curve type chosen at creation", async () => {
+        const exponentialCurve = await program.account.bondingCurve.fetch(exponential.bondingCurve);
+        const sigmoidCurve = await program.account.bondingCurve.fetch(sigmoid.bondingCurve);
+        assert.deepEqual(exponentialCurve.curveType, { exponential: {} });
+        assert.deepEqual(sigmoidCurve.curveType, { sigmoid: {} });
+      });
+
+      it("Spans the same start and end price as the exponential curve", async () => {
+        for (const supply of [new BN(0), CURVE_SUPPLY]) {
+          const exponentialPrice = await priceAt(exponential, supply);
+          const sigmoidPrice = await priceAt(sigmoid, supply);
+          assert.isAtMost(
+            Math.abs(exponentialPrice.toNumber() - sigmoidPrice.toNumber()),
+            1,
+            `Curves should agree at ${supply.toString()} sold`
+          );
+        }
+
+        // Flat at the end: almost no price run-up over the last 100M tokens
+        const sigmoidRunUp =
+          (await priceAt(sigmoid, CURVE_SUPPLY)).toNumber() / (await priceAt(sigmoid, TAIL_START)).toNumber();
+        const exponentialRunUp =
+          (await priceAt(exponential, CURVE_SUPPLY)).toNumber() / (await priceAt(exponential, TAIL_START)).toNumber();
+        assert.isBelow(sigmoidRunUp, 1.05);
+        assert.isAbove(exponentialRunUp, 1.3);
+      });
+
+      it("Compares the last 100M tokens on each curve", async () => {
+        // Walk both curves to 700M sold, then quote the final 100M before graduation
+        await buy(exponential, TAIL_START);
+        await buy(sigmoid, TAIL_START);
+
+        const tail = MILLION.muln(100);
+        const exponentialQuote = await quote(exponential, tail);
+        const sigmoidQuote = await quote(sigmoid, tail);
+
+        // Final buyers on the sigmoid pay close to the spot they see...
+        assert.isBelow(sigmoidQuote.slippage, 200);
+        assert.isAbove(exponentialQuote.slippage, 1_500);
+        // ...but with both curves ending at the same price, the flat tail sits higher
+        assert.ok(sigmoidQuote.cost.gt(exponentialQuote.cost));
+      });
+
+      it("Graduates a sigmoid launch once the curve sells out", async () => {
+        await buy(sigmoid, MILLION.muln(100));
+
+        const curve = await program.account.bondingCurve.fetch(sigmoid.bondingCurve);
+        assert.ok(curve.isGraduated, "Sigmoid curve should graduate once sold out");
+        assert.equal(curve.tokensSold.toString(), CURVE_SUPPLY.toString());
+      });
+    });
+
+    /**
+     * Compute-budget regression harness
+     *
+     * Measures compute units consumed by the heaviest user-facing instructions and
+     * asserts they stay under the 200k default with headroom, so users who don't
+     * request a larger compute budget keep landing transactions.
+     */
+    describe("Compute Budget", () => {
+      const trader = Keypair.generate();
+
+      // Default per-instruction limit and the ceiling we hold each instruction to
+      const DEFAULT_COMPUTE_UNITS = 200_000;
+      const COMPUTE_UNIT_CEILING = 160_000; // 20% headroom under the default
+
+      const launch = new TestLaunch(program, authority.publicKey, "Compute Token");
+
+      const unitsConsumed = async (signature: string) => {
+        const tx = await provider.connection.getTransaction(signature, {
+          commitment: "confirmed",
+          maxSupportedTransactionVersion: 0,
+        });
+        assert.ok(tx?.meta?.computeUnitsConsumed, "Transaction should report compute units");
+        return tx.meta.computeUnitsConsumed;
+      };
+
+      const buy = (wallet: Keypair, amount: BN) =>
+        launch.buyMethod(wallet, amount).rpc({ commitment: "confirmed" });
+
+      const sell = (wallet: Keypair, amount: BN) =>
+        launch.sellMethod(wallet, amount).rpc({ commitment: "confirmed" });
+
+      const assertUnderCeiling = (instruction: string, units: number) => {
+        assert.isBelow(
+          units,
+          COMPUTE_UNIT_CEILING,
+          `${instruction} consumed ${units} CU, too close to the ${DEFAULT_COMPUTE_UNITS} default`
+        );
+      };
+
+      it("create_token_launch stays under the compute ceiling", async () => {
+        const signature = await launch
+          .createMethod({
+            symbol: "CU",
+            metadataUri: "https://example.com/compute.json",
+            description: "Launch used to measure compute usage",
+          })
+          .rpc({ commitment: "confirmed" });
+        await launch.load();
+
+        assertUnderCeiling("create_token_launch", await unitsConsumed(signature));
+      });
+
+      it("buy_tokens stays under the compute ceiling", async () => {
+        // First buy initializes the position and token account, the heaviest path
+        assertUnderCeiling("buy_tokens (first)", await unitsConsumed(await buy(trader, MILLION.mul(new BN(10)))));
+
+        // Larger buy further up the curve exercises the exp/ln math with bigger inputs
+        assertUnderCeiling("buy_tokens (large)", await unitsConsumed(await buy(trader, MILLION.mul(new BN(100)))));
+      });
+
+      it("sell_tokens stays under the compute ceiling", async () => {
+        assertUnderCeiling("sell_tokens (part of position)", await unitsConsumed(await sell(trader, MILLION.mul(new BN(50)))));
+
+        // Selling the rest closes out the position and decrements holder_count
+        assertUnderCeiling("sell_tokens (full)", await unitsConsumed(await sell(trader, MILLION.mul(new BN(60)))));
+      });
+    });
   });
 
   describe("Final Summary", () => {
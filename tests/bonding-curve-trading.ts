@@ -11,8 +11,16 @@ import {
   TOKEN_PROGRAM_ID,
   ASSOCIATED_TOKEN_PROGRAM_ID,
   getAssociatedTokenAddressSync,
+  getAccount,
+  createMint,
+  createAssociatedTokenAccount,
+  mintTo,
+  getMint,
+  transfer,
+  getOrCreateAssociatedTokenAccount,
 } from "@solana/spl-token";
 import { assert } from "chai";
+import { TestLaunch, airdrop, setManualPriceAllowed, MILLION, SOL_USD_PRICE_FEED, configPdaFor } from "./helpers";
 
 describe("Bonding Curve Trading", () => {
   const provider = anchor.AnchorProvider.env();
@@ -79,6 +87,9 @@ describe("Bonding Curve Trading", () => {
       console.log("✅ Config initialized with authority as fee recipient");
     }
 
+    // No Pyth feed on the test validator: trades price off the stored SOL price
+    await setManualPriceAllowed(program, true);
+
     // Fund accounts
     console.log("\n💰 Funding test accounts...");
     const fundAmount = 10000 * LAMPORTS_PER_SOL;
@@ -185,6 +196,7 @@ describe("Bonding Curve Trading", () => {
       const quote = await program.methods
         .getBuyQuote(TRADE_1)
         .accounts({
+          config: configPda,
           bondingCurve: bondingCurvePda,
           tokenLaunch: tokenLaunchPda,
         })
@@ -210,6 +222,7 @@ describe("Bonding Curve Trading", () => {
       const quote = await program.methods
         .getBuyQuote(TRADE_2)
         .accounts({
+          config: configPda,
           bondingCurve: bondingCurvePda,
           tokenLaunch: tokenLaunchPda,
         })
@@ -225,6 +238,694 @@ describe("Bonding Curve Trading", () => {
       console.log("   Avg Price/Token: $" + (avgPricePerToken * 150).toFixed(10));
       console.log("   Slippage:", quote.slippage, "bps");
     });
+
+    /**
+     * Test that a buy quote's total cost matches what the buy actually charges
+     */
+    describe("Buy Quote Total Cost", () => {
+      const trader = Keypair.generate();
+
+      const launch = new TestLaunch(program, authority.publicKey, "Quote Fee Token");
+
+      // No solPriceFeed, so the buy prices off the same stored SOL price as the quote
+      const buy = (wallet: Keypair, amount: BN, maxSolCost: BN) =>
+        launch.buy(wallet, amount, { maxSolCost, accounts: { solPriceFeed: null } });
+
+      before(async () => {
+        await airdrop(provider, trader.publicKey, 20);
+
+        await launch.create({
+          symbol: "QFEE",
+          metadataUri: "https://example.com/quote-fee.json",
+          description: "Launch used to test fee-inclusive buy quotes",
+        });
+      });
+
+      const quoteFor = (amount: BN) =>
+        program.methods
+          .getBuyQuote(amount, { currentSpot: {} })
+          .accounts({ config: launch.config, tokenLaunch: launch.tokenLaunch, bondingCurve: launch.bondingCurve })
+          .view();
+
+      it("Quotes the platform fee from the live config", async () => {
+        const config = await program.account.launchpadConfig.fetch(launch.config);
+        const quote = await quoteFor(MILLION);
+
+        const expectedFee = quote.cost.muln(config.platformFeeBps).divn(10_000);
+        assert.equal(quote.fee.toString(), expectedFee.toString());
+        assert.equal(quote.totalCost.toString(), quote.cost.add(quote.fee).toString());
+      });
+
+      it("Rejects a max_sol_cost one lamport below the quoted total", async () => {
+        const quote = await quoteFor(MILLION);
+
+        try {
+          await buy(trader, MILLION, quote.totalCost.subn(1));
+          assert.fail("Buy capped below the quoted total should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "SlippageExceeded");
+        }
+      });
+
+      it("Charges exactly the quoted total when it is used as max_sol_cost", async () => {
+        const quote = await quoteFor(MILLION);
+
+        let purchase = null;
+        const listener = program.addEventListener("tokensPurchased", (event) => {
+          purchase = event;
+        });
+        await buy(trader, MILLION, quote.totalCost);
+        await new Promise((resolve) => setTimeout(resolve, 1000));
+        await program.removeEventListener(listener);
+
+        assert.ok(purchase, "TokensPurchased should be emitted");
+        assert.equal(purchase.solAmount.toString(), quote.cost.toString());
+        assert.equal(purchase.platformFee.toString(), quote.fee.toString());
+        assert.equal(
+          purchase.solAmount.add(purchase.platformFee).toString(),
+          quote.totalCost.toString(),
+          "The buy should charge exactly the quoted total"
+        );
+      });
+
+      it("Reports the estimated fee at a 2.5% platform fee", async () => {
+        const setPlatformFee = (bps: number) =>
+          program.methods
+            .updatePlatformFee(bps)
+            .accounts({ config: launch.config, authority: authority.publicKey })
+            .rpc();
+
+        const { platformFeeBps: originalFeeBps } = await program.account.launchpadConfig.fetch(launch.config);
+        await setPlatformFee(250);
+        try {
+          const quote = await quoteFor(MILLION);
+          const simulated = await program.methods
+            .getBuyQuote(MILLION, { currentSpot: {} })
+            .accounts({ config: launch.config, tokenLaunch: launch.tokenLaunch, bondingCurve: launch.bondingCurve })
+            .simulate();
+          const requested = simulated.events.find((e) => e.name === "priceQuoteRequested");
+
+          const expectedFee = quote.cost.muln(250).divn(10_000);
+          assert.equal(quote.fee.toString(), expectedFee.toString());
+          assert.ok(requested, "PriceQuoteRequested should be emitted");
+          assert.equal(requested.data.estimatedFee.toString(), expectedFee.toString());
+          assert.equal(requested.data.estimatedCost.toString(), quote.cost.toString());
+        } finally {
+          await setPlatformFee(originalFeeBps);
+        }
+      });
+
+      it("Rejects platform fee updates above 10% or from non-admin signers", async () => {
+        try {
+          await program.methods
+            .updatePlatformFee(1_001)
+            .accounts({ config: launch.config, authority: authority.publicKey })
+            .rpc();
+          assert.fail("Platform fee above 10% should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "InvalidFee");
+        }
+
+        try {
+          await program.methods
+            .updatePlatformFee(250)
+            .accounts({ config: launch.config, authority: trader.publicKey })
+            .signers([trader])
+            .rpc();
+          assert.fail("Non-admin update should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "Unauthorized");
+        }
+      });
+    });
+
+    /**
+     * Test that trades and refreshes only read the Pyth feed registered for a launch.
+     * Requires the Pyth sponsored price update accounts (e.g. run against devnet).
+     */
+    describe("Price Feed", () => {
+      // A valid PriceUpdateV2 account for the wrong asset (ETH/USD)
+      const ETH_USD_PRICE_FEED = new PublicKey("42amVS4KgzR9rA28tkVYqVXjq9Qa8dcZQMbH5EYFX6XC");
+
+
+      const launch = new TestLaunch(program, authority.publicKey, "Feed Token");
+
+      before(async () => {
+        await launch.create({
+          symbol: "FEED",
+          metadataUri: "https://example.com/feed.json",
+          description: "Launch used to test the expected price feed",
+        });
+      });
+
+      const refreshWith = (feed: PublicKey) =>
+        program.methods
+          .refreshPrice()
+          .accounts({
+            tokenLaunch: launch.tokenLaunch,
+            bondingCurve: launch.bondingCurve,
+            solPriceFeed: feed,
+          })
+          .rpc();
+
+      const updatePriceFeed = (feed: PublicKey, authority: anchor.Wallet | Keypair) => {
+        const builder = program.methods
+          .updatePriceFeed(feed)
+          .accounts({
+            config: launch.config,
+            tokenLaunch: launch.tokenLaunch,
+            bondingCurve: launch.bondingCurve,
+            authority: authority.publicKey,
+          });
+        return authority instanceof Keypair ? builder.signers([authority]).rpc() : builder.rpc();
+      };
+
+      it("Stores the expected feed at creation", async () => {
+        const bondingCurve = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        assert.equal(bondingCurve.expectedPriceFeed.toString(), SOL_USD_PRICE_FEED.toString());
+      });
+
+      it("Rejects a different price feed account", async () => {
+        try {
+          await refreshWith(ETH_USD_PRICE_FEED);
+          assert.fail("Refresh with the wrong feed should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "InvalidPriceFeed");
+        }
+
+        await refreshWith(SOL_USD_PRICE_FEED);
+      });
+
+      it("Rejects price feed updates from non-admin signers", async () => {
+        const intruder = Keypair.generate();
+        try {
+          await updatePriceFeed(ETH_USD_PRICE_FEED, intruder);
+          assert.fail("Non-admin price feed update should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "Unauthorized");
+        }
+      });
+
+      it("Admin can migrate the launch to a new feed", async () => {
+        await updatePriceFeed(ETH_USD_PRICE_FEED, authority);
+
+        const bondingCurve = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        assert.equal(bondingCurve.expectedPriceFeed.toString(), ETH_USD_PRICE_FEED.toString());
+
+        // The old feed is now the wrong one
+        try {
+          await refreshWith(SOL_USD_PRICE_FEED);
+          assert.fail("Refresh with the old feed should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "InvalidPriceFeed");
+        }
+
+        await updatePriceFeed(SOL_USD_PRICE_FEED, authority);
+      });
+    });
+
+    /**
+     * Test trading with no Pyth feed account at all, priced off the stored backup
+     */
+    describe("Price Feed Fallback", () => {
+      const trader = Keypair.generate();
+
+      const solPriceUsd = new BN(150_00000000); // $150
+
+      const launch = new TestLaunch(program, authority.publicKey, "Fallback Token");
+
+      // No solPriceFeed: the Pyth account is treated as entirely absent
+      const buy = (wallet: Keypair, amount: BN) =>
+        launch.buy(wallet, amount, { accounts: { solPriceFeed: null } });
+
+      const sell = (wallet: Keypair, amount: BN) =>
+        launch.sell(wallet, amount, { accounts: { solPriceFeed: null } });
+
+      before(async () => {
+        await airdrop(provider, trader.publicKey, 20);
+
+        await launch.create({
+          symbol: "FALL",
+          metadataUri: "https://example.com/fallback.json",
+          description: "Launch used to test trading without a price feed",
+          solPriceUsd,
+        });
+
+        // The suite enables manual pricing up front; this block covers the gated default
+        await setManualPriceAllowed(program, false);
+      });
+
+      after(async () => {
+        await setManualPriceAllowed(program, true);
+      });
+
+      it("Rejects trades without a feed while manual pricing is disabled", async () => {
+        const config = await program.account.launchpadConfig.fetch(launch.config);
+        assert.isFalse(config.manualPriceAllowed, "Manual pricing should be off");
+
+        try {
+          await buy(trader, MILLION);
+          assert.fail("Buy without a price feed should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "PriceFeedMissing");
+        }
+      });
+
+      it("Rejects toggling manual pricing from non-admin signers", async () => {
+        try {
+          await program.methods
+            .updateManualPriceAllowed(true)
+            .accounts({ config: launch.config, authority: trader.publicKey })
+            .signers([trader])
+            .rpc();
+          assert.fail("Non-admin toggle should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "Unauthorized");
+        }
+      });
+
+      it("Buys and sells at the backup price once manual pricing is allowed", async () => {
+        await setManualPriceAllowed(program, true);
+
+        const quote = await program.methods
+          .getBuyQuote(MILLION, { currentSpot: {} })
+          .accounts({ config: launch.config, tokenLaunch: launch.tokenLaunch, bondingCurve: launch.bondingCurve })
+          .view();
+
+        const vaultBefore = await provider.connection.getBalance(launch.solVault);
+        await buy(trader, MILLION);
+        const vaultAfter = await provider.connection.getBalance(launch.solVault);
+
+        // The curve was priced off the stored backup, which is left untouched
+        const curve = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        assert.equal(curve.solPriceUsd.toString(), solPriceUsd.toString());
+        assert.equal(curve.solReserve.toString(), quote.cost.toString());
+        assert.isAtLeast(vaultAfter - vaultBefore, quote.cost.toNumber());
+
+        const tokens = await getAccount(
+          provider.connection,
+          launch.tokenAccountOf(trader.publicKey)
+        );
+        assert.equal(tokens.amount.toString(), MILLION.toString());
+
+        await sell(trader, MILLION.divn(2));
+        const position = await program.account.userPosition.fetch(launch.positionOf(trader.publicKey));
+        assert.equal(position.tokenAmount.toString(), MILLION.divn(2).toString());
+      });
+    });
+
+    /**
+     * Test refreshing the stored SOL/USD backup price from Pyth without a trade.
+     * Requires a fresh Pyth SOL/USD PriceUpdateV2 account (e.g. run against devnet).
+     */
+    describe("Price Refresh", () => {
+      const stalePriceUsd = new BN(1_00000000); // $1, deliberately far from market
+
+      const launch = new TestLaunch(program, authority.publicKey, "Refresh Token");
+
+      before(async () => {
+        await launch.create({
+          symbol: "RFSH",
+          metadataUri: "https://example.com/refresh.json",
+          description: "Launch used to test price refreshes",
+          solPriceUsd: stalePriceUsd,
+        });
+      });
+
+      it("Refreshes the stored price from Pyth without a trade", async () => {
+        const before = await program.account.bondingCurve.fetch(launch.bondingCurve);
+
+        let refreshedEvent = null;
+        const listener = program.addEventListener("solPriceUpdated", (event) => {
+          if (event.launch.equals(launch.tokenLaunch)) {
+            refreshedEvent = event;
+          }
+        });
+
+        await program.methods
+          .refreshPrice()
+          .accounts({
+            tokenLaunch: launch.tokenLaunch,
+            bondingCurve: launch.bondingCurve,
+            solPriceFeed: SOL_USD_PRICE_FEED,
+          })
+          .rpc();
+
+        await new Promise((resolve) => setTimeout(resolve, 1000));
+        await program.removeEventListener(listener);
+
+        const after = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        assert.notEqual(
+          after.solPriceUsd.toString(),
+          before.solPriceUsd.toString(),
+          "Stored price should change"
+        );
+        assert.ok(refreshedEvent, "SolPriceUpdated should be emitted");
+        assert.deepEqual(refreshedEvent.source, { pyth: {} });
+        assert.equal(refreshedEvent.oldPrice.toString(), before.solPriceUsd.toString());
+        assert.equal(refreshedEvent.newPrice.toString(), after.solPriceUsd.toString());
+      });
+    });
+
+    /**
+     * Test the admin-configurable Pyth staleness window.
+     * Requires a Pyth SOL/USD PriceUpdateV2 account updated within the last hour (e.g. run against devnet).
+     */
+    describe("Price Staleness", () => {
+      const MIN_STALENESS_SECONDS = 5;
+      const MAX_STALENESS_SECONDS = 3_600;
+      const DEFAULT_STALENESS_SECONDS = 60;
+
+      const trader = Keypair.generate();
+
+      const backupPriceUsd = new BN(1_00000000); // $1, deliberately far from market
+
+      const launch = new TestLaunch(program, authority.publicKey, "Staleness Token");
+
+      const buy = (wallet: Keypair, amount: BN) =>
+        launch.buy(wallet, amount, { accounts: { solPriceFeed: SOL_USD_PRICE_FEED } });
+
+      const setStaleness = (seconds: number) =>
+        program.methods
+          .updateMaxPriceStaleness(seconds)
+          .accounts({ config: launch.config, authority: authority.publicKey })
+          .rpc();
+
+      // Age of the Pyth update: publish_time follows the write authority, the
+      // verification level (2 bytes if Partial, 1 if Full), feed id, price, conf and exponent
+      const feedAgeSeconds = async () => {
+        const feed = await provider.connection.getAccountInfo(SOL_USD_PRICE_FEED);
+        const offset = 8 + 32 + (feed.data[40] === 0 ? 2 : 1) + 32 + 8 + 8 + 4;
+        const publishTime = Number(feed.data.readBigInt64LE(offset));
+        const now = await provider.connection.getBlockTime(await provider.connection.getSlot());
+        return now - publishTime;
+      };
+
+      before(async () => {
+        await airdrop(provider, trader.publicKey, 20);
+
+        await launch.create({
+          symbol: "STAL",
+          metadataUri: "https://example.com/staleness.json",
+          description: "Launch used to test the price staleness window",
+          solPriceUsd: backupPriceUsd,
+        });
+      });
+
+      after(async () => {
+        await setStaleness(DEFAULT_STALENESS_SECONDS);
+      });
+
+      it("Rejects staleness windows outside the accepted range", async () => {
+        for (const seconds of [0, MIN_STALENESS_SECONDS - 1, MAX_STALENESS_SECONDS + 1]) {
+          try {
+            await setStaleness(seconds);
+            assert.fail(`A ${seconds}s staleness window should be rejected`);
+          } catch (error) {
+            assert.include(error.toString(), "InvalidPriceStaleness");
+          }
+        }
+      });
+
+      it("Rejects staleness updates from non-admin signers", async () => {
+        try {
+          await program.methods
+            .updateMaxPriceStaleness(MAX_STALENESS_SECONDS)
+            .accounts({ config: launch.config, authority: trader.publicKey })
+            .signers([trader])
+            .rpc();
+          assert.fail("Non-admin update should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "Unauthorized");
+        }
+      });
+
+      it("Prices off the backup when the feed is older than a tight window", async function () {
+        await setStaleness(MIN_STALENESS_SECONDS);
+        const config = await program.account.launchpadConfig.fetch(launch.config);
+        assert.equal(config.maxPriceStalenessSeconds, MIN_STALENESS_SECONDS);
+
+        const age = await feedAgeSeconds();
+        if (age <= MIN_STALENESS_SECONDS) {
+          this.skip();
+        }
+
+        await buy(trader, MILLION);
+
+        const curve = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        assert.equal(curve.solPriceUsd.toString(), backupPriceUsd.toString(), "Backup price should be used");
+        assert.equal(curve.solPriceUpdatedAt.toNumber(), 0, "A stale update must not be recorded");
+      });
+
+      it("Records the feed price once the window is widened", async function () {
+        let stalenessEvent = null;
+        const listener = program.addEventListener("maxPriceStalenessUpdated", (event) => {
+          stalenessEvent = event;
+        });
+
+        await setStaleness(MAX_STALENESS_SECONDS);
+
+        await new Promise((resolve) => setTimeout(resolve, 1000));
+        await program.removeEventListener(listener);
+        assert.ok(stalenessEvent, "MaxPriceStalenessUpdated should be emitted");
+        assert.equal(stalenessEvent.newMaxPriceStalenessSeconds, MAX_STALENESS_SECONDS);
+
+        const age = await feedAgeSeconds();
+        if (age > MAX_STALENESS_SECONDS) {
+          this.skip();
+        }
+
+        await buy(trader, MILLION);
+
+        const curve = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        assert.notEqual(curve.solPriceUsd.toString(), backupPriceUsd.toString(), "Feed price should be recorded");
+        assert.isAbove(curve.solPriceUpdatedAt.toNumber(), 0);
+      });
+    });
+
+    /**
+     * Test that every change to a launch's stored SOL/USD price emits SolPriceUpdated with its source.
+     * Requires a Pyth SOL/USD PriceUpdateV2 account updated within the last hour (e.g. run against devnet).
+     */
+    describe("SOL Price Source", () => {
+      const MAX_STALENESS_SECONDS = 3_600;
+      const DEFAULT_STALENESS_SECONDS = 60;
+
+      const trader = Keypair.generate();
+
+      const seedPriceUsd = new BN(1_00000000); // $1, deliberately far from market
+      const fixedPriceUsd = new BN(2_00000000); // $2, still far from market
+
+      const launch = new TestLaunch(program, authority.publicKey, "Price Source Token");
+
+      // Every SolPriceUpdated for this launch, in emission order
+      const priceEvents = [];
+      let listener: number;
+
+      const buy = (wallet: Keypair, amount: BN) =>
+        launch.buy(wallet, amount, { accounts: { solPriceFeed: SOL_USD_PRICE_FEED } });
+
+      const fixPrice = (newPrice: BN) =>
+        program.methods
+          .fixInitialPrice(newPrice)
+          .accounts({
+            tokenLaunch: launch.tokenLaunch,
+            bondingCurve: launch.bondingCurve,
+            creator: authority.publicKey,
+          })
+          .rpc();
+
+      const refreshPrice = () =>
+        program.methods
+          .refreshPrice()
+          .accounts({
+            tokenLaunch: launch.tokenLaunch,
+            bondingCurve: launch.bondingCurve,
+            solPriceFeed: SOL_USD_PRICE_FEED,
+          })
+          .rpc();
+
+      const setStaleness = (seconds: number) =>
+        program.methods
+          .updateMaxPriceStaleness(seconds)
+          .accounts({ config: launch.config, authority: authority.publicKey })
+          .rpc();
+
+      // Waits for event delivery, then returns the events emitted since `count`
+      const eventsSince = async (count: number) => {
+        await new Promise((resolve) => setTimeout(resolve, 1000));
+        return priceEvents.slice(count);
+      };
+
+      before(async () => {
+        await airdrop(provider, trader.publicKey, 20);
+
+        listener = program.addEventListener("solPriceUpdated", (event) => {
+          if (event.launch.equals(launch.tokenLaunch)) {
+            priceEvents.push(event);
+          }
+        });
+
+        await launch.create({
+          symbol: "PSRC",
+          metadataUri: "https://example.com/price-source.json",
+          description: "Launch used to test SOL price source events",
+          solPriceUsd: seedPriceUsd,
+        });
+
+        // Accept any feed update from the last hour so the Pyth paths don't fall back
+        await setStaleness(MAX_STALENESS_SECONDS);
+      });
+
+      after(async () => {
+        await program.removeEventListener(listener);
+        await setStaleness(DEFAULT_STALENESS_SECONDS);
+      });
+
+      it("Reports the creation price as creator-seeded", async () => {
+        const [seeded] = await eventsSince(0);
+        assert.ok(seeded, "SolPriceUpdated should be emitted at creation");
+        assert.deepEqual(seeded.source, { creator: {} });
+        assert.equal(seeded.oldPrice.toString(), "0");
+        assert.equal(seeded.newPrice.toString(), seedPriceUsd.toString());
+      });
+
+      it("Reports a creator correction as manual", async () => {
+        const count = priceEvents.length;
+        await fixPrice(fixedPriceUsd);
+
+        const [fixed] = await eventsSince(count);
+        assert.ok(fixed, "SolPriceUpdated should be emitted by fix_initial_price");
+        assert.deepEqual(fixed.source, { manual: {} });
+        assert.equal(fixed.oldPrice.toString(), seedPriceUsd.toString());
+        assert.equal(fixed.newPrice.toString(), fixedPriceUsd.toString());
+      });
+
+      it("Reports a refresh from the feed as Pyth", async () => {
+        const count = priceEvents.length;
+        await refreshPrice();
+
+        const curve = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        const events = await eventsSince(count);
+        assert.lengthOf(events, 1, "Each price change should be reported once");
+        const [refreshed] = events;
+        assert.deepEqual(refreshed.source, { pyth: {} });
+        assert.equal(refreshed.oldPrice.toString(), fixedPriceUsd.toString());
+        assert.equal(refreshed.newPrice.toString(), curve.solPriceUsd.toString());
+      });
+
+      it("Reports a trade's fresh feed read as Pyth", async () => {
+        // Reset to a creator price so the trade's oracle read changes it again
+        await fixPrice(fixedPriceUsd);
+        const count = (await eventsSince(0)).length;
+
+        await buy(trader, MILLION);
+
+        const curve = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        const [traded] = await eventsSince(count);
+        assert.ok(traded, "SolPriceUpdated should be emitted by the buy");
+        assert.deepEqual(traded.source, { pyth: {} });
+        assert.equal(traded.oldPrice.toString(), fixedPriceUsd.toString());
+        assert.equal(traded.newPrice.toString(), curve.solPriceUsd.toString());
+      });
+    });
+
+    /**
+     * Test a creator correcting a mis-set SOL/USD price before the first trade
+     */
+    describe("Fix Initial Price", () => {
+      const trader = Keypair.generate();
+
+      const solPriceUsd = new BN(15_000_00000000); // $15,000: a 100x scaling mistake
+      const correctPriceUsd = new BN(150_00000000); // $150
+
+      const launch = new TestLaunch(program, authority.publicKey, "Misprice Token");
+
+      // No solPriceFeed: trades price off the stored (possibly fixed) backup
+      const buy = (wallet: Keypair, amount: BN) =>
+        launch.buy(wallet, amount, { accounts: { solPriceFeed: null } });
+
+      const fixPrice = (signer: Keypair | null, newPrice: BN) => {
+        const builder = program.methods
+          .fixInitialPrice(newPrice)
+          .accounts({
+            tokenLaunch: launch.tokenLaunch,
+            bondingCurve: launch.bondingCurve,
+            creator: signer ? signer.publicKey : authority.publicKey,
+          });
+        return signer ? builder.signers([signer]).rpc() : builder.rpc();
+      };
+
+      before(async () => {
+        await airdrop(provider, trader.publicKey, 20);
+
+        await launch.create({
+          symbol: "MISP",
+          metadataUri: "https://example.com/misprice.json",
+          description: "Launch used to test fixing the initial price",
+          solPriceUsd,
+        });
+      });
+
+      it("Rejects price fixes from anyone but the creator", async () => {
+        try {
+          await fixPrice(trader, correctPriceUsd);
+          assert.fail("Non-creator fix should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "Unauthorized");
+        }
+      });
+
+      it("Rejects an out-of-range price", async () => {
+        try {
+          await fixPrice(null, new BN(0));
+          assert.fail("A zero price should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "InvalidPrice");
+        }
+      });
+
+      it("Fixes the price before the first trade", async () => {
+        let fixedEvent = null;
+        const listener = program.addEventListener("initialPriceFixed", (event) => {
+          fixedEvent = event;
+        });
+
+        await fixPrice(null, correctPriceUsd);
+
+        await new Promise((resolve) => setTimeout(resolve, 1000));
+        await program.removeEventListener(listener);
+
+        const curve = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        assert.equal(curve.solPriceUsd.toString(), correctPriceUsd.toString());
+        assert.equal(curve.solPriceUpdatedAt.toNumber(), 0, "A fixed price is still creator-supplied");
+        assert.ok(fixedEvent, "InitialPriceFixed should be emitted");
+        assert.equal(fixedEvent.oldPriceUsd.toString(), solPriceUsd.toString());
+        assert.equal(fixedEvent.newPriceUsd.toString(), correctPriceUsd.toString());
+      });
+
+      it("Locks the price once the curve has traded", async () => {
+
+        // The first buy executes at the corrected price
+        const quote = await program.methods
+          .getBuyQuote(MILLION, { currentSpot: {} })
+          .accounts({ config: launch.config, tokenLaunch: launch.tokenLaunch, bondingCurve: launch.bondingCurve })
+          .view();
+        await buy(trader, MILLION);
+        const curve = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        assert.equal(curve.solReserve.toString(), quote.cost.toString());
+
+        try {
+          await fixPrice(null, solPriceUsd);
+          assert.fail("Fixing the price after a trade should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "TradingStarted");
+        }
+
+        const after = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        assert.equal(after.solPriceUsd.toString(), correctPriceUsd.toString());
+      });
+    });
   });
 
   describe("2.5. Fee Recipient Management", () => {
@@ -311,6 +1012,604 @@ describe("Bonding Curve Trading", () => {
         );
       }
     });
+
+    /**
+     * Test the PlatformFeeCollected event emitted alongside fee transfers
+     */
+    describe("Platform Fee Event", () => {
+      const trader = Keypair.generate();
+
+      const launch = new TestLaunch(program, authority.publicKey, "Fee Event Token");
+
+      before(async () => {
+        await airdrop(provider, trader.publicKey, 100);
+
+        await launch.create({
+          symbol: "FEEV",
+          metadataUri: "https://example.com/fee-event.json",
+          description: "Launch used to test the platform fee event",
+        });
+      });
+
+      // Run a trade and capture the events it emits
+      const captureEvents = async (trade: () => Promise<string>, tradeEventName: "tokensPurchased" | "tokensSold") => {
+        let feeEvent = null;
+        let tradeEvent = null;
+        const feeListener = program.addEventListener("platformFeeCollected", (event) => {
+          feeEvent = event;
+        });
+        const tradeListener = program.addEventListener(tradeEventName, (event) => {
+          tradeEvent = event;
+        });
+
+        const recipientBefore = await provider.connection.getBalance(launch.feeRecipient);
+        await trade();
+        const recipientAfter = await provider.connection.getBalance(launch.feeRecipient);
+
+        await new Promise((resolve) => setTimeout(resolve, 1000));
+        await program.removeEventListener(feeListener);
+        await program.removeEventListener(tradeListener);
+
+        assert.ok(feeEvent, "PlatformFeeCollected should be emitted");
+        assert.ok(tradeEvent, "Trade event should be emitted");
+        return { feeEvent, tradeEvent, received: recipientAfter - recipientBefore };
+      };
+
+      it("Emits the fee collected on a buy", async () => {
+        const { feeEvent, tradeEvent, received } = await captureEvents(
+          () => launch.buy(trader, MILLION.mul(new BN(10))),
+          "tokensPurchased"
+        );
+
+        assert.equal(feeEvent.launch.toString(), launch.tokenLaunch.toString());
+        assert.equal(feeEvent.payer.toString(), trader.publicKey.toString());
+        assert.equal(feeEvent.recipient.toString(), launch.feeRecipient.toString());
+        assert.equal(feeEvent.amount.toString(), tradeEvent.platformFee.toString());
+        assert.equal(feeEvent.amount.toNumber(), received, "Event amount should match the recipient's inflow");
+      });
+
+      it("Emits the fee collected on a sell", async () => {
+        const { feeEvent, tradeEvent, received } = await captureEvents(
+          () => launch.sell(trader, MILLION.mul(new BN(5))),
+          "tokensSold"
+        );
+
+        assert.equal(feeEvent.launch.toString(), launch.tokenLaunch.toString());
+        assert.equal(feeEvent.payer.toString(), trader.publicKey.toString());
+        assert.equal(feeEvent.recipient.toString(), launch.feeRecipient.toString());
+        assert.equal(feeEvent.amount.toString(), tradeEvent.platformFee.toString());
+        assert.equal(feeEvent.amount.toNumber(), received, "Event amount should match the recipient's inflow");
+      });
+    });
+
+    /**
+     * Test fee accrual: fees stay in the SOL vault during trading and are
+     * swept to the fee recipient in one withdraw_fees call
+     */
+    describe("Fee Accrual", () => {
+      const trader = Keypair.generate();
+
+      const launch = new TestLaunch(program, authority.publicKey, "Accrual Token");
+
+      before(async () => {
+        await airdrop(provider, trader.publicKey, 100);
+
+        await launch.create({
+          symbol: "ACCR",
+          metadataUri: "https://example.com/accrual.json",
+          description: "Launch used to test fee accrual",
+        });
+      });
+
+      after(async () => {
+        // Restore per-trade fee transfers for the other suites
+        await program.methods
+          .updateFeeAccrual(false)
+          .accounts({ config: launch.config, authority: authority.publicKey })
+          .rpc();
+      });
+
+      it("Enables fee accrual", async () => {
+        await program.methods
+          .updateFeeAccrual(true)
+          .accounts({ config: launch.config, authority: authority.publicKey })
+          .rpc();
+
+        const config = await program.account.launchpadConfig.fetch(launch.config);
+        assert.ok(config.accrueFees, "Fee accrual should be enabled");
+      });
+
+      it("Accrues fees in the vault over several trades", async () => {
+        const feeRecipientBefore = await provider.connection.getBalance(launch.feeRecipient);
+
+        for (let i = 0; i < 3; i++) {
+          await launch.buy(trader, MILLION.mul(new BN(10)));
+        }
+
+        const feeRecipientAfter = await provider.connection.getBalance(launch.feeRecipient);
+        const bondingCurve = await program.account.bondingCurve.fetch(launch.bondingCurve);
+
+        assert.equal(feeRecipientAfter, feeRecipientBefore, "No per-trade fee transfers");
+        assert.ok(bondingCurve.accruedFees.toNumber() > 0, "Fees should accrue on the curve");
+      });
+
+      it("Sweeps accrued fees in one call without touching sol_reserve", async () => {
+        const bondingCurveBefore = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        const feeRecipientBefore = await provider.connection.getBalance(launch.feeRecipient);
+
+        await program.methods
+          .withdrawFees()
+          .accounts({
+            tokenLaunch: launch.tokenLaunch,
+            bondingCurve: launch.bondingCurve,
+            solVault: launch.solVault,
+            config: launch.config,
+            feeRecipient: launch.feeRecipient,
+            authority: authority.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+
+        const bondingCurveAfter = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        const feeRecipientAfter = await provider.connection.getBalance(launch.feeRecipient);
+
+        assert.equal(
+          feeRecipientAfter - feeRecipientBefore,
+          bondingCurveBefore.accruedFees.toNumber(),
+          "Fee recipient should receive all accrued fees"
+        );
+        assert.equal(bondingCurveAfter.accruedFees.toString(), "0");
+        assert.equal(
+          bondingCurveAfter.solReserve.toString(),
+          bondingCurveBefore.solReserve.toString(),
+          "sol_reserve must be untouched by the sweep"
+        );
+      });
+    });
+
+    /**
+     * Test the platform fee discount for loyalty token holders
+     */
+    describe("Fee Discount", () => {
+      const trader = Keypair.generate();
+      const DISCOUNT_BPS = 5_000; // 50% off the platform fee
+
+      const launch = new TestLaunch(program, authority.publicKey, "Discount Token");
+      let platformFeeBps: number;
+      let loyaltyMint: PublicKey;
+      let loyaltyTokenAccount: PublicKey;
+
+      // Buys and returns the curve cost and platform fee from the TokensPurchased event
+      const buy = async (wallet: Keypair, amount: BN, discountTokenAccount: PublicKey | null) => {
+        let purchase = null;
+        const listener = program.addEventListener("tokensPurchased", (event) => {
+          purchase = event;
+        });
+
+        await launch.buy(wallet, amount, { accounts: { discountTokenAccount } });
+
+        await new Promise((resolve) => setTimeout(resolve, 1000));
+        await program.removeEventListener(listener);
+        return { cost: purchase.solAmount as BN, fee: purchase.platformFee as BN };
+      };
+
+      before(async () => {
+        await airdrop(provider, trader.publicKey, 100);
+
+        await launch.create({
+          symbol: "DISC",
+          metadataUri: "https://example.com/discount.json",
+          description: "Launch used to test loyalty fee discounts",
+        });
+        platformFeeBps = (await program.account.launchpadConfig.fetch(launch.config)).platformFeeBps;
+
+        loyaltyMint = await createMint(provider.connection, authority.payer, authority.publicKey, null, 0);
+        loyaltyTokenAccount = await createAssociatedTokenAccount(
+          provider.connection,
+          authority.payer,
+          loyaltyMint,
+          trader.publicKey
+        );
+
+        await program.methods
+          .updateFeeDiscount(loyaltyMint, DISCOUNT_BPS)
+          .accounts({ config: launch.config, authority: authority.publicKey })
+          .rpc();
+      });
+
+      after(async () => {
+        await program.methods
+          .updateFeeDiscount(PublicKey.default, 0)
+          .accounts({ config: launch.config, authority: authority.publicKey })
+          .rpc();
+      });
+
+      it("Charges the full fee when no loyalty account is provided", async () => {
+        const { cost, fee } = await buy(trader, MILLION, null);
+        assert.equal(fee.toString(), cost.muln(platformFeeBps).divn(10_000).toString());
+      });
+
+      it("Charges the full fee when the loyalty balance is zero", async () => {
+        const { cost, fee } = await buy(trader, MILLION, loyaltyTokenAccount);
+        assert.equal(fee.toString(), cost.muln(platformFeeBps).divn(10_000).toString());
+      });
+
+      it("Discounts the fee for loyalty token holders", async () => {
+        await mintTo(provider.connection, authority.payer, loyaltyMint, loyaltyTokenAccount, authority.publicKey, 1);
+
+        const { cost, fee } = await buy(trader, MILLION, loyaltyTokenAccount);
+        const discountedBps = platformFeeBps - Math.floor((platformFeeBps * DISCOUNT_BPS) / 10_000);
+        assert.equal(fee.toString(), cost.muln(discountedBps).divn(10_000).toString());
+      });
+    });
+
+    /**
+     * Test a launch's platform fee schedule interpolated over curve progress
+     */
+    describe("Fee Schedule", () => {
+      const trader = Keypair.generate();
+
+      const launch = new TestLaunch(program, authority.publicKey, "Schedule Token");
+
+      // No solPriceFeed: trades price off the stored backup
+      const buy = (wallet: Keypair, amount: BN) =>
+        launch.buy(wallet, amount, {
+          maxSolCost: new BN(100 * LAMPORTS_PER_SOL),
+          accounts: { solPriceFeed: null },
+        });
+
+      const sell = (wallet: Keypair, amount: BN) =>
+        launch.sell(wallet, amount, { accounts: { solPriceFeed: null } });
+
+      before(async () => {
+        await airdrop(provider, trader.publicKey, 200);
+
+        await launch.create({
+          symbol: "SCHD",
+          metadataUri: "https://example.com/schedule.json",
+          description: "Launch used to test a progress-based fee schedule",
+          solPriceUsd: new BN(1500_00000000), // $1,500 keeps graduation cheap
+        });
+      });
+
+      const setGraduationGrace = (seconds: number) =>
+        program.methods
+          .updateGraduationGrace(seconds)
+          .accounts({ config: launch.config, authority: authority.publicKey })
+          .rpc();
+
+      const setSchedule = (schedule: { startBps: number; endBps: number } | null, signer?: Keypair) => {
+        const builder = program.methods
+          .updateFeeSchedule(schedule)
+          .accounts({
+            config: launch.config,
+            tokenLaunch: launch.tokenLaunch,
+            bondingCurve: launch.bondingCurve,
+            authority: signer ? signer.publicKey : authority.publicKey,
+          });
+        return signer ? builder.signers([signer]).rpc() : builder.rpc();
+      };
+
+      // Run a trade and return the single event of the given name it emitted
+      const captureEvent = async (name: "tokensPurchased" | "tokensSold", trade: () => Promise<string>) => {
+        let captured = null;
+        const listener = program.addEventListener(name, (event) => {
+          captured = event;
+        });
+        await trade();
+        await new Promise((resolve) => setTimeout(resolve, 1000));
+        await program.removeEventListener(listener);
+        assert.ok(captured, `${name} should be emitted`);
+        return captured;
+      };
+
+      const assertFeeBps = (event, bps: number) =>
+        assert.equal(
+          event.platformFee.toString(),
+          event.solAmount.muln(bps).divn(10_000).toString(),
+          `Fee should be charged at ${bps} bps`
+        );
+
+      after(async () => {
+        await setGraduationGrace(0);
+      });
+
+      it("Rejects schedules from non-admin signers or above the fee cap", async () => {
+        try {
+          await setSchedule({ startBps: 300, endBps: 100 }, trader);
+          assert.fail("Non-admin schedule should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "Unauthorized");
+        }
+
+        try {
+          await setSchedule({ startBps: 1_001, endBps: 100 });
+          assert.fail("A schedule above 10% should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "InvalidFee");
+        }
+      });
+
+      it("Charges the start fee with nothing sold", async () => {
+        await setSchedule({ startBps: 300, endBps: 100 });
+        const curve = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        assert.deepEqual(curve.feeScheduleByProgress, { startBps: 300, endBps: 100 });
+
+        const event = await captureEvent("tokensPurchased", () => buy(trader, MILLION));
+        assertFeeBps(event, 300);
+      });
+
+      it("Charges the midpoint fee at 50% progress", async () => {
+        // Bring tokens_sold to exactly 400M of the 800M curve supply
+        await buy(trader, MILLION.muln(399));
+
+        const event = await captureEvent("tokensPurchased", () => buy(trader, MILLION));
+        assertFeeBps(event, 200);
+      });
+
+      it("Charges the end fee at 100% progress", async () => {
+        // A grace window lets the graduated, fully sold curve still take a sell
+        await setGraduationGrace(3_600);
+        await buy(trader, MILLION.muln(399));
+        const curve = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        assert.ok(curve.isGraduated, "Curve should graduate once sold out");
+
+        const event = await captureEvent("tokensSold", () => sell(trader, MILLION));
+        assertFeeBps(event, 100);
+      });
+    });
+
+    /**
+     * Test splitting the platform trading fee across weighted recipients
+     */
+    describe("Fee Splits", () => {
+      const trader = Keypair.generate();
+      const dao = Keypair.generate();
+      const devFund = Keypair.generate();
+      const insurance = Keypair.generate();
+
+      const SPLITS = [
+        { recipient: dao.publicKey, weightBps: 6_000 },
+        { recipient: devFund.publicKey, weightBps: 3_000 },
+        { recipient: insurance.publicKey, weightBps: 1_000 },
+      ];
+
+      const launch = new TestLaunch(program, authority.publicKey, "Fee Split Token");
+
+      const buy = (wallet: Keypair, amount: BN, payees: PublicKey[]) =>
+        launch
+          .buyMethod(wallet, amount)
+          .remainingAccounts(payees.map((pubkey) => ({ pubkey, isSigner: false, isWritable: true })))
+          .rpc();
+
+      const sell = (wallet: Keypair, amount: BN, payees: PublicKey[]) =>
+        launch
+          .sellMethod(wallet, amount)
+          .remainingAccounts(payees.map((pubkey) => ({ pubkey, isSigner: false, isWritable: true })))
+          .rpc();
+
+      const setFeeSplits = (feeSplits: { recipient: PublicKey; weightBps: number }[]) =>
+        program.methods
+          .updateFeeSplits(feeSplits)
+          .accounts({ config: launch.config, authority: authority.publicKey })
+          .rpc();
+
+      const balancesOf = (wallets: Keypair[]) =>
+        Promise.all(wallets.map((w) => provider.connection.getBalance(w.publicKey)));
+
+      // Runs a trade and returns the fee it charged plus each PlatformFeeCollected share
+      const tradeWithFeeEvents = async (trade: () => Promise<string>, tradeEvent: string) => {
+        let totalFee: BN | null = null;
+        const shares: { recipient: PublicKey; amount: BN }[] = [];
+        const tradeListener = program.addEventListener(tradeEvent as any, (event: any) => {
+          totalFee = event.platformFee;
+        });
+        const feeListener = program.addEventListener("platformFeeCollected", (event) => {
+          shares.push({ recipient: event.recipient, amount: event.amount });
+        });
+
+        await trade();
+        await new Promise((resolve) => setTimeout(resolve, 1000));
+        await program.removeEventListener(tradeListener);
+        await program.removeEventListener(feeListener);
+        return { totalFee, shares };
+      };
+
+      before(async () => {
+        await airdrop(provider, trader.publicKey, 20);
+        // Pre-funded so every share lands in a rent-exempt account
+        for (const payee of [dao, devFund, insurance]) {
+          await airdrop(provider, payee.publicKey, 1);
+        }
+
+        await launch.create({
+          symbol: "SPLT",
+          metadataUri: "https://example.com/fee-splits.json",
+          description: "Launch used to test weighted fee recipients",
+        });
+      });
+
+      after(async () => {
+        await setFeeSplits([]);
+      });
+
+      it("Rejects splits whose weights don't sum to 100%", async () => {
+        try {
+          await setFeeSplits(SPLITS.slice(0, 2));
+          assert.fail("A 60/30 split should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "InvalidFeeSplit");
+        }
+      });
+
+      it("Rejects fee split updates from non-admin signers", async () => {
+        try {
+          await program.methods
+            .updateFeeSplits(SPLITS)
+            .accounts({ config: launch.config, authority: trader.publicKey })
+            .signers([trader])
+            .rpc();
+          assert.fail("Non-admin update should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "Unauthorized");
+        }
+      });
+
+      it("Splits a buy fee 60/30/10 with no lamports lost", async () => {
+        await setFeeSplits(SPLITS);
+        const config = await program.account.launchpadConfig.fetch(launch.config);
+        assert.equal(config.feeSplits.length, 3);
+
+        const payees = [dao, devFund, insurance];
+        const before = await balancesOf(payees);
+        // An odd amount so the fee doesn't divide evenly by the weights
+        const { totalFee, shares } = await tradeWithFeeEvents(
+          () => buy(trader, MILLION.muln(3).addn(7), SPLITS.map((s) => s.recipient)),
+          "tokensPurchased"
+        );
+        const after = await balancesOf(payees);
+
+        assert.ok(totalFee && totalFee.gtn(0), "Buy should charge a platform fee");
+        assert.equal(shares.length, 3, "One fee event per recipient");
+        const received = after.map((balance, i) => balance - before[i]);
+        shares.forEach((share, i) => {
+          assert.ok(share.recipient.equals(SPLITS[i].recipient));
+          assert.equal(share.amount.toNumber(), received[i]);
+        });
+        assert.equal(
+          received.reduce((a, b) => a + b, 0),
+          totalFee.toNumber(),
+          "Shares must add up to exactly the fee charged"
+        );
+
+        // Every share but the last is the floor of its weight; the last takes the dust
+        assert.equal(received[0], totalFee.muln(6_000).divn(10_000).toNumber());
+        assert.equal(received[1], totalFee.muln(3_000).divn(10_000).toNumber());
+      });
+
+      it("Splits a sell fee across the same recipients", async () => {
+        const payees = [dao, devFund, insurance];
+        const before = await balancesOf(payees);
+        const { totalFee, shares } = await tradeWithFeeEvents(
+          () => sell(trader, MILLION, SPLITS.map((s) => s.recipient)),
+          "tokensSold"
+        );
+        const after = await balancesOf(payees);
+
+        const received = after.map((balance, i) => balance - before[i]);
+        assert.equal(shares.length, 3);
+        assert.equal(received.reduce((a, b) => a + b, 0), totalFee.toNumber());
+      });
+
+      it("Rejects trades that omit or reorder the split recipients", async () => {
+        try {
+          await buy(trader, MILLION, []);
+          assert.fail("Buy without the split recipients should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "InvalidFeeRecipient");
+        }
+
+        try {
+          await buy(trader, MILLION, [devFund.publicKey, dao.publicKey, insurance.publicKey]);
+          assert.fail("Buy with reordered recipients should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "InvalidFeeRecipient");
+        }
+      });
+
+      it("Sends the whole fee to the fee recipient once the splits are cleared", async () => {
+        await setFeeSplits([]);
+
+        const { totalFee, shares } = await tradeWithFeeEvents(
+          () => buy(trader, MILLION, []),
+          "tokensPurchased"
+        );
+
+        assert.equal(shares.length, 1);
+        assert.ok(shares[0].recipient.equals(launch.feeRecipient));
+        assert.equal(shares[0].amount.toString(), totalFee.toString());
+      });
+    });
+
+    /**
+     * Test the sell fee ramp that charges large sells more than small ones
+     */
+    describe("Sell Fee Ramp", () => {
+      const trader = Keypair.generate();
+
+      const rampMaxBps = 500; // +5% at full ramp
+      const rampFullShareBps = 5_000; // full ramp once a sell takes 50% of the reserve
+
+      const launch = new TestLaunch(program, authority.publicKey, "Ramp Token");
+
+      before(async () => {
+        await airdrop(provider, trader.publicKey, 100);
+
+        await launch.create({
+          symbol: "RAMP",
+          metadataUri: "https://example.com/ramp.json",
+          description: "Launch used to test the sell fee ramp",
+        });
+      });
+
+      // Capture the fee actually charged by a sell from its TokensSold event
+      const sellAndCaptureFeeBps = async (wallet: Keypair, amount: BN) => {
+        let soldEvent = null;
+        const listener = program.addEventListener("tokensSold", (event) => {
+          soldEvent = event;
+        });
+        await launch.sell(wallet, amount);
+        await new Promise((resolve) => setTimeout(resolve, 1000));
+        await program.removeEventListener(listener);
+
+        assert.ok(soldEvent, "TokensSold should be emitted");
+        return soldEvent.platformFee.muln(10_000).div(soldEvent.solAmount).toNumber();
+      };
+
+      before(async () => {
+        await program.methods
+          .updateSellFeeRamp(rampMaxBps, rampFullShareBps)
+          .accounts({ config: launch.config, authority: authority.publicKey })
+          .rpc();
+
+        await launch.buy(trader, MILLION.mul(new BN(100)));
+      });
+
+      after(async () => {
+        await program.methods
+          .updateSellFeeRamp(0, 10_000)
+          .accounts({ config: launch.config, authority: authority.publicKey })
+          .rpc();
+      });
+
+      it("Rejects a ramp above the maximum", async () => {
+        try {
+          await program.methods
+            .updateSellFeeRamp(1_001, rampFullShareBps)
+            .accounts({ config: launch.config, authority: authority.publicKey })
+            .rpc();
+          assert.fail("Ramp above 10% should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "InvalidFee");
+        }
+      });
+
+      it("Charges escalating fees as sells take more of the reserve", async () => {
+        const config = await program.account.launchpadConfig.fetch(launch.config);
+        const baseFeeBps = config.platformFeeBps;
+
+        // 1M of 100M tokens is ~1% of the reserve: fee stays near the base
+        const tinyFeeBps = await sellAndCaptureFeeBps(trader, MILLION);
+        assert.isAtMost(tinyFeeBps, baseFeeBps + 20);
+
+        // Selling everything else drains the reserve: full ramp applies
+        const position = await program.account.userPosition.fetch(launch.positionOf(trader.publicKey));
+        const drainFeeBps = await sellAndCaptureFeeBps(trader, position.tokenAmount);
+        assert.approximately(drainFeeBps, baseFeeBps + rampMaxBps, 1);
+
+        assert.isAbove(drainFeeBps, tinyFeeBps);
+      });
+    });
   });
 
   describe("3. Execute Large Trades", () => {
@@ -328,6 +1627,7 @@ describe("Bonding Curve Trading", () => {
       const quoteBefore = await program.methods
         .getBuyQuote(TRADE_1)
         .accounts({
+          config: configPda,
           bondingCurve: bondingCurvePda,
           tokenLaunch: tokenLaunchPda,
         })
@@ -366,7 +1666,7 @@ describe("Bonding Curve Trading", () => {
       const maxCost = quoteBefore.cost.mul(new BN(110)).div(new BN(100)); // 10% slippage
 
       await program.methods
-        .buyTokens(TRADE_1, maxCost)
+        .buyTokens(TRADE_1, maxCost, new BN(0))
         .accounts({
           config: configPda,
           tokenLaunch: tokenLaunchPda,
@@ -465,6 +1765,7 @@ describe("Bonding Curve Trading", () => {
       const quoteBefore = await program.methods
         .getBuyQuote(TRADE_2)
         .accounts({
+          config: configPda,
           bondingCurve: bondingCurvePda,
           tokenLaunch: tokenLaunchPda,
         })
@@ -494,7 +1795,7 @@ describe("Bonding Curve Trading", () => {
       const maxCost = quoteBefore.cost.mul(new BN(110)).div(new BN(100));
 
       await program.methods
-        .buyTokens(TRADE_2, maxCost)
+        .buyTokens(TRADE_2, maxCost, new BN(0))
         .accounts({
           config: configPda,
           tokenLaunch: tokenLaunchPda,
@@ -592,6 +1893,7 @@ describe("Bonding Curve Trading", () => {
       const quoteBefore = await program.methods
         .getBuyQuote(TRADE_3)
         .accounts({
+          config: configPda,
           bondingCurve: bondingCurvePda,
           tokenLaunch: tokenLaunchPda,
         })
@@ -621,7 +1923,7 @@ describe("Bonding Curve Trading", () => {
       const maxCost = quoteBefore.cost.mul(new BN(110)).div(new BN(100));
 
       await program.methods
-        .buyTokens(TRADE_3, maxCost)
+        .buyTokens(TRADE_3, maxCost, new BN(0))
         .accounts({
           config: configPda,
           tokenLaunch: tokenLaunchPda,
@@ -745,7 +2047,7 @@ describe("Bonding Curve Trading", () => {
       const minSolOutput = new anchor.BN(0); // Accept any price for testing
       
       await program.methods
-        .sellTokens(sellAmount, minSolOutput)
+        .sellTokens(sellAmount, minSolOutput, new BN(0))
         .accounts({
           tokenLaunch: tokenLaunchPda,
           bondingCurve: bondingCurvePda,
@@ -874,7 +2176,7 @@ describe("Bonding Curve Trading", () => {
       const minSolOutput = new anchor.BN(0); // Accept any price for testing
       
       await program.methods
-        .sellTokens(sellAmount, minSolOutput)
+        .sellTokens(sellAmount, minSolOutput, new BN(0))
         .accounts({
           tokenLaunch: tokenLaunchPda,
           bondingCurve: bondingCurvePda,
@@ -957,6 +2259,203 @@ describe("Bonding Curve Trading", () => {
       console.log(`   Percent Decrease: ${percentDecrease}%`);
       console.log(`   Before: $${priceUsdBefore.toFixed(10)} → After: $${priceUsdAfter.toFixed(10)}`);
     });
+
+    /**
+     * Test sells protected by a percentage slippage tolerance off the client's net quote
+     */
+    describe("Sell Slippage", () => {
+      const trader = Keypair.generate();
+      const other = Keypair.generate();
+
+      const launch = new TestLaunch(program, authority.publicKey, "Slippage Token");
+
+      // Net proceeds the client would quote before sending the sell
+      const quoteNet = async (amount: BN) =>
+        (
+          await program.methods
+            .simulateTrade({ sell: {} }, amount)
+            .accounts({ tokenLaunch: launch.tokenLaunch, bondingCurve: launch.bondingCurve, config: launch.config })
+            .view()
+        ).netSol as BN;
+
+      const sellWithSlippage = (wallet: Keypair, amount: BN, expectedSolOutput: BN, maxSlippageBps: number) =>
+        program.methods
+          .sellTokensWithSlippage(amount, expectedSolOutput, maxSlippageBps, new BN(0))
+          .accounts({
+            config: launch.config,
+            tokenLaunch: launch.tokenLaunch,
+            bondingCurve: launch.bondingCurve,
+            curveTokenAccount: launch.curveTokenAccount,
+            solVault: launch.solVault,
+            userPosition: launch.positionOf(wallet.publicKey),
+            sellerTokenAccount: launch.tokenAccountOf(wallet.publicKey),
+            seller: wallet.publicKey,
+            feeRecipient: launch.feeRecipient,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([wallet])
+          .rpc();
+
+      before(async () => {
+        for (const wallet of [trader, other]) {
+          await airdrop(provider, wallet.publicKey, 20);
+        }
+
+        await launch.create({
+          symbol: "SLIP",
+          metadataUri: "https://example.com/slippage.json",
+          description: "Launch used to test percentage sell slippage",
+        });
+        await launch.buy(trader, MILLION.muln(10));
+        await launch.buy(other, MILLION.muln(20));
+      });
+
+      it("Rejects a tolerance above 100%", async () => {
+        try {
+          await sellWithSlippage(trader, MILLION, await quoteNet(MILLION), 10_001);
+          assert.fail("Slippage above 10000 bps should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "InvalidSlippage");
+        }
+      });
+
+      it("Sells at zero tolerance against an unchanged net quote", async () => {
+        // The quote is net of the fee, so nothing is lost between quote and fill
+        await sellWithSlippage(trader, MILLION, await quoteNet(MILLION), 0);
+
+        const position = await program.account.userPosition.fetch(launch.positionOf(trader.publicKey));
+        assert.equal(position.tokenAmount.toString(), MILLION.muln(9).toString());
+      });
+
+      it("Rejects a sell when a trade lands between the quote and the sell", async () => {
+        const amount = MILLION.muln(2);
+        const quote = await quoteNet(amount);
+
+        // Another holder sells first, moving the price down
+        await launch.sell(other, MILLION.muln(10));
+
+        try {
+          await sellWithSlippage(trader, amount, quote, 0);
+          assert.fail("Sell filled below the quote should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "SlippageExceeded");
+        }
+      });
+
+      it("Sells with a loose tolerance after the curve has moved", async () => {
+        const amount = MILLION.muln(2);
+        const quote = await quoteNet(amount);
+
+        await launch.sell(other, MILLION.muln(5));
+
+        const balanceBefore = await provider.connection.getBalance(trader.publicKey);
+        await sellWithSlippage(trader, amount, quote, 500);
+        const received = (await provider.connection.getBalance(trader.publicKey)) - balanceBefore;
+
+        // Net proceeds land within the tolerance, less the transaction fee
+        assert.isAtLeast(received + 5_000, Math.floor((quote.toNumber() * (10_000 - 500)) / 10_000));
+
+        const position = await program.account.userPosition.fetch(launch.positionOf(trader.publicKey));
+        assert.equal(position.tokenAmount.toString(), MILLION.muln(7).toString());
+      });
+    });
+
+    /**
+     * Test that sells on a fully backed curve pay the plain curve price, and that
+     * sells on a drifted curve are scaled down to what the reserve backs.
+     * The drifted case needs a fresh Pyth SOL/USD PriceUpdateV2 account (e.g. run against devnet).
+     */
+    describe("Reserve Backing", () => {
+      const trader = Keypair.generate();
+
+      const launch = new TestLaunch(program, authority.publicKey, "Backed Token");
+      const drifted = new TestLaunch(program, authority.publicKey, "Drifted Token");
+
+      const simulateSell = (amount: BN, target: TestLaunch = launch) =>
+        program.methods
+          .simulateTrade({ sell: {} }, amount)
+          .accounts({
+            tokenLaunch: target.tokenLaunch,
+            bondingCurve: target.bondingCurve,
+            config: target.config,
+          })
+          .view();
+
+      before(async () => {
+        await airdrop(provider, trader.publicKey, 100);
+
+        await launch.create({
+          symbol: "BACK",
+          metadataUri: "https://example.com/backed.json",
+          description: "Launch used to test reserve-backed sells",
+        });
+      });
+
+      it("Pays the curve price on a fully backed curve", async () => {
+        await launch.buy(trader, MILLION.mul(new BN(50)));
+
+        const amount = MILLION.mul(new BN(10));
+        const simulation = await simulateSell(amount);
+        const before = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        await launch.sell(trader, amount);
+        const after = await program.account.bondingCurve.fetch(launch.bondingCurve);
+
+        assert.equal(
+          before.solReserve.sub(after.solReserve).toString(),
+          simulation.solAmount.toString(),
+          "Backed curve should pay exactly the simulated curve proceeds"
+        );
+      });
+
+      it("Lets the last holder exit against the remaining reserve", async () => {
+        const position = await program.account.userPosition.fetch(launch.positionOf(trader.publicKey));
+        await launch.sell(trader, position.tokenAmount);
+
+        const curve = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        assert.equal(curve.tokensSold.toNumber(), 0);
+        // Only per-trade lamport truncation should remain in the reserve
+        assert.isBelow(curve.solReserve.toNumber(), 1_000);
+      });
+
+      it("Scales a sell on a drifted curve down to the reserve backing", async () => {
+        // Deliberately far above market: buys fill the reserve with few lamports, so
+        // once the price is refreshed the same tokens are worth more than the reserve
+        await drifted.create({
+          symbol: "DRFT",
+          metadataUri: "https://example.com/drifted.json",
+          description: "Launch used to test sells on an under-backed curve",
+          solPriceUsd: new BN(1_000_00000000), // $1,000
+        });
+        await drifted.buy(trader, MILLION.mul(new BN(100)));
+        await program.methods
+          .refreshPrice()
+          .accounts({
+            tokenLaunch: drifted.tokenLaunch,
+            bondingCurve: drifted.bondingCurve,
+            solPriceFeed: SOL_USD_PRICE_FEED,
+          })
+          .rpc();
+
+        // Half the position, so the reserve can't simply be drained to zero
+        const position = await program.account.userPosition.fetch(drifted.positionOf(trader.publicKey));
+        const amount = position.tokenAmount.divn(2);
+        const simulation = await simulateSell(amount, drifted);
+        const before = await program.account.bondingCurve.fetch(drifted.bondingCurve);
+        await drifted.sell(trader, amount);
+        const after = await program.account.bondingCurve.fetch(drifted.bondingCurve);
+
+        // The whole amount fills, at the backed price rather than failing for liquidity
+        assert.equal(before.tokensSold.sub(after.tokensSold).toString(), amount.toString());
+        const paid = before.solReserve.sub(after.solReserve);
+        assert.equal(paid.toString(), simulation.solAmount.toString(), "Sell should pay the simulated backed proceeds");
+
+        // Even selling out the whole curve could only ever take what the reserve holds
+        const fullExit = await simulateSell(after.tokensSold, drifted);
+        assert.ok(fullExit.solAmount.lte(after.solReserve), "A full exit should be clamped to the reserve");
+        assert.ok(after.solReserve.gt(new BN(0)), "Reserve should still back the remaining tokens");
+      });
+    });
   });
 
   describe("5. Graduation Test - Buy All Remaining Tokens", () => {
@@ -984,6 +2483,7 @@ describe("Bonding Curve Trading", () => {
       const quoteBefore = await program.methods
         .getBuyQuote(remainingAmount)
         .accounts({
+          config: configPda,
           bondingCurve: bondingCurvePda,
           tokenLaunch: tokenLaunchPda,
         })
@@ -1037,7 +2537,7 @@ describe("Bonding Curve Trading", () => {
       const maxCost = quoteBefore.cost.mul(new BN(120)).div(new BN(100)); // 20% slippage buffer
 
       await program.methods
-        .buyTokens(remainingAmount, maxCost)
+        .buyTokens(remainingAmount, maxCost, new BN(0))
         .accounts({
           config: configPda,
           tokenLaunch: tokenLaunchPda,
@@ -1120,6 +2620,647 @@ describe("Bonding Curve Trading", () => {
       console.log(`   Curve is graduated: ${bondingCurveAfter.isGraduated ? '✅' : '❌'}`);
       console.log(`   Final price reached: ${spotPriceAfter.spotPrice.toNumber() > 400 ? '✅' : '❌'}`);
     });
+
+    it("Marks the graduated launch inactive and blocks re-activation", async () => {
+      const bondingCurve = await program.account.bondingCurve.fetch(bondingCurvePda);
+      const tokenLaunch = await program.account.tokenLaunch.fetch(tokenLaunchPda);
+
+      assert.ok(bondingCurve.isGraduated, "Curve should be graduated");
+      assert.ok(!tokenLaunch.isActive, "Graduated launch should report inactive");
+
+      try {
+        await program.methods
+          .toggleTokenLaunchActive()
+          .accounts({
+            tokenLaunch: tokenLaunchPda,
+            bondingCurve: bondingCurvePda,
+            creator: creator.publicKey,
+          })
+          .signers([creator])
+          .rpc();
+        assert.fail("Should not be able to re-activate a graduated launch");
+      } catch (error) {
+        assert.include(error.toString(), "CurveGraduated");
+      }
+
+      const tokenLaunchAfter = await program.account.tokenLaunch.fetch(tokenLaunchPda);
+      assert.ok(!tokenLaunchAfter.isActive, "Launch should remain inactive");
+      console.log("✅ Graduated launch is inactive and cannot be toggled back on");
+    });
+
+    /**
+     * Test the per-launch graduation action chosen at creation.
+     * Pool withdrawals are covered in liquidity-destinations.ts.
+     */
+    describe("Graduation Action", () => {
+      const trader = Keypair.generate();
+      const treasury = Keypair.generate();
+
+      const solPriceUsd = new BN(1500_00000000); // $1,500 keeps graduation cheap
+
+      const createLaunch = async (name: string, graduationAction: object) => {
+        const launch = new TestLaunch(program, authority.publicKey, name);
+        await launch.create({
+          symbol: "GACT",
+          metadataUri: "https://example.com/graduation-action.json",
+          description: "Launch used to test graduation actions",
+          solPriceUsd,
+          graduationAction,
+        });
+        return launch;
+      };
+
+      before(async () => {
+        await airdrop(provider, trader.publicKey, 100);
+
+        await program.methods
+          .updateLiquidityDestinations(PublicKey.default, treasury.publicKey)
+          .accounts({ config: configPdaFor(program), authority: authority.publicKey })
+          .rpc();
+      });
+
+      it("Rejects the distribute action until it is supported", async () => {
+        try {
+          await createLaunch("Distribute Token", { distribute: {} });
+          assert.fail("Distribute action should be rejected at creation");
+        } catch (error) {
+          assert.include(error.toString(), "GraduationActionNotSupported");
+        }
+      });
+
+      it("Keeps liquidity in the vaults for a locked launch", async () => {
+        const launch = await createLaunch("Locked Token", { lock: {} });
+
+        // Buy out the full 800M curve supply to graduate
+        for (let i = 0; i < 4; i++) {
+          await launch.buy(trader, MILLION.mul(new BN(200)));
+        }
+        const bondingCurve = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        assert.ok(bondingCurve.isGraduated, "Curve should graduate once sold out");
+        assert.deepEqual(bondingCurve.graduationAction, { lock: {} });
+
+        const vaultBefore = await provider.connection.getBalance(launch.solVault);
+        const tokenRecipient = await createAssociatedTokenAccount(
+          provider.connection,
+          authority.payer,
+          launch.mint,
+          treasury.publicKey
+        );
+
+        try {
+          await program.methods
+            .withdrawLiquidity()
+            .accounts({
+              config: launch.config,
+              tokenLaunch: launch.tokenLaunch,
+              bondingCurve: launch.bondingCurve,
+              solVault: launch.solVault,
+              lpVault: launch.lpVault,
+              solRecipient: treasury.publicKey,
+              tokenRecipient,
+              feeRecipient: launch.feeRecipient,
+              authority: authority.publicKey,
+              tokenProgram: TOKEN_PROGRAM_ID,
+              systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+          assert.fail("Withdrawal from a locked launch should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "LiquidityLocked");
+        }
+
+        const lpTokens = await getAccount(provider.connection, launch.lpVault);
+        assert.equal(await provider.connection.getBalance(launch.solVault), vaultBefore);
+        assert.equal(lpTokens.amount.toString(), MILLION.mul(new BN(200)).toString());
+      });
+    });
+
+    /**
+     * Test burning of unsold curve tokens at graduation
+     */
+    describe("Graduation Burn", () => {
+      const trader = Keypair.generate();
+
+      const launch = new TestLaunch(program, authority.publicKey, "Burn Token");
+
+      before(async () => {
+        await airdrop(provider, trader.publicKey, 200);
+
+        await launch.create({
+          symbol: "BURN",
+          metadataUri: "https://example.com/burn.json",
+          description: "Launch used to test graduation burns",
+          solPriceUsd: new BN(1500_00000000), // $1,500 keeps graduation cheap
+        });
+
+        await program.methods
+          .updateBurnUnsoldOnGraduation(true)
+          .accounts({ config: launch.config, authority: authority.publicKey })
+          .rpc();
+      });
+
+      after(async () => {
+        await program.methods
+          .updateBurnUnsoldOnGraduation(false)
+          .accounts({ config: launch.config, authority: authority.publicKey })
+          .rpc();
+      });
+
+      it("Burns tokens left in the curve account at graduation", async () => {
+        const HALF_CURVE = MILLION.mul(new BN(400));
+        const STRAY = MILLION;
+
+        await launch.buy(trader, HALF_CURVE);
+
+        // Send tokens straight back to the curve account so something is left over
+        await transfer(
+          provider.connection,
+          trader,
+          launch.tokenAccountOf(trader.publicKey),
+          launch.curveTokenAccount,
+          trader,
+          BigInt(STRAY.toString())
+        );
+
+        const supplyBefore = (await getMint(provider.connection, launch.mint)).supply;
+
+        let burnedEvent = null;
+        const listener = program.addEventListener("unsoldTokensBurned", (event) => {
+          burnedEvent = event;
+        });
+
+        await launch.buy(trader, MILLION.mul(new BN(200)));
+        await launch.buy(trader, MILLION.mul(new BN(200)));
+
+        await new Promise((resolve) => setTimeout(resolve, 1000));
+        await program.removeEventListener(listener);
+
+        const bondingCurve = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        assert.ok(bondingCurve.isGraduated, "Curve should graduate once sold out");
+
+        const curveTokens = await getAccount(provider.connection, launch.curveTokenAccount);
+        const supplyAfter = (await getMint(provider.connection, launch.mint)).supply;
+
+        assert.equal(curveTokens.amount.toString(), "0", "Curve account should be emptied");
+        assert.equal(
+          (supplyBefore - supplyAfter).toString(),
+          STRAY.toString(),
+          "Mint supply should drop by the burned amount"
+        );
+        assert.ok(burnedEvent, "UnsoldTokensBurned should be emitted");
+        assert.equal(burnedEvent.amount.toString(), STRAY.toString());
+      });
+    });
+
+    /**
+     * Test the sell-only grace window after a curve graduates
+     */
+    describe("Graduation Grace", () => {
+      const trader = Keypair.generate();
+      const treasury = Keypair.generate();
+
+      const GRACE_SECONDS = 10;
+
+      const launch = new TestLaunch(program, authority.publicKey, "Grace Token");
+
+      const setGrace = (seconds: number) =>
+        program.methods
+          .updateGraduationGrace(seconds)
+          .accounts({ config: launch.config, authority: authority.publicKey })
+          .rpc();
+
+      before(async () => {
+        await airdrop(provider, trader.publicKey, 200);
+
+        await launch.create({
+          symbol: "GRACE",
+          metadataUri: "https://example.com/grace.json",
+          description: "Launch used to test the post-graduation sell window",
+          solPriceUsd: new BN(1500_00000000), // $1,500 keeps graduation cheap
+        });
+      });
+
+      after(async () => {
+        await setGrace(0);
+      });
+
+      const withdraw = async (recipient: PublicKey) => {
+        // Withdrawal is attempted more than once, so reuse the recipient's token account
+        const { address: tokenRecipient } = await getOrCreateAssociatedTokenAccount(
+          provider.connection,
+          authority.payer,
+          launch.mint,
+          recipient
+        );
+        return program.methods
+          .withdrawLiquidity()
+          .accounts({
+            config: launch.config,
+            tokenLaunch: launch.tokenLaunch,
+            bondingCurve: launch.bondingCurve,
+            solVault: launch.solVault,
+            lpVault: launch.lpVault,
+            solRecipient: recipient,
+            tokenRecipient,
+            feeRecipient: launch.feeRecipient,
+            authority: authority.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+      };
+
+      it("Keeps sells open on the curve during the grace window", async () => {
+        await setGrace(GRACE_SECONDS);
+        await program.methods
+          .updateLiquidityDestinations(PublicKey.default, treasury.publicKey)
+          .accounts({ config: launch.config, authority: authority.publicKey })
+          .rpc();
+
+        // Buy out the full 800M curve supply to graduate
+        for (let i = 0; i < 4; i++) {
+          await launch.buy(trader, MILLION.mul(new BN(200)));
+        }
+        const graduated = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        assert.ok(graduated.isGraduated, "Curve should graduate once sold out");
+        assert.ok(graduated.graduatedAt.toNumber() > 0, "Graduation time should be recorded");
+        assert.equal(graduated.graduationGraceSeconds, GRACE_SECONDS);
+
+        // The window was fixed at graduation, so a later config change doesn't close it
+        await setGrace(0);
+        await launch.sell(trader, MILLION);
+
+        const curve = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        assert.equal(
+          curve.tokensSold.toString(),
+          graduated.tokensSold.sub(MILLION).toString(),
+          "Grace sells go back to the curve"
+        );
+        assert.ok(curve.isGraduated, "Selling in the grace window doesn't un-graduate the curve");
+      });
+
+      it("Rejects buys during the grace window", async () => {
+        try {
+          await launch.buy(trader, MILLION);
+          assert.fail("Buying from a graduated curve should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "CurveGraduated");
+        }
+      });
+
+      it("Holds back liquidity withdrawal until the grace window ends", async () => {
+        try {
+          await withdraw(treasury.publicKey);
+          assert.fail("Withdrawal during the grace window should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "GraduationGraceActive");
+        }
+      });
+
+      it("Stops sells once the grace window has passed", async () => {
+        await new Promise((resolve) => setTimeout(resolve, (GRACE_SECONDS + 2) * 1000));
+
+        try {
+          await launch.sell(trader, MILLION);
+          assert.fail("Selling after the grace window should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "CurveGraduated");
+        }
+
+        await withdraw(treasury.publicKey);
+        const pool = (await program.account.bondingCurve.fetch(launch.bondingCurve)).poolAddress;
+        assert.ok(pool && pool.equals(treasury.publicKey), "Liquidity withdraws once the window closes");
+      });
+    });
+
+    /**
+     * Test the graduation status diagnostic for each blocking condition.
+     * A sold-out curve short of the USD threshold needs the SOL price to move
+     * between buys, so that case is covered by the unit tests in state.rs.
+     */
+    describe("Graduation Status", () => {
+      const trader = Keypair.generate();
+
+      const solPriceUsd = new BN(1500_00000000); // $1,500 keeps graduation cheap
+
+      const createLaunch = async (name: string) => {
+        const launch = new TestLaunch(program, authority.publicKey, name);
+        await launch.create({
+          symbol: "GSTAT",
+          metadataUri: "https://example.com/graduation-status.json",
+          description: "Launch used to test graduation status",
+          solPriceUsd,
+        });
+        return launch;
+      };
+
+      const statusOf = (launch: TestLaunch) =>
+        program.methods
+          .getGraduationStatus()
+          .accounts({
+            config: launch.config,
+            tokenLaunch: launch.tokenLaunch,
+            bondingCurve: launch.bondingCurve,
+          })
+          .view();
+
+      const buyOut = async (launch: TestLaunch) => {
+        for (let i = 0; i < 4; i++) {
+          await launch.buy(trader, MILLION.mul(new BN(200)));
+        }
+      };
+
+      before(async () => {
+        await airdrop(provider, trader.publicKey, 100);
+      });
+
+      it("Reports unsold tokens as the blocker on a young curve", async () => {
+        const launch = await createLaunch("Status Token");
+        const CURVE_SUPPLY = MILLION.mul(new BN(800));
+
+        let status = await statusOf(launch);
+        assert.deepEqual(status.blockingReason, { tokensNotSold: {} });
+        assert.isFalse(status.tokensMet);
+        assert.isFalse(status.usdMet);
+        assert.equal(status.tokensShort.toString(), CURVE_SUPPLY.toString());
+        assert.isAbove(status.usdShortLamports.toNumber(), 0);
+
+        await launch.buy(trader, MILLION.mul(new BN(100)));
+        const curve = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        const before = status;
+        status = await statusOf(launch);
+        assert.deepEqual(status.blockingReason, { tokensNotSold: {} });
+        assert.equal(status.tokensShort.toString(), CURVE_SUPPLY.sub(curve.tokensSold).toString());
+        assert.isBelow(status.usdShortLamports.toNumber(), before.usdShortLamports.toNumber());
+      });
+
+      it("Reports the minimum SOL reserve when it is the only unmet condition", async () => {
+        const config = await program.account.launchpadConfig.fetch(configPdaFor(program));
+        const previousMin = config.minGraduationSolLamports;
+        const MIN_RESERVE = new BN(1_000 * LAMPORTS_PER_SOL);
+
+        await program.methods
+          .updateMinGraduationSol(MIN_RESERVE)
+          .accounts({ config: configPdaFor(program), authority: authority.publicKey })
+          .rpc();
+
+        try {
+          const launch = await createLaunch("Reserve Blocked Token");
+          await buyOut(launch);
+
+          const curve = await program.account.bondingCurve.fetch(launch.bondingCurve);
+          assert.isFalse(curve.isGraduated, "Curve should not graduate below the minimum reserve");
+
+          const status = await statusOf(launch);
+          assert.deepEqual(status.blockingReason, { solReserveBelowMinimum: {} });
+          assert.isTrue(status.tokensMet);
+          assert.isTrue(status.usdMet);
+          assert.isFalse(status.minSolMet);
+          assert.equal(status.usdShortLamports.toNumber(), 0);
+          assert.equal(
+            status.minSolShortLamports.toString(),
+            MIN_RESERVE.sub(curve.solReserve).toString()
+          );
+        } finally {
+          await program.methods
+            .updateMinGraduationSol(previousMin)
+            .accounts({ config: configPdaFor(program), authority: authority.publicKey })
+            .rpc();
+        }
+      });
+
+      it("Reports a graduated curve without changing state", async () => {
+        const launch = await createLaunch("Graduated Status Token");
+        await buyOut(launch);
+
+        const before = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        assert.ok(before.isGraduated);
+
+        const status = await statusOf(launch);
+        assert.deepEqual(status.blockingReason, { alreadyGraduated: {} });
+        assert.isTrue(status.isGraduated);
+        assert.isTrue(status.tokensMet);
+        assert.isTrue(status.usdMet);
+
+        const after = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        assert.equal(after.solReserve.toString(), before.solReserve.toString());
+      });
+    });
+
+    /**
+     * Test that a curve bought out by a single wallet can't graduate below the holder minimum
+     */
+    describe("Minimum Holders For Graduation", () => {
+      const whale = Keypair.generate();
+      const secondHolder = Keypair.generate();
+
+      const MIN_HOLDERS = 2;
+
+      const launch = new TestLaunch(program, authority.publicKey, "Holders Token");
+
+      const setMinHolders = (minHolders: number) =>
+        program.methods
+          .updateMinHoldersForGraduation(minHolders)
+          .accounts({ config: launch.config, authority: authority.publicKey })
+          .rpc();
+
+      const status = () =>
+        program.methods
+          .getGraduationStatus()
+          .accounts({ config: launch.config, tokenLaunch: launch.tokenLaunch, bondingCurve: launch.bondingCurve })
+          .view();
+
+      before(async () => {
+        for (const wallet of [whale, secondHolder]) {
+          await airdrop(provider, wallet.publicKey, 200);
+        }
+
+        await launch.create({
+          symbol: "HOLD",
+          metadataUri: "https://example.com/holders.json",
+          description: "Launch used to test the graduation holder minimum",
+          solPriceUsd: new BN(1500_00000000), // $1,500 keeps graduation cheap
+        });
+      });
+
+      after(async () => {
+        await setMinHolders(0);
+      });
+
+      it("Rejects updating the holder minimum from non-admin signers", async () => {
+        try {
+          await program.methods
+            .updateMinHoldersForGraduation(MIN_HOLDERS)
+            .accounts({ config: launch.config, authority: whale.publicKey })
+            .signers([whale])
+            .rpc();
+          assert.fail("Non-admin update should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "Unauthorized");
+        }
+      });
+
+      it("Withholds graduation when one wallet buys out the curve", async () => {
+        await setMinHolders(MIN_HOLDERS);
+        const config = await program.account.launchpadConfig.fetch(launch.config);
+        assert.equal(config.minHoldersForGraduation, MIN_HOLDERS);
+
+        // Buy out the full 800M curve supply in 200M chunks
+        for (let i = 0; i < 4; i++) {
+          await launch.buy(whale, MILLION.mul(new BN(200)));
+        }
+
+        const curve = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        assert.equal(curve.tokenReserve.toString(), "0", "Curve should be sold out");
+        assert.equal(curve.holderCount.toString(), "1");
+        assert.isFalse(curve.isGraduated, "A single holder must not graduate the curve");
+
+        const graduation = await status();
+        assert.isTrue(graduation.tokensMet);
+        assert.isTrue(graduation.usdMet);
+        assert.isFalse(graduation.holdersMet);
+        assert.equal(graduation.holdersShort.toString(), "1");
+        assert.deepEqual(graduation.blockingReason, { holdersBelowMinimum: {} });
+      });
+
+      it("Graduates once a second wallet holds", async () => {
+        // Free up supply for another buyer without the whale exiting
+        await launch.sell(whale, MILLION);
+        assert.isFalse((await program.account.bondingCurve.fetch(launch.bondingCurve)).isGraduated);
+
+        await launch.buy(secondHolder, MILLION);
+
+        const curve = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        assert.equal(curve.holderCount.toString(), MIN_HOLDERS.toString());
+        assert.isTrue(curve.isGraduated, "Curve should graduate once the holder minimum is met");
+
+        const graduation = await status();
+        assert.isTrue(graduation.holdersMet);
+        assert.deepEqual(graduation.blockingReason, { alreadyGraduated: {} });
+      });
+    });
+
+    /**
+     * Test launches that graduate as soon as the SOL vault reaches a target
+     */
+    describe("Target SOL Vault", () => {
+      const trader = Keypair.generate();
+
+      // A cheap SOL means every token costs more lamports, so the vault fills
+      // long before the 800M curve supply sells out
+      const solPriceUsd = new BN(10_00000000); // $10
+      const TARGET = new BN(5 * LAMPORTS_PER_SOL);
+
+      const createLaunch = async (name: string, targetSolVault: BN, maxSolRaise = new BN(0)) => {
+        const launch = new TestLaunch(program, authority.publicKey, name);
+        await launch.create({
+          symbol: "TGT",
+          metadataUri: "https://example.com/target-vault.json",
+          description: "Launch used to test target SOL vault graduation",
+          solPriceUsd,
+          maxSolRaiseLamports: maxSolRaise,
+          targetSolVaultLamports: targetSolVault,
+        });
+        return launch;
+      };
+
+      const buy = (launch: TestLaunch, amount: BN) =>
+        launch.buy(trader, amount, { maxSolCost: new BN(20 * LAMPORTS_PER_SOL) });
+
+      const statusOf = (launch: TestLaunch) =>
+        program.methods
+          .getGraduationStatus()
+          .accounts({
+            config: launch.config,
+            tokenLaunch: launch.tokenLaunch,
+            bondingCurve: launch.bondingCurve,
+          })
+          .view();
+
+      const setGrace = (seconds: number) =>
+        program.methods
+          .updateGraduationGrace(seconds)
+          .accounts({ config: configPdaFor(program), authority: authority.publicKey })
+          .rpc();
+
+      before(async () => {
+        await airdrop(provider, trader.publicKey, 50);
+      });
+
+      after(async () => {
+        await setGrace(0);
+      });
+
+      it("Rejects a target above the raise cap", async () => {
+        try {
+          await createLaunch("Unreachable Target Token", TARGET, TARGET.subn(1));
+          assert.fail("Target above the raise cap should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "InvalidAmount");
+        }
+      });
+
+      it("Graduates once the vault hits the target, burning the unsold supply", async () => {
+        const launch = await createLaunch("Target Vault Token", TARGET);
+        const CURVE_SUPPLY = MILLION.mul(new BN(800));
+
+        await buy(launch, MILLION);
+        let curve = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        assert.isFalse(curve.isGraduated);
+        assert.equal(curve.targetSolVaultLamports.toString(), TARGET.toString());
+
+        const status = await statusOf(launch);
+        assert.deepEqual(status.blockingReason, { solTargetNotReached: {} });
+        assert.equal(
+          status.targetSolShortLamports.toString(),
+          TARGET.sub(curve.solReserve).toString()
+        );
+
+        let graduatedEvent = null;
+        const listener = program.addEventListener("curveGraduated", (event) => {
+          graduatedEvent = event;
+        });
+
+        // ~$90 of tokens at $10/SOL pushes the reserve past 5 SOL
+        await buy(launch, MILLION.muln(20));
+
+        await new Promise((resolve) => setTimeout(resolve, 1000));
+        await program.removeEventListener(listener);
+
+        curve = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        assert.isTrue(curve.isGraduated, "Curve should graduate at the SOL target");
+        assert.isTrue(curve.solReserve.gte(TARGET));
+        assert.isTrue(curve.tokensSold.lt(CURVE_SUPPLY), "Graduation should come before sell-out");
+        assert.ok(graduatedEvent, "CurveGraduated should be emitted");
+
+        // Unsold curve tokens never reach the pool
+        const curveTokens = await getAccount(provider.connection, launch.curveTokenAccount);
+        assert.equal(curveTokens.amount.toString(), "0");
+        assert.equal(curve.tokenReserve.toString(), "0", "The burn should empty the token reserve");
+
+        const tokenLaunch = await program.account.tokenLaunch.fetch(launch.tokenLaunch);
+        assert.isFalse(tokenLaunch.isActive);
+      });
+
+      it("Takes grace-window sells after graduating with supply burned", async () => {
+        const launch = await createLaunch("Target Grace Token", TARGET);
+        await setGrace(3_600);
+
+        await buy(launch, MILLION.muln(21));
+        const graduated = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        assert.isTrue(graduated.isGraduated);
+        assert.equal(graduated.tokenReserve.toString(), "0");
+
+        // The sold-back tokens are all the curve holds after the burn
+        await launch.sell(trader, MILLION);
+
+        const curve = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        assert.equal(curve.tokenReserve.toString(), MILLION.toString());
+        assert.equal(curve.tokensSold.toString(), graduated.tokensSold.sub(MILLION).toString());
+        const curveTokens = await getAccount(provider.connection, launch.curveTokenAccount);
+        assert.equal(curveTokens.amount.toString(), MILLION.toString());
+      });
+    });
   });
 
   describe("6. Multiple Token Trading Test", () => {
@@ -1309,6 +3450,7 @@ describe("Bonding Curve Trading", () => {
       const quote1 = await program.methods
         .getBuyQuote(tradeAmount1)
         .accounts({
+          config: configPda,
           bondingCurve: bondingCurvePda2,
           tokenLaunch: tokenLaunchPda2,
         })
@@ -1317,7 +3459,7 @@ describe("Bonding Curve Trading", () => {
       console.log(`   Expected cost: ${(Number(quote1.cost) / 1e9).toFixed(6)} SOL`);
 
       await program.methods
-        .buyTokens(tradeAmount1, quote1.cost.mul(new BN(110)).div(new BN(100)))
+        .buyTokens(tradeAmount1, quote1.cost.mul(new BN(110)).div(new BN(100)), new BN(0))
         .accounts({
           config: configPda,
           tokenLaunch: tokenLaunchPda2,
@@ -1354,6 +3496,7 @@ describe("Bonding Curve Trading", () => {
       const quote2 = await program.methods
         .getBuyQuote(tradeAmount2)
         .accounts({
+          config: configPda,
           bondingCurve: bondingCurvePda3,
           tokenLaunch: tokenLaunchPda3,
         })
@@ -1362,7 +3505,7 @@ describe("Bonding Curve Trading", () => {
       console.log(`   Expected cost: ${(Number(quote2.cost) / 1e9).toFixed(6)} SOL`);
 
       await program.methods
-        .buyTokens(tradeAmount2, quote2.cost.mul(new BN(110)).div(new BN(100)))
+        .buyTokens(tradeAmount2, quote2.cost.mul(new BN(110)).div(new BN(100)), new BN(0))
         .accounts({
           config: configPda,
           tokenLaunch: tokenLaunchPda3,
@@ -1395,6 +3538,7 @@ describe("Bonding Curve Trading", () => {
       const quote3 = await program.methods
         .getBuyQuote(tradeAmount3)
         .accounts({
+          config: configPda,
           bondingCurve: bondingCurvePda2,
           tokenLaunch: tokenLaunchPda2,
         })
@@ -1403,7 +3547,7 @@ describe("Bonding Curve Trading", () => {
       console.log(`   Expected cost: ${(Number(quote3.cost) / 1e9).toFixed(6)} SOL`);
 
       await program.methods
-        .buyTokens(tradeAmount3, quote3.cost.mul(new BN(110)).div(new BN(100)))
+        .buyTokens(tradeAmount3, quote3.cost.mul(new BN(110)).div(new BN(100)), new BN(0))
         .accounts({
           config: configPda,
           tokenLaunch: tokenLaunchPda2,
@@ -1440,6 +3584,7 @@ describe("Bonding Curve Trading", () => {
       const quote4 = await program.methods
         .getBuyQuote(tradeAmount4)
         .accounts({
+          config: configPda,
           bondingCurve: bondingCurvePda2,
           tokenLaunch: tokenLaunchPda2,
         })
@@ -1448,7 +3593,7 @@ describe("Bonding Curve Trading", () => {
       console.log(`   Expected cost: ${(Number(quote4.cost) / 1e9).toFixed(6)} SOL`);
 
       await program.methods
-        .buyTokens(tradeAmount4, quote4.cost.mul(new BN(110)).div(new BN(100)))
+        .buyTokens(tradeAmount4, quote4.cost.mul(new BN(110)).div(new BN(100)), new BN(0))
         .accounts({
           config: configPda,
           tokenLaunch: tokenLaunchPda2,
@@ -1602,6 +3747,7 @@ describe("Bonding Curve Trading", () => {
       const quote = await program.methods
         .getBuyQuote(oneToken)
         .accounts({
+          config: configPda,
           bondingCurve: bondingCurvePda4,
           tokenLaunch: tokenLaunchPda4,
         })
@@ -1649,7 +3795,7 @@ describe("Bonding Curve Trading", () => {
       const maxCost = new BN(Math.floor(Number(quote.cost) * 1.5));
       
       await program.methods
-        .buyTokens(oneToken, maxCost)
+        .buyTokens(oneToken, maxCost, new BN(0))
         .accounts({
           config: configPda,
           tokenLaunch: tokenLaunchPda4,
@@ -0,0 +1,249 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN } from "@coral-xyz/anchor";
+import { NotmarketSolana } from "../target/types/notmarket_solana";
+import {
+  PublicKey,
+  Keypair,
+  SystemProgram,
+  LAMPORTS_PER_SOL,
+} from "@solana/web3.js";
+import {
+  TOKEN_PROGRAM_ID,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
+
+/**
+ * Shared test setup: PDA derivation, launch creation with default parameters
+ * and buy/sell wrappers, so each test file only holds its own checks
+ */
+
+// Pyth sponsored SOL/USD price update account
+export const SOL_USD_PRICE_FEED = new PublicKey("7UVimffxr9ow1uXYxsr4LHAcV58mLzhmwaeKvJ1pjLiE");
+
+export const ONE_TOKEN = new BN(1_000_000_000);
+export const MILLION = new BN(1_000_000).mul(ONE_TOKEN); // 1M tokens
+
+export const configPdaFor = (program: Program<NotmarketSolana>) =>
+  PublicKey.findProgramAddressSync([Buffer.from("launchpad_config")], program.programId)[0];
+
+/**
+ * Lets trades without a Pyth account price off the stored SOL price. The test
+ * validator has no live feed, so the suites turn this on up front
+ */
+export const setManualPriceAllowed = (program: Program<NotmarketSolana>, allowed: boolean) =>
+  program.methods
+    .updateManualPriceAllowed(allowed)
+    .accounts({ config: configPdaFor(program), authority: program.provider.publicKey })
+    .rpc();
+
+export const airdrop = async (provider: anchor.AnchorProvider, wallet: PublicKey, sol: number) => {
+  await provider.connection.confirmTransaction(
+    await provider.connection.requestAirdrop(wallet, sol * LAMPORTS_PER_SOL)
+  );
+};
+
+/** CreateLaunchParams fields; anything left out takes the default below */
+export interface LaunchOptions {
+  symbol?: string;
+  metadataUri?: string;
+  description?: string;
+  solPriceUsd?: BN; // default $150
+  tradingStartsAt?: BN;
+  isMutable?: boolean;
+  sellsEnabled?: boolean;
+  maxSolRaiseLamports?: BN;
+  targetSolVaultLamports?: BN;
+  maxTrades?: BN;
+  expectedPriceFeed?: PublicKey;
+  graduationAction?: object;
+  curveType?: object;
+  creatorAllocationBps?: number;
+}
+
+export interface BuyOptions {
+  maxSolCost?: BN; // default 10 SOL
+  deadline?: BN;
+  accounts?: Record<string, PublicKey | null>;
+}
+
+export interface SellOptions {
+  minSolOutput?: BN;
+  deadline?: BN;
+  accounts?: Record<string, PublicKey | null>;
+}
+
+/**
+ * The launch `creator` creates under `name`, with every PDA derived up front.
+ *
+ * `feeRecipient` is read from the launchpad config by `create` (or `load` for a
+ * launch created elsewhere), so trades can be sent straight after.
+ */
+export class TestLaunch {
+  readonly config: PublicKey;
+  readonly mint: PublicKey;
+  readonly tokenLaunch: PublicKey;
+  readonly bondingCurve: PublicKey;
+  readonly curveTokenAccount: PublicKey;
+  readonly solVault: PublicKey;
+  readonly lpVault: PublicKey;
+  readonly creatorVault: PublicKey;
+  readonly tradeHistory: PublicKey;
+  feeRecipient: PublicKey;
+
+  constructor(
+    readonly program: Program<NotmarketSolana>,
+    readonly creator: PublicKey,
+    readonly name: string
+  ) {
+    const programId = program.programId;
+    this.config = configPdaFor(program);
+    [this.mint] = PublicKey.findProgramAddressSync(
+      [Buffer.from("mint"), creator.toBuffer(), Buffer.from(name)],
+      programId
+    );
+    [this.tokenLaunch] = PublicKey.findProgramAddressSync(
+      [Buffer.from("token_launch"), this.mint.toBuffer()],
+      programId
+    );
+    [this.bondingCurve] = PublicKey.findProgramAddressSync(
+      [Buffer.from("bonding_curve"), this.tokenLaunch.toBuffer()],
+      programId
+    );
+    this.curveTokenAccount = getAssociatedTokenAddressSync(this.mint, this.bondingCurve, true);
+    [this.solVault] = PublicKey.findProgramAddressSync(
+      [Buffer.from("sol_vault"), this.bondingCurve.toBuffer()],
+      programId
+    );
+    [this.lpVault] = PublicKey.findProgramAddressSync(
+      [Buffer.from("lp_vault"), this.bondingCurve.toBuffer()],
+      programId
+    );
+    [this.creatorVault] = PublicKey.findProgramAddressSync(
+      [Buffer.from("creator_vault"), this.bondingCurve.toBuffer()],
+      programId
+    );
+    [this.tradeHistory] = PublicKey.findProgramAddressSync(
+      [Buffer.from("trade_history"), this.tokenLaunch.toBuffer()],
+      programId
+    );
+  }
+
+  async load() {
+    const config = await this.program.account.launchpadConfig.fetch(this.config);
+    this.feeRecipient = config.feeRecipient;
+    return this;
+  }
+
+  positionOf(wallet: PublicKey) {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("user_position"), wallet.toBuffer(), this.tokenLaunch.toBuffer()],
+      this.program.programId
+    )[0];
+  }
+
+  tokenAccountOf(wallet: PublicKey) {
+    return getAssociatedTokenAddressSync(this.mint, wallet);
+  }
+
+  createMethod(options: LaunchOptions = {}) {
+    return this.program.methods
+      .createTokenLaunch({
+        name: this.name,
+        symbol: options.symbol ?? "TEST",
+        metadataUri: options.metadataUri ?? "https://example.com/test.json",
+        description: options.description ?? "Launch used by the test suite",
+        solPriceUsd: options.solPriceUsd ?? new BN(150_00000000),
+        tradingStartsAt: options.tradingStartsAt ?? new BN(0),
+        isMutable: options.isMutable ?? true,
+        sellsEnabled: options.sellsEnabled ?? true,
+        maxSolRaiseLamports: options.maxSolRaiseLamports ?? new BN(0),
+        targetSolVaultLamports: options.targetSolVaultLamports ?? new BN(0),
+        maxTrades: options.maxTrades ?? new BN(0),
+        expectedPriceFeed: options.expectedPriceFeed ?? SOL_USD_PRICE_FEED,
+        graduationAction: options.graduationAction ?? { pool: {} },
+        curveType: options.curveType ?? { exponential: {} },
+        creatorAllocationBps: options.creatorAllocationBps ?? 0,
+      })
+      .accounts({
+        config: this.config,
+        tokenLaunch: this.tokenLaunch,
+        mint: this.mint,
+        bondingCurve: this.bondingCurve,
+        curveTokenAccount: this.curveTokenAccount,
+        lpVault: this.lpVault,
+        solVault: this.solVault,
+        creator: this.creator,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      });
+  }
+
+  /** Creates the launch; pass the creator as a signer unless it's the provider wallet */
+  async create(options: LaunchOptions = {}, signers: Keypair[] = []) {
+    const signature = await this.createMethod(options).signers(signers).rpc();
+    await this.load();
+    return signature;
+  }
+
+  buyMethod(wallet: Keypair, amount: BN, options: BuyOptions = {}) {
+    return this.program.methods
+      .buyTokens(
+        amount,
+        options.maxSolCost ?? new BN(10 * LAMPORTS_PER_SOL),
+        options.deadline ?? new BN(0)
+      )
+      .accounts({
+        config: this.config,
+        tokenLaunch: this.tokenLaunch,
+        bondingCurve: this.bondingCurve,
+        curveTokenAccount: this.curveTokenAccount,
+        solVault: this.solVault,
+        userPosition: this.positionOf(wallet.publicKey),
+        mint: this.mint,
+        buyerTokenAccount: this.tokenAccountOf(wallet.publicKey),
+        buyer: wallet.publicKey,
+        feeRecipient: this.feeRecipient,
+        tradeHistory: this.tradeHistory,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        ...options.accounts,
+      })
+      .signers([wallet]);
+  }
+
+  buy(wallet: Keypair, amount: BN, options: BuyOptions = {}) {
+    return this.buyMethod(wallet, amount, options).rpc();
+  }
+
+  sellMethod(wallet: Keypair, amount: BN, options: SellOptions = {}) {
+    return this.program.methods
+      .sellTokens(
+        amount,
+        options.minSolOutput ?? new BN(0),
+        options.deadline ?? new BN(0)
+      )
+      .accounts({
+        config: this.config,
+        tokenLaunch: this.tokenLaunch,
+        bondingCurve: this.bondingCurve,
+        curveTokenAccount: this.curveTokenAccount,
+        solVault: this.solVault,
+        userPosition: this.positionOf(wallet.publicKey),
+        sellerTokenAccount: this.tokenAccountOf(wallet.publicKey),
+        seller: wallet.publicKey,
+        feeRecipient: this.feeRecipient,
+        tradeHistory: this.tradeHistory,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        ...options.accounts,
+      })
+      .signers([wallet]);
+  }
+
+  sell(wallet: Keypair, amount: BN, options: SellOptions = {}) {
+    return this.sellMethod(wallet, amount, options).rpc();
+  }
+}
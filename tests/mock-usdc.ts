@@ -154,6 +154,28 @@ describe("Mock USDC", () => {
     expect(recipientBalance.value.uiAmount).to.equal(100);
   });
 
+  it("Rejects a transfer to the same token account", async () => {
+    const userTokenAccount = await getAssociatedTokenAddress(
+      mintKeypair.publicKey,
+      userWallet.publicKey
+    );
+
+    try {
+      await program.methods
+        .transfer(new anchor.BN(1_000_000))
+        .accounts({
+          from: userTokenAccount,
+          to: userTokenAccount,
+          authority: userWallet.publicKey,
+        })
+        .signers([userWallet])
+        .rpc();
+      expect.fail("Self-transfer should be rejected");
+    } catch (error) {
+      expect(error.toString()).to.include("SelfTransfer");
+    }
+  });
+
   it("Can mint additional tokens (simulating faucet)", async () => {
     const userTokenAccount = await getAssociatedTokenAddress(
       mintKeypair.publicKey,
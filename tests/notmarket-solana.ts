@@ -1,18 +1,25 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program, BN } from "@coral-xyz/anchor";
 import { NotmarketSolana } from "../target/types/notmarket_solana";
-import { 
-  PublicKey, 
-  Keypair, 
+import {
+  PublicKey,
+  Keypair,
   SystemProgram,
-  LAMPORTS_PER_SOL 
+  LAMPORTS_PER_SOL,
+  Transaction,
 } from "@solana/web3.js";
 import {
   TOKEN_PROGRAM_ID,
   ASSOCIATED_TOKEN_PROGRAM_ID,
   getAssociatedTokenAddressSync,
+  createMint,
+  getAccount,
+  getOrCreateAssociatedTokenAccount,
+  transfer,
+  createAssociatedTokenAccount,
 } from "@solana/spl-token";
 import { assert } from "chai";
+import { TestLaunch, configPdaFor, airdrop, setManualPriceAllowed, MILLION } from "./helpers";
 
 describe("notmarket-solana", () => {
   const provider = anchor.AnchorProvider.env();
@@ -79,6 +86,9 @@ describe("notmarket-solana", () => {
       console.log(`   Fee Recipient: ${feeRecipient.toString()}`);
     }
 
+    // No Pyth feed on the test validator: trades price off the stored SOL price
+    await setManualPriceAllowed(program, true);
+
     // Airdrop SOL to test accounts - 100,000 SOL each to ensure we have enough for all rent-exempt minimums
     const airdropAmount = 100000 * LAMPORTS_PER_SOL;
     
@@ -198,315 +208,741 @@ describe("notmarket-solana", () => {
           .rpc();
         assert.fail("Should have failed to create duplicate");
       } catch (error) {
-        // Expected to fail
-        assert.ok(error);
+        // Should surface the friendly error rather than "already in use"
+        assert.include(error.toString(), "AlreadyInitialized");
       }
     });
-  });
 
-  describe("Buying Tokens", () => {
-    let buyerTokenAccount: PublicKey;
-    let userPositionPda: PublicKey;
+    it("Resolves all launch addresses from the mint", async () => {
+      const addresses = await program.methods
+        .resolveLaunch()
+        .accounts({
+          mint: mintPda,
+          tokenLaunch: tokenLaunchPda,
+          bondingCurve: bondingCurvePda,
+          solVault: solVaultPda,
+          curveTokenAccount,
+        })
+        .view();
 
-    before(() => {
-      buyerTokenAccount = getAssociatedTokenAddressSync(
-        mintPda,
-        buyer.publicKey,
-        false
-      );
+      assert.ok(addresses.mint.equals(mintPda));
+      assert.ok(addresses.tokenLaunch.equals(tokenLaunchPda));
+      assert.ok(addresses.bondingCurve.equals(bondingCurvePda));
+      assert.ok(addresses.solVault.equals(solVaultPda));
+      assert.ok(addresses.curveTokenAccount.equals(curveTokenAccount));
+      console.log("✅ Resolved launch addresses match creation");
+    });
 
-      [userPositionPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("user_position"), buyer.publicKey.toBuffer(), tokenLaunchPda.toBuffer()],
-        program.programId
+    it("Verifies the launch creator from the mint", async () => {
+      const verified = await program.methods
+        .verifyLaunch()
+        .accounts({ mint: mintPda })
+        .view();
+
+      assert.ok(verified.creator.equals(creator.publicKey));
+      assert.ok(verified.bondingCurve.equals(bondingCurvePda));
+      assert.isTrue(verified.isActive);
+      assert.isFalse(verified.isGraduated);
+    });
+
+    it("Rejects verification of a mint not launched by the program", async () => {
+      const foreignMint = await createMint(
+        provider.connection,
+        creator,
+        creator.publicKey,
+        null,
+        9
       );
+
+      try {
+        await program.methods
+          .verifyLaunch()
+          .accounts({ mint: foreignMint })
+          .view();
+        assert.fail("Foreign mint should not verify");
+      } catch (error) {
+        assert.include(error.toString(), "TokenLaunchNotFound");
+      }
     });
 
-    it("Buys tokens from bonding curve", async () => {
-      const buyAmount = new BN(1_000_000_000); // 1 token (with 9 decimals)
-      const maxSolCost = new BN(LAMPORTS_PER_SOL * 10); // 10 SOL max to cover token cost + rent + fees
+    /**
+     * Test character validation of token names and symbols
+     */
+    describe("Token Name Validation", () => {
+      const createLaunch = async (name: string, symbol: string) => {
+        const launch = new TestLaunch(program, authority.publicKey, name);
+        await launch.create({
+          symbol,
+          metadataUri: "https://example.com/name.json",
+          description: "Launch used to test name validation",
+        });
+        return launch;
+      };
 
-      const buyerBalanceBefore = await provider.connection.getBalance(buyer.publicKey);
+      const expectRejected = async (name: string, symbol: string, errorName: string) => {
+        try {
+          await createLaunch(name, symbol);
+          assert.fail(`Launch "${name}" / "${symbol}" should be rejected`);
+        } catch (error) {
+          assert.include(error.toString(), errorName);
+        }
+      };
 
-      // Set up event listener for tokensPurchased event
-      let eventReceived = false;
-      let purchaseEvent: any = null;
+      it("Rejects names with control or zero-width characters", async () => {
+        await expectRejected("Bad\nName", "BAD", "InvalidTokenName");
+        await expectRejected("Zero\u200BWidth", "ZERO", "InvalidTokenName");
+      });
 
-      const listener = program.addEventListener("tokensPurchased", (event, slot) => {
-        console.log("\n🎯 EVENT RECEIVED - TokensPurchased:");
-        console.log("   Buyer:", event.buyer.toString());
-        console.log("   Token Amount:", event.tokenAmount.toString(), `(${(event.tokenAmount.toNumber() / 1e9).toFixed(2)} tokens)`);
-        console.log("   SOL Amount:", event.solAmount.toString(), `(${(event.solAmount.toNumber() / 1e9).toFixed(6)} SOL)`);
-        console.log("   Platform Fee:", event.platformFee.toString(), `(${(event.platformFee.toNumber() / 1e9).toFixed(6)} SOL)`);
-        console.log("   Tokens Sold After:", event.tokensSoldAfter.toString(), `(${(event.tokensSoldAfter.toNumber() / 1e9).toFixed(2)} tokens)`);
-        console.log("   SOL Reserve After:", event.solReserveAfter.toString(), `(${(event.solReserveAfter.toNumber() / 1e9).toFixed(6)} SOL)`);
-        console.log("   Price Per Token:", event.pricePerToken.toString(), `(${(event.pricePerToken.toNumber() / 1e9).toFixed(9)} SOL per token)`);
-        console.log("   Slot:", slot);
-        eventReceived = true;
-        purchaseEvent = event;
+      it("Rejects homoglyph names and symbols", async () => {
+        // Cyrillic "о" and "О" standing in for Latin "o" and "O"
+        await expectRejected("D\u043Ege Coin", "DOGE", "InvalidTokenName");
+        await expectRejected("Doge Coin", "D\u041EGE", "InvalidTokenSymbol");
       });
 
-      let txSignature: string;
-      try {
-        // Send transaction normally  
-        txSignature = await program.methods
-          .buyTokens(buyAmount, maxSolCost)
+      it("Rejects symbols with whitespace", async () => {
+        await expectRejected("Spaced Symbol", "SP ACE", "InvalidTokenSymbol");
+      });
+
+      it("Checks multibyte names at the 32-byte boundary", async () => {
+        // 16 two-byte characters fill exactly 32 bytes: length passes, charset doesn't
+        const multibyte = "\u00e9".repeat(16);
+        assert.equal(Buffer.byteLength(multibyte), 32);
+        await expectRejected(multibyte, "MB", "InvalidTokenName");
+      });
+
+      it("Stores a full 32-byte name without truncation", async () => {
+        const fullName = "N".repeat(32);
+        const launch = await createLaunch(fullName, "FULL");
+
+        const tokenLaunch = await program.account.tokenLaunch.fetch(launch.tokenLaunch);
+        assert.equal(tokenLaunch.name, fullName);
+      });
+
+      it("Accepts ordinary printable names and symbols", async () => {
+        const launch = await createLaunch("Plain Name 2.0", "PLN2");
+
+        const tokenLaunch = await program.account.tokenLaunch.fetch(launch.tokenLaunch);
+        assert.equal(tokenLaunch.name, "Plain Name 2.0");
+        assert.equal(tokenLaunch.symbol, "PLN2");
+      });
+    });
+
+    /**
+     * Test the metadata URI length bound at the limit this build was compiled with
+     */
+    describe("URI Length", () => {
+      const uriPrefix = "https://arweave.net/";
+
+      // The bound is compile-time configurable, so read it from the IDL rather than hardcoding 200
+      const maxUriLen = Number(
+        program.idl.constants.find((c) => c.name.replace(/_/g, "").toLowerCase() === "maxmetadataurilen").value
+      );
+      const uriOfLength = (length: number) => uriPrefix + "a".repeat(length - uriPrefix.length);
+
+      const createLaunch = async (name: string, uri: string) => {
+        const launch = new TestLaunch(program, authority.publicKey, name);
+        await launch.create({
+          symbol: "ULEN",
+          metadataUri: uri,
+          description: "Launch used to test the URI length bound",
+        });
+        return launch;
+      };
+
+      const updateUri = (launch: TestLaunch, uri: string) =>
+        program.methods
+          .updateMetadataUri(uri)
           .accounts({
-            config: configPda,
-            tokenLaunch: tokenLaunchPda,
-            bondingCurve: bondingCurvePda,
-            curveTokenAccount,
-            solVault: solVaultPda,
-            userPosition: userPositionPda,
-            mint: mintPda,
-            buyerTokenAccount,
-            buyer: buyer.publicKey,
-            feeRecipient: feeRecipient,
-            tokenProgram: TOKEN_PROGRAM_ID,
-            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-            systemProgram: SystemProgram.programId,
+            config: launch.config,
+            tokenLaunch: launch.tokenLaunch,
+            bondingCurve: launch.bondingCurve,
+            creator: authority.publicKey,
           })
-          .signers([buyer])
           .rpc();
 
-        console.log("✅ Buy tokens tx:", txSignature);
+      it("Accepts a URI of exactly the maximum length", async () => {
+        assert.isAtLeast(maxUriLen, 200, "The default build allows 200 bytes");
+        const uri = uriOfLength(maxUriLen);
+        const launch = await createLaunch("Max Uri Token", uri);
 
-        // Wait a bit for event to be processed
-        await new Promise(resolve => setTimeout(resolve, 1000));
+        const tokenLaunch = await program.account.tokenLaunch.fetch(launch.tokenLaunch);
+        assert.equal(tokenLaunch.metadataUri, uri);
+        assert.equal(tokenLaunch.metadataUri.length, maxUriLen);
+      });
 
-        // Verify event was received and amounts match
-        if (eventReceived && purchaseEvent) {
-          console.log("\n✅ Event verification:");
-          console.log("   Requested amount:", buyAmount.toString(), "tokens");
-          console.log("   Event amount:", purchaseEvent.tokenAmount.toString(), "tokens");
-          assert.equal(purchaseEvent.tokenAmount.toString(), buyAmount.toString(), "Event token amount should match requested amount");
-          console.log("   ✓ Amounts match!");
-        }
-        
-        // Get transaction details
-        const txDetails = await provider.connection.getTransaction(txSignature, {
-          maxSupportedTransactionVersion: 0,
-          commitment: "confirmed"
-        });
-        
-        if (txDetails?.meta?.logMessages) {
-          console.log("\n=== TRANSACTION LOGS ===");
-          txDetails.meta.logMessages.forEach((log: string) => console.log(log));
-          console.log("=== END LOGS ===\n");
-        }
-        
-        // Check vault balance immediately after transaction
-        const vaultBalanceAfterTx = await provider.connection.getBalance(solVaultPda);
-        console.log("\n🏦 SOL VAULT BALANCE (immediately after tx):", vaultBalanceAfterTx, "lamports");
-        
-      } catch (error) {
-        // Remove listener in case of error
-        await program.removeEventListener(listener);
-        // Catch any errors and show logs
-        console.log("\n❌ Transaction error:", error.message);
-        const vaultBalanceOnError = await provider.connection.getBalance(solVaultPda);
-        console.log("🏦 SOL VAULT BALANCE (on error):", vaultBalanceOnError, "lamports");
-        
-        if (error.logs) {
-          console.log("\n=== ERROR LOGS ===");
-          error.logs.forEach((log: string) => console.log(log));
-          console.log("=== END LOGS ===\n");
+      it("Rejects a launch with a URI one byte over the maximum", async () => {
+        try {
+          await createLaunch("Long Uri Token", uriOfLength(maxUriLen + 1));
+          assert.fail("URI above the maximum length should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "UriTooLong");
         }
-        
-        // If it's just a rent simulation error but the program logic succeeded, continue
-        if (error.message?.includes("insufficient funds for rent") && error.logs) {
-          const hasSuccessLog = error.logs.some((log: string) => log.includes("Bought"));
-          if (hasSuccessLog) {
-            console.log("⚠️  Transaction simulated successfully (program logic works), continuing test...\n");
-            return; // Don't throw, let test continue
-          }
+      });
+
+      it("Applies the same bound to URI updates", async () => {
+        const launch = new TestLaunch(program, authority.publicKey, "Max Uri Token");
+
+        try {
+          await updateUri(launch, uriOfLength(maxUriLen + 1));
+          assert.fail("URI update above the maximum length should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "UriTooLong");
         }
-        throw error; // Re-throw all other errors
-      }
 
-      // Verify buyer received tokens
-      const buyerBalance = await provider.connection.getTokenAccountBalance(buyerTokenAccount);
-      console.log("\n💰 BUYER TOKEN BALANCE:", buyerBalance.value.amount, "tokens");
-      assert.ok(new BN(buyerBalance.value.amount).gte(buyAmount));
+        const tokenLaunch = await program.account.tokenLaunch.fetch(launch.tokenLaunch);
+        assert.equal(tokenLaunch.metadataUri, uriOfLength(maxUriLen), "Rejected update leaves the URI untouched");
+      });
+    });
 
-      // Verify bonding curve updated
-      const bondingCurve = await program.account.bondingCurve.fetch(bondingCurvePda);
-      assert.ok(bondingCurve.tokensSold.gt(new BN(0)), "Tokens should be sold");
-      
-      console.log("\n📊 BONDING CURVE STATE:");
-      console.log("  Tokens Sold:", bondingCurve.tokensSold.toString(), `(${Number(bondingCurve.tokensSold) / 1e9} tokens)`);
-      console.log("  SOL Reserve:", bondingCurve.solReserve.toString(), "lamports");
-      console.log("  Token Reserve:", bondingCurve.tokenReserve.toString(), `(${Number(bondingCurve.tokenReserve) / 1e9} tokens)`);
-      console.log("  Total Volume:", bondingCurve.totalVolume.toString(), "lamports");
-      console.log("  Trade Count:", bondingCurve.tradeCount.toString());
-      console.log("  Is Graduated:", bondingCurve.isGraduated);
-      
-      assert.equal(bondingCurve.tradeCount.toString(), "1");
+    /**
+     * Test the admin allowlist of metadata URI hosts
+     */
+    describe("URI Allowlist", () => {
+      const allowedPrefix = "https://arweave.net/";
+
+      const createLaunch = async (name: string, uri: string) => {
+        const launch = new TestLaunch(program, authority.publicKey, name);
+        await launch.create({
+          symbol: "URI",
+          metadataUri: uri,
+          description: "Launch used to test the URI allowlist",
+        });
+        return launch;
+      };
 
-      // Verify user position created
-      const userPosition = await program.account.userPosition.fetch(userPositionPda);
-      assert.ok(userPosition.tokenAmount.gte(buyAmount), "User should have tokens");
-      
-      console.log("\n👤 USER POSITION:");
-      console.log("  Token Amount:", userPosition.tokenAmount.toString(), `(${userPosition.tokenAmount.toNumber() / 1e9} tokens)`);
-      console.log("  SOL Invested:", userPosition.solInvested.toString(), "lamports");
-      console.log("  SOL Received:", userPosition.solReceived.toString(), "lamports");
-      console.log("  Buy Count:", userPosition.buyCount);
-      console.log("  Sell Count:", userPosition.sellCount);
-      
-      assert.equal(userPosition.buyCount, 1, "Buy count should be 1");
+      const setAllowedPrefixes = (prefixes: string[]) =>
+        program.methods
+          .updateAllowedUriPrefixes(prefixes)
+          .accounts({ config: configPdaFor(program), authority: authority.publicKey })
+          .rpc();
 
-      // Verify SOL was spent (balance should decrease including fees)
-      const buyerBalanceAfter = await provider.connection.getBalance(buyer.publicKey);
-      console.log("\n💵 BUYER SOL BALANCE:");
-      console.log("  Before:", buyerBalanceBefore, "lamports");
-      console.log("  After:", buyerBalanceAfter, "lamports");
-      console.log("  Spent:", buyerBalanceBefore - buyerBalanceAfter, "lamports");
-      assert.ok(buyerBalanceAfter < buyerBalanceBefore, "Buyer balance should decrease after buying");
-      
-      // Check SOL vault balance
-      const vaultBalance = await provider.connection.getBalance(solVaultPda);
-      console.log("\n🏦 SOL VAULT BALANCE:", vaultBalance, "lamports");
+      before(async () => {
+        await setAllowedPrefixes([allowedPrefix]);
+      });
 
-      // Cleanup: ensure listener is removed
-      await program.removeEventListener(listener);
-    });
+      after(async () => {
+        await setAllowedPrefixes([]);
+      });
 
-    it("Buys more tokens (second purchase)", async () => {
-      const buyAmount = new BN(5_000_000_000); // 5 tokens
-      const maxSolCost = new BN(LAMPORTS_PER_SOL * 10); // 10 SOL max
+      it("Rejects prefixes that don't pin a host", async () => {
+        try {
+          await setAllowedPrefixes(["https://arweave.net"]);
+          assert.fail("Prefix without a trailing slash should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "InvalidUri");
+        }
 
-      const bondingCurveBefore = await program.account.bondingCurve.fetch(bondingCurvePda);
-      const tokensSoldBefore = bondingCurveBefore.tokensSold;
-      
-      console.log("\n📊 BEFORE 2ND BUY - Bonding Curve:");
-      console.log("  Tokens Sold:", bondingCurveBefore.tokensSold.toString(), `(${bondingCurveBefore.tokensSold.toNumber() / 1e9} tokens)`);
-      console.log("  SOL Reserve:", bondingCurveBefore.solReserve.toString(), "lamports");
+        const config = await program.account.launchpadConfig.fetch(configPdaFor(program));
+        assert.deepEqual(config.allowedUriPrefixes, [allowedPrefix]);
+      });
 
-      let txSignature: string;
-      try {
-        txSignature = await program.methods
-          .buyTokens(buyAmount, maxSolCost)
+      it("Rejects a launch hosted on a disallowed host", async () => {
+        try {
+          await createLaunch("Phishing Uri Token", "https://arweave.net.phishing.example/meta.json");
+          assert.fail("Launch on a disallowed host should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "InvalidUri");
+        }
+      });
+
+      it("Allows a launch on an allowed host, but not a URI update off it", async () => {
+        const launch = await createLaunch(
+          "Allowed Uri Token",
+          `${allowedPrefix}abc123`
+        );
+
+        try {
+          await program.methods
+            .updateMetadataUri("https://phishing.example/meta.json")
+            .accounts({
+              config: launch.config,
+              tokenLaunch: launch.tokenLaunch,
+              bondingCurve: launch.bondingCurve,
+              creator: authority.publicKey,
+            })
+            .rpc();
+          assert.fail("URI update to a disallowed host should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "InvalidUri");
+        }
+
+        const newUri = `${allowedPrefix}def456`;
+        await program.methods
+          .updateMetadataUri(newUri)
           .accounts({
-            config: configPda,
-            tokenLaunch: tokenLaunchPda,
-            bondingCurve: bondingCurvePda,
-            curveTokenAccount,
-            solVault: solVaultPda,
-            userPosition: userPositionPda,
-            mint: mintPda,
-            buyerTokenAccount,
-            buyer: buyer.publicKey,
-            feeRecipient: feeRecipient,
-            tokenProgram: TOKEN_PROGRAM_ID,
-            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-            systemProgram: SystemProgram.programId,
+            config: launch.config,
+            tokenLaunch: launch.tokenLaunch,
+            bondingCurve: launch.bondingCurve,
+            creator: authority.publicKey,
           })
-          .signers([buyer])
           .rpc();
 
-        console.log("✅ Second buy tx:", txSignature);
-        
-        // Get transaction details
-        const txDetails = await provider.connection.getTransaction(txSignature, {
-          maxSupportedTransactionVersion: 0,
-          commitment: "confirmed"
+        const tokenLaunch = await program.account.tokenLaunch.fetch(launch.tokenLaunch);
+        assert.equal(tokenLaunch.metadataUri, newUri);
+      });
+    });
+
+    /**
+     * Test the sanity bound on the creator-supplied SOL price at launch creation
+     */
+    describe("Initial Price Bounds", () => {
+      const createLaunch = async (name: string, solPriceUsd: BN) => {
+        const launch = new TestLaunch(program, authority.publicKey, name);
+        await launch.create({
+          symbol: "PRICE",
+          metadataUri: "https://example.com/price.json",
+          description: "Launch used to test initial price bounds",
+          solPriceUsd,
         });
-        
-        if (txDetails?.meta?.logMessages) {
-          console.log("\n=== TRANSACTION LOGS (2nd Buy) ===");
-          txDetails.meta.logMessages.forEach((log: string) => console.log(log));
-          console.log("=== END LOGS ===\n");
+        return launch;
+      };
+
+      it("Rejects a SOL price below $1", async () => {
+        try {
+          // 1 = $0.00000001
+          await createLaunch("Too Cheap Token", new BN(1));
+          assert.fail("SOL price below $1 should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "InvalidPrice");
         }
-        
-        // Check vault balance immediately after transaction
-        const vaultBalanceAfterTx = await provider.connection.getBalance(solVaultPda);
-        console.log("\n🏦 SOL VAULT BALANCE (immediately after 2nd buy):", vaultBalanceAfterTx, "lamports");
-        
-      } catch (error) {
-        console.log("\n❌ 2nd buy error:", error.message);
-        const vaultBalanceOnError = await provider.connection.getBalance(solVaultPda);
-        console.log("🏦 SOL VAULT BALANCE (on error):", vaultBalanceOnError, "lamports");
-        
-        if (error.logs) {
-          console.log("\n=== ERROR LOGS (2nd Buy) ===");
-          error.logs.forEach((log: string) => console.log(log));
-          console.log("=== END LOGS ===\n");
+      });
+
+      it("Rejects a SOL price above $100,000", async () => {
+        try {
+          await createLaunch("Too Dear Token", new BN(100_001).mul(new BN(100_000_000)));
+          assert.fail("SOL price above $100,000 should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "InvalidPrice");
         }
-        
-        // If it's just a rent simulation error but the program logic succeeded, continue
-        if (error.message?.includes("insufficient funds for rent") && error.logs) {
-          const hasSuccessLog = error.logs.some((log: string) => log.includes("Bought"));
-          if (hasSuccessLog) {
-            console.log("⚠️  Transaction simulated successfully (program logic works), continuing test...\n");
-            return; // Don't throw, let test continue
-          }
+      });
+
+      it("Accepts prices at the bounds and in between", async () => {
+        for (const [name, dollars] of [
+          ["Floor Price Token", 1],
+          ["Typical Price Token", 150],
+          ["Ceiling Price Token", 100_000],
+        ] as [string, number][]) {
+          const solPriceUsd = new BN(dollars).mul(new BN(100_000_000));
+          const launch = await createLaunch(name, solPriceUsd);
+          const bondingCurve = await program.account.bondingCurve.fetch(launch.bondingCurve);
+          assert.equal(bondingCurve.solPriceUsd.toString(), solPriceUsd.toString());
         }
-        throw error;
-      }
+      });
+    });
 
-      // Verify tokens sold increased
-      const bondingCurveAfter = await program.account.bondingCurve.fetch(bondingCurvePda);
-      
-      console.log("\n📊 AFTER 2ND BUY - Bonding Curve:");
-      console.log("  Tokens Sold:", bondingCurveAfter.tokensSold.toString(), `(${Number(bondingCurveAfter.tokensSold) / 1e9} tokens)`);
-      console.log("  SOL Reserve:", bondingCurveAfter.solReserve.toString(), "lamports");
-      console.log("  Token Reserve:", bondingCurveAfter.tokenReserve.toString(), `(${Number(bondingCurveAfter.tokenReserve) / 1e9} tokens)`);
-      console.log("  Total Volume:", bondingCurveAfter.totalVolume.toString(), "lamports");
-      console.log("  Trade Count:", bondingCurveAfter.tradeCount.toString());
-      
-      assert.ok(bondingCurveAfter.tokensSold.gt(tokensSoldBefore));
-      assert.equal(bondingCurveAfter.tradeCount.toString(), "2");
-      
-      // Check user position
-      const userPosition = await program.account.userPosition.fetch(userPositionPda);
-      console.log("\n👤 USER POSITION (After 2nd Buy):");
-      console.log("  Token Amount:", userPosition.tokenAmount.toString(), `(${userPosition.tokenAmount.toNumber() / 1e9} tokens)`);
-      console.log("  SOL Invested:", userPosition.solInvested.toString(), "lamports");
-      console.log("  Buy Count:", userPosition.buyCount);
-      
-      // Check vault balance
-      const vaultBalance = await provider.connection.getBalance(solVaultPda);
-      console.log("\n🏦 SOL VAULT BALANCE:", vaultBalance, "lamports");
+    /**
+     * Test that TokenLaunchCreated reports where the minted supply actually sits
+     */
+    describe("Launch Created Event", () => {
+      const launch = new TestLaunch(program, authority.publicKey, "Event Token");
+
+      it("Reports allocations matching the curve token account and state", async () => {
+        let createdEvent = null;
+        const listener = program.addEventListener("tokenLaunchCreated", (event) => {
+          createdEvent = event;
+        });
+
+        const signature = await launch
+          .createMethod({
+            symbol: "EVT",
+            metadataUri: "https://example.com/event.json",
+            description: "Launch used to test the creation event",
+          })
+          .rpc({ commitment: "confirmed" });
+
+        await new Promise((resolve) => setTimeout(resolve, 1000));
+        await program.removeEventListener(listener);
+
+        assert.ok(createdEvent, "TokenLaunchCreated should be emitted");
+
+        const curveTokens = await getAccount(provider.connection, launch.curveTokenAccount);
+        const lpTokens = await getAccount(provider.connection, launch.lpVault);
+        const tokenLaunch = await program.account.tokenLaunch.fetch(launch.tokenLaunch);
+        const bondingCurve = await program.account.bondingCurve.fetch(launch.bondingCurve);
+
+        assert.equal(
+          createdEvent.mintedToCurveAccount.toString(),
+          curveTokens.amount.toString(),
+          "minted_to_curve_account should match the curve token balance"
+        );
+        assert.equal(
+          createdEvent.sellableOnCurve.toString(),
+          bondingCurve.tokenReserve.toString(),
+          "sellable_on_curve should match token_reserve"
+        );
+        assert.equal(
+          createdEvent.lpReserved.toString(),
+          lpTokens.amount.toString(),
+          "lp_reserved should match the LP vault balance"
+        );
+        assert.equal(
+          createdEvent.mintedToCurveAccount.add(createdEvent.lpReserved).toString(),
+          tokenLaunch.totalSupply.toString(),
+          "Curve account + LP vault should account for the full supply"
+        );
+
+        // Creation is anchored to the slot the transaction landed in, not just the clock
+        const tx = await provider.connection.getTransaction(signature, {
+          commitment: "confirmed",
+          maxSupportedTransactionVersion: 0,
+        });
+        assert.ok(tokenLaunch.launchSlot.toNumber() > 0, "launch_slot should be recorded");
+        assert.equal(tokenLaunch.launchSlot.toNumber(), tx.slot, "launch_slot should be the creation slot");
+        assert.equal(createdEvent.launchSlot.toString(), tokenLaunch.launchSlot.toString());
+        assert.equal(createdEvent.timestamp.toString(), tokenLaunch.launchTimestamp.toString());
+      });
     });
 
-    it("Fails when slippage exceeded", async () => {
-      const buyAmount = new BN(10_000_000_000); // 10 tokens
-      const maxSolCost = new BN(1); // Unrealistically low
+    /**
+     * Test scheduled launches: trading is rejected until `trading_starts_at`
+     * while view functions keep working for previews
+     */
+    describe("Scheduled Launch", () => {
+      const creator = provider.wallet as anchor.Wallet;
+      const trader = Keypair.generate();
 
-      try {
-        await program.methods
-          .buyTokens(buyAmount, maxSolCost)
+      const START_DELAY_SECONDS = 10;
+
+      const launch = new TestLaunch(program, creator.publicKey, "Scheduled Token");
+      let tradingStartsAt: number;
+
+      const buyOneToken = () =>
+        launch.buy(trader, new BN(1_000_000_000), { maxSolCost: new BN(LAMPORTS_PER_SOL) });
+
+      before(async () => {
+        await airdrop(provider, trader.publicKey, 10);
+      });
+
+      it("Creates a launch with a future trading start", async () => {
+        const slot = await provider.connection.getSlot();
+        const now = await provider.connection.getBlockTime(slot);
+        tradingStartsAt = now + START_DELAY_SECONDS;
+
+        await launch.create({
+          symbol: "SCHED",
+          metadataUri: "https://example.com/scheduled.json",
+          description: "Launch with a delayed trading start",
+          tradingStartsAt: new BN(tradingStartsAt),
+        });
+
+        const tokenLaunch = await program.account.tokenLaunch.fetch(launch.tokenLaunch);
+        assert.equal(tokenLaunch.tradingStartsAt.toNumber(), tradingStartsAt);
+      });
+
+      it("Still serves quotes before trading starts", async () => {
+        const spotPrice = await program.methods
+          .getSpotPrice()
           .accounts({
-            config: configPda,
-            tokenLaunch: tokenLaunchPda,
-            bondingCurve: bondingCurvePda,
-            curveTokenAccount,
-            solVault: solVaultPda,
-            userPosition: userPositionPda,
-            mint: mintPda,
-            buyerTokenAccount,
-            buyer: buyer.publicKey,
-            feeRecipient: feeRecipient,
+            tokenLaunch: launch.tokenLaunch,
+            bondingCurve: launch.bondingCurve,
+          })
+          .view();
+
+        assert.ok(spotPrice.spotPrice.toNumber() > 0, "Spot price should be available");
+      });
+
+      it("Rejects buys before the scheduled start", async () => {
+        try {
+          await buyOneToken();
+          assert.fail("Buy should fail before trading starts");
+        } catch (error) {
+          assert.include(error.toString(), "TradingNotStarted");
+        }
+      });
+
+      it("Allows buys after the scheduled start", async () => {
+        await new Promise((resolve) => setTimeout(resolve, (START_DELAY_SECONDS + 2) * 1000));
+
+        await buyOneToken();
+
+        const bondingCurve = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        assert.equal(bondingCurve.tokensSold.toString(), "1000000000");
+      });
+    });
+
+    /**
+     * Test buy-only launches, which reject sells back to the curve
+     */
+    describe("Buy-Only Launch", () => {
+      const trader = Keypair.generate();
+
+      const launch = new TestLaunch(program, authority.publicKey, "Buy Only Token");
+
+      before(async () => {
+        await airdrop(provider, trader.publicKey, 20);
+
+        await launch.create({
+          symbol: "BUYO",
+          metadataUri: "https://example.com/buy-only.json",
+          description: "Launch used to test buy-only mechanics",
+          sellsEnabled: false,
+        });
+      });
+
+      it("Records the launch as buy-only", async () => {
+        const tokenLaunch = await program.account.tokenLaunch.fetch(launch.tokenLaunch);
+        assert.isTrue(tokenLaunch.sellsDisabled);
+      });
+
+      it("Allows buys", async () => {
+        await launch.buy(trader, MILLION.muln(2));
+
+        const position = await program.account.userPosition.fetch(launch.positionOf(trader.publicKey));
+        assert.equal(position.tokenAmount.toString(), MILLION.muln(2).toString());
+      });
+
+      it("Rejects sells back to the curve", async () => {
+        const curveBefore = await program.account.bondingCurve.fetch(launch.bondingCurve);
+
+        try {
+          await launch.sell(trader, MILLION);
+          assert.fail("Sell on a buy-only launch should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "SellsDisabled");
+        }
+
+        try {
+          await program.methods
+            .simulateTrade({ sell: {} }, MILLION)
+            .accounts({ tokenLaunch: launch.tokenLaunch, bondingCurve: launch.bondingCurve, config: launch.config })
+            .view();
+          assert.fail("Simulated sell on a buy-only launch should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "SellsDisabled");
+        }
+
+        const curveAfter = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        assert.equal(curveAfter.solReserve.toString(), curveBefore.solReserve.toString());
+      });
+    });
+
+    /**
+     * Test the creator allocation minted to a vesting vault instead of the curve
+     */
+    describe("Creator Allocation", () => {
+      // The admin is the creator here
+      const trader = Keypair.generate();
+
+      const CURVE_SUPPLY = MILLION.muln(800);
+      const ALLOCATION_BPS = 500; // 5% of 1B = 50M
+      const ALLOCATION = MILLION.muln(50);
+
+      const launchFor = (name: string) => new TestLaunch(program, authority.publicKey, name);
+
+      const createLaunch = (launch: TestLaunch, creatorAllocationBps: number) =>
+        launch.create({
+          symbol: "ALLOC",
+          metadataUri: "https://example.com/allocation.json",
+          description: "Launch used to test creator allocations",
+          creatorAllocationBps,
+        });
+
+      const claim = (launch: TestLaunch, creator: Keypair | null = null) =>
+        program.methods
+          .claimCreatorAllocation()
+          .accounts({
+            tokenLaunch: launch.tokenLaunch,
+            bondingCurve: launch.bondingCurve,
+            creatorVault: launch.creatorVault,
+            mint: launch.mint,
+            creatorTokenAccount: launch.tokenAccountOf(creator ? creator.publicKey : authority.publicKey),
+            creator: creator ? creator.publicKey : authority.publicKey,
             tokenProgram: TOKEN_PROGRAM_ID,
             associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
           })
-          .signers([buyer])
+          .signers(creator ? [creator] : [])
           .rpc();
-        assert.fail("Should have failed due to slippage");
-      } catch (error) {
-        // Accept any error - slippage or insufficient funds
-        assert.ok(error);
-      }
+
+      const setCreatorVesting = (seconds: number) =>
+        program.methods
+          .updateCreatorVesting(seconds)
+          .accounts({ config: configPdaFor(program), authority: authority.publicKey })
+          .rpc();
+
+      const allocated = launchFor("Allocated Token");
+
+      before(async () => {
+        await airdrop(provider, trader.publicKey, 2);
+      });
+
+      after(async () => {
+        await setCreatorVesting(0);
+      });
+
+      it("Rejects an allocation that would leave the curve unable to graduate", async () => {
+        // 20% of supply leaves 600M on the curve, which sells out short of $12k
+        try {
+          await createLaunch(launchFor("Starved Token"), 2_000);
+          assert.fail("An allocation that starves the curve should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "InvalidCreatorAllocation");
+        }
+      });
+
+      it("Mints the allocation to the creator vault and the rest to the curve", async () => {
+        await setCreatorVesting(0);
+        await createLaunch(allocated, ALLOCATION_BPS);
+
+        const creatorVault = await getAccount(provider.connection, allocated.creatorVault);
+        const curveTokens = await getAccount(provider.connection, allocated.curveTokenAccount);
+        const lpTokens = await getAccount(provider.connection, allocated.lpVault);
+        assert.equal(creatorVault.amount.toString(), ALLOCATION.toString());
+        assert.equal(curveTokens.amount.toString(), CURVE_SUPPLY.sub(ALLOCATION).toString());
+        assert.equal(lpTokens.amount.toString(), MILLION.muln(200).toString());
+
+        const curve = await program.account.bondingCurve.fetch(allocated.bondingCurve);
+        assert.equal(curve.creatorAllocation.toString(), ALLOCATION.toString());
+        assert.equal(curve.tokenReserve.toString(), CURVE_SUPPLY.sub(ALLOCATION).toString());
+      });
+
+      it("Rejects claims from anyone but the creator", async () => {
+        try {
+          await claim(allocated, trader);
+          assert.fail("Non-creator claim should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "Unauthorized");
+        }
+      });
+
+      it("Releases the vested allocation to the creator's wallet", async () => {
+        await claim(allocated);
+
+        const creatorTokens = await getAccount(
+          provider.connection,
+          allocated.tokenAccountOf(authority.publicKey)
+        );
+        const creatorVault = await getAccount(provider.connection, allocated.creatorVault);
+        assert.equal(creatorTokens.amount.toString(), ALLOCATION.toString());
+        assert.equal(creatorVault.amount.toString(), "0");
+
+        const curve = await program.account.bondingCurve.fetch(allocated.bondingCurve);
+        assert.equal(curve.creatorAllocationClaimed.toString(), ALLOCATION.toString());
+
+        try {
+          await claim(allocated);
+          assert.fail("Claiming twice should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "NothingToClaim");
+        }
+      });
+
+      it("Fixes the vesting period on the launch at creation", async () => {
+        const VESTING_SECONDS = 3_600;
+        await setCreatorVesting(VESTING_SECONDS);
+        const vesting = launchFor("Vesting Token");
+        await createLaunch(vesting, ALLOCATION_BPS);
+
+        // Later config changes don't touch launches already created
+        await setCreatorVesting(0);
+        const curve = await program.account.bondingCurve.fetch(vesting.bondingCurve);
+        assert.equal(curve.creatorVestingSeconds, VESTING_SECONDS);
+
+        // Only seconds in, at most a sliver of the allocation has vested
+        let claimed = new BN(0);
+        try {
+          await claim(vesting);
+          claimed = (await program.account.bondingCurve.fetch(vesting.bondingCurve)).creatorAllocationClaimed;
+        } catch (error) {
+          assert.include(error.toString(), "NothingToClaim");
+        }
+        assert.ok(claimed.lt(ALLOCATION.divn(100)), "Vesting should release the allocation gradually");
+      });
     });
 
-    it("Fails with invalid amount (zero)", async () => {
-      const buyAmount = new BN(0);
-      const maxSolCost = new BN(LAMPORTS_PER_SOL);
+    /**
+     * Test that every account's reserved headroom is zeroed on creation
+     */
+    describe("Account Headroom", () => {
+      const trader = Keypair.generate();
+
+      const launch = new TestLaunch(program, authority.publicKey, "Headroom Token");
+
+      before(async () => {
+        await airdrop(provider, trader.publicKey, 20);
+
+        await launch.create({
+          symbol: "HEAD",
+          metadataUri: "https://example.com/headroom.json",
+          description: "Launch used to test reserved account space",
+        });
+      });
+
+      const RESERVED_BYTES = 64;
+      const assertZeroed = (reserved: number[], account: string) => {
+        assert.equal(reserved.length, RESERVED_BYTES, `${account} should reserve ${RESERVED_BYTES} bytes`);
+        assert.isTrue(reserved.every((b) => b === 0), `${account} headroom should be zeroed`);
+      };
+
+      it("Zeroes the headroom on the config, launch and curve", async () => {
+        const config = await program.account.launchpadConfig.fetch(launch.config);
+        const tokenLaunch = await program.account.tokenLaunch.fetch(launch.tokenLaunch);
+        const bondingCurve = await program.account.bondingCurve.fetch(launch.bondingCurve);
+
+        assertZeroed(config.reserved, "LaunchpadConfig");
+        assertZeroed(tokenLaunch.reserved, "TokenLaunch");
+        assertZeroed(bondingCurve.reserved, "BondingCurve");
+      });
+
+      it("Zeroes the headroom on a new user position", async () => {
+        await launch.buy(trader, MILLION);
+
+        const position = await program.account.userPosition.fetch(launch.positionOf(trader.publicKey));
+        assertZeroed(position.reserved, "UserPosition");
+
+        // Fixed-size accounts end with the headroom, so the raw tail is zero too
+        const info = await provider.connection.getAccountInfo(launch.positionOf(trader.publicKey));
+        assert.isTrue(info.data.subarray(info.data.length - RESERVED_BYTES).every((b) => b === 0));
+      });
+    });
+  });
+
+  describe("Buying Tokens", () => {
+    let buyerTokenAccount: PublicKey;
+    let userPositionPda: PublicKey;
+
+    before(() => {
+      buyerTokenAccount = getAssociatedTokenAddressSync(
+        mintPda,
+        buyer.publicKey,
+        false
+      );
+
+      [userPositionPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("user_position"), buyer.publicKey.toBuffer(), tokenLaunchPda.toBuffer()],
+        program.programId
+      );
+    });
+
+    it("Buys tokens from bonding curve", async () => {
+      const buyAmount = new BN(1_000_000_000); // 1 token (with 9 decimals)
+      const maxSolCost = new BN(LAMPORTS_PER_SOL * 10); // 10 SOL max to cover token cost + rent + fees
+
+      const buyerBalanceBefore = await provider.connection.getBalance(buyer.publicKey);
+
+      // Set up event listener for tokensPurchased event
+      let eventReceived = false;
+      let purchaseEvent: any = null;
+
+      const listener = program.addEventListener("tokensPurchased", (event, slot) => {
+        console.log("\n🎯 EVENT RECEIVED - TokensPurchased:");
+        console.log("   Buyer:", event.buyer.toString());
+        console.log("   Token Amount:", event.tokenAmount.toString(), `(${(event.tokenAmount.toNumber() / 1e9).toFixed(2)} tokens)`);
+        console.log("   SOL Amount:", event.solAmount.toString(), `(${(event.solAmount.toNumber() / 1e9).toFixed(6)} SOL)`);
+        console.log("   Platform Fee:", event.platformFee.toString(), `(${(event.platformFee.toNumber() / 1e9).toFixed(6)} SOL)`);
+        console.log("   Tokens Sold After:", event.tokensSoldAfter.toString(), `(${(event.tokensSoldAfter.toNumber() / 1e9).toFixed(2)} tokens)`);
+        console.log("   SOL Reserve After:", event.solReserveAfter.toString(), `(${(event.solReserveAfter.toNumber() / 1e9).toFixed(6)} SOL)`);
+        console.log("   Price Per Token:", event.pricePerToken.toString(), `(${(event.pricePerToken.toNumber() / 1e9).toFixed(9)} SOL per token)`);
+        console.log("   Slot:", slot);
+        eventReceived = true;
+        purchaseEvent = event;
+      });
 
+      let txSignature: string;
       try {
-        await program.methods
-          .buyTokens(buyAmount, maxSolCost)
+        // Send transaction normally  
+        txSignature = await program.methods
+          .buyTokens(buyAmount, maxSolCost, new BN(0))
           .accounts({
             config: configPda,
             tokenLaunch: tokenLaunchPda,
@@ -524,742 +960,3408 @@ describe("notmarket-solana", () => {
           })
           .signers([buyer])
           .rpc();
-        assert.fail("Should have failed with zero amount");
-      } catch (error) {
-        // Accept any error
-        assert.ok(error);
+
+        console.log("✅ Buy tokens tx:", txSignature);
+
+        // Wait a bit for event to be processed
+        await new Promise(resolve => setTimeout(resolve, 1000));
+
+        // Verify event was received and amounts match
+        if (eventReceived && purchaseEvent) {
+          console.log("\n✅ Event verification:");
+          console.log("   Requested amount:", buyAmount.toString(), "tokens");
+          console.log("   Event amount:", purchaseEvent.tokenAmount.toString(), "tokens");
+          assert.equal(purchaseEvent.tokenAmount.toString(), buyAmount.toString(), "Event token amount should match requested amount");
+          console.log("   ✓ Amounts match!");
+        }
+        
+        // Get transaction details
+        const txDetails = await provider.connection.getTransaction(txSignature, {
+          maxSupportedTransactionVersion: 0,
+          commitment: "confirmed"
+        });
+        
+        if (txDetails?.meta?.logMessages) {
+          console.log("\n=== TRANSACTION LOGS ===");
+          txDetails.meta.logMessages.forEach((log: string) => console.log(log));
+          console.log("=== END LOGS ===\n");
+        }
+        
+        // Check vault balance immediately after transaction
+        const vaultBalanceAfterTx = await provider.connection.getBalance(solVaultPda);
+        console.log("\n🏦 SOL VAULT BALANCE (immediately after tx):", vaultBalanceAfterTx, "lamports");
+        
+      } catch (error) {
+        // Remove listener in case of error
+        await program.removeEventListener(listener);
+        // Catch any errors and show logs
+        console.log("\n❌ Transaction error:", error.message);
+        const vaultBalanceOnError = await provider.connection.getBalance(solVaultPda);
+        console.log("🏦 SOL VAULT BALANCE (on error):", vaultBalanceOnError, "lamports");
+        
+        if (error.logs) {
+          console.log("\n=== ERROR LOGS ===");
+          error.logs.forEach((log: string) => console.log(log));
+          console.log("=== END LOGS ===\n");
+        }
+        
+        // If it's just a rent simulation error but the program logic succeeded, continue
+        if (error.message?.includes("insufficient funds for rent") && error.logs) {
+          const hasSuccessLog = error.logs.some((log: string) => log.includes("Bought"));
+          if (hasSuccessLog) {
+            console.log("⚠️  Transaction simulated successfully (program logic works), continuing test...\n");
+            return; // Don't throw, let test continue
+          }
+        }
+        throw error; // Re-throw all other errors
       }
-    });
-  });
 
-  describe("Selling Tokens", () => {
-    let sellerTokenAccount: PublicKey;
-    let sellerUserPositionPda: PublicKey;
+      // Verify buyer received tokens
+      const buyerBalance = await provider.connection.getTokenAccountBalance(buyerTokenAccount);
+      console.log("\n💰 BUYER TOKEN BALANCE:", buyerBalance.value.amount, "tokens");
+      assert.ok(new BN(buyerBalance.value.amount).gte(buyAmount));
 
-    before(async () => {
-      sellerTokenAccount = getAssociatedTokenAddressSync(
-        mintPda,
-        seller.publicKey,
-        false
-      );
+      // Verify bonding curve updated
+      const bondingCurve = await program.account.bondingCurve.fetch(bondingCurvePda);
+      assert.ok(bondingCurve.tokensSold.gt(new BN(0)), "Tokens should be sold");
+      
+      console.log("\n📊 BONDING CURVE STATE:");
+      console.log("  Tokens Sold:", bondingCurve.tokensSold.toString(), `(${Number(bondingCurve.tokensSold) / 1e9} tokens)`);
+      console.log("  SOL Reserve:", bondingCurve.solReserve.toString(), "lamports");
+      console.log("  Token Reserve:", bondingCurve.tokenReserve.toString(), `(${Number(bondingCurve.tokenReserve) / 1e9} tokens)`);
+      console.log("  Total Volume:", bondingCurve.totalVolume.toString(), "lamports");
+      console.log("  Trade Count:", bondingCurve.tradeCount.toString());
+      console.log("  Is Graduated:", bondingCurve.isGraduated);
+      
+      assert.equal(bondingCurve.tradeCount.toString(), "1");
 
-      [sellerUserPositionPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("user_position"), seller.publicKey.toBuffer(), tokenLaunchPda.toBuffer()],
-        program.programId
-      );
+      // Verify user position created
+      const userPosition = await program.account.userPosition.fetch(userPositionPda);
+      assert.ok(userPosition.tokenAmount.gte(buyAmount), "User should have tokens");
+      
+      console.log("\n👤 USER POSITION:");
+      console.log("  Token Amount:", userPosition.tokenAmount.toString(), `(${userPosition.tokenAmount.toNumber() / 1e9} tokens)`);
+      console.log("  SOL Invested:", userPosition.solInvested.toString(), "lamports");
+      console.log("  SOL Received:", userPosition.solReceived.toString(), "lamports");
+      console.log("  Buy Count:", userPosition.buyCount);
+      console.log("  Sell Count:", userPosition.sellCount);
+      
+      assert.equal(userPosition.buyCount, 1, "Buy count should be 1");
 
-      // First, seller needs to buy some tokens
-      const buyAmount = new BN(10_000_000_000); // 10 tokens
-      const maxSolCost = new BN(LAMPORTS_PER_SOL * 10);
+      // Verify SOL was spent (balance should decrease including fees)
+      const buyerBalanceAfter = await provider.connection.getBalance(buyer.publicKey);
+      console.log("\n💵 BUYER SOL BALANCE:");
+      console.log("  Before:", buyerBalanceBefore, "lamports");
+      console.log("  After:", buyerBalanceAfter, "lamports");
+      console.log("  Spent:", buyerBalanceBefore - buyerBalanceAfter, "lamports");
+      assert.ok(buyerBalanceAfter < buyerBalanceBefore, "Buyer balance should decrease after buying");
+      
+      // Check SOL vault balance
+      const vaultBalance = await provider.connection.getBalance(solVaultPda);
+      console.log("\n🏦 SOL VAULT BALANCE:", vaultBalance, "lamports");
+
+      // Cleanup: ensure listener is removed
+      await program.removeEventListener(listener);
+    });
+
+    it("Buys more tokens (second purchase)", async () => {
+      const buyAmount = new BN(5_000_000_000); // 5 tokens
+      const maxSolCost = new BN(LAMPORTS_PER_SOL * 10); // 10 SOL max
+
+      const bondingCurveBefore = await program.account.bondingCurve.fetch(bondingCurvePda);
+      const tokensSoldBefore = bondingCurveBefore.tokensSold;
+      
+      console.log("\n📊 BEFORE 2ND BUY - Bonding Curve:");
+      console.log("  Tokens Sold:", bondingCurveBefore.tokensSold.toString(), `(${bondingCurveBefore.tokensSold.toNumber() / 1e9} tokens)`);
+      console.log("  SOL Reserve:", bondingCurveBefore.solReserve.toString(), "lamports");
 
+      let txSignature: string;
       try {
-        await program.methods
-          .buyTokens(buyAmount, maxSolCost)
+        txSignature = await program.methods
+          .buyTokens(buyAmount, maxSolCost, new BN(0))
           .accounts({
             config: configPda,
             tokenLaunch: tokenLaunchPda,
             bondingCurve: bondingCurvePda,
             curveTokenAccount,
             solVault: solVaultPda,
-            userPosition: sellerUserPositionPda,
+            userPosition: userPositionPda,
             mint: mintPda,
-            buyerTokenAccount: sellerTokenAccount,
-            buyer: seller.publicKey,
+            buyerTokenAccount,
+            buyer: buyer.publicKey,
             feeRecipient: feeRecipient,
             tokenProgram: TOKEN_PROGRAM_ID,
             associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
           })
-          .signers([seller])
+          .signers([buyer])
           .rpc();
+
+        console.log("✅ Second buy tx:", txSignature);
+        
+        // Get transaction details
+        const txDetails = await provider.connection.getTransaction(txSignature, {
+          maxSupportedTransactionVersion: 0,
+          commitment: "confirmed"
+        });
+        
+        if (txDetails?.meta?.logMessages) {
+          console.log("\n=== TRANSACTION LOGS (2nd Buy) ===");
+          txDetails.meta.logMessages.forEach((log: string) => console.log(log));
+          console.log("=== END LOGS ===\n");
+        }
+        
+        // Check vault balance immediately after transaction
+        const vaultBalanceAfterTx = await provider.connection.getBalance(solVaultPda);
+        console.log("\n🏦 SOL VAULT BALANCE (immediately after 2nd buy):", vaultBalanceAfterTx, "lamports");
         
-        console.log("✅ Seller bought tokens successfully");
       } catch (error) {
-        console.log("\n⚠️  Seller buy simulation failed (rent check), but checking if logic succeeded...");
+        console.log("\n❌ 2nd buy error:", error.message);
+        const vaultBalanceOnError = await provider.connection.getBalance(solVaultPda);
+        console.log("🏦 SOL VAULT BALANCE (on error):", vaultBalanceOnError, "lamports");
         
         if (error.logs) {
+          console.log("\n=== ERROR LOGS (2nd Buy) ===");
+          error.logs.forEach((log: string) => console.log(log));
+          console.log("=== END LOGS ===\n");
+        }
+        
+        // If it's just a rent simulation error but the program logic succeeded, continue
+        if (error.message?.includes("insufficient funds for rent") && error.logs) {
           const hasSuccessLog = error.logs.some((log: string) => log.includes("Bought"));
           if (hasSuccessLog) {
-            console.log("✅ Program logic succeeded, continuing with sell test...\n");
-            return; // Continue with test setup
+            console.log("⚠️  Transaction simulated successfully (program logic works), continuing test...\n");
+            return; // Don't throw, let test continue
           }
         }
-        throw error; // Re-throw if it's a real error
+        throw error;
       }
-    });
 
-    it("Sells tokens back to bonding curve", async () => {
-      const sellAmount = new BN(3_000_000_000); // 3 tokens
-      const minSolOutput = new BN(0); // Accept any amount (no slippage protection)
+      // Verify tokens sold increased
+      const bondingCurveAfter = await program.account.bondingCurve.fetch(bondingCurvePda);
+      
+      console.log("\n📊 AFTER 2ND BUY - Bonding Curve:");
+      console.log("  Tokens Sold:", bondingCurveAfter.tokensSold.toString(), `(${Number(bondingCurveAfter.tokensSold) / 1e9} tokens)`);
+      console.log("  SOL Reserve:", bondingCurveAfter.solReserve.toString(), "lamports");
+      console.log("  Token Reserve:", bondingCurveAfter.tokenReserve.toString(), `(${Number(bondingCurveAfter.tokenReserve) / 1e9} tokens)`);
+      console.log("  Total Volume:", bondingCurveAfter.totalVolume.toString(), "lamports");
+      console.log("  Trade Count:", bondingCurveAfter.tradeCount.toString());
+      
+      assert.ok(bondingCurveAfter.tokensSold.gt(tokensSoldBefore));
+      assert.equal(bondingCurveAfter.tradeCount.toString(), "2");
+      
+      // Check user position
+      const userPosition = await program.account.userPosition.fetch(userPositionPda);
+      console.log("\n👤 USER POSITION (After 2nd Buy):");
+      console.log("  Token Amount:", userPosition.tokenAmount.toString(), `(${userPosition.tokenAmount.toNumber() / 1e9} tokens)`);
+      console.log("  SOL Invested:", userPosition.solInvested.toString(), "lamports");
+      console.log("  Buy Count:", userPosition.buyCount);
+      
+      // Check vault balance
+      const vaultBalance = await provider.connection.getBalance(solVaultPda);
+      console.log("\n🏦 SOL VAULT BALANCE:", vaultBalance, "lamports");
+    });
 
-      const sellerBalanceBefore = await provider.connection.getBalance(seller.publicKey);
-      const bondingCurveBefore = await program.account.bondingCurve.fetch(bondingCurvePda);
+    it("Simulated buy matches the executed buy's resulting state", async () => {
+      const buyAmount = new BN(2_000_000_000); // 2 tokens
 
-      // Set up event listener for tokensSold event
-      let eventReceived = false;
-      let sellEvent: any = null;
+      const simulation = await program.methods
+        .simulateTrade({ buy: {} }, buyAmount)
+        .accounts({
+          tokenLaunch: tokenLaunchPda,
+          bondingCurve: bondingCurvePda,
+          config: configPda,
+        })
+        .view();
 
-      const listener = program.addEventListener("tokensSold", (event, slot) => {
-        console.log("\n🎯 EVENT RECEIVED - TokensSold:");
-        console.log("   Seller:", event.seller.toString());
-        console.log("   Token Amount:", event.tokenAmount.toString(), `(${(event.tokenAmount.toNumber() / 1e9).toFixed(2)} tokens)`);
-        console.log("   SOL Amount:", event.solAmount.toString(), `(${(event.solAmount.toNumber() / 1e9).toFixed(6)} SOL)`);
-        console.log("   Platform Fee:", event.platformFee.toString(), `(${(event.platformFee.toNumber() / 1e9).toFixed(6)} SOL)`);
-        console.log("   Tokens Sold After:", event.tokensSoldAfter.toString(), `(${(event.tokensSoldAfter.toNumber() / 1e9).toFixed(2)} tokens)`);
-        console.log("   SOL Reserve After:", event.solReserveAfter.toString(), `(${(event.solReserveAfter.toNumber() / 1e9).toFixed(6)} SOL)`);
-        console.log("   Price Per Token:", event.pricePerToken.toString(), `(${(event.pricePerToken.toNumber() / 1e9).toFixed(9)} SOL per token)`);
-        console.log("   Slot:", slot);
-        eventReceived = true;
-        sellEvent = event;
-      });
+      // Simulation must not write anything
+      const bondingCurveBefore = await program.account.bondingCurve.fetch(bondingCurvePda);
+      assert.notEqual(
+        bondingCurveBefore.tokensSold.toString(),
+        simulation.tokensSoldAfter.toString(),
+        "Simulation should not mutate the curve"
+      );
 
-      const tx = await program.methods
-        .sellTokens(sellAmount, minSolOutput)
+      await program.methods
+        .buyTokens(buyAmount, simulation.netSol, new BN(0))
         .accounts({
           config: configPda,
           tokenLaunch: tokenLaunchPda,
           bondingCurve: bondingCurvePda,
           curveTokenAccount,
           solVault: solVaultPda,
-          userPosition: sellerUserPositionPda,
-          sellerTokenAccount,
-          seller: seller.publicKey,
+          userPosition: userPositionPda,
+          mint: mintPda,
+          buyerTokenAccount,
+          buyer: buyer.publicKey,
           feeRecipient: feeRecipient,
           tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
         })
-        .signers([seller])
+        .signers([buyer])
         .rpc();
 
-      console.log("Sell tokens tx:", tx);
-
-      // Wait for event to be processed
-      await new Promise(resolve => setTimeout(resolve, 1000));
+      const bondingCurveAfter = await program.account.bondingCurve.fetch(bondingCurvePda);
+      assert.equal(bondingCurveAfter.tokensSold.toString(), simulation.tokensSoldAfter.toString());
+      assert.equal(bondingCurveAfter.tokenReserve.toString(), simulation.tokenReserveAfter.toString());
+      assert.equal(bondingCurveAfter.solReserve.toString(), simulation.solReserveAfter.toString());
+      assert.equal(bondingCurveAfter.isGraduated, simulation.triggersGraduation);
+      console.log("✅ Simulation matched executed buy");
+    });
 
-      // Remove listener
-      await program.removeEventListener(listener);
+    it("Fails when slippage exceeded", async () => {
+      const buyAmount = new BN(10_000_000_000); // 10 tokens
+      const maxSolCost = new BN(1); // Unrealistically low
 
-      // Verify event was received and amounts match
-      if (eventReceived && sellEvent) {
-        console.log("\n✅ Event verification:");
-        console.log("   Requested amount:", sellAmount.toString(), "tokens");
-        console.log("   Event amount:", sellEvent.tokenAmount.toString(), "tokens");
-        assert.equal(sellEvent.tokenAmount.toString(), sellAmount.toString(), "Event token amount should match requested amount");
-        console.log("   ✓ Amounts match!");
-      }
-
-      // Verify tokens sold decreased
-      const bondingCurveAfter = await program.account.bondingCurve.fetch(bondingCurvePda);
-      assert.ok(bondingCurveAfter.tokensSold.lt(bondingCurveBefore.tokensSold));
-
-      // Note: Seller's SOL balance might not change much due to tx fees and how SOL flows
-      const sellerBalanceAfter = await provider.connection.getBalance(seller.publicKey);
-      console.log("Seller balance before:", sellerBalanceBefore, "after:", sellerBalanceAfter);
-      const balanceDiff = sellerBalanceAfter - sellerBalanceBefore;
-      console.log("Balance difference:", balanceDiff);
-      // Main verification is through user position which tracks SOL received
-      // Balance diff can be 0 or negative due to fees
-
-      // Verify user position updated
-      const userPosition = await program.account.userPosition.fetch(sellerUserPositionPda);
-      console.log("User position after sell:", {
-        tokenAmount: userPosition.tokenAmount.toString(),
-        solReceived: userPosition.solReceived.toString(),
-        sellCount: userPosition.sellCount
-      });
-      assert.equal(userPosition.sellCount, 1, "Sell count should be 1");
-      // Note: solReceived might be 0 if program doesn't track it in user position
-
-      // Cleanup: ensure listener is removed
       try {
-        await program.removeEventListener(listener);
-      } catch (err) {
-        // Listener might already be removed, ignore
+        await program.methods
+          .buyTokens(buyAmount, maxSolCost, new BN(0))
+          .accounts({
+            config: configPda,
+            tokenLaunch: tokenLaunchPda,
+            bondingCurve: bondingCurvePda,
+            curveTokenAccount,
+            solVault: solVaultPda,
+            userPosition: userPositionPda,
+            mint: mintPda,
+            buyerTokenAccount,
+            buyer: buyer.publicKey,
+            feeRecipient: feeRecipient,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([buyer])
+          .rpc();
+        assert.fail("Should have failed due to slippage");
+      } catch (error) {
+        // Accept any error - slippage or insufficient funds
+        assert.ok(error);
       }
     });
 
-    it("Fails when slippage exceeded on sell", async () => {
-      const sellAmount = new BN(1_000_000_000); // 1 token
-      const minSolOutput = new BN(LAMPORTS_PER_SOL * 100); // Unrealistically high
+    it("Fails with invalid amount (zero)", async () => {
+      const buyAmount = new BN(0);
+      const maxSolCost = new BN(LAMPORTS_PER_SOL);
 
       try {
         await program.methods
-          .sellTokens(sellAmount, minSolOutput)
+          .buyTokens(buyAmount, maxSolCost, new BN(0))
           .accounts({
             config: configPda,
             tokenLaunch: tokenLaunchPda,
             bondingCurve: bondingCurvePda,
             curveTokenAccount,
             solVault: solVaultPda,
-            userPosition: sellerUserPositionPda,
-            sellerTokenAccount,
-            seller: seller.publicKey,
+            userPosition: userPositionPda,
+            mint: mintPda,
+            buyerTokenAccount,
+            buyer: buyer.publicKey,
             feeRecipient: feeRecipient,
             tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
           })
-          .signers([seller])
+          .signers([buyer])
           .rpc();
-        assert.fail("Should have failed due to slippage");
+        assert.fail("Should have failed with zero amount");
       } catch (error) {
         // Accept any error
         assert.ok(error);
       }
     });
 
-    it("Fails to sell more than balance", async () => {
-      const userPosition = await program.account.userPosition.fetch(sellerUserPositionPda);
-      const sellAmount = userPosition.tokenAmount.add(new BN(1_000_000_000)); // More than owned
-      const minSolOutput = new BN(0);
+    it("Fails when a foreign mint is passed", async () => {
+      const foreignMint = await createMint(
+        provider.connection,
+        buyer,
+        buyer.publicKey,
+        null,
+        9
+      );
+      const foreignTokenAccount = getAssociatedTokenAddressSync(foreignMint, buyer.publicKey);
 
       try {
         await program.methods
-          .sellTokens(sellAmount, minSolOutput)
+          .buyTokens(new BN(1_000_000_000), new BN(LAMPORTS_PER_SOL), new BN(0))
           .accounts({
             config: configPda,
             tokenLaunch: tokenLaunchPda,
             bondingCurve: bondingCurvePda,
             curveTokenAccount,
             solVault: solVaultPda,
-            userPosition: sellerUserPositionPda,
-            sellerTokenAccount,
-            seller: seller.publicKey,
+            userPosition: userPositionPda,
+            mint: foreignMint,
+            buyerTokenAccount: foreignTokenAccount,
+            buyer: buyer.publicKey,
             feeRecipient: feeRecipient,
             tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
           })
-          .signers([seller])
+          .signers([buyer])
           .rpc();
-        assert.fail("Should have failed with insufficient balance");
+        assert.fail("Should have failed with a foreign mint");
       } catch (error) {
-        // Will fail at token transfer level
-        assert.ok(error);
+        assert.include(error.toString(), "TokenMintMismatch");
+        console.log("✅ Foreign mint correctly rejected");
       }
     });
-  });
 
-  describe("Token Launch Management", () => {
-    it("Toggles token launch active status", async () => {
-      const tokenLaunchBefore = await program.account.tokenLaunch.fetch(tokenLaunchPda);
-      const activeStatusBefore = tokenLaunchBefore.isActive;
+    /**
+     * Test that an underfunded first buyer gets a clear error covering the vault rent top-up
+     */
+    describe("Insufficient SOL For Rent", () => {
+      const buyer = Keypair.generate();
 
-      const tx = await program.methods
-        .toggleTokenLaunchActive()
-        .accounts({
-          tokenLaunch: tokenLaunchPda,
-          creator: creator.publicKey,
-        })
-        .signers([creator])
-        .rpc();
+      const buyAmount = new BN(100_000).mul(new BN(1_000_000_000)); // 100K tokens
 
-      console.log("Toggle active tx:", tx);
+      const launch = new TestLaunch(program, authority.publicKey, "Underfunded Token");
 
-      const tokenLaunchAfter = await program.account.tokenLaunch.fetch(tokenLaunchPda);
-      assert.equal(tokenLaunchAfter.isActive, !activeStatusBefore);
+      before(async () => {
+        await launch.create({
+          symbol: "POOR",
+          metadataUri: "https://example.com/underfunded.json",
+          description: "Launch used to test underfunded first buys",
+        });
+      });
 
-      // Toggle back
-      await program.methods
-        .toggleTokenLaunchActive()
-        .accounts({
-          tokenLaunch: tokenLaunchPda,
-          creator: creator.publicKey,
-        })
-        .signers([creator])
-        .rpc();
+      const fund = (lamports: number) =>
+        provider.sendAndConfirm(
+          new Transaction().add(
+            SystemProgram.transfer({
+              fromPubkey: authority.publicKey,
+              toPubkey: buyer.publicKey,
+              lamports,
+            })
+          )
+        );
 
-      const tokenLaunchRestored = await program.account.tokenLaunch.fetch(tokenLaunchPda);
-      assert.equal(tokenLaunchRestored.isActive, activeStatusBefore);
-    });
+      it("Rejects a first buy that can pay the cost but not the vault rent", async () => {
+        const quote = await program.methods
+          .getBuyQuote(buyAmount, null)
+          .accounts({ config: launch.config, tokenLaunch: launch.tokenLaunch, bondingCurve: launch.bondingCurve })
+          .view();
+        const config = await program.account.launchpadConfig.fetch(launch.config);
+        const cost = quote.cost.toNumber();
+        const fee = Math.floor((cost * config.platformFeeBps) / 10_000);
+
+        const vaultRent = await provider.connection.getMinimumBalanceForRentExemption(0);
+        const tokenAccountRent = await provider.connection.getMinimumBalanceForRentExemption(165);
+        const positionRent = await provider.connection.getMinimumBalanceForRentExemption(
+          program.account.userPosition.size
+        );
 
-    it("Updates metadata URI", async () => {
-      const newUri = "https://example.com/new-metadata.json";
+        // Enough for the accounts created by the buy, the cost and the fee, but
+        // only half of the vault's rent top-up
+        await fund(tokenAccountRent + positionRent + cost + fee + Math.floor(vaultRent / 2));
 
-      const tx = await program.methods
-        .updateMetadataUri(newUri)
-        .accounts({
-          tokenLaunch: tokenLaunchPda,
-          creator: creator.publicKey,
-        })
-        .signers([creator])
-        .rpc();
+        try {
+          await launch.buy(buyer, buyAmount);
+          assert.fail("Underfunded first buy should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "InsufficientSolForRent");
+        }
 
-      console.log("Update metadata URI tx:", tx);
+        const bondingCurve = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        assert.equal(bondingCurve.tokensSold.toString(), "0");
+      });
 
-      const tokenLaunch = await program.account.tokenLaunch.fetch(tokenLaunchPda);
-      assert.equal(tokenLaunch.metadataUri, newUri);
-    });
+      it("Succeeds once the buyer can cover the rent top-up", async () => {
+        const vaultRent = await provider.connection.getMinimumBalanceForRentExemption(0);
+        await fund(2 * vaultRent);
 
-    it("Fails to toggle active status from non-creator", async () => {
-      try {
-        await program.methods
-          .toggleTokenLaunchActive()
-          .accounts({
-            tokenLaunch: tokenLaunchPda,
-            creator: buyer.publicKey, // Wrong creator
-          })
-          .signers([buyer])
-          .rpc();
-        assert.fail("Should have failed with unauthorized");
-      } catch (error) {
-        assert.ok(error);
-      }
+        await launch.buy(buyer, buyAmount);
+
+        const position = await program.account.userPosition.fetch(launch.positionOf(buyer.publicKey));
+        assert.equal(position.tokenAmount.toString(), buyAmount.toString());
+        assert.isAtLeast(await provider.connection.getBalance(launch.solVault), vaultRent);
+      });
     });
 
-    it("Fails to update metadata from non-creator", async () => {
-      const newUri = "https://malicious.com/fake.json";
+    /**
+     * Test that buys and sells with a deadline are rejected once it has passed
+     */
+    describe("Trade Deadline", () => {
+      const trader = Keypair.generate();
 
-      try {
-        await program.methods
-          .updateMetadataUri(newUri)
-          .accounts({
-            tokenLaunch: tokenLaunchPda,
-            creator: buyer.publicKey, // Wrong creator
-          })
-          .signers([buyer])
-          .rpc();
-        assert.fail("Should have failed with unauthorized");
-      } catch (error) {
-        console.log("✅ Correctly rejected metadata update from non-creator");
-        assert.ok(error.toString().includes("ConstraintHasOne") || 
-                 error.toString().includes("Unauthorized"));
-      }
-    });
-  });
+      const launch = new TestLaunch(program, authority.publicKey, "Deadline Token");
 
-  describe("Authorization Tests", () => {
-    const unauthorizedUser = Keypair.generate();
+      const buy = (wallet: Keypair, amount: BN, deadline: BN) =>
+        launch.buy(wallet, amount, { deadline });
 
-    before(async () => {
-      // Fund unauthorized user
-      const airdropAmount = 10 * LAMPORTS_PER_SOL;
-      await provider.connection.confirmTransaction(
-        await provider.connection.requestAirdrop(unauthorizedUser.publicKey, airdropAmount)
-      );
-      console.log("\n🔐 Testing authorization controls...");
-    });
+      const sell = (wallet: Keypair, amount: BN, deadline: BN) =>
+        launch.sell(wallet, amount, { deadline });
 
-    it("Only authority can initialize launchpad", async () => {
-      // Try to reinitialize with wrong authority
-      const fakeConfigPda = Keypair.generate().publicKey;
+      // Deadlines are checked against the cluster clock, not the local one
+      const chainNow = async () => {
+        const slot = await provider.connection.getSlot();
+        return (await provider.connection.getBlockTime(slot)) ?? Math.floor(Date.now() / 1000);
+      };
 
-      try {
-        await program.methods
-          .initializeLaunchpad(200)
-          .accounts({
-            config: fakeConfigPda,
-            authority: unauthorizedUser.publicKey,
-            feeRecipient: feeRecipient,
-            systemProgram: SystemProgram.programId,
-          })
-          .signers([unauthorizedUser])
-          .rpc();
-        assert.fail("Should have failed - unauthorized initialization");
-      } catch (error) {
-        console.log("✅ Correctly prevented unauthorized launchpad initialization");
-        assert.ok(error);
-      }
-    });
+      before(async () => {
+        await airdrop(provider, trader.publicKey, 20);
 
-    it("Only token creator can toggle launch active status", async () => {
-      try {
-        await program.methods
-          .toggleTokenLaunchActive()
-          .accounts({
-            tokenLaunch: tokenLaunchPda,
-            creator: unauthorizedUser.publicKey,
-          })
-          .signers([unauthorizedUser])
-          .rpc();
-        assert.fail("Should have failed - unauthorized toggle");
-      } catch (error) {
-        console.log("✅ Correctly prevented unauthorized toggle");
-        // Any error is expected - could be constraint, signer, or simulation error
-        assert.ok(error, "Should throw error for unauthorized access");
-      }
-    });
+        await launch.create({
+          symbol: "DEAD",
+          metadataUri: "https://example.com/deadline.json",
+          description: "Launch used to test trade deadlines",
+        });
+      });
 
-    it("Only token creator can update metadata", async () => {
-      const maliciousUri = "https://phishing.com/fake-metadata.json";
+      it("Rejects a buy whose deadline has passed", async () => {
+        const expired = new BN((await chainNow()) - 60);
 
-      try {
-        await program.methods
-          .updateMetadataUri(maliciousUri)
-          .accounts({
-            tokenLaunch: tokenLaunchPda,
-            creator: unauthorizedUser.publicKey,
-          })
-          .signers([unauthorizedUser])
-          .rpc();
-        assert.fail("Should have failed - unauthorized metadata update");
-      } catch (error) {
-        console.log("✅ Correctly prevented unauthorized metadata update");
-        // Any error is expected - could be constraint, signer, or simulation error
-        assert.ok(error, "Should throw error for unauthorized access");
-      }
-    });
+        try {
+          await buy(trader, MILLION, expired);
+          assert.fail("Buy after its deadline should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "DeadlineExceeded");
+        }
 
-    it("Only token creator can withdraw liquidity after graduation", async () => {
-      try {
-        const liquidityRecipient = Keypair.generate();
-        const tokenRecipient = getAssociatedTokenAddressSync(
-          mintPda,
-          liquidityRecipient.publicKey
-        );
+        const curve = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        assert.equal(curve.tokensSold.toString(), "0", "Expired buy must not move the curve");
+      });
 
-        await program.methods
-          .withdrawLiquidity()
-          .accounts({
-            tokenLaunch: tokenLaunchPda,
-            bondingCurve: bondingCurvePda,
-            solVault: solVaultPda,
-            curveTokenAccount,
-            solRecipient: liquidityRecipient.publicKey,
-            tokenRecipient,
-            authority: unauthorizedUser.publicKey, // Wrong authority
-            tokenProgram: TOKEN_PROGRAM_ID,
-            systemProgram: SystemProgram.programId,
-          })
-          .signers([unauthorizedUser])
+      it("Buys before a future deadline", async () => {
+        const valid = new BN((await chainNow()) + 300);
+        await buy(trader, MILLION, valid);
+
+        const position = await program.account.userPosition.fetch(launch.positionOf(trader.publicKey));
+        assert.equal(position.tokenAmount.toString(), MILLION.toString());
+      });
+
+      it("Rejects a sell whose deadline has passed", async () => {
+        const expired = new BN((await chainNow()) - 60);
+
+        try {
+          await sell(trader, MILLION.divn(2), expired);
+          assert.fail("Sell after its deadline should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "DeadlineExceeded");
+        }
+
+        const position = await program.account.userPosition.fetch(launch.positionOf(trader.publicKey));
+        assert.equal(position.tokenAmount.toString(), MILLION.toString());
+      });
+
+      it("Sells before a future deadline, and treats 0 as no deadline", async () => {
+        await sell(trader, MILLION.divn(2), new BN((await chainNow()) + 300));
+        await sell(trader, MILLION.divn(2), new BN(0));
+
+        const position = await program.account.userPosition.fetch(launch.positionOf(trader.publicKey));
+        assert.equal(position.tokenAmount.toString(), "0");
+      });
+    });
+
+    /**
+     * Test the curve-wide cooldown between trades from any wallet
+     */
+    describe("Trade Cooldown", () => {
+      const trader = Keypair.generate();
+      const otherTrader = Keypair.generate();
+
+      const launch = new TestLaunch(program, authority.publicKey, "Cooldown Token");
+
+      before(async () => {
+        for (const wallet of [trader, otherTrader]) {
+          await airdrop(provider, wallet.publicKey, 100);
+        }
+
+        await launch.create({
+          symbol: "COOL",
+          metadataUri: "https://example.com/cooldown.json",
+          description: "Launch used to test the curve trade cooldown",
+        });
+      });
+
+      const setCooldown = (seconds: number) =>
+        program.methods
+          .updateMinInterTradeSeconds(seconds)
+          .accounts({ config: launch.config, authority: authority.publicKey })
           .rpc();
-        assert.fail("Should have failed - unauthorized liquidity withdrawal");
-      } catch (error) {
-        console.log("✅ Correctly prevented unauthorized liquidity withdrawal");
-        // Any error is expected - could be auth, graduation, or simulation error
-        assert.ok(error, "Should throw error for unauthorized access");
-      }
+
+      before(async () => {
+        await setCooldown(30);
+      });
+
+      after(async () => {
+        await setCooldown(0);
+      });
+
+      it("Throttles rapid trades from different wallets", async () => {
+        await launch.buy(trader, MILLION.mul(new BN(2)));
+
+        const bondingCurve = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        assert.ok(bondingCurve.lastTradeTime.gt(new BN(0)), "last_trade_time should be recorded");
+
+        try {
+          await launch.buy(otherTrader, MILLION);
+          assert.fail("Buy within the cooldown should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "CooldownActive");
+        }
+
+        // Sells are throttled too, including by the wallet that just traded
+        try {
+          await launch.sell(trader, MILLION);
+          assert.fail("Sell within the cooldown should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "CooldownActive");
+        }
+      });
+
+      it("Allows trading again once the cooldown is lifted", async () => {
+        await setCooldown(0);
+        await launch.buy(otherTrader, MILLION);
+
+        const position = await program.account.userPosition.fetch(launch.positionOf(otherTrader.publicKey));
+        assert.equal(position.tokenAmount.toString(), MILLION.toString());
+      });
     });
 
-    it("Verifies creator constraint on TokenLaunch account", async () => {
-      const tokenLaunch = await program.account.tokenLaunch.fetch(tokenLaunchPda);
-      
-      console.log("\n🔒 Creator Authorization Check:");
-      console.log("  Token Launch Creator:", tokenLaunch.creator.toString());
-      console.log("  Actual Creator:", creator.publicKey.toString());
-      console.log("  Unauthorized User:", unauthorizedUser.publicKey.toString());
-      
-      assert.ok(tokenLaunch.creator.equals(creator.publicKey), 
-        "Creator must match original creator");
-      assert.ok(!tokenLaunch.creator.equals(unauthorizedUser.publicKey), 
-        "Unauthorized user cannot be creator");
+    /**
+     * Test per-trade limits enforced by buy/sell
+     */
+    describe("Trade Limits", () => {
+      const trader = Keypair.generate();
+
+      const launch = new TestLaunch(program, authority.publicKey, "Limits Token");
+
+      before(async () => {
+        await airdrop(provider, trader.publicKey, 100);
+
+        await launch.create({
+          symbol: "LIMIT",
+          metadataUri: "https://example.com/limits.json",
+          description: "Launch used to test trade limits",
+        });
+      });
+
+      describe("Minimum Trade Size", () => {
+        before(async () => {
+          await program.methods
+            .updateMinTradeTokens(MILLION)
+            .accounts({ config: launch.config, authority: authority.publicKey })
+            .rpc();
+        });
+
+        after(async () => {
+          await program.methods
+            .updateMinTradeTokens(new BN(0))
+            .accounts({ config: launch.config, authority: authority.publicKey })
+            .rpc();
+        });
+
+        it("Rejects a partial sell that would strand dust below the minimum", async () => {
+          await launch.buy(trader, MILLION.mul(new BN(2)));
+
+          try {
+            // Leaves 0.5M tokens, below the 1M minimum
+            await launch.sell(trader, MILLION.mul(new BN(3)).div(new BN(2)));
+            assert.fail("Sell leaving dust should be rejected");
+          } catch (error) {
+            assert.include(error.toString(), "MinimumTradeAmount");
+          }
+
+          const position = await program.account.userPosition.fetch(launch.positionOf(trader.publicKey));
+          assert.equal(position.tokenAmount.toString(), MILLION.mul(new BN(2)).toString());
+        });
+
+        it("Allows selling the full balance", async () => {
+          await launch.sell(trader, MILLION.mul(new BN(2)));
+
+          const position = await program.account.userPosition.fetch(launch.positionOf(trader.publicKey));
+          assert.equal(position.tokenAmount.toString(), "0");
+        });
+      });
+
+      describe("Curve Supply", () => {
+        it("Rejects selling more than the curve has ever sold", async () => {
+          await launch.buy(trader, MILLION);
+          const curve = await program.account.bondingCurve.fetch(launch.bondingCurve);
+
+          try {
+            await launch.sell(trader, curve.tokensSold.add(MILLION));
+            assert.fail("Sell above tokens_sold should be rejected");
+          } catch (error) {
+            assert.include(error.toString(), "SellExceedsTokensSold");
+          }
+
+          const after = await program.account.bondingCurve.fetch(launch.bondingCurve);
+          assert.equal(after.tokensSold.toString(), curve.tokensSold.toString());
+        });
+      });
     });
 
-    it("Verifies authority constraint on LaunchpadConfig", async () => {
-      const config = await program.account.launchpadConfig.fetch(configPda);
-      
-      console.log("\n🔒 Launchpad Authority Check:");
-      console.log("  Config Authority:", config.authority.toString());
-      console.log("  Wallet Authority:", authority.publicKey.toString());
-      console.log("  Unauthorized User:", unauthorizedUser.publicKey.toString());
-      
-      assert.ok(config.authority.equals(authority.publicKey), 
-        "Config authority must match wallet");
-      assert.ok(!config.authority.equals(unauthorizedUser.publicKey), 
-        "Unauthorized user cannot be authority");
+    /**
+     * Test the per-curve hard stop on the number of trades
+     */
+    describe("Max Trades", () => {
+      const trader = Keypair.generate();
+
+      const MAX_TRADES = 3;
+
+      const launch = new TestLaunch(program, authority.publicKey, "Max Trades Token");
+
+      before(async () => {
+        await airdrop(provider, trader.publicKey, 20);
+
+        await launch.create({
+          symbol: "MAXT",
+          metadataUri: "https://example.com/max-trades.json",
+          description: "Launch used to test the trade limit",
+          maxTrades: new BN(MAX_TRADES),
+        });
+      });
+
+      it("Allows trades up to the limit", async () => {
+        await launch.buy(trader, MILLION.muln(2));
+        await launch.sell(trader, MILLION);
+        await launch.buy(trader, MILLION);
+
+        const curve = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        assert.equal(curve.maxTrades.toNumber(), MAX_TRADES);
+        assert.equal(curve.tradeCount.toNumber(), MAX_TRADES);
+      });
+
+      it("Rejects the next buy and sell once the limit is reached", async () => {
+        try {
+          await launch.buy(trader, MILLION);
+          assert.fail("Buy past the trade limit should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "TradeLimitReached");
+        }
+
+        try {
+          await launch.sell(trader, MILLION);
+          assert.fail("Sell past the trade limit should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "TradeLimitReached");
+        }
+
+        const curve = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        assert.equal(curve.tradeCount.toNumber(), MAX_TRADES);
+      });
+
+      it("Still serves views after the limit is reached", async () => {
+        const quote = await program.methods
+          .getBuyQuote(MILLION, { currentSpot: {} })
+          .accounts({ config: launch.config, tokenLaunch: launch.tokenLaunch, bondingCurve: launch.bondingCurve })
+          .view();
+        assert.ok(quote.cost.gtn(0), "Quote should still be priced");
+
+        const position = await program.account.userPosition.fetch(launch.positionOf(trader.publicKey));
+        assert.equal(position.tokenAmount.toString(), MILLION.muln(2).toString());
+      });
     });
 
-    it("Anyone can buy tokens (no auth required)", async () => {
-      // Positive test - unauthorized user CAN buy
-      const buyAmount = new BN(1_000_000_000); // 1 token
-      const maxSolCost = new BN(LAMPORTS_PER_SOL);
+    /**
+     * Test the maximum buy as a fraction of the curve's remaining supply
+     */
+    describe("Max Buy Fraction", () => {
+      const trader = Keypair.generate();
 
-      const unauthorizedBuyerTokenAccount = getAssociatedTokenAddressSync(
+      const MAX_BUY_FRACTION_BPS = 1_000; // 10% of the remaining reserve
+
+      const launch = new TestLaunch(program, authority.publicKey, "Fraction Token");
+
+      before(async () => {
+        await airdrop(provider, trader.publicKey, 200);
+
+        await launch.create({
+          symbol: "FRAC",
+          metadataUri: "https://example.com/fraction.json",
+          description: "Launch used to test the max buy fraction",
+          solPriceUsd: new BN(1500_00000000), // $1,500 keeps buying out the curve cheap
+        });
+      });
+
+      const setMaxBuyFraction = (bps: number) =>
+        program.methods
+          .updateMaxBuyFraction(bps)
+          .accounts({ config: launch.config, authority: authority.publicKey })
+          .rpc();
+
+      const expectCapped = async (amount: BN) => {
+        try {
+          await launch.buy(trader, amount);
+          assert.fail("Buy above the fractional cap should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "MaximumTradeAmount");
+        }
+      };
+
+      after(async () => {
+        await setMaxBuyFraction(0);
+        await program.methods
+          .updateMinTradeTokens(new BN(0))
+          .accounts({ config: launch.config, authority: authority.publicKey })
+          .rpc();
+      });
+
+      it("Rejects a fraction above 10000 bps", async () => {
+        try {
+          await setMaxBuyFraction(10_001);
+          assert.fail("Fraction above 100% should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "InvalidConfiguration");
+        }
+      });
+
+      it("Rejects updates from non-admin signers", async () => {
+        try {
+          await program.methods
+            .updateMaxBuyFraction(MAX_BUY_FRACTION_BPS)
+            .accounts({ config: launch.config, authority: trader.publicKey })
+            .signers([trader])
+            .rpc();
+          assert.fail("Non-admin update should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "Unauthorized");
+        }
+      });
+
+      it("Caps a buy at 10% of a fresh 800M reserve", async () => {
+        await setMaxBuyFraction(MAX_BUY_FRACTION_BPS);
+        const config = await program.account.launchpadConfig.fetch(launch.config);
+        assert.equal(config.maxBuyFractionBps, MAX_BUY_FRACTION_BPS);
+
+        await expectCapped(MILLION.mul(new BN(81)));
+        await launch.buy(trader, MILLION.mul(new BN(80)));
+
+        const curve = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        assert.equal(curve.tokensSold.toString(), MILLION.mul(new BN(80)).toString());
+      });
+
+      it("Tightens near the end of the curve, where the same size is now rejected", async () => {
+        // Fill the curve to 5M remaining with the cap lifted
+        await setMaxBuyFraction(0);
+        for (const chunk of [200, 200, 200, 115]) {
+          await launch.buy(trader, MILLION.mul(new BN(chunk)));
+        }
+        const curve = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        assert.equal(curve.tokenReserve.toString(), MILLION.mul(new BN(5)).toString());
+
+        // 10% of 5M is 0.5M: a 1M buy, trivially inside the cap at launch, is too large now
+        await setMaxBuyFraction(MAX_BUY_FRACTION_BPS);
+        await expectCapped(MILLION);
+        await launch.buy(trader, MILLION.divn(2));
+
+        const after = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        assert.equal(after.tokenReserve.toString(), MILLION.mul(new BN(9)).divn(2).toString());
+        assert.isFalse(after.isGraduated);
+      });
+
+      it("Never caps below the minimum trade size", async () => {
+        // 10% of 4.5M is 0.45M, but a 1M minimum must still be buyable
+        await program.methods
+          .updateMinTradeTokens(MILLION)
+          .accounts({ config: launch.config, authority: authority.publicKey })
+          .rpc();
+
+        await expectCapped(MILLION.add(new BN(1)));
+        await launch.buy(trader, MILLION);
+
+        const curve = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        assert.equal(curve.tokenReserve.toString(), MILLION.mul(new BN(7)).divn(2).toString());
+      });
+    });
+
+    /**
+     * Test the per-launch hard cap on SOL raised
+     */
+    describe("Raise Cap", () => {
+      const trader = Keypair.generate();
+
+      const maxSolRaise = new BN(LAMPORTS_PER_SOL); // 1 SOL
+
+      const launch = new TestLaunch(program, authority.publicKey, "Capped Token");
+
+      before(async () => {
+        await airdrop(provider, trader.publicKey, 100);
+
+        await launch.create({
+          symbol: "CAP",
+          metadataUri: "https://example.com/capped.json",
+          description: "Launch used to test the SOL raise cap",
+          maxSolRaiseLamports: maxSolRaise,
+        });
+      });
+
+      it("Stores the cap on the bonding curve", async () => {
+        const bondingCurve = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        assert.equal(bondingCurve.maxSolRaiseLamports.toString(), maxSolRaise.toString());
+      });
+
+      it("Allows buys that stay under the cap", async () => {
+        await launch.buy(trader, MILLION.mul(new BN(10)));
+
+        const bondingCurve = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        assert.ok(bondingCurve.solReserve.gt(new BN(0)));
+        assert.ok(bondingCurve.solReserve.lte(maxSolRaise));
+      });
+
+      it("Rejects a buy that would push the reserve past the cap", async () => {
+        const before = await program.account.bondingCurve.fetch(launch.bondingCurve);
+
+        try {
+          // 200M tokens cost several SOL at $150
+          await launch.buy(trader, MILLION.mul(new BN(200)));
+          assert.fail("Buy past the raise cap should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "RaiseCapReached");
+        }
+
+        const after = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        assert.equal(after.solReserve.toString(), before.solReserve.toString());
+        assert.equal(after.tokensSold.toString(), before.tokensSold.toString());
+      });
+    });
+
+    /**
+     * Test that trade events report slippage against the pre-trade spot price
+     */
+    describe("Trade Slippage Events", () => {
+      const trader = Keypair.generate();
+
+      const launch = new TestLaunch(program, authority.publicKey, "Slippage Token");
+
+      // No solPriceFeed, so trades price off the same stored SOL price as the views
+      const buy = (wallet: Keypair, amount: BN) =>
+        launch.buy(wallet, amount, { accounts: { solPriceFeed: null } });
+
+      const sell = (wallet: Keypair, amount: BN) =>
+        launch.sell(wallet, amount, { accounts: { solPriceFeed: null } });
+
+      before(async () => {
+        await airdrop(provider, trader.publicKey, 20);
+
+        await launch.create({
+          symbol: "SLIP",
+          metadataUri: "https://example.com/slippage.json",
+          description: "Launch used to test slippage in trade events",
+        });
+      });
+
+      // Run a trade and return the single event of the given name it emitted
+      const captureEvent = async (name: "tokensPurchased" | "tokensSold", trade: () => Promise<string>) => {
+        let captured = null;
+        const listener = program.addEventListener(name, (event) => {
+          captured = event;
+        });
+        await trade();
+        await new Promise((resolve) => setTimeout(resolve, 1000));
+        await program.removeEventListener(listener);
+        assert.ok(captured, `${name} should be emitted`);
+        return captured;
+      };
+
+      const spotPrice = async () =>
+        (
+          await program.methods
+            .getSpotPrice()
+            .accounts({ bondingCurve: launch.bondingCurve, tokenLaunch: launch.tokenLaunch })
+            .view()
+        ).spotPrice as BN;
+
+      it("Reports the same buy slippage the quote predicted", async () => {
+        const amount = MILLION.muln(50);
+
+        const quote = await program.methods
+          .getBuyQuote(amount, { currentSpot: {} })
+          .accounts({ config: launch.config, tokenLaunch: launch.tokenLaunch, bondingCurve: launch.bondingCurve })
+          .view();
+
+        const event = await captureEvent("tokensPurchased", () => buy(trader, amount));
+
+        assert.equal(event.solAmount.toString(), quote.cost.toString());
+        assert.equal(event.slippageBps, quote.slippage, "Emitted slippage should match calculate_slippage");
+        assert.isAbove(event.slippageBps, 0);
+      });
+
+      it("Reports sell slippage below the pre-trade spot price", async () => {
+        const amount = MILLION.muln(25);
+        const spotBefore = await spotPrice();
+
+        const event = await captureEvent("tokensSold", () => sell(trader, amount));
+
+        // Same integer math as the program: average fill per whole token, shortfall over spot
+        const average = event.solAmount.mul(new BN(1_000_000_000)).div(amount);
+        const expected = spotBefore.sub(average).muln(10_000).div(spotBefore);
+        assert.equal(event.slippageBps, expected.toNumber());
+        assert.isAbove(event.slippageBps, 0);
+      });
+    });
+  });
+
+  describe("Selling Tokens", () => {
+    let sellerTokenAccount: PublicKey;
+    let sellerUserPositionPda: PublicKey;
+
+    before(async () => {
+      sellerTokenAccount = getAssociatedTokenAddressSync(
         mintPda,
-        unauthorizedUser.publicKey
+        seller.publicKey,
+        false
       );
 
-      const [unauthorizedUserPositionPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("user_position"), unauthorizedUser.publicKey.toBuffer(), tokenLaunchPda.toBuffer()],
+      [sellerUserPositionPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("user_position"), seller.publicKey.toBuffer(), tokenLaunchPda.toBuffer()],
         program.programId
       );
 
+      // First, seller needs to buy some tokens
+      const buyAmount = new BN(10_000_000_000); // 10 tokens
+      const maxSolCost = new BN(LAMPORTS_PER_SOL * 10);
+
       try {
         await program.methods
-          .buyTokens(buyAmount, maxSolCost)
+          .buyTokens(buyAmount, maxSolCost, new BN(0))
           .accounts({
             config: configPda,
             tokenLaunch: tokenLaunchPda,
             bondingCurve: bondingCurvePda,
             curveTokenAccount,
             solVault: solVaultPda,
-            userPosition: unauthorizedUserPositionPda,
+            userPosition: sellerUserPositionPda,
             mint: mintPda,
-            buyerTokenAccount: unauthorizedBuyerTokenAccount,
-            buyer: unauthorizedUser.publicKey,
+            buyerTokenAccount: sellerTokenAccount,
+            buyer: seller.publicKey,
             feeRecipient: feeRecipient,
             tokenProgram: TOKEN_PROGRAM_ID,
             associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
           })
-          .signers([unauthorizedUser])
+          .signers([seller])
           .rpc();
+        
+        console.log("✅ Seller bought tokens successfully");
+      } catch (error) {
+        console.log("\n⚠️  Seller buy simulation failed (rent check), but checking if logic succeeded...");
+        
+        if (error.logs) {
+          const hasSuccessLog = error.logs.some((log: string) => log.includes("Bought"));
+          if (hasSuccessLog) {
+            console.log("✅ Program logic succeeded, continuing with sell test...\n");
+            return; // Continue with test setup
+          }
+        }
+        throw error; // Re-throw if it's a real error
+      }
+    });
+
+    it("Sells tokens back to bonding curve", async () => {
+      const sellAmount = new BN(3_000_000_000); // 3 tokens
+      const minSolOutput = new BN(0); // Accept any amount (no slippage protection)
+
+      const sellerBalanceBefore = await provider.connection.getBalance(seller.publicKey);
+      const bondingCurveBefore = await program.account.bondingCurve.fetch(bondingCurvePda);
+
+      // Set up event listener for tokensSold event
+      let eventReceived = false;
+      let sellEvent: any = null;
+
+      const listener = program.addEventListener("tokensSold", (event, slot) => {
+        console.log("\n🎯 EVENT RECEIVED - TokensSold:");
+        console.log("   Seller:", event.seller.toString());
+        console.log("   Token Amount:", event.tokenAmount.toString(), `(${(event.tokenAmount.toNumber() / 1e9).toFixed(2)} tokens)`);
+        console.log("   SOL Amount:", event.solAmount.toString(), `(${(event.solAmount.toNumber() / 1e9).toFixed(6)} SOL)`);
+        console.log("   Platform Fee:", event.platformFee.toString(), `(${(event.platformFee.toNumber() / 1e9).toFixed(6)} SOL)`);
+        console.log("   Tokens Sold After:", event.tokensSoldAfter.toString(), `(${(event.tokensSoldAfter.toNumber() / 1e9).toFixed(2)} tokens)`);
+        console.log("   SOL Reserve After:", event.solReserveAfter.toString(), `(${(event.solReserveAfter.toNumber() / 1e9).toFixed(6)} SOL)`);
+        console.log("   Price Per Token:", event.pricePerToken.toString(), `(${(event.pricePerToken.toNumber() / 1e9).toFixed(9)} SOL per token)`);
+        console.log("   Slot:", slot);
+        eventReceived = true;
+        sellEvent = event;
+      });
+
+      const tx = await program.methods
+        .sellTokens(sellAmount, minSolOutput, new BN(0))
+        .accounts({
+          config: configPda,
+          tokenLaunch: tokenLaunchPda,
+          bondingCurve: bondingCurvePda,
+          curveTokenAccount,
+          solVault: solVaultPda,
+          userPosition: sellerUserPositionPda,
+          sellerTokenAccount,
+          seller: seller.publicKey,
+          feeRecipient: feeRecipient,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([seller])
+        .rpc();
+
+      console.log("Sell tokens tx:", tx);
+
+      // Wait for event to be processed
+      await new Promise(resolve => setTimeout(resolve, 1000));
+
+      // Remove listener
+      await program.removeEventListener(listener);
+
+      // Verify event was received and amounts match
+      if (eventReceived && sellEvent) {
+        console.log("\n✅ Event verification:");
+        console.log("   Requested amount:", sellAmount.toString(), "tokens");
+        console.log("   Event amount:", sellEvent.tokenAmount.toString(), "tokens");
+        assert.equal(sellEvent.tokenAmount.toString(), sellAmount.toString(), "Event token amount should match requested amount");
+        console.log("   ✓ Amounts match!");
+      }
+
+      // Verify tokens sold decreased
+      const bondingCurveAfter = await program.account.bondingCurve.fetch(bondingCurvePda);
+      assert.ok(bondingCurveAfter.tokensSold.lt(bondingCurveBefore.tokensSold));
+
+      // Note: Seller's SOL balance might not change much due to tx fees and how SOL flows
+      const sellerBalanceAfter = await provider.connection.getBalance(seller.publicKey);
+      console.log("Seller balance before:", sellerBalanceBefore, "after:", sellerBalanceAfter);
+      const balanceDiff = sellerBalanceAfter - sellerBalanceBefore;
+      console.log("Balance difference:", balanceDiff);
+      // Main verification is through user position which tracks SOL received
+      // Balance diff can be 0 or negative due to fees
+
+      // Verify user position updated
+      const userPosition = await program.account.userPosition.fetch(sellerUserPositionPda);
+      console.log("User position after sell:", {
+        tokenAmount: userPosition.tokenAmount.toString(),
+        solReceived: userPosition.solReceived.toString(),
+        sellCount: userPosition.sellCount
+      });
+      assert.equal(userPosition.sellCount, 1, "Sell count should be 1");
+      // Note: solReceived might be 0 if program doesn't track it in user position
+
+      // Cleanup: ensure listener is removed
+      try {
+        await program.removeEventListener(listener);
+      } catch (err) {
+        // Listener might already be removed, ignore
+      }
+    });
+
+    it("Fails when slippage exceeded on sell", async () => {
+      const sellAmount = new BN(1_000_000_000); // 1 token
+      const minSolOutput = new BN(LAMPORTS_PER_SOL * 100); // Unrealistically high
+
+      try {
+        await program.methods
+          .sellTokens(sellAmount, minSolOutput, new BN(0))
+          .accounts({
+            config: configPda,
+            tokenLaunch: tokenLaunchPda,
+            bondingCurve: bondingCurvePda,
+            curveTokenAccount,
+            solVault: solVaultPda,
+            userPosition: sellerUserPositionPda,
+            sellerTokenAccount,
+            seller: seller.publicKey,
+            feeRecipient: feeRecipient,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([seller])
+          .rpc();
+        assert.fail("Should have failed due to slippage");
+      } catch (error) {
+        // Accept any error
+        assert.ok(error);
+      }
+    });
+
+    it("Fails to sell more than balance", async () => {
+      const userPosition = await program.account.userPosition.fetch(sellerUserPositionPda);
+      const sellAmount = userPosition.tokenAmount.add(new BN(1_000_000_000)); // More than owned
+      const minSolOutput = new BN(0);
+
+      try {
+        await program.methods
+          .sellTokens(sellAmount, minSolOutput, new BN(0))
+          .accounts({
+            config: configPda,
+            tokenLaunch: tokenLaunchPda,
+            bondingCurve: bondingCurvePda,
+            curveTokenAccount,
+            solVault: solVaultPda,
+            userPosition: sellerUserPositionPda,
+            sellerTokenAccount,
+            seller: seller.publicKey,
+            feeRecipient: feeRecipient,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([seller])
+          .rpc();
+        assert.fail("Should have failed with insufficient balance");
+      } catch (error) {
+        // Will fail at token transfer level
+        assert.ok(error);
+      }
+    });
+  });
+
+  describe("Token Launch Management", () => {
+    it("Toggles token launch active status", async () => {
+      const tokenLaunchBefore = await program.account.tokenLaunch.fetch(tokenLaunchPda);
+      const activeStatusBefore = tokenLaunchBefore.isActive;
+
+      const tx = await program.methods
+        .toggleTokenLaunchActive()
+        .accounts({
+          tokenLaunch: tokenLaunchPda,
+          creator: creator.publicKey,
+        })
+        .signers([creator])
+        .rpc();
+
+      console.log("Toggle active tx:", tx);
+
+      const tokenLaunchAfter = await program.account.tokenLaunch.fetch(tokenLaunchPda);
+      assert.equal(tokenLaunchAfter.isActive, !activeStatusBefore);
+
+      // Toggle back
+      await program.methods
+        .toggleTokenLaunchActive()
+        .accounts({
+          tokenLaunch: tokenLaunchPda,
+          creator: creator.publicKey,
+        })
+        .signers([creator])
+        .rpc();
+
+      const tokenLaunchRestored = await program.account.tokenLaunch.fetch(tokenLaunchPda);
+      assert.equal(tokenLaunchRestored.isActive, activeStatusBefore);
+    });
+
+    it("Updates metadata URI", async () => {
+      const newUri = "https://example.com/new-metadata.json";
+
+      const tx = await program.methods
+        .updateMetadataUri(newUri)
+        .accounts({
+          tokenLaunch: tokenLaunchPda,
+          creator: creator.publicKey,
+        })
+        .signers([creator])
+        .rpc();
+
+      console.log("Update metadata URI tx:", tx);
+
+      const tokenLaunch = await program.account.tokenLaunch.fetch(tokenLaunchPda);
+      assert.equal(tokenLaunch.metadataUri, newUri);
+    });
+
+    it("Fails to toggle active status from non-creator", async () => {
+      try {
+        await program.methods
+          .toggleTokenLaunchActive()
+          .accounts({
+            tokenLaunch: tokenLaunchPda,
+            creator: buyer.publicKey, // Wrong creator
+          })
+          .signers([buyer])
+          .rpc();
+        assert.fail("Should have failed with unauthorized");
+      } catch (error) {
+        assert.ok(error);
+      }
+    });
+
+    it("Fails to update metadata from non-creator", async () => {
+      const newUri = "https://malicious.com/fake.json";
+
+      try {
+        await program.methods
+          .updateMetadataUri(newUri)
+          .accounts({
+            tokenLaunch: tokenLaunchPda,
+            creator: buyer.publicKey, // Wrong creator
+          })
+          .signers([buyer])
+          .rpc();
+        assert.fail("Should have failed with unauthorized");
+      } catch (error) {
+        console.log("✅ Correctly rejected metadata update from non-creator");
+        assert.ok(error.toString().includes("ConstraintHasOne") || 
+                 error.toString().includes("Unauthorized"));
+      }
+    });
+
+    /**
+     * Test the metadata mutability flag chosen at launch creation
+     */
+    describe("Metadata Mutability", () => {
+      const createLaunch = async (name: string, isMutable: boolean) => {
+        const launch = new TestLaunch(program, authority.publicKey, name);
+        await launch.create({
+          symbol: "META",
+          metadataUri: "https://example.com/meta.json",
+          description: "Launch used to test metadata mutability",
+          isMutable,
+        });
+        return launch;
+      };
+
+      it("Immutable launch rejects metadata URI and description updates", async () => {
+        const launch = await createLaunch("Immutable Token", false);
+        const accounts = {
+          tokenLaunch: launch.tokenLaunch,
+          bondingCurve: launch.bondingCurve,
+          creator: authority.publicKey,
+        };
+
+        const tokenLaunch = await program.account.tokenLaunch.fetch(launch.tokenLaunch);
+        assert.isFalse(tokenLaunch.isMutable);
+
+        try {
+          await program.methods
+            .updateMetadataUri("https://example.com/changed.json")
+            .accounts(accounts)
+            .rpc();
+          assert.fail("URI update on an immutable launch should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "MetadataFrozen");
+        }
+
+        try {
+          await program.methods
+            .updateDescription("Changed description")
+            .accounts(accounts)
+            .rpc();
+          assert.fail("Description update on an immutable launch should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "MetadataFrozen");
+        }
+      });
+
+      it("Mutable launch still accepts updates", async () => {
+        const launch = await createLaunch("Mutable Token", true);
+        const newUri = "https://example.com/updated.json";
+
+        await program.methods
+          .updateMetadataUri(newUri)
+          .accounts({
+            tokenLaunch: launch.tokenLaunch,
+            bondingCurve: launch.bondingCurve,
+            creator: authority.publicKey,
+          })
+          .rpc();
+
+        const tokenLaunch = await program.account.tokenLaunch.fetch(launch.tokenLaunch);
+        assert.equal(tokenLaunch.metadataUri, newUri);
+      });
+    });
+
+    /**
+     * Test the cooldown between metadata URI / description updates
+     */
+    describe("Metadata Update Cooldown", () => {
+      const createLaunch = async (name: string) => {
+        const launch = new TestLaunch(program, authority.publicKey, name);
+        await launch.create({
+          symbol: "MCOOL",
+          metadataUri: "https://example.com/cooldown.json",
+          description: "Launch used to test metadata update cooldown",
+        });
+        return launch;
+      };
+
+      const setCooldown = (seconds: number) =>
+        program.methods
+          .updateMetadataUpdateCooldown(seconds)
+          .accounts({ config: configPdaFor(program), authority: authority.publicKey })
+          .rpc();
+
+      let accounts: { tokenLaunch: PublicKey; bondingCurve: PublicKey; creator: PublicKey };
+
+      before(async () => {
+        const launch = await createLaunch("Cooldown Token");
+        accounts = {
+          tokenLaunch: launch.tokenLaunch,
+          bondingCurve: launch.bondingCurve,
+          creator: authority.publicKey,
+        };
+      });
+
+      after(async () => {
+        // Other suites update metadata back to back
+        await setCooldown(0);
+      });
+
+      it("Rejects cooldown updates from non-admin signers", async () => {
+        const outsider = Keypair.generate();
+        try {
+          await program.methods
+            .updateMetadataUpdateCooldown(60)
+            .accounts({ config: configPdaFor(program), authority: outsider.publicKey })
+            .signers([outsider])
+            .rpc();
+          assert.fail("Non-admin cooldown update should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "Unauthorized");
+        }
+      });
+
+      it("Throttles rapid URI and description updates", async () => {
+        await setCooldown(60);
+        const config = await program.account.launchpadConfig.fetch(configPdaFor(program));
+        assert.equal(config.metadataUpdateCooldownSeconds, 60);
+
+        await program.methods
+          .updateMetadataUri("https://example.com/first.json")
+          .accounts(accounts)
+          .rpc();
+        const tokenLaunch = await program.account.tokenLaunch.fetch(accounts.tokenLaunch);
+        assert.isAbove(tokenLaunch.lastMetadataUpdate.toNumber(), 0);
+
+        try {
+          await program.methods
+            .updateMetadataUri("https://example.com/second.json")
+            .accounts(accounts)
+            .rpc();
+          assert.fail("Second URI update within the cooldown should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "CooldownActive");
+        }
+
+        // URI and description share one cooldown
+        try {
+          await program.methods
+            .updateDescription("Changed right away")
+            .accounts(accounts)
+            .rpc();
+          assert.fail("Description update within the cooldown should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "CooldownActive");
+        }
+
+        const after = await program.account.tokenLaunch.fetch(accounts.tokenLaunch);
+        assert.equal(after.metadataUri, "https://example.com/first.json");
+      });
+
+      it("Allows the next update once the cooldown has passed", async () => {
+        await setCooldown(2);
+        await new Promise((resolve) => setTimeout(resolve, 3000));
+
+        await program.methods
+          .updateDescription("Updated after the cooldown")
+          .accounts(accounts)
+          .rpc();
+        const tokenLaunch = await program.account.tokenLaunch.fetch(accounts.tokenLaunch);
+        assert.equal(tokenLaunch.description, "Updated after the cooldown");
+      });
+    });
+
+    /**
+     * Test pause/resume semantics and accumulated pause time on a launch
+     */
+    describe("Launch Pause", () => {
+      const launch = new TestLaunch(program, authority.publicKey, "Pausable Token");
+
+      const toggle = async () => {
+        let toggledEvent = null;
+        const listener = program.addEventListener("launchStatusToggled", (event) => {
+          toggledEvent = event;
+        });
+
+        await program.methods
+          .toggleTokenLaunchActive()
+          .accounts({
+            tokenLaunch: launch.tokenLaunch,
+            bondingCurve: launch.bondingCurve,
+            creator: authority.publicKey,
+          })
+          .rpc();
+
+        await new Promise((resolve) => setTimeout(resolve, 1000));
+        await program.removeEventListener(listener);
+        assert.ok(toggledEvent, "LaunchStatusToggled should be emitted");
+        return toggledEvent;
+      };
+
+      before(async () => {
+        await launch.create({
+          symbol: "PAUSE",
+          metadataUri: "https://example.com/pause.json",
+          description: "Launch used to test pause tracking",
+        });
+      });
+
+      it("Records a pause with its start time", async () => {
+        const event = await toggle();
+        assert.deepEqual(event.change, { pause: {} });
+        assert.isFalse(event.isActive);
+        assert.equal(event.pausedSeconds.toNumber(), 0);
+
+        const tokenLaunch = await program.account.tokenLaunch.fetch(launch.tokenLaunch);
+        assert.isFalse(tokenLaunch.isActive);
+        assert.equal(tokenLaunch.pausedAt.toNumber(), event.timestamp.toNumber());
+        assert.equal(tokenLaunch.totalPausedSeconds.toNumber(), 0);
+      });
+
+      it("Accumulates the paused duration on resume", async () => {
+        await new Promise((resolve) => setTimeout(resolve, 3000));
+        const pausedAt = (await program.account.tokenLaunch.fetch(launch.tokenLaunch)).pausedAt;
+
+        const event = await toggle();
+        assert.deepEqual(event.change, { resume: {} });
+        assert.isTrue(event.isActive);
+        assert.equal(event.pausedSeconds.toNumber(), event.timestamp.sub(pausedAt).toNumber());
+        assert.isAbove(event.pausedSeconds.toNumber(), 0);
+
+        const tokenLaunch = await program.account.tokenLaunch.fetch(launch.tokenLaunch);
+        assert.isTrue(tokenLaunch.isActive);
+        assert.equal(tokenLaunch.pausedAt.toNumber(), 0);
+        assert.equal(tokenLaunch.totalPausedSeconds.toString(), event.pausedSeconds.toString());
+      });
+
+      it("Adds later pauses to the running total", async () => {
+        const before = (await program.account.tokenLaunch.fetch(launch.tokenLaunch)).totalPausedSeconds;
+
+        await toggle();
+        await new Promise((resolve) => setTimeout(resolve, 2000));
+        const event = await toggle();
+
+        const tokenLaunch = await program.account.tokenLaunch.fetch(launch.tokenLaunch);
+        assert.equal(
+          tokenLaunch.totalPausedSeconds.toString(),
+          before.add(event.pausedSeconds).toString()
+        );
+        assert.equal(event.totalPausedSeconds.toString(), tokenLaunch.totalPausedSeconds.toString());
+      });
+    });
+
+    /**
+     * Test that creator pauses give in-flight trades a notice delay before taking effect
+     */
+    describe("Delayed Deactivation", () => {
+      const trader = Keypair.generate();
+
+      const DELAY_SECONDS = 4;
+
+      const launch = new TestLaunch(program, authority.publicKey, "Delayed Pause Token");
+
+      const toggle = async () => {
+        let toggledEvent = null;
+        const listener = program.addEventListener("launchStatusToggled", (event) => {
+          toggledEvent = event;
+        });
+
+        await program.methods
+          .toggleTokenLaunchActive()
+          .accounts({
+            tokenLaunch: launch.tokenLaunch,
+            bondingCurve: launch.bondingCurve,
+            creator: authority.publicKey,
+          })
+          .rpc();
+
+        await new Promise((resolve) => setTimeout(resolve, 1000));
+        await program.removeEventListener(listener);
+        assert.ok(toggledEvent, "LaunchStatusToggled should be emitted");
+        return toggledEvent;
+      };
+
+      const setDelay = (seconds: number) =>
+        program.methods
+          .updateDeactivationDelay(seconds)
+          .accounts({ config: launch.config, authority: authority.publicKey })
+          .rpc();
+
+      before(async () => {
+        await airdrop(provider, trader.publicKey, 10);
+
+        await launch.create({
+          symbol: "DELAY",
+          metadataUri: "https://example.com/delayed-pause.json",
+          description: "Launch used to test delayed deactivation",
+        });
+
+        await setDelay(DELAY_SECONDS);
+      });
+
+      after(async () => {
+        await setDelay(0);
+      });
+
+      it("Schedules the pause and keeps trading open during the notice", async () => {
+        const event = await toggle();
+        assert.deepEqual(event.change, { pauseScheduled: {} });
+        assert.isTrue(event.isActive);
+        assert.equal(event.deactivatesAt.toNumber(), event.timestamp.toNumber() + DELAY_SECONDS);
+
+        // An in-flight buy still lands
+        await launch.buy(trader, MILLION);
+        const tokenLaunch = await program.account.tokenLaunch.fetch(launch.tokenLaunch);
+        assert.isTrue(tokenLaunch.isActive);
+        assert.equal(tokenLaunch.deactivatesAt.toString(), event.deactivatesAt.toString());
+        assert.isNull(tokenLaunch.deactivationReason);
+      });
+
+      it("Cancels the scheduled pause when toggled again", async () => {
+        const event = await toggle();
+        assert.deepEqual(event.change, { pauseCancelled: {} });
+        assert.isTrue(event.isActive);
+
+        await new Promise((resolve) => setTimeout(resolve, (DELAY_SECONDS + 1) * 1000));
+        await launch.buy(trader, MILLION);
+        const tokenLaunch = await program.account.tokenLaunch.fetch(launch.tokenLaunch);
+        assert.equal(tokenLaunch.deactivatesAt.toNumber(), 0);
+      });
+
+      it("Rejects trades once the notice runs out", async () => {
+        const scheduled = await toggle();
+        await new Promise((resolve) => setTimeout(resolve, (DELAY_SECONDS + 2) * 1000));
+
+        try {
+          await launch.buy(trader, MILLION);
+          assert.fail("Buy after the pause took effect should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "TradingInactive");
+        }
+
+        const summary = await program.methods
+          .getLaunchSummary()
+          .accounts({ tokenLaunch: launch.tokenLaunch, bondingCurve: launch.bondingCurve })
+          .view();
+        assert.isFalse(summary.isActive, "Views report the pause once it has taken effect");
+
+        // Resuming dates the pause from when it took effect, not from the resume
+        const resumed = await toggle();
+        assert.deepEqual(resumed.change, { resume: {} });
+        assert.equal(
+          resumed.pausedSeconds.toNumber(),
+          resumed.timestamp.sub(scheduled.deactivatesAt).toNumber()
+        );
+        await launch.buy(trader, MILLION);
+      });
+
+      it("Records why trading stopped for an immediate pause", async () => {
+        await setDelay(0);
+        const event = await toggle();
+        assert.deepEqual(event.change, { pause: {} });
+
+        const tokenLaunch = await program.account.tokenLaunch.fetch(launch.tokenLaunch);
+        assert.deepEqual(tokenLaunch.deactivationReason, { pause: {} });
+        assert.equal(tokenLaunch.pausedAt.toNumber(), event.timestamp.toNumber());
+
+        await toggle();
+        const resumed = await program.account.tokenLaunch.fetch(launch.tokenLaunch);
+        assert.isNull(resumed.deactivationReason);
+      });
+    });
+
+    /**
+     * Test admin freezes on individual launches
+     */
+    describe("Launch Freeze", () => {
+      const trader = Keypair.generate();
+
+      const launch = new TestLaunch(program, authority.publicKey, "Freeze Token");
+
+      before(async () => {
+        await airdrop(provider, trader.publicKey, 100);
+
+        await launch.create({
+          symbol: "FRZ",
+          metadataUri: "https://example.com/freeze.json",
+          description: "Launch used to test admin freezes",
+        });
+      });
+
+      describe("Admin Freeze", () => {
+        const setFrozen = (frozen: boolean) =>
+          (frozen ? program.methods.adminFreezeLaunch() : program.methods.adminUnfreezeLaunch())
+            .accounts({
+              config: launch.config,
+              tokenLaunch: launch.tokenLaunch,
+              authority: authority.publicKey,
+            })
+            .rpc();
+
+        it("Blocks buys and sells on a frozen launch", async () => {
+          await launch.buy(trader, MILLION.mul(new BN(2)));
+          await setFrozen(true);
+
+          const tokenLaunch = await program.account.tokenLaunch.fetch(launch.tokenLaunch);
+          assert.ok(tokenLaunch.adminFrozen, "Launch should be frozen");
+          assert.ok(tokenLaunch.isActive, "Freeze is independent of is_active");
+
+          try {
+            await launch.buy(trader, MILLION);
+            assert.fail("Buy on a frozen launch should be rejected");
+          } catch (error) {
+            assert.include(error.toString(), "LaunchFrozen");
+          }
+
+          try {
+            await launch.sell(trader, MILLION);
+            assert.fail("Sell on a frozen launch should be rejected");
+          } catch (error) {
+            assert.include(error.toString(), "LaunchFrozen");
+          }
+        });
+
+        it("Creator cannot toggle a frozen launch back on", async () => {
+          try {
+            await program.methods
+              .toggleTokenLaunchActive()
+              .accounts({
+                tokenLaunch: launch.tokenLaunch,
+                bondingCurve: launch.bondingCurve,
+                creator: authority.publicKey,
+              })
+              .rpc();
+            assert.fail("Toggle on a frozen launch should be rejected");
+          } catch (error) {
+            assert.include(error.toString(), "LaunchFrozen");
+          }
+        });
+
+        it("Rejects freezes from non-admin signers", async () => {
+          try {
+            await program.methods
+              .adminFreezeLaunch()
+              .accounts({
+                config: launch.config,
+                tokenLaunch: launch.tokenLaunch,
+                authority: trader.publicKey,
+              })
+              .signers([trader])
+              .rpc();
+            assert.fail("Non-admin freeze should be rejected");
+          } catch (error) {
+            assert.include(error.toString(), "Unauthorized");
+          }
+        });
+
+        it("Trading resumes once the admin unfreezes", async () => {
+          await setFrozen(false);
+          await launch.sell(trader, MILLION.mul(new BN(2)));
+
+          const position = await program.account.userPosition.fetch(launch.positionOf(trader.publicKey));
+          assert.equal(position.tokenAmount.toString(), "0");
+        });
+      });
+    });
+
+    /**
+     * Test closing a wound-down launch and reclaiming its account rent
+     */
+    describe("Close Launch", () => {
+      const trader = Keypair.generate();
+
+      const launch = new TestLaunch(program, authority.publicKey, "Closable Token");
+
+      const toggle = () =>
+        program.methods
+          .toggleTokenLaunchActive()
+          .accounts({
+            tokenLaunch: launch.tokenLaunch,
+            bondingCurve: launch.bondingCurve,
+            creator: authority.publicKey,
+          })
+          .rpc();
+
+      const closeLaunch = () =>
+        program.methods
+          .closeLaunch()
+          .accounts({
+            tokenLaunch: launch.tokenLaunch,
+            mint: launch.mint,
+            bondingCurve: launch.bondingCurve,
+            curveTokenAccount: launch.curveTokenAccount,
+            lpVault: launch.lpVault,
+            creatorVault: launch.creatorVault,
+            solVault: launch.solVault,
+            tradeHistory: launch.tradeHistory,
+            creator: authority.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+
+      before(async () => {
+        await airdrop(provider, trader.publicKey, 100);
+
+        await launch.create({
+          symbol: "CLOSE",
+          metadataUri: "https://example.com/closable.json",
+          description: "Launch used to test closing launches",
+        });
+      });
+
+      it("Refuses to close a live launch", async () => {
+        try {
+          await closeLaunch();
+          assert.fail("Closing an active launch should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "LaunchStillLive");
+        }
+      });
+
+      it("Refuses to close a paused launch that still has holders", async () => {
+        await launch.buy(trader, MILLION.mul(new BN(10)));
+        await toggle();
+
+        try {
+          await closeLaunch();
+          assert.fail("Closing a launch with holders should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "LaunchStillFunded");
+        }
+      });
+
+      it("Closes a wound-down launch and returns all rent to the creator", async () => {
+        // Unwind: let the holder exit, then pause again
+        await toggle();
+        const position = await program.account.userPosition.fetch(launch.positionOf(trader.publicKey));
+        await launch.sell(trader, position.tokenAmount);
+        await toggle();
+
+        const closedAccounts = [
+          launch.tokenLaunch,
+          launch.bondingCurve,
+          launch.curveTokenAccount,
+          launch.lpVault,
+          launch.creatorVault,
+          launch.solVault,
+          launch.tradeHistory,
+        ];
+        let expectedReclaim = 0;
+        for (const account of closedAccounts) {
+          expectedReclaim += await provider.connection.getBalance(account);
+        }
+        // The trade history is closed with the launch, not left holding its rent
+        const historyRent = await provider.connection.getBalance(launch.tradeHistory);
+        assert.isAbove(historyRent, 0, "Trade history rent should be reclaimed too");
+
+        let closedEvent = null;
+        const listener = program.addEventListener("launchClosed", (event) => {
+          closedEvent = event;
+        });
+
+        const creatorBefore = await provider.connection.getBalance(authority.publicKey);
+        await closeLaunch();
+        const creatorAfter = await provider.connection.getBalance(authority.publicKey);
+
+        await new Promise((resolve) => setTimeout(resolve, 1000));
+        await program.removeEventListener(listener);
+
+        for (const account of closedAccounts) {
+          assert.isNull(
+            await provider.connection.getAccountInfo(account),
+            `${account.toBase58()} should be closed`
+          );
+        }
+
+        // Only the transaction fee separates the creator's gain from the reclaimed rent
+        const TX_FEE = 5_000;
+        assert.equal(creatorAfter - creatorBefore, expectedReclaim - TX_FEE);
+
+        assert.ok(closedEvent, "LaunchClosed should be emitted");
+        assert.equal(closedEvent.lamportsReclaimed.toNumber(), expectedReclaim);
+        // The 800M curve supply and 200M LP allocation are burned
+        assert.equal(
+          closedEvent.tokensBurned.toString(),
+          MILLION.mul(new BN(1_000)).toString()
+        );
+      });
+    });
+  });
+
+  describe("Authorization Tests", () => {
+    const unauthorizedUser = Keypair.generate();
+
+    before(async () => {
+      // Fund unauthorized user
+      const airdropAmount = 10 * LAMPORTS_PER_SOL;
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(unauthorizedUser.publicKey, airdropAmount)
+      );
+      console.log("\n🔐 Testing authorization controls...");
+    });
+
+    it("Only authority can initialize launchpad", async () => {
+      // Try to reinitialize with wrong authority
+      const fakeConfigPda = Keypair.generate().publicKey;
+
+      try {
+        await program.methods
+          .initializeLaunchpad(200)
+          .accounts({
+            config: fakeConfigPda,
+            authority: unauthorizedUser.publicKey,
+            feeRecipient: feeRecipient,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([unauthorizedUser])
+          .rpc();
+        assert.fail("Should have failed - unauthorized initialization");
+      } catch (error) {
+        console.log("✅ Correctly prevented unauthorized launchpad initialization");
+        assert.ok(error);
+      }
+    });
+
+    it("Only token creator can toggle launch active status", async () => {
+      try {
+        await program.methods
+          .toggleTokenLaunchActive()
+          .accounts({
+            tokenLaunch: tokenLaunchPda,
+            creator: unauthorizedUser.publicKey,
+          })
+          .signers([unauthorizedUser])
+          .rpc();
+        assert.fail("Should have failed - unauthorized toggle");
+      } catch (error) {
+        console.log("✅ Correctly prevented unauthorized toggle");
+        // Any error is expected - could be constraint, signer, or simulation error
+        assert.ok(error, "Should throw error for unauthorized access");
+      }
+    });
+
+    it("Only token creator can update metadata", async () => {
+      const maliciousUri = "https://phishing.com/fake-metadata.json";
+
+      try {
+        await program.methods
+          .updateMetadataUri(maliciousUri)
+          .accounts({
+            tokenLaunch: tokenLaunchPda,
+            creator: unauthorizedUser.publicKey,
+          })
+          .signers([unauthorizedUser])
+          .rpc();
+        assert.fail("Should have failed - unauthorized metadata update");
+      } catch (error) {
+        console.log("✅ Correctly prevented unauthorized metadata update");
+        // Any error is expected - could be constraint, signer, or simulation error
+        assert.ok(error, "Should throw error for unauthorized access");
+      }
+    });
+
+    it("Only token creator can withdraw liquidity after graduation", async () => {
+      try {
+        const liquidityRecipient = Keypair.generate();
+        const tokenRecipient = getAssociatedTokenAddressSync(
+          mintPda,
+          liquidityRecipient.publicKey
+        );
+
+        await program.methods
+          .withdrawLiquidity()
+          .accounts({
+            config: configPda,
+            tokenLaunch: tokenLaunchPda,
+            bondingCurve: bondingCurvePda,
+            solVault: solVaultPda,
+            lpVault: PublicKey.findProgramAddressSync(
+              [Buffer.from("lp_vault"), bondingCurvePda.toBuffer()],
+              program.programId
+            )[0],
+            solRecipient: liquidityRecipient.publicKey,
+            tokenRecipient,
+            feeRecipient,
+            authority: unauthorizedUser.publicKey, // Wrong authority
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([unauthorizedUser])
+          .rpc();
+        assert.fail("Should have failed - unauthorized liquidity withdrawal");
+      } catch (error) {
+        console.log("✅ Correctly prevented unauthorized liquidity withdrawal");
+        // Any error is expected - could be auth, graduation, or simulation error
+        assert.ok(error, "Should throw error for unauthorized access");
+      }
+    });
+
+    it("Verifies creator constraint on TokenLaunch account", async () => {
+      const tokenLaunch = await program.account.tokenLaunch.fetch(tokenLaunchPda);
+      
+      console.log("\n🔒 Creator Authorization Check:");
+      console.log("  Token Launch Creator:", tokenLaunch.creator.toString());
+      console.log("  Actual Creator:", creator.publicKey.toString());
+      console.log("  Unauthorized User:", unauthorizedUser.publicKey.toString());
+      
+      assert.ok(tokenLaunch.creator.equals(creator.publicKey), 
+        "Creator must match original creator");
+      assert.ok(!tokenLaunch.creator.equals(unauthorizedUser.publicKey), 
+        "Unauthorized user cannot be creator");
+    });
+
+    it("Verifies authority constraint on LaunchpadConfig", async () => {
+      const config = await program.account.launchpadConfig.fetch(configPda);
+      
+      console.log("\n🔒 Launchpad Authority Check:");
+      console.log("  Config Authority:", config.authority.toString());
+      console.log("  Wallet Authority:", authority.publicKey.toString());
+      console.log("  Unauthorized User:", unauthorizedUser.publicKey.toString());
+      
+      assert.ok(config.authority.equals(authority.publicKey), 
+        "Config authority must match wallet");
+      assert.ok(!config.authority.equals(unauthorizedUser.publicKey), 
+        "Unauthorized user cannot be authority");
+    });
+
+    it("Anyone can buy tokens (no auth required)", async () => {
+      // Positive test - unauthorized user CAN buy
+      const buyAmount = new BN(1_000_000_000); // 1 token
+      const maxSolCost = new BN(LAMPORTS_PER_SOL);
+
+      const unauthorizedBuyerTokenAccount = getAssociatedTokenAddressSync(
+        mintPda,
+        unauthorizedUser.publicKey
+      );
+
+      const [unauthorizedUserPositionPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("user_position"), unauthorizedUser.publicKey.toBuffer(), tokenLaunchPda.toBuffer()],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .buyTokens(buyAmount, maxSolCost, new BN(0))
+          .accounts({
+            config: configPda,
+            tokenLaunch: tokenLaunchPda,
+            bondingCurve: bondingCurvePda,
+            curveTokenAccount,
+            solVault: solVaultPda,
+            userPosition: unauthorizedUserPositionPda,
+            mint: mintPda,
+            buyerTokenAccount: unauthorizedBuyerTokenAccount,
+            buyer: unauthorizedUser.publicKey,
+            feeRecipient: feeRecipient,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([unauthorizedUser])
+          .rpc();
+
+        console.log("✅ Anyone can buy tokens (permissionless trading confirmed)");
+      } catch (error) {
+        // This is OK - might fail due to insufficient funds or other reasons
+        // The important thing is it's not an authorization error
+        if (error.toString().includes("Unauthorized") || 
+            error.toString().includes("ConstraintHasOne")) {
+          assert.fail("Buy should not have authorization restrictions");
+        }
+        console.log("⚠️  Buy failed for non-auth reason (OK):", error.message?.substring(0, 100));
+      }
+    });
+
+    it("Anyone can sell tokens they own (no auth required)", async () => {
+      // Positive test - any token holder can sell
+      console.log("✅ Sell tokens is permissionless (verified in earlier tests)");
+      // Already tested in "Selling Tokens" section with seller account
+      // No additional auth restrictions beyond token ownership
+    });
+
+    it("Summary: Authorization model", async () => {
+      console.log("\n" + "=".repeat(70));
+      console.log("🔐 AUTHORIZATION MODEL SUMMARY");
+      console.log("=".repeat(70));
+      
+      console.log("\n📋 Restricted Operations (Creator/Authority Only):");
+      console.log("  ✅ initialize_launchpad - Authority only");
+      console.log("  ✅ toggle_token_launch_active - Token creator only");
+      console.log("  ✅ update_metadata_uri - Token creator only");
+      console.log("  ✅ withdraw_liquidity - Token creator only");
+      
+      console.log("\n🌐 Permissionless Operations (Anyone):");
+      console.log("  ✅ create_token_launch - Any user can create");
+      console.log("  ✅ buy_tokens - Any user can buy");
+      console.log("  ✅ sell_tokens - Any token holder can sell");
+      console.log("  ✅ get_buy_quote - Any user can query");
+      
+      console.log("\n🔒 Security Mechanisms:");
+      console.log("  • has_one constraint on TokenLaunch.creator");
+      console.log("  • has_one constraint on LaunchpadConfig.authority");
+      console.log("  • PDA derivation prevents address spoofing");
+      console.log("  • Signer verification on all mutations");
+      
+      console.log("\n✅ All authorization tests passed!");
+      console.log("=".repeat(70));
+    });
+  });
+
+  describe("Get Buy Quote", () => {
+    it("Gets accurate buy quote", async () => {
+      const quoteAmount = new BN(1_000_000_000); // 1 token
+
+      try {
+        const quote = await program.methods
+          .getBuyQuote(quoteAmount)
+          .accounts({
+            config: configPda,
+            bondingCurve: bondingCurvePda,
+            tokenLaunch: tokenLaunchPda,
+          })
+          .view();
+
+        console.log("Buy quote result:", {
+          cost: quote.cost.toString(),
+          spotPrice: quote.spotPrice.toString(),
+          slippage: quote.slippage,
+        });
+        
+        // Verify the quote has expected fields
+        assert.ok(quote.cost, "Quote should have cost");
+        assert.ok(quote.spotPrice, "Quote should have spotPrice");
+        assert.ok(typeof quote.slippage === 'number', "Quote should have slippage as number");
+        
+        console.log("✅ View function works correctly!");
+      } catch (error) {
+        console.log("Quote test error:", error.message);
+        throw error;
+      }
+    });
+  });
+
+  describe("Get Spot Price", () => {
+    it("Gets current spot price without transaction", async () => {
+      try {
+        const spotPriceInfo = await program.methods
+          .getSpotPrice()
+          .accounts({
+            bondingCurve: bondingCurvePda,
+            tokenLaunch: tokenLaunchPda,
+          })
+          .view();
+
+        console.log("\n📊 Spot Price Info:");
+        console.log("  Spot Price:", spotPriceInfo.spotPrice.toString(), "lamports per token");
+        console.log("  Tokens Sold:", spotPriceInfo.tokensSold.toString(), 
+          `(${spotPriceInfo.tokensSold.toNumber() / 1e9} tokens)`);
+        console.log("  SOL Reserve:", spotPriceInfo.solReserve.toString(), 
+          `(${spotPriceInfo.solReserve.toNumber() / LAMPORTS_PER_SOL} SOL)`);
+        
+        // Verify the spot price info has expected fields
+        assert.ok(spotPriceInfo.spotPrice, "SpotPrice should have spotPrice");
+        assert.ok(spotPriceInfo.tokensSold !== undefined, "SpotPrice should have tokensSold");
+        assert.ok(spotPriceInfo.solReserve !== undefined, "SpotPrice should have solReserve");
+        
+        // Calculate and display USD price
+        const SOL_PRICE_USD = 150;
+        const spotPriceUsd = (spotPriceInfo.spotPrice.toNumber() / LAMPORTS_PER_SOL) * SOL_PRICE_USD;
+        console.log("  USD Price: $" + spotPriceUsd.toFixed(10) + " per token");
+        
+        // Verify spot price is positive if tokens have been sold
+        const bondingCurve = await program.account.bondingCurve.fetch(bondingCurvePda);
+        if (bondingCurve.tokensSold.gt(new BN(0))) {
+          assert.ok(spotPriceInfo.spotPrice.gt(new BN(0)), 
+            "Spot price should be positive when tokens are sold");
+        }
+        
+        console.log("\n✅ Spot price view function works correctly!");
+      } catch (error) {
+        console.log("Spot price test error:", error.message);
+        throw error;
+      }
+    });
+
+    it("Verifies spot price increases as tokens are sold", async () => {
+      // Get initial spot price
+      const spotPriceBefore = await program.methods
+        .getSpotPrice()
+        .accounts({
+          bondingCurve: bondingCurvePda,
+          tokenLaunch: tokenLaunchPda,
+        })
+        .view();
+
+      console.log("\n📈 Testing spot price progression:");
+      console.log("  Before buy - Spot Price:", spotPriceBefore.spotPrice.toString());
+      console.log("  Before buy - Tokens Sold:", spotPriceBefore.tokensSold.toString());
+
+      // Make a massive purchase to ensure visible price impact on 800M supply curve
+      const buyAmount = new BN(150_000).mul(new BN(1_000_000_000)); // 150,000 tokens (0.01875% of 800M supply)
+      const maxSolCost = new BN(LAMPORTS_PER_SOL * 5000); // 5000 SOL max cost
+
+      const tempBuyer = Keypair.generate();
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(tempBuyer.publicKey, 10000 * LAMPORTS_PER_SOL) // 10,000 SOL for large purchase
+      );
+
+      const tempBuyerTokenAccount = getAssociatedTokenAddressSync(
+        mintPda,
+        tempBuyer.publicKey
+      );
+
+      const [tempUserPositionPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("user_position"), tempBuyer.publicKey.toBuffer(), tokenLaunchPda.toBuffer()],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .buyTokens(buyAmount, maxSolCost, new BN(0))
+          .accounts({
+            config: configPda,
+            tokenLaunch: tokenLaunchPda,
+            bondingCurve: bondingCurvePda,
+            curveTokenAccount,
+            solVault: solVaultPda,
+            userPosition: tempUserPositionPda,
+            mint: mintPda,
+            buyerTokenAccount: tempBuyerTokenAccount,
+            buyer: tempBuyer.publicKey,
+            feeRecipient: feeRecipient,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([tempBuyer])
+          .rpc();
+
+        // Get spot price after purchase
+        const spotPriceAfter = await program.methods
+          .getSpotPrice()
+          .accounts({
+            bondingCurve: bondingCurvePda,
+            tokenLaunch: tokenLaunchPda,
+          })
+          .view();
+
+        console.log("  After buy - Spot Price:", spotPriceAfter.spotPrice.toString());
+        console.log("  After buy - Tokens Sold:", spotPriceAfter.tokensSold.toString());
+
+        // Verify spot price increased
+        assert.ok(
+          spotPriceAfter.spotPrice.gt(spotPriceBefore.spotPrice),
+          "Spot price should increase after tokens are sold"
+        );
+
+        // Verify tokens sold increased
+        assert.ok(
+          spotPriceAfter.tokensSold.gt(spotPriceBefore.tokensSold),
+          "Tokens sold should increase"
+        );
+
+        const priceIncrease = spotPriceAfter.spotPrice.sub(spotPriceBefore.spotPrice);
+        const percentIncrease = priceIncrease.mul(new BN(10000))
+          .div(spotPriceBefore.spotPrice.gt(new BN(0)) ? spotPriceBefore.spotPrice : new BN(1));
+        
+        console.log("  Price Increase:", priceIncrease.toString(), "lamports");
+        console.log("  Percent Increase:", (percentIncrease.toNumber() / 100).toFixed(2) + "%");
+        console.log("\n✅ Verified spot price increases with sales!");
+
+      } catch (error) {
+        if (error.message?.includes("insufficient funds")) {
+          console.log("⚠️  Skipping progression test due to insufficient funds");
+          return;
+        }
+        throw error;
+      }
+    });
+  });
+
+  describe("Graduation Logic", () => {
+    it("Checks if bonding curve should graduate", async () => {
+      const bondingCurve = await program.account.bondingCurve.fetch(bondingCurvePda);
+      
+      console.log("Current state:", {
+        tokensSold: bondingCurve.tokensSold.toString(),
+        solReserve: bondingCurve.solReserve.toString(),
+        isGraduated: bondingCurve.isGraduated,
+        tradeCount: bondingCurve.tradeCount.toString(),
+      });
+
+      // Note: With current test purchases, unlikely to reach 800M tokens + $12k
+      // This is just checking the state
+      assert.ok(!bondingCurve.isGraduated, "Should not be graduated yet");
+    });
+  });
+
+  describe("Account State Verification", () => {
+    it("Verifies all account states are consistent", async () => {
+      const tokenLaunch = await program.account.tokenLaunch.fetch(tokenLaunchPda);
+      const bondingCurve = await program.account.bondingCurve.fetch(bondingCurvePda);
+
+      // Verify relationships
+      assert.ok(tokenLaunch.bondingCurve.equals(bondingCurvePda));
+      assert.ok(tokenLaunch.mint.equals(mintPda));
+      assert.ok(bondingCurve.tokenLaunch.equals(tokenLaunchPda));
+      
+      console.log("Final state:", {
+        totalSupply: tokenLaunch.totalSupply.toString(),
+        circulatingSupply: tokenLaunch.circulatingSupply.toString(),
+        tokensSold: bondingCurve.tokensSold.toString(),
+        solReserve: bondingCurve.solReserve.toString(),
+        totalVolume: bondingCurve.totalVolume.toString(),
+        tradeCount: bondingCurve.tradeCount.toString(),
+        isActive: tokenLaunch.isActive,
+        isGraduated: bondingCurve.isGraduated,
+      });
+
+      // Verify config if initialized
+      try {
+        const config = await program.account.launchpadConfig.fetch(configPda);
+        assert.ok(config.platformFeeBps === platformFeeBps);
+        console.log("Config verified:", {
+          authority: config.authority.toString(),
+          feeRecipient: config.feeRecipient.toString(),
+          platformFeeBps: config.platformFeeBps,
+        });
+      } catch (error) {
+        console.log("Config not initialized in this test run");
+      }
+    });
+
+    /**
+     * Test the launch positions aggregate used for pro-rata distribution
+     */
+    describe("Launch Positions", () => {
+      const alice = Keypair.generate();
+      const bob = Keypair.generate();
+      const carol = Keypair.generate();
+
+      let launch: TestLaunch;
+      let otherLaunch: TestLaunch;
+
+      const createLaunch = async (name: string) => {
+        const target = new TestLaunch(program, authority.publicKey, name);
+        await target.create({
+          symbol: "POS",
+          metadataUri: "https://example.com/positions.json",
+          description: "Launch used to test the positions aggregate",
+        });
+        return target;
+      };
+
+      const allPositions = (positions: PublicKey[]) =>
+        program.methods
+          .getAllPositionsForLaunch()
+          .accounts({ tokenLaunch: launch.tokenLaunch })
+          .remainingAccounts(
+            positions.map((pubkey) => ({ pubkey, isSigner: false, isWritable: false }))
+          )
+          .view();
+
+      before(async () => {
+        for (const trader of [alice, bob, carol]) {
+          await airdrop(provider, trader.publicKey, 10);
+        }
+
+        launch = await createLaunch("Positions Token");
+        otherLaunch = await createLaunch("Other Positions Token");
+
+        await launch.buy(alice, MILLION.muln(5));
+        await launch.buy(bob, MILLION.muln(3));
+        await launch.buy(carol, MILLION.muln(2));
+        await otherLaunch.buy(alice, MILLION);
+      });
+
+      it("Sums multiple positions and reports each holder's share", async () => {
+        const result = await allPositions([alice, bob, carol].map((t) => launch.positionOf(t.publicKey)));
+
+        assert.equal(result.totalTokens.toString(), MILLION.muln(10).toString());
+        assert.equal(result.positions.length, 3);
+        assert.ok(result.positions[0].user.equals(alice.publicKey));
+        assert.equal(result.positions[0].tokenAmount.toString(), MILLION.muln(5).toString());
+        assert.deepEqual(
+          result.positions.map((p) => p.shareBps),
+          [5_000, 3_000, 2_000]
+        );
+
+        // The curve agrees with the positions on how much has been sold
+        const curve = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        assert.equal(curve.tokensSold.toString(), result.totalTokens.toString());
+      });
+
+      it("Rejects a position from another launch", async () => {
+        try {
+          await allPositions([launch.positionOf(bob.publicKey), otherLaunch.positionOf(alice.publicKey)]);
+          assert.fail("Foreign position should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "UserPositionNotFound");
+        }
+      });
+
+      it("Rejects the same position supplied twice", async () => {
+        try {
+          await allPositions([launch.positionOf(alice.publicKey), launch.positionOf(alice.publicKey)]);
+          assert.fail("Duplicate position should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "DuplicatePosition");
+        }
+      });
+
+      it("Rejects accounts that aren't positions", async () => {
+        try {
+          await allPositions([launch.bondingCurve]);
+          assert.fail("Non-position account should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "AccountDiscriminatorMismatch");
+        }
+      });
+    });
+
+    /**
+     * Test the position return view and its annualized figure
+     */
+    describe("Position Return", () => {
+      const trader = Keypair.generate();
+
+      const launch = new TestLaunch(program, authority.publicKey, "Return Token");
+
+      const positionReturn = () =>
+        program.methods
+          .getPositionReturn()
+          .accounts({
+            tokenLaunch: launch.tokenLaunch,
+            bondingCurve: launch.bondingCurve,
+            userPosition: launch.positionOf(trader.publicKey),
+          })
+          .view();
+
+      before(async () => {
+        await airdrop(provider, trader.publicKey, 100);
+
+        await launch.create({
+          symbol: "RET",
+          metadataUri: "https://example.com/return.json",
+          description: "Launch used to test position returns",
+        });
+      });
+
+      it("Records the first buy time once per position", async () => {
+        await launch.buy(trader, MILLION.mul(new BN(10)));
+        const first = await program.account.userPosition.fetch(launch.positionOf(trader.publicKey));
+        assert.isAbove(first.firstBuyTimestamp.toNumber(), 0);
+
+        await new Promise((resolve) => setTimeout(resolve, 1000));
+        await launch.buy(trader, MILLION.mul(new BN(10)));
+        const second = await program.account.userPosition.fetch(launch.positionOf(trader.publicKey));
+        assert.equal(
+          second.firstBuyTimestamp.toString(),
+          first.firstBuyTimestamp.toString(),
+          "Later buys must not move the first buy time"
+        );
+      });
+
+      it("Marks a held position to spot and annualizes over the holding time", async () => {
+        // Let the position age on the validator clock
+        await new Promise((resolve) => setTimeout(resolve, 3000));
+
+        const result = await positionReturn();
+        const position = await program.account.userPosition.fetch(launch.positionOf(trader.publicKey));
+        const spotPrice = await program.methods
+          .getSpotPrice()
+          .accounts({ tokenLaunch: launch.tokenLaunch, bondingCurve: launch.bondingCurve })
+          .view();
+
+        const expectedValue = position.tokenAmount
+          .mul(spotPrice.spotPrice)
+          .div(new BN(1_000_000_000));
+        assert.equal(result.currentValue.toString(), expectedValue.toString());
+        assert.equal(
+          result.absoluteReturn.toString(),
+          result.currentValue.add(position.solReceived).sub(position.solInvested).toString()
+        );
+        assert.equal(
+          result.returnBps.toString(),
+          result.absoluteReturn.mul(new BN(10_000)).div(position.solInvested).toString()
+        );
+
+        assert.isAbove(result.heldSeconds.toNumber(), 0, "Position should have aged");
+        const SECONDS_PER_YEAR = new BN(365 * 24 * 60 * 60);
+        assert.equal(
+          result.annualizedReturnBps.toString(),
+          result.returnBps.mul(SECONDS_PER_YEAR).div(result.heldSeconds).toString()
+        );
+      });
+
+      it("Counts realized sell proceeds in the return", async () => {
+        await launch.sell(trader, MILLION.mul(new BN(5)));
+
+        const result = await positionReturn();
+        const position = await program.account.userPosition.fetch(launch.positionOf(trader.publicKey));
+        assert.isAbove(position.solReceived.toNumber(), 0);
+        assert.equal(result.solReceived.toString(), position.solReceived.toString());
+        assert.equal(
+          result.absoluteReturn.toString(),
+          result.currentValue.add(position.solReceived).sub(position.solInvested).toString()
+        );
+      });
+    });
+
+    /**
+     * Test position snapshots emitted for off-curve airdrops
+     */
+    describe("Position Snapshots", () => {
+      const alice = Keypair.generate();
+      const bob = Keypair.generate();
+      const carol = Keypair.generate();
+
+      let launch: TestLaunch;
+      let otherLaunch: TestLaunch;
+
+      const createLaunch = async (name: string) => {
+        const target = new TestLaunch(program, authority.publicKey, name);
+        await target.create({
+          symbol: "SNAP",
+          metadataUri: "https://example.com/snapshot.json",
+          description: "Launch used to test position snapshots",
+        });
+        return target;
+      };
+
+      const snapshot = async (positions: PublicKey[], signer?: Keypair) => {
+        const events = [];
+        const listener = program.addEventListener("positionSnapshot", (event) => {
+          events.push(event);
+        });
+        try {
+          const builder = program.methods
+            .snapshotPositions()
+            .accounts({
+              tokenLaunch: launch.tokenLaunch,
+              bondingCurve: launch.bondingCurve,
+              config: launch.config,
+              authority: signer ? signer.publicKey : authority.publicKey,
+            })
+            .remainingAccounts(
+              positions.map((pubkey) => ({ pubkey, isSigner: false, isWritable: false }))
+            );
+          await (signer ? builder.signers([signer]) : builder).rpc();
+          await new Promise((resolve) => setTimeout(resolve, 1000));
+        } finally {
+          await program.removeEventListener(listener);
+        }
+        return events;
+      };
+
+      before(async () => {
+        for (const trader of [alice, bob, carol]) {
+          await airdrop(provider, trader.publicKey, 10);
+        }
+
+        launch = await createLaunch("Snapshot Token");
+        otherLaunch = await createLaunch("Other Snapshot Token");
+
+        await launch.buy(alice, MILLION.muln(5));
+        await launch.buy(bob, MILLION.muln(3));
+        await launch.buy(carol, MILLION.muln(2));
+        await otherLaunch.buy(alice, MILLION);
+      });
+
+      it("Emits one event per position under a shared snapshot id", async () => {
+        const curveBefore = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        assert.equal(curveBefore.snapshotId.toString(), "0", "No snapshot taken yet");
+
+        const events = await snapshot([alice, bob, carol].map((t) => launch.positionOf(t.publicKey)));
+
+        assert.equal(events.length, 3);
+        assert.deepEqual(
+          events.map((e) => e.snapshotId.toString()),
+          ["1", "1", "1"]
+        );
+        assert.deepEqual(
+          events.map((e) => e.tokenAmount.toString()),
+          [MILLION.muln(5), MILLION.muln(3), MILLION.muln(2)].map((a) => a.toString())
+        );
+        assert.ok(events[0].user.equals(alice.publicKey));
+        assert.ok(events[2].user.equals(carol.publicKey));
+        assert.ok(events.every((e) => e.launch.equals(launch.tokenLaunch)));
+
+        const curveAfter = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        assert.equal(curveAfter.snapshotId.toString(), "1");
+      });
+
+      it("Tags the next snapshot with a new id", async () => {
+        await launch.buy(bob, MILLION);
+        const events = await snapshot([alice, bob].map((t) => launch.positionOf(t.publicKey)));
+
+        assert.equal(events.length, 2);
+        assert.ok(events.every((e) => e.snapshotId.toString() === "2"));
+        assert.equal(events[1].tokenAmount.toString(), MILLION.muln(4).toString());
+      });
+
+      it("Rejects a position from another launch", async () => {
+        try {
+          await snapshot([launch.positionOf(bob.publicKey), otherLaunch.positionOf(alice.publicKey)]);
+          assert.fail("Foreign position should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "UserPositionNotFound");
+        }
+      });
+
+      it("Rejects the same position supplied twice", async () => {
+        try {
+          await snapshot([launch.positionOf(alice.publicKey), launch.positionOf(alice.publicKey)]);
+          assert.fail("Duplicate position should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "DuplicatePosition");
+        }
+      });
+
+      it("Rejects snapshots from anyone but the admin or creator", async () => {
+        try {
+          await snapshot([launch.positionOf(alice.publicKey)], bob);
+          assert.fail("Snapshot by a holder should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "Unauthorized");
+        }
+
+        const curve = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        assert.equal(curve.snapshotId.toString(), "2", "Failed snapshots must not consume an id");
+      });
+    });
+
+    /**
+     * Test per-launch statistics tracked on the bonding curve
+     */
+    describe("Launch Stats", () => {
+      const alice = Keypair.generate();
+      const bob = Keypair.generate();
+      const carol = Keypair.generate();
+
+      const launch = new TestLaunch(program, authority.publicKey, "Stats Token");
+
+      const holderCount = async () =>
+        (await program.account.bondingCurve.fetch(launch.bondingCurve)).holderCount.toNumber();
+
+      const uniqueBuyers = async () =>
+        (await program.account.bondingCurve.fetch(launch.bondingCurve)).uniqueBuyers.toNumber();
+
+      before(async () => {
+        for (const trader of [alice, bob, carol]) {
+          await airdrop(provider, trader.publicKey, 100);
+        }
+
+        await launch.create({
+          symbol: "STAT",
+          metadataUri: "https://example.com/stats.json",
+          description: "Launch used to test statistics",
+        });
+      });
+
+      describe("Holder Count", () => {
+        it("Counts each wallet once on first acquisition", async () => {
+          assert.equal(await holderCount(), 0);
+
+          await launch.buy(alice, MILLION);
+          assert.equal(await holderCount(), 1);
+
+          // A second buy by the same wallet is not a new holder
+          await launch.buy(alice, MILLION);
+          assert.equal(await holderCount(), 1);
+
+          await launch.buy(bob, MILLION);
+          assert.equal(await holderCount(), 2);
+        });
+
+        it("Decrements only when a position is fully sold", async () => {
+          await launch.sell(alice, MILLION);
+          assert.equal(await holderCount(), 2, "Partial sell keeps the holder");
+
+          await launch.sell(alice, MILLION);
+          assert.equal(await holderCount(), 1, "Full sell removes the holder");
+        });
+
+        it("Re-counts a wallet that buys back in after a full sell", async () => {
+          await launch.buy(alice, MILLION);
+          assert.equal(await holderCount(), 2);
+
+          await launch.sell(alice, MILLION);
+          await launch.sell(bob, MILLION);
+          assert.equal(await holderCount(), 0);
+
+          const spotPrice = await program.methods
+            .getSpotPrice()
+            .accounts({ tokenLaunch: launch.tokenLaunch, bondingCurve: launch.bondingCurve })
+            .view();
+          assert.equal(spotPrice.holderCount.toNumber(), 0, "Stats should surface holder count");
+        });
+      });
+
+      describe("Launch Summary", () => {
+        it("Matches the TokenLaunch, BondingCurve and spot price sources", async () => {
+          await launch.buy(bob, MILLION.mul(new BN(5)));
+
+          const summary = await program.methods
+            .getLaunchSummary()
+            .accounts({ tokenLaunch: launch.tokenLaunch, bondingCurve: launch.bondingCurve })
+            .view();
+          const tokenLaunch = await program.account.tokenLaunch.fetch(launch.tokenLaunch);
+          const bondingCurve = await program.account.bondingCurve.fetch(launch.bondingCurve);
+          const spotPrice = await program.methods
+            .getSpotPrice()
+            .accounts({ tokenLaunch: launch.tokenLaunch, bondingCurve: launch.bondingCurve })
+            .view();
+
+          assert.equal(summary.name, tokenLaunch.name);
+          assert.equal(summary.symbol, tokenLaunch.symbol);
+          assert.equal(summary.metadataUri, tokenLaunch.metadataUri);
+          assert.ok(summary.mint.equals(launch.mint));
+          assert.equal(summary.circulatingSupply.toString(), tokenLaunch.circulatingSupply.toString());
+          assert.equal(summary.tokensSold.toString(), bondingCurve.tokensSold.toString());
+          assert.equal(summary.solReserve.toString(), bondingCurve.solReserve.toString());
+          assert.equal(summary.tradeCount.toString(), bondingCurve.tradeCount.toString());
+          assert.equal(summary.totalVolume.toString(), bondingCurve.totalVolume.toString());
+          assert.equal(summary.holderCount.toNumber(), 1);
+          assert.equal(summary.spotPrice.toString(), spotPrice.spotPrice.toString());
+          assert.equal(
+            summary.tokensUntilGraduation.toString(),
+            spotPrice.tokensUntilGraduation.toString()
+          );
+
+          const CURVE_SUPPLY = new BN(800_000_000).mul(new BN(1_000_000_000));
+          const expectedProgress = bondingCurve.tokensSold.mul(new BN(10_000)).div(CURVE_SUPPLY);
+          assert.equal(summary.graduationProgressBps, expectedProgress.toNumber());
+
+          const expectedUsdRaised = bondingCurve.solReserve
+            .mul(bondingCurve.solPriceUsd)
+            .div(new BN(1_000_000_000));
+          assert.equal(summary.usdRaised.toString(), expectedUsdRaised.toString());
+        });
+      });
+
+      describe("Unique Buyers", () => {
+        it("Does not count repeat or re-entering buyers again", async () => {
+          // Alice and Bob both bought above, Alice also re-entered after a full sell
+          assert.equal(await uniqueBuyers(), 2);
+
+          await launch.buy(alice, MILLION);
+          await launch.buy(bob, MILLION);
+          assert.equal(await uniqueBuyers(), 2, "Repeat buys must not inflate the count");
+        });
+
+        it("Increments for a first-time buyer and surfaces in the summary", async () => {
+          await launch.buy(carol, MILLION);
+          assert.equal(await uniqueBuyers(), 3);
+
+          const summary = await program.methods
+            .getLaunchSummary()
+            .accounts({ tokenLaunch: launch.tokenLaunch, bondingCurve: launch.bondingCurve })
+            .view();
+          assert.equal(summary.uniqueBuyers.toNumber(), 3);
+          assert.isAbove(summary.tradeCount.toNumber(), summary.uniqueBuyers.toNumber());
+        });
+      });
+    });
+
+    /**
+     * Test that the global stats PDA tracks protocol-wide totals across launches
+     */
+    describe("Global Stats", () => {
+      const trader = Keypair.generate();
+
+      const solPriceUsd = new BN(1500_00000000); // $1,500 keeps graduation cheap
+
+      const [globalStatsPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("global_stats")],
+        program.programId
+      );
+      let quiet: TestLaunch;
+      let graduating: TestLaunch;
+
+      const create = async (tokenName: string, symbol: string) => {
+        const launch = new TestLaunch(program, authority.publicKey, tokenName);
+        await launch.create({
+          symbol,
+          metadataUri: "https://example.com/global.json",
+          description: "Launch used to test global stats",
+          solPriceUsd,
+        });
+        return launch;
+      };
+
+      const buy = (launch: TestLaunch, amount: BN) =>
+        launch.buy(trader, amount, {
+          maxSolCost: new BN(100 * LAMPORTS_PER_SOL),
+          accounts: { globalStats: globalStatsPda },
+        });
+
+      const sell = (launch: TestLaunch, amount: BN) =>
+        launch.sell(trader, amount, { accounts: { globalStats: globalStatsPda } });
+
+      const globalTotals = () =>
+        program.methods
+          .getGlobalStats()
+          .accounts({ globalStats: globalStatsPda })
+          .view();
+
+      before(async () => {
+        await airdrop(provider, trader.publicKey, 200);
+      });
+
+      it("Creates the global stats account alongside the launchpad config", async () => {
+        const stats = await program.account.globalStats.fetch(globalStatsPda);
+        const totals = await globalTotals();
+        assert.equal(totals.totalLaunches.toString(), stats.totalLaunches.toString());
+        assert.equal(totals.totalVolume.toString(), stats.totalVolume.toString());
+        assert.equal(totals.totalGraduated.toString(), stats.totalGraduated.toString());
+        assert.equal(totals.totalFees.toString(), stats.totalFees.toString());
+      });
+
+      it("Rejects re-initializing global stats", async () => {
+        try {
+          await program.methods
+            .initializeGlobalStats()
+            .accounts({ config: configPdaFor(program), authority: authority.publicKey })
+            .rpc();
+          assert.fail("Global stats already exist and cannot be re-created");
+        } catch (error) {
+          assert.notInclude(error.toString(), "Global stats already exist");
+        }
+      });
+
+      it("Tracks launches, volume, fees and graduations across launches", async () => {
+        const before = await globalTotals();
+
+        let volume = new BN(0);
+        let fees = new BN(0);
+        const onTrade = (event) => {
+          volume = volume.add(event.solAmount);
+          fees = fees.add(event.platformFee);
+        };
+        const buyListener = program.addEventListener("tokensPurchased", onTrade);
+        const sellListener = program.addEventListener("tokensSold", onTrade);
+
+        quiet = await create("Global Quiet Token", "GQT");
+        graduating = await create("Global Graduating Token", "GGT");
+
+        // Interleave trades on both launches
+        await buy(quiet, MILLION);
+        await buy(graduating, MILLION.mul(new BN(200)));
+        await sell(quiet, MILLION.divn(2));
+        for (let i = 0; i < 3; i++) {
+          await buy(graduating, MILLION.mul(new BN(200)));
+        }
+
+        await new Promise((resolve) => setTimeout(resolve, 1000));
+        await program.removeEventListener(buyListener);
+        await program.removeEventListener(sellListener);
+
+        const curve = await program.account.bondingCurve.fetch(graduating.bondingCurve);
+        assert.ok(curve.isGraduated, "Second launch should graduate once sold out");
+
+        const after = await globalTotals();
+        assert.equal(after.totalLaunches.sub(before.totalLaunches).toString(), "2");
+        assert.equal(after.totalGraduated.sub(before.totalGraduated).toString(), "1");
+        assert.equal(after.totalVolume.sub(before.totalVolume).toString(), volume.toString());
+        assert.equal(after.totalFees.sub(before.totalFees).toString(), fees.toString());
+        assert.ok(fees.gtn(0), "Trades should have charged a platform fee");
+
+        // Both launches are fresh, so the global volume delta is exactly their combined curve volume
+        const quietCurve = await program.account.bondingCurve.fetch(quiet.bondingCurve);
+        assert.equal(
+          after.totalVolume.sub(before.totalVolume).toString(),
+          quietCurve.totalVolume.add(curve.totalVolume).toString()
+        );
+      });
+
+      it("Leaves the totals unchanged on a rejected trade", async () => {
+        const before = await globalTotals();
+        try {
+          await buy(graduating, MILLION);
+          assert.fail("Buying from a graduated curve should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "CurveGraduated");
+        }
+        const after = await globalTotals();
+        assert.equal(after.totalVolume.toString(), before.totalVolume.toString());
+        assert.equal(after.totalLaunches.toString(), before.totalLaunches.toString());
+      });
+
+      it("Trades without locking the stats account when it is left out", async () => {
+        const before = await globalTotals();
+        const curveBefore = await program.account.bondingCurve.fetch(quiet.bondingCurve);
+
+        await quiet.buy(trader, MILLION, { accounts: { globalStats: null } });
+        await quiet.sell(trader, MILLION.divn(2), { accounts: { globalStats: null } });
+
+        const after = await globalTotals();
+        assert.equal(after.totalVolume.toString(), before.totalVolume.toString());
+        assert.equal(after.totalFees.toString(), before.totalFees.toString());
+
+        // The launch's own volume still counts the trades
+        const curveAfter = await program.account.bondingCurve.fetch(quiet.bondingCurve);
+        assert.ok(curveAfter.totalVolume.gt(curveBefore.totalVolume));
+      });
+    });
+
+    /**
+     * Test the on-chain ring buffer of a launch's most recent trades
+     */
+    describe("Trade History", () => {
+      const trader = Keypair.generate();
+
+      const THOUSAND = new BN(1_000).mul(new BN(1_000_000_000)); // 1K tokens
+      const CAPACITY = 32;
+      const PAGE_SIZE = 16;
+
+      const launch = new TestLaunch(program, authority.publicKey, "History Token");
+      const tradeHistoryPda = launch.tradeHistory;
+
+      const recentTrades = (offset: number, limit: number) =>
+        program.methods
+          .getRecentTrades(offset, limit)
+          .accounts({ tokenLaunch: launch.tokenLaunch, tradeHistory: tradeHistoryPda })
+          .view();
+
+      before(async () => {
+        await airdrop(provider, trader.publicKey, 20);
+
+        await launch.create({
+          symbol: "HIST",
+          metadataUri: "https://example.com/history.json",
+          description: "Launch used to test the trade history ring buffer",
+        });
+      });
+
+      it("Creates an empty trade history with the launch", async () => {
+        const history = await program.account.tradeHistory.fetch(tradeHistoryPda);
+        assert.ok(history.tokenLaunch.equals(launch.tokenLaunch));
+        assert.equal(history.totalTrades.toString(), "0");
+        assert.equal(history.trades.length, CAPACITY);
+
+        const page = await recentTrades(0, PAGE_SIZE);
+        assert.equal(page.trades.length, 0);
+      });
+
+      it("Rejects pages larger than the return data limit allows", async () => {
+        try {
+          await recentTrades(0, PAGE_SIZE + 1);
+          assert.fail("Oversized page should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "InvalidAmount");
+        }
+      });
+
+      it("Keeps only the last trades once the buffer wraps", async () => {
+        // Buy n thousand tokens on trade n, then sell 1K: CAPACITY + 5 trades in total
+        const buys = CAPACITY + 4;
+        for (let n = 1; n <= buys; n++) {
+          await launch.buy(trader, THOUSAND.muln(n));
+        }
+        await launch.sell(trader, THOUSAND);
+        const total = buys + 1;
+
+        const history = await program.account.tradeHistory.fetch(tradeHistoryPda);
+        assert.equal(history.totalTrades.toString(), total.toString());
+
+        // Page newest first until the oldest surviving trade
+        const trades = [];
+        for (let offset = 0; ; offset += PAGE_SIZE) {
+          const page = await recentTrades(offset, PAGE_SIZE);
+          assert.equal(page.totalTrades.toString(), total.toString());
+          if (page.trades.length === 0) break;
+          trades.push(...page.trades);
+        }
+        assert.equal(trades.length, CAPACITY, "Only the last CAPACITY trades are kept");
+
+        const newest = trades[0];
+        assert.deepEqual(newest.side, { sell: {} });
+        assert.ok(newest.trader.equals(trader.publicKey));
+        assert.equal(newest.tokenAmount.toString(), THOUSAND.toString());
+        assert.ok(newest.pricePerToken.gtn(0));
+        assert.ok(newest.timestamp.gtn(0));
+
+        // The first five buys were overwritten; the rest follow in reverse order
+        const buyAmounts = trades.slice(1).map((trade) => {
+          assert.deepEqual(trade.side, { buy: {} });
+          return trade.tokenAmount.div(THOUSAND).toNumber();
+        });
+        const expected = [];
+        for (let n = buys; n > total - CAPACITY; n--) expected.push(n);
+        assert.deepEqual(buyAmounts, expected);
+      });
+
+      it("Trades without a history account, as launches created before it must", async () => {
+        const before = await program.account.tradeHistory.fetch(tradeHistoryPda);
+
+        await launch.buy(trader, THOUSAND, { accounts: { tradeHistory: null } });
+        await launch.sell(trader, THOUSAND, { accounts: { tradeHistory: null } });
+
+        const after = await program.account.tradeHistory.fetch(tradeHistoryPda);
+        assert.equal(after.totalTrades.toString(), before.totalTrades.toString());
+      });
+    });
+
+    /**
+     * Test the curve health view used for solvency monitoring
+     */
+    describe("Curve Health", () => {
+      const trader = Keypair.generate();
+      const otherTrader = Keypair.generate();
+
+      const launch = new TestLaunch(program, authority.publicKey, "Health Token");
+
+      const curveHealth = () =>
+        program.methods
+          .getCurveHealth()
+          .accounts({ tokenLaunch: launch.tokenLaunch, bondingCurve: launch.bondingCurve })
+          .view();
+
+      before(async () => {
+        for (const wallet of [trader, otherTrader]) {
+          await airdrop(provider, wallet.publicKey, 20);
+        }
+
+        await launch.create({
+          symbol: "HLTH",
+          metadataUri: "https://example.com/health.json",
+          description: "Launch used to test curve solvency reporting",
+        });
+      });
+
+      it("Reports a fresh curve as solvent with nothing owed", async () => {
+        const health = await curveHealth();
+
+        assert.isTrue(health.isSolvent);
+        assert.equal(health.reserveLamports.toString(), "0");
+        assert.equal(health.liabilityLamports.toString(), "0");
+        assert.equal(health.coverageBps.toNumber(), 10_000);
+      });
+
+      it("Reports a traded curve as fully covered", async () => {
+        await launch.buy(trader, MILLION.muln(5));
+        await launch.buy(otherTrader, MILLION.muln(3));
+        await launch.sell(trader, MILLION.muln(2));
+
+        const health = await curveHealth();
+        const curve = await program.account.bondingCurve.fetch(launch.bondingCurve);
+
+        assert.isTrue(health.isSolvent, "A curve only touched by trades should be solvent");
+        assert.equal(health.reserveLamports.toString(), curve.solReserve.toString());
+        assert.ok(health.liabilityLamports.gtn(0), "Outstanding holders are owed proceeds");
+        // Per-trade lamport truncation is the only gap between reserve and liability
+        assert.isAtMost(
+          health.liabilityLamports.sub(health.reserveLamports).abs().toNumber(),
+          curve.tradeCount.toNumber()
+        );
+        assert.isAtLeast(health.coverageBps.toNumber(), 9_999);
+      });
+    });
+
+    /**
+     * Test SOL vault accounting: rent-exempt top-up on the first buy and
+     * vault solvency as trading drains the reserve, plus drift reconciliation
+     */
+    describe("SOL Vault", () => {
+      const trader = Keypair.generate();
+
+      const launch = new TestLaunch(program, authority.publicKey, "Vault Token");
+
+      before(async () => {
+        await airdrop(provider, trader.publicKey, 100);
+
+        await launch.create({
+          symbol: "VAULT",
+          metadataUri: "https://example.com/vault.json",
+          description: "Launch used to test SOL vault accounting",
+        });
+      });
+
+      it("Stores the canonical vault bump on the bonding curve at creation", async () => {
+        const [, canonicalBump] = PublicKey.findProgramAddressSync(
+          [Buffer.from("sol_vault"), launch.bondingCurve.toBuffer()],
+          program.programId
+        );
+        const bondingCurve = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        assert.equal(bondingCurve.solVaultBump, canonicalBump);
+      });
+
+      it("Tops up the vault with the live rent-exempt minimum on the first buy", async () => {
+        const rentExemptMinimum = await provider.connection.getMinimumBalanceForRentExemption(0);
+
+        await launch.buy(trader, MILLION.mul(new BN(10)));
+
+        const bondingCurve = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        const vaultBalance = await provider.connection.getBalance(launch.solVault);
+
+        assert.equal(
+          vaultBalance,
+          rentExemptMinimum + bondingCurve.solReserve.toNumber() + bondingCurve.accruedFees.toNumber(),
+          "Vault should hold exactly rent + reserve + accrued fees"
+        );
+      });
+
+      it("Vault stays rent-exempt after a sell drains most of the reserve", async () => {
+        const rentExemptMinimum = await provider.connection.getMinimumBalanceForRentExemption(0);
+        const position = await program.account.userPosition.fetch(launch.positionOf(trader.publicKey));
+        const sellAmount = position.tokenAmount.mul(new BN(9)).div(new BN(10)); // 90%
+        const vaultBefore = await provider.connection.getBalance(launch.solVault);
+        const traderBefore = await provider.connection.getBalance(trader.publicKey);
+
+        await launch.sell(trader, sellAmount);
+
+        // The vault signs the payout with the bump stored on the curve
+        const vaultBalance = await provider.connection.getBalance(launch.solVault);
+        const traderAfter = await provider.connection.getBalance(trader.publicKey);
+        assert.isBelow(vaultBalance, vaultBefore, "Proceeds should leave the vault");
+        assert.isAbove(traderAfter, traderBefore, "Seller should receive the proceeds");
+        assert.ok(vaultBalance >= rentExemptMinimum, "Vault must remain rent-exempt");
+      });
+
+      describe("Vault Account Type", () => {
+        const buyWithVault = (solVault: PublicKey) =>
+        launch.buy(trader, MILLION, { accounts: { solVault } });
+
+        it("Rejects a vault account that isn't system-owned", async () => {
+          // The curve's token account is owned by the token program
+          try {
+            await buyWithVault(launch.curveTokenAccount);
+            assert.fail("A token-program-owned vault should be rejected");
+          } catch (error) {
+            assert.include(error.toString(), "AccountNotSystemOwned");
+          }
+        });
+
+        it("Rejects a system-owned account at the wrong address", async () => {
+          try {
+            await buyWithVault(Keypair.generate().publicKey);
+            assert.fail("A vault that isn't the derived PDA should be rejected");
+          } catch (error) {
+            assert.include(error.toString(), "ConstraintSeeds");
+          }
+        });
+      });
 
-        console.log("✅ Anyone can buy tokens (permissionless trading confirmed)");
-      } catch (error) {
-        // This is OK - might fail due to insufficient funds or other reasons
-        // The important thing is it's not an authorization error
-        if (error.toString().includes("Unauthorized") || 
-            error.toString().includes("ConstraintHasOne")) {
-          assert.fail("Buy should not have authorization restrictions");
-        }
-        console.log("⚠️  Buy failed for non-auth reason (OK):", error.message?.substring(0, 100));
-      }
-    });
+      describe("Vault Reconciliation", () => {
+        const reconcile = () =>
+          program.methods
+            .getVaultReconciliation()
+            .accounts({
+              tokenLaunch: launch.tokenLaunch,
+              bondingCurve: launch.bondingCurve,
+              solVault: launch.solVault,
+            })
+            .view();
 
-    it("Anyone can sell tokens they own (no auth required)", async () => {
-      // Positive test - any token holder can sell
-      console.log("✅ Sell tokens is permissionless (verified in earlier tests)");
-      // Already tested in "Selling Tokens" section with seller account
-      // No additional auth restrictions beyond token ownership
-    });
+        it("Accounts for every vault lamport after trading", async () => {
+          const recon = await reconcile();
+          const vaultBalance = await provider.connection.getBalance(launch.solVault);
+          const bondingCurve = await program.account.bondingCurve.fetch(launch.bondingCurve);
+
+          assert.equal(recon.vaultLamports.toString(), vaultBalance.toString());
+          assert.equal(recon.solReserve.toString(), bondingCurve.solReserve.toString());
+          assert.equal(recon.accruedFees.toString(), bondingCurve.accruedFees.toString());
+          assert.equal(
+            recon.rentExemptMinimum.toString(),
+            (await provider.connection.getMinimumBalanceForRentExemption(0)).toString()
+          );
+          // The first-buy rent top-up is covered by rent_exempt_minimum, not drift
+          assert.equal(recon.unattributed.toString(), "0");
+        });
 
-    it("Summary: Authorization model", async () => {
-      console.log("\n" + "=".repeat(70));
-      console.log("🔐 AUTHORIZATION MODEL SUMMARY");
-      console.log("=".repeat(70));
-      
-      console.log("\n📋 Restricted Operations (Creator/Authority Only):");
-      console.log("  ✅ initialize_launchpad - Authority only");
-      console.log("  ✅ toggle_token_launch_active - Token creator only");
-      console.log("  ✅ update_metadata_uri - Token creator only");
-      console.log("  ✅ withdraw_liquidity - Token creator only");
-      
-      console.log("\n🌐 Permissionless Operations (Anyone):");
-      console.log("  ✅ create_token_launch - Any user can create");
-      console.log("  ✅ buy_tokens - Any user can buy");
-      console.log("  ✅ sell_tokens - Any token holder can sell");
-      console.log("  ✅ get_buy_quote - Any user can query");
-      
-      console.log("\n🔒 Security Mechanisms:");
-      console.log("  • has_one constraint on TokenLaunch.creator");
-      console.log("  • has_one constraint on LaunchpadConfig.authority");
-      console.log("  • PDA derivation prevents address spoofing");
-      console.log("  • Signer verification on all mutations");
-      
-      console.log("\n✅ All authorization tests passed!");
-      console.log("=".repeat(70));
+        it("Surfaces a direct transfer into the vault as unattributed", async () => {
+          const donation = 12_345;
+          await provider.sendAndConfirm(
+            new Transaction().add(
+              SystemProgram.transfer({
+                fromPubkey: trader.publicKey,
+                toPubkey: launch.solVault,
+                lamports: donation,
+              })
+            ),
+            [trader]
+          );
+
+          const recon = await reconcile();
+          assert.equal(recon.unattributed.toString(), donation.toString());
+        });
+      });
     });
-  });
 
-  describe("Get Buy Quote", () => {
-    it("Gets accurate buy quote", async () => {
-      const quoteAmount = new BN(1_000_000_000); // 1 token
+    /**
+     * Test that SOL sent straight to a launch's vault before the first buy can't
+     * distort reserve accounting
+     */
+    describe("Vault Pre-Funding", () => {
+      const trader = Keypair.generate();
+      const griefer = Keypair.generate();
+
+      const launch = new TestLaunch(program, authority.publicKey, "Prefund Token");
+
+      const sendToVault = (lamports: number) =>
+        provider.sendAndConfirm(
+          new Transaction().add(
+            SystemProgram.transfer({ fromPubkey: griefer.publicKey, toPubkey: launch.solVault, lamports })
+          ),
+          [griefer]
+        );
 
-      try {
-        const quote = await program.methods
-          .getBuyQuote(quoteAmount)
-          .accounts({
-            bondingCurve: bondingCurvePda,
-            tokenLaunch: tokenLaunchPda,
-          })
+      const reconcile = () =>
+        program.methods
+          .getVaultReconciliation()
+          .accounts({ tokenLaunch: launch.tokenLaunch, bondingCurve: launch.bondingCurve, solVault: launch.solVault })
           .view();
 
-        console.log("Buy quote result:", {
-          cost: quote.cost.toString(),
-          spotPrice: quote.spotPrice.toString(),
-          slippage: quote.slippage,
+      before(async () => {
+        for (const wallet of [trader, griefer]) {
+          await airdrop(provider, wallet.publicKey, 20);
+        }
+
+        await launch.create({
+          symbol: "PFND",
+          metadataUri: "https://example.com/prefund.json",
+          description: "Launch used to test a pre-funded SOL vault",
         });
-        
-        // Verify the quote has expected fields
-        assert.ok(quote.cost, "Quote should have cost");
-        assert.ok(quote.spotPrice, "Quote should have spotPrice");
-        assert.ok(typeof quote.slippage === 'number', "Quote should have slippage as number");
-        
-        console.log("✅ View function works correctly!");
-      } catch (error) {
-        console.log("Quote test error:", error.message);
-        throw error;
-      }
-    });
-  });
+      });
 
-  describe("Get Spot Price", () => {
-    it("Gets current spot price without transaction", async () => {
-      try {
-        const spotPriceInfo = await program.methods
-          .getSpotPrice()
-          .accounts({
-            bondingCurve: bondingCurvePda,
-            tokenLaunch: tokenLaunchPda,
-          })
-          .view();
+      it("Can't leave the empty vault just under the rent-exempt minimum", async () => {
+        const rentExemptMinimum = await provider.connection.getMinimumBalanceForRentExemption(0);
 
-        console.log("\n📊 Spot Price Info:");
-        console.log("  Spot Price:", spotPriceInfo.spotPrice.toString(), "lamports per token");
-        console.log("  Tokens Sold:", spotPriceInfo.tokensSold.toString(), 
-          `(${spotPriceInfo.tokensSold.toNumber() / 1e9} tokens)`);
-        console.log("  SOL Reserve:", spotPriceInfo.solReserve.toString(), 
-          `(${spotPriceInfo.solReserve.toNumber() / LAMPORTS_PER_SOL} SOL)`);
-        
-        // Verify the spot price info has expected fields
-        assert.ok(spotPriceInfo.spotPrice, "SpotPrice should have spotPrice");
-        assert.ok(spotPriceInfo.tokensSold !== undefined, "SpotPrice should have tokensSold");
-        assert.ok(spotPriceInfo.solReserve !== undefined, "SpotPrice should have solReserve");
-        
-        // Calculate and display USD price
-        const SOL_PRICE_USD = 150;
-        const spotPriceUsd = (spotPriceInfo.spotPrice.toNumber() / LAMPORTS_PER_SOL) * SOL_PRICE_USD;
-        console.log("  USD Price: $" + spotPriceUsd.toFixed(10) + " per token");
-        
-        // Verify spot price is positive if tokens have been sold
-        const bondingCurve = await program.account.bondingCurve.fetch(bondingCurvePda);
-        if (bondingCurve.tokensSold.gt(new BN(0))) {
-          assert.ok(spotPriceInfo.spotPrice.gt(new BN(0)), 
-            "Spot price should be positive when tokens are sold");
+        try {
+          await sendToVault(rentExemptMinimum - 1);
+          assert.fail("A sub-rent deposit into an empty account should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "rent");
         }
-        
-        console.log("\n✅ Spot price view function works correctly!");
-      } catch (error) {
-        console.log("Spot price test error:", error.message);
-        throw error;
-      }
-    });
+        assert.equal(await provider.connection.getBalance(launch.solVault), 0);
+      });
 
-    it("Verifies spot price increases as tokens are sold", async () => {
-      // Get initial spot price
-      const spotPriceBefore = await program.methods
-        .getSpotPrice()
-        .accounts({
-          bondingCurve: bondingCurvePda,
-          tokenLaunch: tokenLaunchPda,
-        })
-        .view();
+      it("Keeps pre-funded lamports out of the reserve on the first buy", async () => {
+        const rentExemptMinimum = await provider.connection.getMinimumBalanceForRentExemption(0);
+        const dust = 4_321;
+        await sendToVault(rentExemptMinimum + dust);
 
-      console.log("\n📈 Testing spot price progression:");
-      console.log("  Before buy - Spot Price:", spotPriceBefore.spotPrice.toString());
-      console.log("  Before buy - Tokens Sold:", spotPriceBefore.tokensSold.toString());
+        const quote = await program.methods
+          .getBuyQuote(MILLION, { currentSpot: {} })
+          .accounts({ config: launch.config, tokenLaunch: launch.tokenLaunch, bondingCurve: launch.bondingCurve })
+          .view();
+        await launch.buy(trader, MILLION);
+
+        const curve = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        const recon = await reconcile();
+        assert.equal(curve.solReserve.toString(), quote.cost.toString(), "Reserve is only what the buyer paid");
+        assert.equal(recon.rentExemptMinimum.toNumber(), rentExemptMinimum);
+        assert.equal(recon.unattributed.toNumber(), dust, "Excess over the rent minimum is tracked as unattributed");
+        assert.equal(
+          recon.vaultLamports.toNumber(),
+          rentExemptMinimum + dust + curve.solReserve.toNumber() + curve.accruedFees.toNumber()
+        );
+      });
+    });
 
-      // Make a massive purchase to ensure visible price impact on 800M supply curve
-      const buyAmount = new BN(150_000).mul(new BN(1_000_000_000)); // 150,000 tokens (0.01875% of 800M supply)
-      const maxSolCost = new BN(LAMPORTS_PER_SOL * 5000); // 5000 SOL max cost
+    /**
+     * Test sweeping tokens sent directly to the curve token account
+     */
+    describe("Stray Curve Tokens", () => {
+      const trader = Keypair.generate();
+      const treasury = Keypair.generate();
 
-      const tempBuyer = Keypair.generate();
-      await provider.connection.confirmTransaction(
-        await provider.connection.requestAirdrop(tempBuyer.publicKey, 10000 * LAMPORTS_PER_SOL) // 10,000 SOL for large purchase
-      );
+      const launch = new TestLaunch(program, authority.publicKey, "Stray Token");
 
-      const tempBuyerTokenAccount = getAssociatedTokenAddressSync(
-        mintPda,
-        tempBuyer.publicKey
-      );
+      before(async () => {
+        await airdrop(provider, trader.publicKey, 20);
 
-      const [tempUserPositionPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("user_position"), tempBuyer.publicKey.toBuffer(), tokenLaunchPda.toBuffer()],
-        program.programId
-      );
+        await launch.create({
+          symbol: "STRAY",
+          metadataUri: "https://example.com/stray.json",
+          description: "Launch used to test sweeping stray curve tokens",
+        });
+      });
 
-      try {
-        await program.methods
-          .buyTokens(buyAmount, maxSolCost)
+      const reconcile = (recipientTokenAccount: PublicKey, authority: Keypair | null = null) => {
+        const builder = program.methods
+          .reconcileCurveTokens()
           .accounts({
-            config: configPda,
-            tokenLaunch: tokenLaunchPda,
-            bondingCurve: bondingCurvePda,
-            curveTokenAccount,
-            solVault: solVaultPda,
-            userPosition: tempUserPositionPda,
-            mint: mintPda,
-            buyerTokenAccount: tempBuyerTokenAccount,
-            buyer: tempBuyer.publicKey,
-            feeRecipient: feeRecipient,
+            tokenLaunch: launch.tokenLaunch,
+            bondingCurve: launch.bondingCurve,
+            curveTokenAccount: launch.curveTokenAccount,
+            recipientTokenAccount,
+            config: launch.config,
+            authority: authority ? authority.publicKey : authority.publicKey,
             tokenProgram: TOKEN_PROGRAM_ID,
-            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-            systemProgram: SystemProgram.programId,
-          })
-          .signers([tempBuyer])
-          .rpc();
+          });
+        return (authority ? builder.signers([authority]) : builder).rpc();
+      };
+
+      const curveBalance = async () =>
+        (await getAccount(provider.connection, launch.curveTokenAccount)).amount.toString();
+
+      it("Sweeps tokens sent straight to the curve token account", async () => {
+        await launch.buy(trader, MILLION.muln(10));
+
+        // A holder mistakenly transfers tokens to the curve instead of selling
+        await transfer(
+          provider.connection,
+          trader,
+          launch.tokenAccountOf(trader.publicKey),
+          launch.curveTokenAccount,
+          trader,
+          BigInt(MILLION.toString())
+        );
+        const curve = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        assert.equal(await curveBalance(), curve.tokenReserve.add(MILLION).toString());
+
+        const { address: treasuryTokens } = await getOrCreateAssociatedTokenAccount(
+          provider.connection,
+          authority.payer,
+          launch.mint,
+          treasury.publicKey
+        );
+        await reconcile(treasuryTokens);
 
-        // Get spot price after purchase
-        const spotPriceAfter = await program.methods
-          .getSpotPrice()
+        assert.equal(await curveBalance(), curve.tokenReserve.toString(), "Curve holds exactly its reserve");
+        const swept = await getAccount(provider.connection, treasuryTokens);
+        assert.equal(swept.amount.toString(), MILLION.toString());
+
+        // State is untouched: the stray tokens were never part of the curve
+        const curveAfter = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        assert.equal(curveAfter.tokensSold.toString(), curve.tokensSold.toString());
+      });
+
+      it("Rejects a sweep with nothing stray to move", async () => {
+        const treasuryTokens = launch.tokenAccountOf(treasury.publicKey);
+        try {
+          await reconcile(treasuryTokens);
+          assert.fail("Sweep of an aligned curve account should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "InvalidAmount");
+        }
+      });
+
+      it("Rejects sweeps from non-admin signers", async () => {
+        const traderTokens = launch.tokenAccountOf(trader.publicKey);
+        try {
+          await reconcile(traderTokens, trader);
+          assert.fail("Non-admin sweep should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "Unauthorized");
+        }
+      });
+    });
+
+    /**
+     * Test that circulating supply rebuilt from trade events matches state and token balances
+     */
+    describe("Supply Reconciliation", () => {
+      const alice = Keypair.generate();
+      const bob = Keypair.generate();
+
+      const launch = new TestLaunch(program, authority.publicKey, "Supply Token");
+
+      const supplyBreakdown = () =>
+        program.methods
+          .getSupplyBreakdown()
           .accounts({
-            bondingCurve: bondingCurvePda,
-            tokenLaunch: tokenLaunchPda,
+            tokenLaunch: launch.tokenLaunch,
+            bondingCurve: launch.bondingCurve,
+            mint: launch.mint,
+            curveTokenAccount: launch.curveTokenAccount,
+            lpVault: launch.lpVault,
           })
           .view();
 
-        console.log("  After buy - Spot Price:", spotPriceAfter.spotPrice.toString());
-        console.log("  After buy - Tokens Sold:", spotPriceAfter.tokensSold.toString());
-
-        // Verify spot price increased
-        assert.ok(
-          spotPriceAfter.spotPrice.gt(spotPriceBefore.spotPrice),
-          "Spot price should increase after tokens are sold"
-        );
-
-        // Verify tokens sold increased
-        assert.ok(
-          spotPriceAfter.tokensSold.gt(spotPriceBefore.tokensSold),
-          "Tokens sold should increase"
+      // Wallets that haven't bought yet have no token account
+      const tokenBalance = async (wallet: Keypair) => {
+        try {
+          const account = await getAccount(
+            provider.connection,
+            launch.tokenAccountOf(wallet.publicKey)
+          );
+          return new BN(account.amount.toString());
+        } catch {
+          return new BN(0);
+        }
+      };
+
+      // Circulating supply as rebuilt purely from this launch's trade events
+      let bought = new BN(0);
+      let soldBack = new BN(0);
+      let graduatedEvent = null;
+      const eventCirculating = () => bought.sub(soldBack);
+
+      const reconcile = async () => {
+        // Give the listeners time to receive the last transaction's logs
+        await new Promise((resolve) => setTimeout(resolve, 1000));
+
+        const breakdown = await supplyBreakdown();
+        const launch = await program.account.tokenLaunch.fetch(launch.tokenLaunch);
+        const curve = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        const held = (await tokenBalance(alice)).add(await tokenBalance(bob));
+
+        assert.equal(eventCirculating().toString(), launch.circulatingSupply.toString(), "Events vs state");
+        assert.equal(breakdown.circulating.toString(), launch.circulatingSupply.toString());
+        assert.equal(held.toString(), eventCirculating().toString(), "Events vs holder balances");
+        assert.equal(breakdown.onCurve.toString(), curve.tokenReserve.toString());
+        assert.equal(breakdown.lpReserved.toString(), MILLION.muln(200).toString());
+        assert.equal(
+          breakdown.total.toString(),
+          breakdown.circulating.add(breakdown.onCurve).add(breakdown.lpReserved).toString(),
+          "Mint supply must be fully accounted for"
         );
+        return breakdown;
+      };
 
-        const priceIncrease = spotPriceAfter.spotPrice.sub(spotPriceBefore.spotPrice);
-        const percentIncrease = priceIncrease.mul(new BN(10000))
-          .div(spotPriceBefore.spotPrice.gt(new BN(0)) ? spotPriceBefore.spotPrice : new BN(1));
-        
-        console.log("  Price Increase:", priceIncrease.toString(), "lamports");
-        console.log("  Percent Increase:", (percentIncrease.toNumber() / 100).toFixed(2) + "%");
-        console.log("\n✅ Verified spot price increases with sales!");
+      let listeners: number[] = [];
 
-      } catch (error) {
-        if (error.message?.includes("insufficient funds")) {
-          console.log("⚠️  Skipping progression test due to insufficient funds");
-          return;
+      before(async () => {
+        for (const wallet of [alice, bob]) {
+          await airdrop(provider, wallet.publicKey, 100);
         }
-        throw error;
-      }
-    });
-  });
 
-  describe("Graduation Logic", () => {
-    it("Checks if bonding curve should graduate", async () => {
-      const bondingCurve = await program.account.bondingCurve.fetch(bondingCurvePda);
-      
-      console.log("Current state:", {
-        tokensSold: bondingCurve.tokensSold.toString(),
-        solReserve: bondingCurve.solReserve.toString(),
-        isGraduated: bondingCurve.isGraduated,
-        tradeCount: bondingCurve.tradeCount.toString(),
-      });
+        await launch.create({
+          symbol: "SUPPLY",
+          metadataUri: "https://example.com/supply.json",
+          description: "Launch used to reconcile supply against trade events",
+          solPriceUsd: new BN(1500_00000000), // $1,500 keeps graduation cheap
+        });
 
-      // Note: With current test purchases, unlikely to reach 800M tokens + $12k
-      // This is just checking the state
-      assert.ok(!bondingCurve.isGraduated, "Should not be graduated yet");
-    });
-  });
+        listeners = [
+          program.addEventListener("tokensPurchased", (event) => {
+            if (event.launch.equals(launch.tokenLaunch)) bought = bought.add(event.tokenAmount);
+          }),
+          program.addEventListener("tokensSold", (event) => {
+            if (event.launch.equals(launch.tokenLaunch)) soldBack = soldBack.add(event.tokenAmount);
+          }),
+          program.addEventListener("curveGraduated", (event) => {
+            if (event.launch.equals(launch.tokenLaunch)) graduatedEvent = event;
+          }),
+        ];
+      });
 
-  describe("Account State Verification", () => {
-    it("Verifies all account states are consistent", async () => {
-      const tokenLaunch = await program.account.tokenLaunch.fetch(tokenLaunchPda);
-      const bondingCurve = await program.account.bondingCurve.fetch(bondingCurvePda);
+      after(async () => {
+        for (const listener of listeners) {
+          await program.removeEventListener(listener);
+        }
+      });
 
-      // Verify relationships
-      assert.ok(tokenLaunch.bondingCurve.equals(bondingCurvePda));
-      assert.ok(tokenLaunch.mint.equals(mintPda));
-      assert.ok(bondingCurve.tokenLaunch.equals(tokenLaunchPda));
-      
-      console.log("Final state:", {
-        totalSupply: tokenLaunch.totalSupply.toString(),
-        circulatingSupply: tokenLaunch.circulatingSupply.toString(),
-        tokensSold: bondingCurve.tokensSold.toString(),
-        solReserve: bondingCurve.solReserve.toString(),
-        totalVolume: bondingCurve.totalVolume.toString(),
-        tradeCount: bondingCurve.tradeCount.toString(),
-        isActive: tokenLaunch.isActive,
-        isGraduated: bondingCurve.isGraduated,
+      it("Starts with the whole supply on the curve and in the LP vault", async () => {
+        const breakdown = await reconcile();
+        assert.equal(breakdown.circulating.toString(), "0");
+        assert.equal(breakdown.total.toString(), MILLION.muln(1_000).toString());
       });
 
-      // Verify config if initialized
-      try {
-        const config = await program.account.launchpadConfig.fetch(configPda);
-        assert.ok(config.platformFeeBps === platformFeeBps);
-        console.log("Config verified:", {
-          authority: config.authority.toString(),
-          feeRecipient: config.feeRecipient.toString(),
-          platformFeeBps: config.platformFeeBps,
-        });
-      } catch (error) {
-        console.log("Config not initialized in this test run");
-      }
+      it("Reconciles after a mix of buys and sells", async () => {
+        await launch.buy(alice, MILLION.muln(5));
+        await launch.buy(bob, MILLION.muln(3));
+        await launch.sell(alice, MILLION.muln(2));
+        await launch.buy(bob, MILLION.muln(40));
+        await launch.sell(bob, MILLION.muln(13));
+        await launch.buy(alice, MILLION.muln(7));
+
+        const breakdown = await reconcile();
+        assert.equal(breakdown.circulating.toString(), MILLION.muln(40).toString());
+      });
+
+      it("Reconciles through graduation", async () => {
+        const curve = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        await launch.buy(alice, MILLION.muln(400));
+        await launch.buy(bob, MILLION.muln(800).sub(curve.tokensSold).sub(MILLION.muln(400)));
+
+        const breakdown = await reconcile();
+        assert.ok(graduatedEvent, "CurveGraduated should be emitted");
+        assert.equal(
+          graduatedEvent.tokensSold.toString(),
+          eventCirculating().toString(),
+          "Every curve token sold at graduation is circulating"
+        );
+        assert.equal(breakdown.onCurve.toString(), "0");
+        assert.equal(breakdown.circulating.toString(), MILLION.muln(800).toString());
+      });
     });
   });
 
@@ -1334,12 +4436,17 @@ describe("notmarket-solana", () => {
           await program.methods
             .withdrawLiquidity()
             .accounts({
+              config: configPda,
               tokenLaunch: tokenLaunchPda,
               bondingCurve: bondingCurvePda,
               solVault: solVaultPda,
-              curveTokenAccount,
+              lpVault: PublicKey.findProgramAddressSync(
+                [Buffer.from("lp_vault"), bondingCurvePda.toBuffer()],
+                program.programId
+              )[0],
               solRecipient: liquidityRecipient.publicKey,
               tokenRecipient,
+              feeRecipient,
               authority: creator.publicKey,
               tokenProgram: TOKEN_PROGRAM_ID,
               systemProgram: SystemProgram.programId,
@@ -1420,6 +4527,625 @@ describe("notmarket-solana", () => {
       assert.ok(feeRecipientBalance > 0, 
         "Fee recipient should have balance");
     });
+
+    /**
+     * Test the platform fee skimmed from each withdraw_liquidity call
+     */
+    describe("Withdraw Fee", () => {
+      const trader = Keypair.generate();
+      const treasury = Keypair.generate();
+
+      const launch = new TestLaunch(program, authority.publicKey, "Withdraw Fee Token");
+      let tokenRecipient: PublicKey;
+
+      before(async () => {
+        await airdrop(provider, trader.publicKey, 200);
+
+        await launch.create({
+          symbol: "WFEE",
+          metadataUri: "https://example.com/withdraw-fee.json",
+          description: "Launch used to test the withdrawal fee",
+          solPriceUsd: new BN(1500_00000000), // $1,500 keeps graduation cheap
+        });
+
+        // Sell out the curve so it graduates
+        for (let i = 0; i < 4; i++) {
+          await launch.buy(trader, MILLION.mul(new BN(200)));
+        }
+
+        await program.methods
+          .updateLiquidityDestinations(PublicKey.default, treasury.publicKey)
+          .accounts({ config: launch.config, authority: authority.publicKey })
+          .rpc();
+
+        tokenRecipient = await createAssociatedTokenAccount(
+          provider.connection,
+          authority.payer,
+          launch.mint,
+          treasury.publicKey
+        );
+      });
+
+      const setGraduationFee = (graduationFeeBps: number) =>
+        program.methods
+          .updateGraduationFee(graduationFeeBps)
+          .accounts({ config: launch.config, authority: authority.publicKey })
+          .rpc();
+
+      const setWithdrawFee = (withdrawFeeBps: number) =>
+        program.methods
+          .updateWithdrawFee(withdrawFeeBps)
+          .accounts({ config: launch.config, authority: authority.publicKey })
+          .rpc();
+
+      // Withdraw to the registered treasury and return the WithdrawFeeCollected event
+      const withdraw = async () => {
+        let feeEvent = null;
+        const listener = program.addEventListener("withdrawFeeCollected", (event) => {
+          feeEvent = event;
+        });
+
+        await program.methods
+          .withdrawLiquidity()
+          .accounts({
+            config: launch.config,
+            tokenLaunch: launch.tokenLaunch,
+            bondingCurve: launch.bondingCurve,
+            solVault: launch.solVault,
+            lpVault: launch.lpVault,
+            solRecipient: treasury.publicKey,
+            tokenRecipient,
+            feeRecipient: launch.feeRecipient,
+            authority: authority.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+
+        await new Promise((resolve) => setTimeout(resolve, 1000));
+        await program.removeEventListener(listener);
+        return feeEvent;
+      };
+
+      after(async () => {
+        await setWithdrawFee(0);
+      });
+
+      it("Rejects a withdraw fee above the cap", async () => {
+        try {
+          await setWithdrawFee(1_001);
+          assert.fail("Withdraw fee above 10% should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "InvalidFee");
+        }
+      });
+
+      it("Takes the fee proportionally on each withdrawal", async () => {
+        const WITHDRAW_FEE_BPS = 200; // 2%
+        await setGraduationFee(0);
+        await setWithdrawFee(WITHDRAW_FEE_BPS);
+
+        const recipientBefore = await provider.connection.getBalance(launch.feeRecipient);
+        const first = await withdraw();
+        assert.ok(first, "WithdrawFeeCollected should be emitted");
+        assert.equal(
+          first.fee.toString(),
+          first.solWithdrawn.muln(WITHDRAW_FEE_BPS).divn(10_000).toString()
+        );
+        const recipientAfterFirst = await provider.connection.getBalance(launch.feeRecipient);
+        assert.equal(recipientAfterFirst - recipientBefore, first.fee.toNumber());
+
+        const treasuryBalance = await provider.connection.getBalance(treasury.publicKey);
+        assert.equal(
+          treasuryBalance,
+          first.solWithdrawn.sub(first.fee).toNumber(),
+          "Treasury should receive the withdrawal minus the fee"
+        );
+
+        // SOL that lands in the vault later is withdrawn, and charged, separately
+        const topUp = LAMPORTS_PER_SOL;
+        await provider.sendAndConfirm(
+          new anchor.web3.Transaction().add(
+            SystemProgram.transfer({
+              fromPubkey: authority.publicKey,
+              toPubkey: launch.solVault,
+              lamports: topUp,
+            })
+          )
+        );
+
+        const second = await withdraw();
+        assert.ok(second, "WithdrawFeeCollected should be emitted again");
+        assert.isAtMost(second.solWithdrawn.toNumber(), topUp + first.solWithdrawn.toNumber());
+        assert.equal(
+          second.fee.toString(),
+          second.solWithdrawn.muln(WITHDRAW_FEE_BPS).divn(10_000).toString()
+        );
+        const recipientAfterSecond = await provider.connection.getBalance(launch.feeRecipient);
+        assert.equal(recipientAfterSecond - recipientAfterFirst, second.fee.toNumber());
+      });
+
+      it("Skips the fee when it is disabled", async () => {
+        await setWithdrawFee(0);
+        await provider.sendAndConfirm(
+          new anchor.web3.Transaction().add(
+            SystemProgram.transfer({
+              fromPubkey: authority.publicKey,
+              toPubkey: launch.solVault,
+              lamports: LAMPORTS_PER_SOL,
+            })
+          )
+        );
+
+        const recipientBefore = await provider.connection.getBalance(launch.feeRecipient);
+        const event = await withdraw();
+        assert.isNull(event, "No withdraw fee should be collected at 0 bps");
+        assert.equal(await provider.connection.getBalance(launch.feeRecipient), recipientBefore);
+      });
+    });
+
+    /**
+     * Test that graduation liquidity can only go to registered destinations
+     */
+    describe("Liquidity Destinations", () => {
+      const trader = Keypair.generate();
+      const treasury = Keypair.generate();
+      const creatorWallet = Keypair.generate();
+
+      const launch = new TestLaunch(program, authority.publicKey, "Graduating Token");
+
+      before(async () => {
+        await airdrop(provider, trader.publicKey, 200);
+
+        await launch.create({
+          symbol: "GRAD",
+          metadataUri: "https://example.com/graduating.json",
+          description: "Launch used to test liquidity destinations",
+          solPriceUsd: new BN(1500_00000000), // $1,500 keeps graduation cheap
+        });
+      });
+
+      const withdraw = async (recipient: PublicKey) => {
+        const tokenRecipient = await createAssociatedTokenAccount(
+          provider.connection,
+          authority.payer,
+          launch.mint,
+          recipient
+        );
+        return program.methods
+          .withdrawLiquidity()
+          .accounts({
+            config: launch.config,
+            tokenLaunch: launch.tokenLaunch,
+            bondingCurve: launch.bondingCurve,
+            solVault: launch.solVault,
+            lpVault: launch.lpVault,
+            solRecipient: recipient,
+            tokenRecipient,
+            feeRecipient: launch.feeRecipient,
+            authority: authority.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+      };
+
+      it("Registers the treasury as a liquidity destination", async () => {
+        await program.methods
+          .updateLiquidityDestinations(PublicKey.default, treasury.publicKey)
+          .accounts({ config: launch.config, authority: authority.publicKey })
+          .rpc();
+
+        const config = await program.account.launchpadConfig.fetch(launch.config);
+        assert.ok(config.liquidityTreasury.equals(treasury.publicKey));
+      });
+
+      it("Graduates the curve with reserves reconciled after every buy", async () => {
+        const CURVE_SUPPLY = MILLION.mul(new BN(800));
+        const LP_SUPPLY = MILLION.mul(new BN(200));
+
+        // Buy out the full 800M curve supply in 200M chunks
+        for (let i = 0; i < 4; i++) {
+          await launch.buy(trader, MILLION.mul(new BN(200)));
+
+          const curve = await program.account.bondingCurve.fetch(launch.bondingCurve);
+          const curveTokens = await getAccount(provider.connection, launch.curveTokenAccount);
+          const lpTokens = await getAccount(provider.connection, launch.lpVault);
+          assert.equal(
+            curve.tokenReserve.add(curve.tokensSold).toString(),
+            CURVE_SUPPLY.toString(),
+            "token_reserve + tokens_sold must equal the curve supply"
+          );
+          assert.equal(
+            curveTokens.amount.toString(),
+            curve.tokenReserve.toString(),
+            "Curve token account must hold exactly the unsold reserve"
+          );
+          assert.equal(
+            lpTokens.amount.toString(),
+            LP_SUPPLY.toString(),
+            "LP vault must hold the untouched LP allocation"
+          );
+        }
+
+        const bondingCurve = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        assert.ok(bondingCurve.isGraduated, "Curve should graduate once sold out");
+        assert.deepEqual(bondingCurve.graduationAction, { pool: {} });
+      });
+
+      const poolAddress = () =>
+        program.methods
+          .getPoolAddress()
+          .accounts({ tokenLaunch: launch.tokenLaunch, bondingCurve: launch.bondingCurve })
+          .view();
+
+      it("Points traders at the DEX once graduated, before a pool exists", async () => {
+        try {
+          await launch.buy(trader, MILLION);
+          assert.fail("Buying from a graduated curve should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "CurveGraduated");
+        }
+
+        assert.isNull(await poolAddress(), "No pool address until liquidity is withdrawn");
+      });
+
+      it("Rejects an arbitrary recipient chosen by the creator", async () => {
+        try {
+          await withdraw(creatorWallet.publicKey);
+          assert.fail("Withdrawal to an unregistered recipient should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "Unauthorized");
+        }
+      });
+
+      it("Rejects a token recipient for a different mint", async () => {
+        // Held by the registered treasury, so only the mint is wrong
+        const otherMint = await createMint(provider.connection, authority.payer, authority.publicKey, null, 9);
+        const wrongMintRecipient = await createAssociatedTokenAccount(
+          provider.connection,
+          authority.payer,
+          otherMint,
+          treasury.publicKey
+        );
+
+        try {
+          await program.methods
+            .withdrawLiquidity()
+            .accounts({
+              config: launch.config,
+              tokenLaunch: launch.tokenLaunch,
+              bondingCurve: launch.bondingCurve,
+              solVault: launch.solVault,
+              lpVault: launch.lpVault,
+              solRecipient: treasury.publicKey,
+              tokenRecipient: wrongMintRecipient,
+              feeRecipient: launch.feeRecipient,
+              authority: authority.publicKey,
+              tokenProgram: TOKEN_PROGRAM_ID,
+              systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+          assert.fail("Withdrawal to a wrong-mint token account should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "TokenMintMismatch");
+        }
+      });
+
+      it("Rejects a graduation fee above the cap", async () => {
+        try {
+          await program.methods
+            .updateGraduationFee(1_001)
+            .accounts({ config: launch.config, authority: authority.publicKey })
+            .rpc();
+          assert.fail("Graduation fee above 10% should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "InvalidFee");
+        }
+      });
+
+      it("Allows withdrawal to the registered treasury, minus the graduation fee", async () => {
+        const GRADUATION_FEE_BPS = 500; // 5%
+        await program.methods
+          .updateGraduationFee(GRADUATION_FEE_BPS)
+          .accounts({ config: launch.config, authority: authority.publicKey })
+          .rpc();
+
+        let feeEvent = null;
+        const listener = program.addEventListener("graduationFeeCollected", (event) => {
+          feeEvent = event;
+        });
+
+        const vaultBefore = await provider.connection.getBalance(launch.solVault);
+        const curveTokensBefore = await getAccount(provider.connection, launch.curveTokenAccount);
+        await withdraw(treasury.publicKey);
+
+        // Only the LP allocation is pooled; the curve token account is untouched
+        const treasuryTokens = await getAccount(
+          provider.connection,
+          launch.tokenAccountOf(treasury.publicKey)
+        );
+        const lpTokens = await getAccount(provider.connection, launch.lpVault);
+        const curveTokensAfter = await getAccount(provider.connection, launch.curveTokenAccount);
+        assert.equal(treasuryTokens.amount.toString(), MILLION.mul(new BN(200)).toString());
+        assert.equal(lpTokens.amount.toString(), "0");
+        assert.equal(curveTokensAfter.amount.toString(), curveTokensBefore.amount.toString());
+
+        await new Promise((resolve) => setTimeout(resolve, 1000));
+        await program.removeEventListener(listener);
+
+        assert.ok(feeEvent, "GraduationFeeCollected should be emitted");
+        const expectedFee = feeEvent.solWithdrawn.muln(GRADUATION_FEE_BPS).divn(10_000);
+        assert.equal(feeEvent.fee.toString(), expectedFee.toString());
+
+        // The graduated curve now reports where its liquidity went
+        const pool = await poolAddress();
+        assert.ok(pool && pool.equals(treasury.publicKey), "Pool address should be the liquidity destination");
+
+        const treasuryBalance = await provider.connection.getBalance(treasury.publicKey);
+        assert.equal(
+          treasuryBalance,
+          feeEvent.solWithdrawn.sub(feeEvent.fee).toNumber(),
+          "Treasury should receive the SOL reserve minus the graduation fee"
+        );
+        assert.ok(vaultBefore > 0);
+
+        await program.methods
+          .updateGraduationFee(0)
+          .accounts({ config: launch.config, authority: authority.publicKey })
+          .rpc();
+      });
+    });
+
+    /**
+     * Test the minimum liquidity lock enforced on withdrawal after graduation
+     */
+    describe("Liquidity Lock", () => {
+      const trader = Keypair.generate();
+      const treasury = Keypair.generate();
+
+      const LOCK_SECONDS = 10;
+
+      const launch = new TestLaunch(program, authority.publicKey, "Locked Liquidity Token");
+
+      const setLock = (seconds: number) =>
+        program.methods
+          .updateMinLiquidityLock(seconds)
+          .accounts({ config: launch.config, authority: authority.publicKey })
+          .rpc();
+
+      before(async () => {
+        await airdrop(provider, trader.publicKey, 200);
+
+        await launch.create({
+          symbol: "LOCK",
+          metadataUri: "https://example.com/locked-liquidity.json",
+          description: "Launch used to test the post-graduation liquidity lock",
+          solPriceUsd: new BN(1500_00000000), // $1,500 keeps graduation cheap
+        });
+      });
+
+      after(async () => {
+        await setLock(0);
+      });
+
+      const withdraw = async (recipient: PublicKey) => {
+        // Withdrawal is attempted more than once, so reuse the recipient's token account
+        const { address: tokenRecipient } = await getOrCreateAssociatedTokenAccount(
+          provider.connection,
+          authority.payer,
+          launch.mint,
+          recipient
+        );
+        return program.methods
+          .withdrawLiquidity()
+          .accounts({
+            config: launch.config,
+            tokenLaunch: launch.tokenLaunch,
+            bondingCurve: launch.bondingCurve,
+            solVault: launch.solVault,
+            lpVault: launch.lpVault,
+            solRecipient: recipient,
+            tokenRecipient,
+            feeRecipient: launch.feeRecipient,
+            authority: authority.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+      };
+
+      it("Rejects lock updates from non-admin signers", async () => {
+        try {
+          await program.methods
+            .updateMinLiquidityLock(LOCK_SECONDS)
+            .accounts({ config: launch.config, authority: trader.publicKey })
+            .signers([trader])
+            .rpc();
+          assert.fail("Non-admin lock update should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "Unauthorized");
+        }
+      });
+
+      it("Holds liquidity in the vaults until the lock expires", async () => {
+        await setLock(LOCK_SECONDS);
+        const config = await program.account.launchpadConfig.fetch(launch.config);
+        assert.equal(config.minLockAfterGraduationSeconds, LOCK_SECONDS);
+        await program.methods
+          .updateLiquidityDestinations(PublicKey.default, treasury.publicKey)
+          .accounts({ config: launch.config, authority: authority.publicKey })
+          .rpc();
+
+        // Buy out the full 800M curve supply to graduate
+        for (let i = 0; i < 4; i++) {
+          await launch.buy(trader, MILLION.mul(new BN(200)));
+        }
+        const curve = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        assert.ok(curve.isGraduated, "Curve should graduate once sold out");
+        assert.ok(curve.graduatedAt.toNumber() > 0, "Graduation time should be recorded");
+
+        try {
+          await withdraw(treasury.publicKey);
+          assert.fail("Withdrawal inside the lock period should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "LiquidityLockActive");
+        }
+        const lpTokens = await getAccount(provider.connection, launch.lpVault);
+        assert.ok(lpTokens.amount > BigInt(0), "LP allocation should still be in the vault");
+      });
+
+      it("Allows withdrawal once the lock has passed", async () => {
+        await new Promise((resolve) => setTimeout(resolve, (LOCK_SECONDS + 2) * 1000));
+
+        await withdraw(treasury.publicKey);
+        const curve = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        assert.ok(curve.poolAddress && curve.poolAddress.equals(treasury.publicKey), "Liquidity withdraws once the lock ends");
+        const lpTokens = await getAccount(provider.connection, launch.lpVault);
+        assert.equal(lpTokens.amount.toString(), "0");
+      });
+    });
+
+    /**
+     * Test the admin path that completes a graduated launch's liquidity withdrawal
+     */
+    describe("Recover Withdraw", () => {
+      const trader = Keypair.generate();
+      const treasury = Keypair.generate();
+
+      const launch = new TestLaunch(program, authority.publicKey, "Recovery Token");
+
+      before(async () => {
+        await airdrop(provider, trader.publicKey, 200);
+
+        await launch.create({
+          symbol: "RCVR",
+          metadataUri: "https://example.com/recovery.json",
+          description: "Launch used to test liquidity withdrawal recovery",
+          solPriceUsd: new BN(1500_00000000), // $1,500 keeps graduation cheap
+        });
+      });
+
+      let treasuryTokens: PublicKey;
+
+      const liquidityAccounts = (authority: PublicKey) => ({
+        config: launch.config,
+        tokenLaunch: launch.tokenLaunch,
+        bondingCurve: launch.bondingCurve,
+        solVault: launch.solVault,
+        lpVault: launch.lpVault,
+        solRecipient: treasury.publicKey,
+        tokenRecipient: treasuryTokens,
+        feeRecipient: launch.feeRecipient,
+        authority,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      });
+
+      const recover = (signer?: Keypair) => {
+        const builder = program.methods
+          .recoverWithdraw()
+          .accounts(liquidityAccounts(signer ? signer.publicKey : authority.publicKey));
+        return signer ? builder.signers([signer]).rpc() : builder.rpc();
+      };
+
+      it("Rejects recovery before graduation", async () => {
+        // The corrected recipient is a registered treasury
+        await program.methods
+          .updateLiquidityDestinations(PublicKey.default, treasury.publicKey)
+          .accounts({ config: launch.config, authority: authority.publicKey })
+          .rpc();
+        treasuryTokens = await createAssociatedTokenAccount(
+          provider.connection,
+          authority.payer,
+          launch.mint,
+          treasury.publicKey
+        );
+
+        try {
+          await recover();
+          assert.fail("Recovery before graduation should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "NotGraduated");
+        }
+      });
+
+      it("Graduates the curve", async () => {
+        for (let i = 0; i < 4; i++) {
+          await launch.buy(trader, MILLION.mul(new BN(200)));
+        }
+        const curve = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        assert.ok(curve.isGraduated, "Curve should graduate once sold out");
+        assert.equal(curve.liquiditySolWithdrawn.toString(), "0");
+        assert.equal(curve.liquidityTokensWithdrawn.toString(), "0");
+      });
+
+      it("Rejects recovery from a non-admin signer", async () => {
+        try {
+          await recover(trader);
+          assert.fail("Non-admin recovery should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "Unauthorized");
+        }
+      });
+
+      it("Completes the withdrawal to the corrected recipient", async () => {
+        let recoveredEvent = null;
+        const listener = program.addEventListener("liquidityRecovered", (event) => {
+          recoveredEvent = event;
+        });
+
+        const before = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        await recover();
+
+        await new Promise((resolve) => setTimeout(resolve, 1000));
+        await program.removeEventListener(listener);
+
+        const curve = await program.account.bondingCurve.fetch(launch.bondingCurve);
+        const tokens = await getAccount(provider.connection, treasuryTokens);
+        const lpTokens = await getAccount(provider.connection, launch.lpVault);
+        assert.equal(tokens.amount.toString(), MILLION.mul(new BN(200)).toString());
+        assert.equal(lpTokens.amount.toString(), "0");
+        assert.ok(curve.poolAddress.equals(treasury.publicKey));
+
+        // Only the SOL reserve is owed; vault rent and stray lamports are not liquidity
+        assert.ok(recoveredEvent, "LiquidityRecovered should be emitted");
+        assert.equal(recoveredEvent.solAmount.toString(), before.solReserve.toString());
+        assert.equal(recoveredEvent.tokenAmount.toString(), MILLION.mul(new BN(200)).toString());
+        assert.equal(curve.liquiditySolWithdrawn.toString(), before.solReserve.toString());
+        assert.equal(curve.liquidityTokensWithdrawn.toString(), MILLION.mul(new BN(200)).toString());
+      });
+
+      it("Never pays the liquidity out twice", async () => {
+        // Lamports sent to the vault afterwards are not claimable as liquidity
+        await provider.sendAndConfirm(
+          new anchor.web3.Transaction().add(
+            SystemProgram.transfer({
+              fromPubkey: authority.publicKey,
+              toPubkey: launch.solVault,
+              lamports: LAMPORTS_PER_SOL,
+            })
+          )
+        );
+
+        try {
+          await recover();
+          assert.fail("A second recovery should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "NothingToRecover");
+        }
+
+        try {
+          await program.methods
+            .withdrawLiquidity()
+            .accounts(liquidityAccounts(authority.publicKey))
+            .rpc();
+          assert.fail("Withdrawing after a recovery should be rejected");
+        } catch (error) {
+          assert.include(error.toString(), "LiquidityAlreadyWithdrawn");
+        }
+      });
+    });
   });
 
   describe("Bonding Curve Price Query & Verification", () => {
@@ -1521,6 +5247,7 @@ describe("notmarket-solana", () => {
           const quote = await program.methods
             .getBuyQuote(oneToken)
             .accounts({
+              config: configPda,
               tokenLaunch: tokenLaunchPda,
               bondingCurve: bondingCurvePda,
             })
@@ -1690,7 +5417,7 @@ describe("notmarket-solana", () => {
 
           try {
             await program.methods
-              .buyTokens(amount, maxSolCost)
+              .buyTokens(amount, maxSolCost, new BN(0))
               .accounts({
                 config: configPda,
                 tokenLaunch: tokenLaunchPda,
@@ -1818,7 +5545,7 @@ describe("notmarket-solana", () => {
 
         try {
           await program.methods
-            .buyTokens(buyAmount, maxSolCost)
+            .buyTokens(buyAmount, maxSolCost, new BN(0))
             .accounts({
               config: configPda,
               tokenLaunch: tokenLaunchPda,
@@ -1855,7 +5582,7 @@ describe("notmarket-solana", () => {
           const minSolOutput = new BN(0);
 
           await program.methods
-            .sellTokens(sellAmount, minSolOutput)
+            .sellTokens(sellAmount, minSolOutput, new BN(0))
             .accounts({
               config: configPda,
               tokenLaunch: tokenLaunchPda,
@@ -1941,7 +5668,7 @@ describe("notmarket-solana", () => {
 
         try {
           await program.methods
-            .buyTokens(buyAmount, maxSolCost)
+            .buyTokens(buyAmount, maxSolCost, new BN(0))
             .accounts({
               config: configPda,
               tokenLaunch: tokenLaunchPda,
@@ -2017,7 +5744,7 @@ describe("notmarket-solana", () => {
 
         try {
           await program.methods
-            .buyTokens(buyAmount, maxSolCost)
+            .buyTokens(buyAmount, maxSolCost, new BN(0))
             .accounts({
               config: configPda,
               tokenLaunch: tokenLaunchPda,
@@ -2086,7 +5813,7 @@ describe("notmarket-solana", () => {
 
           try {
             await program.methods
-              .buyTokens(excessAmount, maxSolCost)
+              .buyTokens(excessAmount, maxSolCost, new BN(0))
               .accounts({
                 config: configPda,
                 tokenLaunch: tokenLaunchPda,
@@ -2128,7 +5855,7 @@ describe("notmarket-solana", () => {
 
         try {
           await program.methods
-            .buyTokens(zeroAmount, maxSolCost)
+            .buyTokens(zeroAmount, maxSolCost, new BN(0))
             .accounts({
               config: configPda,
               tokenLaunch: tokenLaunchPda,